@@ -10,6 +10,7 @@ pub struct Bounds {
 }
 
 impl Bounds {
+    /// Creates a new bounds of the given size with the top-left corner at the origin.
     pub fn new(width: f64, height: f64) -> Self {
         Self {
             left: 0.0,
@@ -50,34 +51,52 @@ impl Bounds {
         Self::from_points(left, top, right, bottom)
     }
 
+    /// Creates an outer bounds from an inner bounds. The inverse of [`Bounds::shrink`]: grows each edge outwards by the size given. Negative sizes are clamped to zero.
+    pub fn expand(self, top: f64, right: f64, bottom: f64, left: f64) -> Self {
+        Self::from_points(
+            self.left - left.max(0.0),
+            self.top - top.max(0.0),
+            self.right + right.max(0.0),
+            self.bottom + bottom.max(0.0),
+        )
+    }
+
+    /// Returns the X coordinate of the left edge.
     pub fn left_x(&self) -> f64 {
         self.left
     }
 
+    /// Returns the X coordinate of the right edge.
     pub fn right_x(&self) -> f64 {
         self.right
     }
 
+    /// Returns the Y coordinate of the top edge.
     pub fn top_y(&self) -> f64 {
         self.top
     }
 
+    /// Returns the Y coordinate of the bottom edge.
     pub fn bottom_y(&self) -> f64 {
         self.bottom
     }
 
+    /// Returns the X coordinate halfway between the left and right edges.
     pub fn centre_x(&self) -> f64 {
         self.left_x() + (self.width() / 2.0)
     }
 
+    /// Returns the Y coordinate halfway between the top and bottom edges.
     pub fn centre_y(&self) -> f64 {
         self.top_y() + (self.height() / 2.0)
     }
 
+    /// Returns the width of the bounds.
     pub fn width(&self) -> f64 {
         self.right - self.left
     }
 
+    /// Returns the height of the bounds.
     pub fn height(&self) -> f64 {
         self.bottom - self.top
     }
@@ -88,12 +107,6 @@ impl Bounds {
     }
 }
 
-impl From<web_sys::DomRectReadOnly> for Bounds {
-    fn from(r: web_sys::DomRectReadOnly) -> Self {
-        Self::from_points(r.left(), r.top(), r.right(), r.bottom())
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +127,22 @@ mod tests {
         assert!(!b.contains(5.5, 6.6),);
     }
 
+    #[test]
+    fn test_expand() {
+        let b = Bounds::from_points(40.0, 10.0, 80.0, 170.0);
+        assert_eq!(
+            b.expand(10.0, 20.0, 30.0, 40.0),
+            Bounds::from_points(0.0, 0.0, 100.0, 200.0)
+        );
+        assert_eq!(
+            b.expand(10.0, 20.0, 30.0, 40.0)
+                .shrink(10.0, 20.0, 30.0, 40.0),
+            b
+        );
+        // Negative sizes do nothing
+        assert_eq!(b.expand(-1.0, -1.0, -1.0, -1.0), b);
+    }
+
     #[test]
     fn test_shrink() {
         let b = Bounds::new(100.0, 200.0);
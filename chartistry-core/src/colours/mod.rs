@@ -2,10 +2,14 @@ mod colourmaps;
 mod scheme;
 
 pub use colourmaps::*;
-pub use scheme::{ColourScheme, DivergingGradient, LinearGradientSvg, SequentialGradient};
+pub use scheme::{ColourScheme, DivergingGradient, SequentialGradient};
 
-use leptos::prelude::*;
-use std::str::FromStr;
+use crate::math;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::str::FromStr;
 
 /// A colour in RGB format.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -33,7 +37,7 @@ impl Colour {
             let pre = pre as f64;
             let post = post as f64;
             let diff = post - pre;
-            (pre + (diff * ratio)).round() as u8
+            math::round(pre + (diff * ratio)) as u8
         };
         Colour {
             red: interpolate(self.red, rhs.red),
@@ -43,8 +47,8 @@ impl Colour {
     }
 }
 
-impl std::fmt::Display for Colour {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Colour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "#{:02X}{:02X}{:02X}", self.red, self.green, self.blue)
     }
 }
@@ -1,9 +1,9 @@
 use super::Colour;
-use leptos::{either::Either, prelude::*};
+use alloc::vec::Vec;
 
 /// A gradient of colours. Maps to a [ColourScheme]
 pub type SequentialGradient = (Colour, &'static [Colour]);
-/// A diverging gradient of colours i.e., a gradient that tends to a central value then a second gradient away. Maps to a [ColourScheme]. Use with [Line::with_gradient](crate::Line::with_gradient).
+/// A diverging gradient of colours i.e., a gradient that tends to a central value then a second gradient away. Maps to a [ColourScheme]. Use with `Line::with_gradient` in leptos-chartistry.
 pub type DivergingGradient = (SequentialGradient, SequentialGradient);
 
 /// A colour scheme with at least one colour.
@@ -20,12 +20,12 @@ impl ColourScheme {
     /// Create a new colour scheme with the given colours. Must have at least one colour.
     pub fn new(first: Colour, rest: impl IntoIterator<Item = Colour>) -> Self {
         Self {
-            swatches: std::iter::once(first).chain(rest).collect(),
+            swatches: core::iter::once(first).chain(rest).collect(),
             zero: None,
         }
     }
 
-    /// Creates a diverging colour scheme value from two sequential gradients. For use with [Line::with_gradient](crate::Line::with_gradient).
+    /// Creates a diverging colour scheme value from two sequential gradients. For use with `Line::with_gradient` in leptos-chartistry.
     ///
     /// A diverging colour scheme is useful for data that has a central value. For example, a temperature scale with a central value of 0°C. Assuming a light background the `before` scheme could then be blue to a black while `after` could be black to red.
     ///
@@ -106,37 +106,54 @@ impl ColourScheme {
     }
 }
 
-#[component]
-pub fn LinearGradientSvg(
-    #[prop(into)] id: String,
-    scheme: Signal<ColourScheme>,
-    range_y: Signal<Option<(f64, f64)>>,
-) -> impl IntoView {
-    view! {
-        <linearGradient id=Some(id) x1="0%" y1="100%" x2="0%" y2="0%">
-            {move || scheme.get().stops(range_y.get().unwrap_or_default())}
-        </linearGradient>
-    }
-}
-
 impl ColourScheme {
-    fn stops(&self, range_y: (f64, f64)) -> impl IntoView {
+    /// Returns the stops of an SVG linear gradient for this scheme as `(offset, colour)` pairs. Offsets run from 0.0 (bottom) to 1.0 (top) of the given Y range. Diverging schemes place their zero value relative to `range_y`.
+    pub fn gradient_stops(&self, range_y: (f64, f64)) -> Vec<(f64, Colour)> {
         // TODO: collect more colour scheme uses and convert schemes into an enum / trait
         if self.zero.is_some() {
-            Either::Left(self.diverging_stops(range_y))
+            self.diverging_stops(range_y)
         } else {
-            Either::Right(self.sequential_stops())
+            self.sequential_stops()
+        }
+    }
+
+    /// Returns the stops of a stepped SVG linear gradient: `bands` discrete colours with hard edges instead of a smooth blend e.g., for threshold encodings like air-quality bands. Each band takes the colour at its centre of the smooth [gradient](Self::gradient_stops).
+    pub fn stepped_gradient_stops(&self, range_y: (f64, f64), bands: usize) -> Vec<(f64, Colour)> {
+        let smooth = self.gradient_stops(range_y);
+        let bands = bands.max(1);
+        (0..bands)
+            .flat_map(|band| {
+                let colour = self.colour_at(&smooth, (band as f64 + 0.5) / bands as f64);
+                let from = band as f64 / bands as f64;
+                let to = (band + 1) as f64 / bands as f64;
+                [(from, colour), (to, colour)]
+            })
+            .collect()
+    }
+
+    // Colour at an offset (0.0 to 1.0) of the given stops. Pads beyond the first and last stops like SVG
+    fn colour_at(&self, stops: &[(f64, Colour)], offset: f64) -> Colour {
+        let after = stops.iter().position(|&(stop, _)| stop >= offset);
+        match after {
+            None => stops
+                .last()
+                .map_or(self.swatches[self.swatches.len() - 1], |s| s.1),
+            Some(0) => stops[0].1,
+            Some(i) => {
+                let ((from, before), (to, after)) = (stops[i - 1], stops[i]);
+                before.interpolate(after, (offset - from) / (to - from))
+            }
         }
     }
 
     // Stops for a sequential gradient. Evenly spreads the swatches over 0% to 100%.
-    fn sequential_stops(&self) -> impl IntoView {
+    fn sequential_stops(&self) -> Vec<(f64, Colour)> {
         let step = 1.0 / self.swatches.len().saturating_sub(1) as f64;
         generate_stops(&self.swatches, 0.0, step)
     }
 
     // Stops for a diverging gradient. Finds the zero value and spreads the swatches over 0% to zero and zero to 100%.
-    fn diverging_stops(&self, (bottom_y, top_y): (f64, f64)) -> impl IntoView {
+    fn diverging_stops(&self, (bottom_y, top_y): (f64, f64)) -> Vec<(f64, Colour)> {
         // Find zero value as a % of the range (0.0 to 1.0)
         let zero = (1.0 - (-bottom_y) / (top_y - bottom_y)).clamp(0.0, 1.0);
         // Separate swatches
@@ -148,10 +165,9 @@ impl ColourScheme {
         // Start at the midpoint of first step so that offset is in the middle of the step
         let below_start = below_step / 2.0;
         let above_start = above_step / 2.0 + below_zero.len() as f64 * below_step;
-        view! {
-            {generate_stops(below_zero, below_start, below_step)}
-            {generate_stops(above_zero, above_start, above_step)}
-        }
+        let mut stops = generate_stops(below_zero, below_start, below_step);
+        stops.extend(generate_stops(above_zero, above_start, above_step));
+        stops
     }
 
     // Separate the swatches into two halves at the zero value. The first half is below zero and the second half is the rest (zero and above). If not a diverging gradient, all swatches will be seen as above zero.
@@ -164,23 +180,16 @@ impl ColourScheme {
     }
 }
 
-// Generates a <stop> for each swatch. Offset is generated using `from + i * step` where i is the index of the swatch. The offset is formatted as a percentage (0% to 100%). `from` and `step` must be 0.0 to 1.0.
-fn generate_stops(swatches: &[Colour], from: f64, step: f64) -> impl IntoView {
+// Generates a stop for each swatch. Offset is generated using `from + i * step` where i is the index of the swatch. `from` and `step` must be 0.0 to 1.0.
+fn generate_stops(swatches: &[Colour], from: f64, step: f64) -> Vec<(f64, Colour)> {
     swatches
         .iter()
         .enumerate()
         // % of the index (0.0 - 1.0)
-        .map(|(i, colour)| (from + i as f64 * step, colour))
+        .map(|(i, &colour)| (from + i as f64 * step, colour))
         // Keep percentages in range
         .filter(|&(percent, _)| percent > 0.0 && percent < 1.0)
-        .map(|(percent, colour)| {
-            // Format as a percentage (0% - 100%)
-            let offset = format!("{:.2}%", percent * 100.0);
-            view! {
-                <stop offset=offset stop-color=colour.to_string() />
-            }
-        })
-        .collect_view()
+        .collect()
 }
 
 impl From<SequentialGradient> for ColourScheme {
@@ -290,6 +299,25 @@ mod tests {
         assert_eq!(scheme10.line_to_prior_swatch_index(1, 0), 0);
     }
 
+    #[test]
+    fn test_stepped_gradient_stops() {
+        let black = Colour::from_rgb(0, 0, 0);
+        let white = Colour::from_rgb(255, 255, 255);
+        let scheme = ColourScheme::from([black, black, white, white, white]);
+        // Smooth stops at 25%, 50% and 75%
+        let stops = scheme.stepped_gradient_stops((0.0, 1.0), 2);
+        assert_eq!(
+            stops,
+            vec![(0.0, black), (0.5, black), (0.5, white), (1.0, white)]
+        );
+        let stops = scheme.stepped_gradient_stops((0.0, 1.0), 4);
+        assert_eq!(stops.len(), 8);
+        assert_eq!(stops[2], (0.25, black.interpolate(white, 0.5)));
+        // One band is a flat colour
+        let stops = scheme.stepped_gradient_stops((0.0, 1.0), 0);
+        assert_eq!(stops, vec![(0.0, white), (1.0, white)]);
+    }
+
     #[test]
     fn test_scheme_interpolation() {
         let scheme3 = scheme3();
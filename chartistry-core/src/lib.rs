@@ -0,0 +1,27 @@
+//! The framework-agnostic core of [Chartistry](https://docs.rs/leptos-chartistry).
+//!
+//! This crate holds the pure logic used to build a chart: bounds and padding for layout, projecting data onto those bounds, tick generation and colour schemes. It has no dependency on Leptos or the browser so it can be used for testing, server-side rendering or alternative frontends.
+//!
+//! Most users should depend on [leptos-chartistry](https://docs.rs/leptos-chartistry) which re-exports the public parts of this crate.
+//!
+//! The crate is `no_std` with `alloc` when the default `std` feature is disabled. Float maths then comes from `libm` and timestamp ticks can't be relative to now (`Timestamps::with_relative_to_now`) as there's no clock.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod bounds;
+pub mod colours;
+mod math;
+pub mod padding;
+pub mod projection;
+pub mod scale;
+pub mod ticks;
+
+pub use bounds::Bounds;
+pub use colours::{Colour, ColourScheme, DivergingGradient, SequentialGradient};
+pub use padding::Padding;
+pub use projection::Projection;
+pub use scale::{AxisScale, CustomScale, LinearScale, LogScale, PowerScale, Scale, SymLogScale};
+pub use ticks::{
+    AlignedFloats, AlignedIntegers, LogFloats, Period, Tick, TickFormat, Timestamps, Variants,
+};
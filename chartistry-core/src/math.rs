@@ -0,0 +1,53 @@
+//! Float maths missing from `core`. Uses `std` when available and `libm` otherwise.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub fn ceil(x: f64) -> f64 {
+        x.ceil()
+    }
+
+    pub fn exp_m1(x: f64) -> f64 {
+        x.exp_m1()
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    pub fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    pub fn ln_1p(x: f64) -> f64 {
+        x.ln_1p()
+    }
+
+    pub fn log10(x: f64) -> f64 {
+        x.log10()
+    }
+
+    pub fn powf(x: f64, n: f64) -> f64 {
+        x.powf(n)
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+
+    pub fn round(x: f64) -> f64 {
+        x.round()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub use libm::{
+        ceil, expm1 as exp_m1, floor, log as ln, log10, log1p as ln_1p, pow as powf, round,
+    };
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        libm::pow(x, n as f64)
+    }
+}
+
+pub use imp::*;
@@ -1,12 +1,13 @@
-use super::bounds::Bounds;
+use crate::bounds::Bounds;
+use alloc::{format, string::String};
 
 /// Represents padding around the edges of a component.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct Padding {
-    pub(crate) top: f64,
-    pub(crate) right: f64,
-    pub(crate) bottom: f64,
-    pub(crate) left: f64,
+    top: f64,
+    right: f64,
+    bottom: f64,
+    left: f64,
 }
 
 /// Represents padding around a component. Note that the context matters on how it's applied. For example, padding applied to the whole chart will shrink the available space whereas padding applied to a label will increase the size used.
@@ -31,23 +32,48 @@ impl Padding {
         Self::sides(h, v, h, v)
     }
 
+    /// Returns the top padding.
+    pub fn top(&self) -> f64 {
+        self.top
+    }
+
+    /// Returns the right padding.
+    pub fn right(&self) -> f64 {
+        self.right
+    }
+
+    /// Returns the bottom padding.
+    pub fn bottom(&self) -> f64 {
+        self.bottom
+    }
+
+    /// Returns the left padding.
+    pub fn left(&self) -> f64 {
+        self.left
+    }
+
     /// Returns the total height of the padding.
-    pub(crate) fn height(&self) -> f64 {
+    pub fn height(&self) -> f64 {
         self.top + self.bottom
     }
 
     /// Returns the total width of the padding.
-    pub(crate) fn width(&self) -> f64 {
+    pub fn width(&self) -> f64 {
         self.left + self.right
     }
 
     /// Applies the padding to the given bounds. Shrinks the bounds by the padding.
-    pub(crate) fn apply(self, outer: Bounds) -> Bounds {
+    pub fn apply(self, outer: Bounds) -> Bounds {
         outer.shrink(self.top, self.right, self.bottom, self.left)
     }
 
+    /// Grows the given bounds by the padding. The inverse of [`Padding::apply`] e.g., from a label's text to its padded box.
+    pub fn expand(self, inner: Bounds) -> Bounds {
+        inner.expand(self.top, self.right, self.bottom, self.left)
+    }
+
     /// Converts the padding to a CSS style string.
-    pub(crate) fn to_css_style(self) -> String {
+    pub fn to_css_style(self) -> String {
         format!(
             "{}px {}px {}px {}px",
             self.top, self.right, self.bottom, self.left
@@ -78,6 +104,10 @@ mod tests {
             p.apply(Bounds::new(100.0, 200.0)),
             Bounds::from_points(4.4, 1.1, 97.8, 196.7)
         );
+        assert_eq!(
+            p.expand(Bounds::from_points(10.0, 10.0, 20.0, 20.0)),
+            Bounds::from_points(10.0 - 4.4, 10.0 - 1.1, 20.0 + 2.2, 20.0 + 3.3)
+        );
         assert_eq!(p.to_css_style(), "1.1px 2.2px 3.3px 4.4px");
         assert_eq!(Padding::zero().to_css_style(), "0px 0px 0px 0px");
         assert_eq!(
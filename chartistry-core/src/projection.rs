@@ -0,0 +1,277 @@
+use crate::{bounds::Bounds, scale::AxisScale};
+
+/// A projection converts between data and SVG coordinates. SVG has zero in the top left corner. Data coordinates have zero in the bottom left. Each axis may transform data by a [Scale](crate::Scale) first e.g., a log scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Projection {
+    // SVG bounds
+    bounds: Bounds,
+    scale_x: AxisScale,
+    scale_y: AxisScale,
+    // Data offset in scaled coords
+    left_x: f64,
+    bottom_y: f64,
+    top_y: f64,
+
+    x_mult: f64,
+    y_mult: f64,
+    // Optional Y break: (low, high) in scaled coords and the gap drawn in its place in SVG coords
+    y_break: Option<(f64, f64, f64)>,
+}
+
+impl Projection {
+    pub fn new(bounds: Bounds, range_x: Option<(f64, f64)>, range_y: Option<(f64, f64)>) -> Self {
+        Self::with_scales(
+            bounds,
+            range_x,
+            range_y,
+            AxisScale::default(),
+            AxisScale::default(),
+        )
+    }
+
+    /// Creates a projection that transforms data by a scale on each axis before mapping it onto the bounds. Ranges are in data coords. A range end without a value on the scale (e.g., zero on a log scale) is clamped to the other end.
+    pub fn with_scales(
+        bounds: Bounds,
+        range_x: Option<(f64, f64)>,
+        range_y: Option<(f64, f64)>,
+        scale_x: AxisScale,
+        scale_y: AxisScale,
+    ) -> Self {
+        let scale_range = |scale: &AxisScale, range: Option<(f64, f64)>| {
+            let (min, max) = range
+                .map(|(min, max)| (scale.apply(min), scale.apply(max)))
+                .unwrap_or_default();
+            match (min.is_finite(), max.is_finite()) {
+                (true, true) => (min, max),
+                (true, false) => (min, min),
+                (false, true) => (max, max),
+                (false, false) => (0.0, 0.0),
+            }
+        };
+        let (left_x, right_x) = scale_range(&scale_x, range_x);
+        let (bottom_y, top_y) = scale_range(&scale_y, range_y);
+        // If the range is zero, skip projection
+        let width = right_x - left_x;
+        let x_mult = bounds.width() / if width == 0.0 { 0.5 } else { width };
+        let height = top_y - bottom_y;
+        let y_mult = bounds.height() / if height == 0.0 { 0.5 } else { height };
+        Projection {
+            bounds,
+            scale_x,
+            scale_y,
+            left_x,
+            bottom_y,
+            top_y,
+            x_mult,
+            y_mult,
+            y_break: None,
+        }
+    }
+
+    /// Cuts a (low, high) band out of the Y axis e.g., to show two widely separated clusters. The band is squeezed into a `gap` of SVG height. Ignored unless the band is inside the Y range and the gap fits.
+    pub fn with_y_break(mut self, y_break: Option<(f64, f64)>, gap: f64) -> Self {
+        let height = self.top_y - self.bottom_y;
+        let scale_y = &self.scale_y;
+        self.y_break = y_break
+            .map(|(low, high)| (scale_y.apply(low), scale_y.apply(high)))
+            .filter(|&(low, high)| self.bottom_y < low && low < high && high < self.top_y)
+            .filter(|_| gap >= 0.0 && gap < self.bounds.height())
+            .map(|(low, high)| (low, high, gap));
+        if let Some((low, high, gap)) = self.y_break {
+            self.y_mult = (self.bounds.height() - gap) / (height - (high - low));
+        }
+        self
+    }
+
+    /// Flips the Y axis so that values increase downwards e.g., depth. Drops any Y break.
+    pub fn with_reversed_y(mut self, reversed: bool) -> Self {
+        if reversed {
+            core::mem::swap(&mut self.bottom_y, &mut self.top_y);
+            self.y_break = None;
+            let height = self.top_y - self.bottom_y;
+            self.y_mult = self.bounds.height() / if height == 0.0 { 0.5 } else { height };
+        }
+        self
+    }
+
+    /// Whether Y values increase downwards. See [Projection::with_reversed_y].
+    pub fn is_reversed_y(&self) -> bool {
+        self.top_y < self.bottom_y
+    }
+
+    /// Returns the (low, high) Y break in data coords if set. See [Projection::with_y_break].
+    pub fn y_break(&self) -> Option<(f64, f64)> {
+        (self.y_break).map(|(low, high, _)| (self.scale_y.invert(low), self.scale_y.invert(high)))
+    }
+
+    // Distance of a scaled Y above the bottom of the bounds in SVG coords
+    fn y_offset(&self, y: f64) -> f64 {
+        let y = y - self.bottom_y;
+        let Some((low, high, gap)) = self.y_break else {
+            return y * self.y_mult;
+        };
+        let (low, high) = (low - self.bottom_y, high - self.bottom_y);
+        if y <= low {
+            y * self.y_mult
+        } else if y >= high {
+            (y - (high - low)) * self.y_mult + gap
+        } else {
+            low * self.y_mult + (y - low) / (high - low) * gap
+        }
+    }
+
+    // Inverse of [Projection::y_offset]
+    fn offset_to_y(&self, offset: f64) -> f64 {
+        let Some((low, high, gap)) = self.y_break else {
+            return self.bottom_y + offset / self.y_mult;
+        };
+        let low_offset = (low - self.bottom_y) * self.y_mult;
+        if offset <= low_offset {
+            self.bottom_y + offset / self.y_mult
+        } else if offset >= low_offset + gap {
+            high + (offset - low_offset - gap) / self.y_mult
+        } else {
+            low + (offset - low_offset) / gap * (high - low)
+        }
+    }
+
+    /// Converts a data point to SVG view coordinates. View coordinates are in SVG space with zero at top left. Data coordinates are in chart space with zero at bottom left.
+    pub fn position_to_svg(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = self.bounds.left_x() + (self.scale_x.apply(x) - self.left_x) * self.x_mult;
+        let y = self.bounds.bottom_y() - self.y_offset(self.scale_y.apply(y));
+        (x, y)
+    }
+
+    /// Converts an SVG point to data coordinates. View coordinates are in SVG space with zero at top left. Data coordinates are in chart space with zero at bottom left.
+    pub fn svg_to_position(&self, x: f64, y: f64) -> (f64, f64) {
+        let (x, y) = self.svg_to_scaled(x, y);
+        (self.scale_x.invert(x), self.scale_y.invert(y))
+    }
+
+    /// Converts an SVG point to scaled data coordinates i.e., before inverting each axis' [Scale](crate::Scale). Distances between scaled coordinates are uniform on screen e.g., powers of 10 on a log scale.
+    pub fn svg_to_scaled(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = self.left_x + (x - self.bounds.left_x()) / self.x_mult;
+        let y = self.offset_to_y(self.bounds.bottom_y() - y);
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scale::{LogScale, PowerScale};
+
+    fn assert_coords(p: &Projection, pos: (f64, f64), svg: (f64, f64)) {
+        assert_eq!(p.position_to_svg(pos.0, pos.1), (svg.0, svg.1), "to svg");
+        assert_eq!(p.svg_to_position(svg.0, svg.1), (pos.0, pos.1), "to pos");
+    }
+
+    #[test]
+    fn test_projection() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::new(bounds, Some((0.0, 100.0)), Some((0.0, 100.0)));
+
+        // Data range -> view bounds
+        assert_coords(&p, (0.0, 0.0), (10.0, 90.0)); // Bottom left
+        assert_coords(&p, (100.0, 0.0), (90.0, 90.0)); // Bottom right
+        assert_coords(&p, (0.0, 100.0), (10.0, 10.0)); // Top left
+        assert_coords(&p, (100.0, 100.0), (90.0, 10.0)); // Top right
+        assert_coords(&p, (50.0, 50.0), (50.0, 50.0)); // Centre
+    }
+
+    #[test]
+    fn test_incl_zero() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::new(bounds, Some((0.0, 200.0)), Some((0.0, 200.0)));
+        // Data range (0, 0) to (200, 200) -> view bounds
+        assert_coords(&p, (0.0, 0.0), (10.0, 90.0)); // Bottom left
+        assert_coords(&p, (200.0, 0.0), (90.0, 90.0)); // Bottom right
+        assert_coords(&p, (0.0, 200.0), (10.0, 10.0)); // Top left
+        assert_coords(&p, (200.0, 200.0), (90.0, 10.0)); // Top right
+        assert_coords(&p, (100.0, 100.0), (50.0, 50.0)); // Centre
+    }
+
+    #[test]
+    fn test_y_break() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::new(bounds, Some((0.0, 100.0)), Some((0.0, 110.0)))
+            .with_y_break(Some((10.0, 100.0)), 20.0);
+        assert_eq!(p.y_break(), Some((10.0, 100.0)));
+        assert_coords(&p, (0.0, 0.0), (10.0, 90.0)); // Bottom
+        assert_coords(&p, (0.0, 5.0), (10.0, 75.0)); // Below break
+        assert_coords(&p, (0.0, 10.0), (10.0, 60.0)); // Bottom of break
+        assert_coords(&p, (0.0, 55.0), (10.0, 50.0)); // Inside break
+        assert_coords(&p, (0.0, 100.0), (10.0, 40.0)); // Top of break
+        assert_coords(&p, (0.0, 110.0), (10.0, 10.0)); // Top
+                                                       // Outside the range
+        let p = Projection::new(bounds, Some((0.0, 100.0)), Some((0.0, 110.0)));
+        assert_eq!(
+            p.clone().with_y_break(Some((50.0, 200.0)), 20.0).y_break(),
+            None
+        );
+        assert_eq!(
+            p.clone().with_y_break(Some((50.0, 20.0)), 20.0).y_break(),
+            None
+        );
+        assert_eq!(p.with_y_break(Some((10.0, 100.0)), 100.0).y_break(), None);
+    }
+
+    #[test]
+    fn test_reversed_y() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::new(bounds, Some((0.0, 100.0)), Some((0.0, 100.0)))
+            .with_y_break(Some((10.0, 20.0)), 20.0)
+            .with_reversed_y(true);
+        assert!(p.is_reversed_y());
+        assert_eq!(p.y_break(), None);
+        assert_coords(&p, (0.0, 0.0), (10.0, 10.0)); // Top left
+        assert_coords(&p, (0.0, 100.0), (10.0, 90.0)); // Bottom left
+        assert_coords(&p, (0.0, 25.0), (10.0, 30.0));
+    }
+
+    #[test]
+    fn test_scales() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::with_scales(
+            bounds,
+            Some((0.0, 100.0)),
+            Some((1.0, 10_000.0)),
+            PowerScale::sqrt().into(),
+            LogScale::default().into(),
+        );
+        let (x, y) = p.position_to_svg(25.0, 100.0);
+        assert!((x - 50.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+        let (x, y) = p.svg_to_position(50.0, 50.0);
+        assert!((x - 25.0).abs() < 1e-9 && (y - 100.0).abs() < 1e-9);
+        let (x, y) = p.svg_to_scaled(50.0, 50.0);
+        assert!((x - 5.0).abs() < 1e-9 && (y - 2.0).abs() < 1e-9);
+        // Y range end without a value on the scale: clamped to the other end
+        let p = Projection::with_scales(
+            bounds,
+            Some((0.0, 100.0)),
+            Some((0.0, 100.0)),
+            AxisScale::default(),
+            LogScale::default().into(),
+        );
+        assert_eq!(p.position_to_svg(0.0, 100.0), (10.0, 90.0));
+        let (_, y) = p.position_to_svg(0.0, 10.0);
+        assert!(y > 90.0);
+    }
+
+    #[test]
+    fn test_projection_zero_range() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        Projection::new(bounds, None, None);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::new(
+            bounds,
+            Some((bounds.left_x(), bounds.right_x())),
+            Some((bounds.bottom_y(), bounds.top_y())),
+        );
+        assert_eq!(p, p.clone());
+    }
+}
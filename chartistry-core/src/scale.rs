@@ -0,0 +1,293 @@
+use crate::math;
+use alloc::sync::Arc;
+use core::fmt;
+
+/// A monotonic transform of data positions applied by a [Projection](crate::Projection) before mapping them onto the chart e.g., a log scale. Implement it to use your own transform without changing the projection.
+///
+/// ```rust
+/// # use chartistry_core::scale::Scale;
+/// /// Squares positions e.g., to emphasise large values
+/// #[derive(Debug)]
+/// struct Squared;
+///
+/// impl Scale for Squared {
+///     fn apply(&self, position: f64) -> f64 {
+///         position.signum() * position * position
+///     }
+///
+///     fn invert(&self, scaled: f64) -> f64 {
+///         scaled.signum() * scaled.abs().sqrt()
+///     }
+/// }
+/// ```
+pub trait Scale: fmt::Debug + Send + Sync {
+    /// Transforms a data position. Must be monotonic. Positions without a value on the scale (e.g., zero on a log scale) return NaN.
+    fn apply(&self, position: f64) -> f64;
+
+    /// Inverse of [Scale::apply]. Transforms a scaled value back to a data position.
+    fn invert(&self, scaled: f64) -> f64;
+
+    /// Kind and parameters of the scale e.g., `("log", &[10.0])`. Scales with the same identity compare equal in an [AxisScale]. Default is `None` which only compares a handle to its clones.
+    fn identity(&self) -> Option<(&'static str, &[f64])> {
+        None
+    }
+}
+
+/// Leaves positions unchanged. The default scale.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LinearScale;
+
+/// Logarithmic scale. Positions must be above zero, others are NaN (drawn as gaps).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LogScale {
+    /// Base of the logarithm. Default is 10.
+    pub base: f64,
+}
+
+/// Symmetric log scale: linear near zero and logarithmic further out. Unlike [LogScale] it handles zero and negative positions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SymLogScale {
+    /// Size of the linear region around zero. Default is 1.
+    pub constant: f64,
+}
+
+/// Raises positions to a power keeping their sign e.g., an exponent of 0.5 for a square root scale which suits areas.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PowerScale {
+    /// Exponent to raise positions to. Must be above zero.
+    pub exponent: f64,
+}
+
+/// A scale from a pair of closures. See [Scale] for implementing your own type instead.
+#[derive(Clone)]
+pub struct CustomScale {
+    apply: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+    invert: Arc<dyn Fn(f64) -> f64 + Send + Sync>,
+}
+
+/// Shared handle to a [Scale] used by a [Projection](crate::Projection). Compared by [Scale::identity] when available so `LogScale::default()` equals another `LogScale::default()`. Otherwise only cloned handles are equal.
+#[derive(Clone, Debug)]
+pub struct AxisScale(Arc<dyn Scale>);
+
+impl Scale for LinearScale {
+    fn apply(&self, position: f64) -> f64 {
+        position
+    }
+
+    fn invert(&self, scaled: f64) -> f64 {
+        scaled
+    }
+
+    fn identity(&self) -> Option<(&'static str, &[f64])> {
+        Some(("linear", &[]))
+    }
+}
+
+impl LogScale {
+    /// Creates a log scale with the given base.
+    pub fn new(base: f64) -> Self {
+        Self { base }
+    }
+}
+
+impl Default for LogScale {
+    fn default() -> Self {
+        Self::new(10.0)
+    }
+}
+
+impl Scale for LogScale {
+    fn apply(&self, position: f64) -> f64 {
+        if position > 0.0 {
+            math::ln(position) / math::ln(self.base)
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn invert(&self, scaled: f64) -> f64 {
+        math::powf(self.base, scaled)
+    }
+
+    fn identity(&self) -> Option<(&'static str, &[f64])> {
+        Some(("log", core::slice::from_ref(&self.base)))
+    }
+}
+
+impl SymLogScale {
+    /// Creates a symmetric log scale that's linear within `constant` of zero.
+    pub fn new(constant: f64) -> Self {
+        Self { constant }
+    }
+}
+
+impl Default for SymLogScale {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Scale for SymLogScale {
+    fn apply(&self, position: f64) -> f64 {
+        position.signum() * math::ln_1p(position.abs() / self.constant)
+    }
+
+    fn invert(&self, scaled: f64) -> f64 {
+        scaled.signum() * math::exp_m1(scaled.abs()) * self.constant
+    }
+
+    fn identity(&self) -> Option<(&'static str, &[f64])> {
+        Some(("symlog", core::slice::from_ref(&self.constant)))
+    }
+}
+
+impl PowerScale {
+    /// Creates a power scale with the given exponent.
+    pub fn new(exponent: f64) -> Self {
+        Self { exponent }
+    }
+
+    /// Creates a square root scale.
+    pub fn sqrt() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Scale for PowerScale {
+    fn apply(&self, position: f64) -> f64 {
+        position.signum() * math::powf(position.abs(), self.exponent)
+    }
+
+    fn invert(&self, scaled: f64) -> f64 {
+        scaled.signum() * math::powf(scaled.abs(), self.exponent.recip())
+    }
+
+    fn identity(&self) -> Option<(&'static str, &[f64])> {
+        Some(("power", core::slice::from_ref(&self.exponent)))
+    }
+}
+
+impl CustomScale {
+    /// Creates a scale from a monotonic transform and its inverse.
+    pub fn new(
+        apply: impl Fn(f64) -> f64 + Send + Sync + 'static,
+        invert: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            apply: Arc::new(apply),
+            invert: Arc::new(invert),
+        }
+    }
+}
+
+impl fmt::Debug for CustomScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomScale").finish_non_exhaustive()
+    }
+}
+
+impl Scale for CustomScale {
+    fn apply(&self, position: f64) -> f64 {
+        (self.apply)(position)
+    }
+
+    fn invert(&self, scaled: f64) -> f64 {
+        (self.invert)(scaled)
+    }
+}
+
+impl AxisScale {
+    /// Wraps a scale for use by a projection.
+    pub fn new(scale: impl Scale + 'static) -> Self {
+        Self(Arc::new(scale))
+    }
+
+    /// See [Scale::apply].
+    pub fn apply(&self, position: f64) -> f64 {
+        self.0.apply(position)
+    }
+
+    /// See [Scale::invert].
+    pub fn invert(&self, scaled: f64) -> f64 {
+        self.0.invert(scaled)
+    }
+}
+
+impl Default for AxisScale {
+    fn default() -> Self {
+        // Shared to avoid allocating a scale per default
+        #[cfg(feature = "std")]
+        {
+            static LINEAR: std::sync::OnceLock<AxisScale> = std::sync::OnceLock::new();
+            LINEAR.get_or_init(|| Self::new(LinearScale)).clone()
+        }
+        #[cfg(not(feature = "std"))]
+        Self::new(LinearScale)
+    }
+}
+
+impl PartialEq for AxisScale {
+    fn eq(&self, other: &Self) -> bool {
+        if Arc::ptr_eq(&self.0, &other.0) {
+            return true;
+        }
+        match (self.0.identity(), other.0.identity()) {
+            (Some(ours), Some(theirs)) => ours == theirs,
+            _ => false,
+        }
+    }
+}
+
+impl<S: Scale + 'static> From<S> for AxisScale {
+    fn from(scale: S) -> Self {
+        Self::new(scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip(scale: &dyn Scale, positions: &[f64]) {
+        for &position in positions {
+            let back = scale.invert(scale.apply(position));
+            assert!(
+                (back - position).abs() < 1e-9,
+                "{scale:?}: {position} -> {back}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scales() {
+        assert_eq!(LinearScale.apply(4.0), 4.0);
+        assert!((LogScale::default().apply(100.0) - 2.0).abs() < 1e-9);
+        assert!(LogScale::default().apply(0.0).is_nan());
+        assert_eq!(SymLogScale::default().apply(0.0), 0.0);
+        assert_eq!(PowerScale::sqrt().apply(-9.0), -3.0);
+        let custom = CustomScale::new(|x| x * 2.0, |x| x / 2.0);
+        assert_eq!(custom.apply(3.0), 6.0);
+        // Inverts
+        let all = [-100.0, -1.5, 0.0, 0.5, 1.0, 42.0];
+        assert_round_trip(&LinearScale, &all);
+        assert_round_trip(&LogScale::new(2.0), &[0.5, 1.0, 42.0]);
+        assert_round_trip(&SymLogScale::new(2.0), &all);
+        assert_round_trip(&PowerScale::new(3.0), &all);
+        assert_round_trip(&custom, &all);
+    }
+
+    #[test]
+    fn test_axis_scale_eq() {
+        let scale = AxisScale::new(LogScale::default());
+        assert_eq!(scale, scale.clone());
+        // Compared by kind and parameters
+        assert_eq!(scale, AxisScale::new(LogScale::default()));
+        assert_ne!(scale, AxisScale::new(LogScale::new(2.0)));
+        assert_ne!(scale, AxisScale::new(SymLogScale::new(10.0)));
+        assert_eq!(AxisScale::default(), AxisScale::new(LinearScale));
+        // Custom scales only equal their clones
+        let custom = AxisScale::new(CustomScale::new(|x| x, |x| x));
+        assert_eq!(custom, custom.clone());
+        assert_ne!(custom, AxisScale::new(CustomScale::new(|x| x, |x| x)));
+    }
+}
@@ -1,15 +1,48 @@
 use super::{Format, GeneratedTicks, Generator, Span};
+use crate::math;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// Generates f64 ticks. Aligned to nice values (powers of 10).
+///
+/// Optionally appends a unit with a metric prefix picked for the range e.g., "1.2 GB/s". See [AlignedFloats::with_unit].
 #[derive(Clone, Debug, Default, PartialEq)]
 #[non_exhaustive]
-pub struct AlignedFloats {}
+pub struct AlignedFloats {
+    unit: Option<String>,
+}
 
 #[derive(Clone, Debug, PartialEq)]
 struct State {
     scale: isize,
+    unit: Option<Unit>,
+}
+
+// A unit with a metric prefix given as a power of 10 (a multiple of 3)
+#[derive(Clone, Debug, PartialEq)]
+struct Unit {
+    exponent: isize,
+    symbol: String,
 }
 
+const PREFIXES: &[(isize, &str)] = &[
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "µ"),
+    (-3, "m"),
+    (0, ""),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+    (15, "P"),
+    (18, "E"),
+];
+
 impl Generator for AlignedFloats {
     type Tick = f64;
 
@@ -19,31 +52,48 @@ impl Generator for AlignedFloats {
         &last: &Self::Tick,
         span: &dyn Span<Self::Tick>,
     ) -> GeneratedTicks<Self::Tick> {
-        let (scale, count) = Self::find_precision(first, last, span);
+        let unit = self.unit(first, last);
+        let (scale, count) = self.find_precision(first, last, span);
         let (scale, ticks) = Self::generate_count(first, last, scale, count);
-        let state = State::new(scale);
+        let state = State::new(scale, unit);
         GeneratedTicks::new(state, ticks)
     }
 }
 
 impl AlignedFloats {
+    /// Appends a unit (e.g., "B/s") to each tick. A metric prefix (from pico to exa) is picked for the largest value in the range so ticks read as "1.2 GB/s" rather than "1200000000 B/s".
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Picks the unit prefix for the given range
+    fn unit(&self, first: f64, last: f64) -> Option<Unit> {
+        let symbol = self.unit.as_ref()?;
+        let exponent = prefix_exponent(first.abs().max(last.abs()));
+        Some(Unit {
+            exponent,
+            symbol: symbol.clone(),
+        })
+    }
+
     /// Returns the scale and count to use for the given range and span
-    fn find_precision(first: f64, last: f64, span: &dyn Span<f64>) -> (isize, usize) {
+    fn find_precision(&self, first: f64, last: f64, span: &dyn Span<f64>) -> (isize, usize) {
         // Determine scale e.g., are we in the 100s, 10s, 0.1s, etc. Then display one more (-1)
         let mut scale = scale10(last - first) - 1;
         // Naively calculate our count i.e., how many ticks can we fit in the span. This is the lower bound for count
-        let lower_count = Self::mock_value_count(first, last, scale, span);
+        let lower_count = self.mock_value_count(first, last, scale, span);
         // Lower the scale (increase precision) so that we can always distinguish between ticks e.g., a range of 0-10 with a count of 30 would result in runs of 0.1. Subtract 2 to account for first and last before the jump to a higher precision
         scale -= scale10(lower_count as f64 - 2.0);
         // Calculate the upper count bound. The max number of ticks we can fit in the span with the higher precision
-        let upper_count = Self::mock_value_count(first, last, scale, span);
+        let upper_count = self.mock_value_count(first, last, scale, span);
 
         (scale, upper_count)
     }
 
     /// Finds the longest string that could be displayed between first and last inclusive
-    fn mock_value_count(first: f64, last: f64, scale: isize, span: &dyn Span<f64>) -> usize {
-        let state = State::new(scale);
+    fn mock_value_count(&self, first: f64, last: f64, scale: isize, span: &dyn Span<f64>) -> usize {
+        let state = State::new(scale, self.unit(first, last));
         let first_consumed = span.consumed(&state, &[first]);
         let last_consumed = span.consumed(&state, &[last]);
         let consumed = first_consumed.max(last_consumed);
@@ -74,8 +124,8 @@ impl AlignedFloats {
 }
 
 impl State {
-    pub fn new(scale: isize) -> State {
-        Self { scale }
+    fn new(scale: isize, unit: Option<Unit>) -> State {
+        Self { scale, unit }
     }
 }
 
@@ -87,26 +137,48 @@ impl Format for State {
             return "-".to_string();
         }
 
-        let scale = self.scale;
-        let precision = if scale < 0 { -scale as usize } else { 0 };
-        let mut value = format!("{value:.precision$}");
-        // The format! macro doesn't handle negative precision. For us, this means zero pad to the left of the decimal point
-        if scale > 0 {
-            // Clamp scale to leave leftmost digit if it's too large
-            let neg_offset = if value.starts_with('-') { 1 } else { 0 };
-            let scale = (scale as usize).min(value.len() - 1 - neg_offset);
-            // Truncate from offset to the end with zeros
-            if let Some(offset) = value.len().checked_sub(scale) {
-                value.replace_range(offset.., &"0".repeat(scale));
+        match &self.unit {
+            None => format_scaled(*value, self.scale),
+            Some(unit) => {
+                // Shift value and scale by the prefix
+                let value = value / math::powi(10.0, unit.exponent as i32);
+                let value = format_scaled(value, self.scale - unit.exponent);
+                let prefix = PREFIXES
+                    .iter()
+                    .find(|(exponent, _)| *exponent == unit.exponent)
+                    .map_or("", |(_, prefix)| prefix);
+                format!("{value} {prefix}{}", unit.symbol)
             }
         }
-        value
     }
 }
 
+/// Formats a value to the precision of the given scale.
+pub(super) fn format_scaled(value: f64, scale: isize) -> String {
+    let precision = if scale < 0 { -scale as usize } else { 0 };
+    let mut value = format!("{value:.precision$}");
+    // The format! macro doesn't handle negative precision. For us, this means zero pad to the left of the decimal point
+    if scale > 0 {
+        // Clamp scale to leave leftmost digit if it's too large
+        let neg_offset = if value.starts_with('-') { 1 } else { 0 };
+        let scale = (scale as usize).min(value.len() - 1 - neg_offset);
+        // Truncate from offset to the end with zeros
+        if let Some(offset) = value.len().checked_sub(scale) {
+            value.replace_range(offset.., &"0".repeat(scale));
+        }
+    }
+    value
+}
+
+/// Picks the metric prefix (as a power of 10) for a value e.g., 1234.0 gives 3 (kilo). Clamped to the available prefixes.
+fn prefix_exponent(value: f64) -> isize {
+    let (min, max) = (PREFIXES[0].0, PREFIXES[PREFIXES.len() - 1].0);
+    (scale10(value).div_euclid(3) * 3).clamp(min, max)
+}
+
 /// Determines the scale e.g. are we in the 10s, 100s, 0.1s, etc.
-fn scale10(range: f64) -> isize {
-    let scale = range.abs().log10().floor();
+pub(super) fn scale10(range: f64) -> isize {
+    let scale = math::floor(math::log10(range.abs()));
     if scale.is_infinite() {
         0
     } else {
@@ -131,7 +203,7 @@ mod tests {
 
     fn assert_precision(first: f64, last: f64, width: f64, scale: isize, count: usize) {
         let span = mk_span(width + 1.0);
-        let precision = AlignedFloats::find_precision(first, last, span.as_ref());
+        let precision = AlignedFloats::default().find_precision(first, last, span.as_ref());
         assert_eq!(precision, (scale, count));
     }
 
@@ -143,7 +215,7 @@ mod tests {
         expected: Vec<&'static str>,
     ) {
         let (scale, ticks) = AlignedFloats::generate_count(first, last, scale, count);
-        let state = State::new(scale);
+        let state = State::new(scale, None);
         let ticks = (ticks.into_iter())
             .map(|tick| state.format(&tick))
             .collect::<Vec<_>>();
@@ -248,7 +320,7 @@ mod tests {
 
     #[test]
     fn test_format() {
-        let format = |scale: isize, value: f64| State::new(scale).format(&value);
+        let format = |scale: isize, value: f64| State::new(scale, None).format(&value);
 
         // Significant digits
         assert_eq!(format(0, 1.0), "1");
@@ -307,4 +379,44 @@ mod tests {
         assert_eq!(scale10(f64::MIN), 308);
         assert_eq!(scale10(f64::MIN_POSITIVE), -308);
     }
+
+    #[test]
+    fn test_prefix_exponent() {
+        assert_eq!(prefix_exponent(0.0), 0);
+        assert_eq!(prefix_exponent(999.0), 0);
+        assert_eq!(prefix_exponent(1_000.0), 3);
+        assert_eq!(prefix_exponent(1.2e9), 9);
+        assert_eq!(prefix_exponent(0.05), -3);
+        assert_eq!(prefix_exponent(3e-7), -9);
+        // Clamped
+        assert_eq!(prefix_exponent(1e30), 18);
+        assert_eq!(prefix_exponent(1e-30), -12);
+        assert_eq!(prefix_exponent(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_format_unit() {
+        let unit = |exponent: isize| {
+            Some(Unit {
+                exponent,
+                symbol: "B/s".to_string(),
+            })
+        };
+        let format = |scale: isize, exponent: isize, value: f64| {
+            State::new(scale, unit(exponent)).format(&value)
+        };
+        assert_eq!(format(8, 9, 1_234_567_890.0), "1.2 GB/s");
+        assert_eq!(format(8, 9, 0.0), "0.0 GB/s");
+        assert_eq!(format(1, 0, 123.0), "120 B/s");
+        assert_eq!(format(-4, -3, 0.0125), "12.5 mB/s");
+        assert_eq!(format(3, 3, 12_345.0), "12 kB/s");
+        assert_eq!(format(0, 9, f64::NAN), "-");
+        // Prefix picked from the range
+        let gen = AlignedFloats::default().with_unit("B/s");
+        let ticks = gen.generate(&0.0, &2e9, mk_span(30.0).as_ref());
+        let labels = (ticks.ticks.iter())
+            .map(|tick| ticks.state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["0.0 GB/s", "1.0 GB/s", "2.0 GB/s"]);
+    }
 }
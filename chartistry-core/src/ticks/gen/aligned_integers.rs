@@ -0,0 +1,139 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use crate::{math, Tick};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{fmt::Display, marker::PhantomData};
+
+/// Generates integer ticks e.g., counts. Aligned to nice steps (1, 2 or 5 times a power of 10) of at least one so labels are never fractional.
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub struct AlignedIntegers<T> {
+    tick: PhantomData<T>,
+}
+
+impl<T> Clone for AlignedIntegers<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AlignedIntegers<T> {}
+
+impl<T> Default for AlignedIntegers<T> {
+    fn default() -> Self {
+        Self { tick: PhantomData }
+    }
+}
+
+impl<T: Tick + Display> Generator for AlignedIntegers<T> {
+    type Tick = T;
+
+    fn generate(
+        &self,
+        first: &Self::Tick,
+        last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        // How many of the widest label fit in the span
+        let consumed = span
+            .consumed(self, core::slice::from_ref(first))
+            .max(span.consumed(self, core::slice::from_ref(last)));
+        let count = (span.length() / consumed) as usize;
+        let ticks = generate_positions(first.position(), last.position(), count)
+            .into_iter()
+            .filter_map(|position| first.with_position(position))
+            .collect();
+        GeneratedTicks::new(*self, ticks)
+    }
+}
+
+impl<T: Tick + Display> Format for AlignedIntegers<T> {
+    type Tick = T;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        value.to_string()
+    }
+}
+
+/// Generates up to `count` integer positions between `first` and `last` inclusive on a nice step. If count is 1 or 0 (or no step fits), returns the (rounded) midpoint of the range.
+fn generate_positions(first: f64, last: f64, count: usize) -> Vec<f64> {
+    if !(first.is_finite() && last.is_finite()) {
+        return vec![];
+    }
+    let midpoint = vec![math::round((first + last) / 2.0)];
+    if count <= 1 || first == last {
+        return midpoint;
+    }
+    let step = nice_step((last - first) / (count - 1) as f64);
+    let start = math::ceil(first / step) as i64;
+    let end = math::floor(last / step) as i64;
+    if start > end {
+        return midpoint;
+    }
+    (start..=end).map(|i| i as f64 * step).collect()
+}
+
+/// Smallest step of 1, 2 or 5 times a power of 10 that's at least `min_step`. Never less than one.
+fn nice_step(min_step: f64) -> f64 {
+    if min_step <= 1.0 || !min_step.is_finite() {
+        return 1.0;
+    }
+    let power = math::powf(10.0, math::floor(math::log10(min_step)));
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|mult| mult * power)
+        .find(|&step| step >= min_step)
+        .unwrap_or(10.0 * power)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn generate(first: i64, last: i64, width: f64) -> Vec<String> {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let gen = AlignedIntegers::default();
+        let ticks = gen.generate(&first, &last, &span);
+        (ticks.ticks.iter())
+            .map(|tick| ticks.state.format(tick))
+            .collect()
+    }
+
+    #[test]
+    fn test_nice_step() {
+        assert_eq!(nice_step(0.25), 1.0);
+        assert_eq!(nice_step(1.0), 1.0);
+        assert_eq!(nice_step(1.5), 2.0);
+        assert_eq!(nice_step(3.0), 5.0);
+        assert_eq!(nice_step(7.0), 10.0);
+        assert_eq!(nice_step(18.0), 20.0);
+        assert_eq!(nice_step(f64::NAN), 1.0);
+    }
+
+    #[test]
+    fn test_generate_positions() {
+        // Small ranges never step by fractions
+        assert_eq!(generate_positions(0.0, 3.0, 10), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(
+            generate_positions(0.0, 100.0, 6),
+            vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+        );
+        assert_eq!(generate_positions(-10.0, 10.0, 3), vec![-10.0, 0.0, 10.0]);
+        assert_eq!(generate_positions(-7.0, 7.0, 3), vec![0.0]);
+        assert_eq!(generate_positions(0.0, 5.0, 1), vec![3.0]);
+        assert_eq!(generate_positions(4.0, 4.0, 5), vec![4.0]);
+        assert_eq!(generate_positions(1.0, 9.0, 2), vec![5.0]);
+        assert!(generate_positions(f64::NAN, 4.0, 5).is_empty());
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(0, 2, 100.0), vec!["0", "1", "2"]);
+        assert_eq!(generate(0, 10, 6.0), vec!["0", "5", "10"]);
+        assert_eq!(generate(0, 10, 12.0), vec!["0", "2", "4", "6", "8", "10"]);
+    }
+}
@@ -0,0 +1,151 @@
+use super::{
+    aligned_floats::{format_scaled, scale10},
+    AlignedFloats, Format, GeneratedTicks, Generator, Span,
+};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Generates f64 ticks for a log scale e.g., [LogScale](crate::LogScale). Ticks are placed on powers of 10 (1, 10, 100, ...). Short ranges add multiples of 2 and 5 while wide ranges skip powers so labels don't overlap.
+///
+/// Ranges that aren't above zero (no value on a log scale) or within a single power of 10 fall back to [AlignedFloats].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct LogFloats {}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State;
+
+// Multiples of each power of 10 from the densest to sparsest
+const MULTIPLES: &[&[u8]] = &[&[1, 2, 5], &[1]];
+
+impl Generator for LogFloats {
+    type Tick = f64;
+
+    fn generate(
+        &self,
+        &first: &Self::Tick,
+        &last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let (min, max) = (first.min(last), first.max(last));
+        if !(min > 0.0 && max.is_finite()) {
+            return AlignedFloats::default().generate(&first, &last, span);
+        }
+        let (low, high) = (scale10(min), scale10(max) + 1);
+        let fits = |ticks: &[f64]| span.consumed(&State, ticks) <= span.length();
+        // Every power of 10 with increasingly sparse multiples
+        let dense = MULTIPLES.iter().map(|&multiples| (multiples, 1));
+        // Then skip powers
+        let sparse = (2..=(high - low).max(2)).map(|every| (MULTIPLES[1], every));
+        let mut candidates = dense
+            .chain(sparse)
+            .map(|(multiples, every)| log_positions(min, max, low, high, multiples, every));
+        let Some(densest) = candidates.next().filter(|ticks| ticks.len() >= 2) else {
+            return AlignedFloats::default().generate(&first, &last, span);
+        };
+        let mut sparsest = densest.clone();
+        for ticks in core::iter::once(densest).chain(candidates) {
+            if fits(&ticks) {
+                return GeneratedTicks::new(State, ticks);
+            }
+            sparsest = ticks;
+        }
+        // Nothing fits: show a single tick
+        sparsest.truncate(1);
+        GeneratedTicks::new(State, sparsest)
+    }
+}
+
+/// Multiples of every `every` power of 10 from `low` to `high` that fall within `min` and `max` inclusive.
+fn log_positions(
+    min: f64,
+    max: f64,
+    low: isize,
+    high: isize,
+    multiples: &[u8],
+    every: isize,
+) -> Vec<f64> {
+    (low..=high)
+        .filter(|power| (power - low) % every == 0)
+        .flat_map(|power| {
+            // Parse the decimal rather than multiply to avoid errors e.g., 0.30000000000000004
+            multiples
+                .iter()
+                .filter_map(move |multiple| format!("{multiple}e{power}").parse::<f64>().ok())
+        })
+        .filter(|&tick| min <= tick && tick <= max)
+        .collect()
+}
+
+impl Format for State {
+    type Tick = f64;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        if value.is_nan() {
+            return "-".to_string();
+        }
+        // Ticks have a single significant digit
+        format_scaled(*value, scale10(*value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn assert_ticks(first: f64, last: f64, width: f64, expected: Vec<&'static str>) {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let gen = LogFloats::default().generate(&first, &last, &span);
+        let labels = (gen.ticks.iter())
+            .map(|tick| gen.state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn test_log_positions() {
+        assert_eq!(
+            log_positions(1.0, 100.0, 0, 2, &[1, 2, 5], 1),
+            vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]
+        );
+        assert_eq!(log_positions(0.02, 3.0, -2, 1, &[1], 1), vec![0.1, 1.0]);
+        assert_eq!(
+            log_positions(1.0, 1e6, 0, 7, &[1], 3),
+            vec![1.0, 1000.0, 1e6]
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        // Room for multiples
+        let exp = vec!["1", "2", "5", "10", "20", "50", "100"];
+        assert_ticks(1.0, 100.0, 100.0, exp);
+        // Only powers
+        assert_ticks(1.0, 100.0, 10.0, vec!["1", "10", "100"]);
+        // Skip powers
+        assert_ticks(1.0, 1e6, 21.0, vec!["1", "1000", "1000000"]);
+        assert_ticks(
+            0.001,
+            10.0,
+            100.0,
+            vec![
+                "0.001", "0.002", "0.005", "0.01", "0.02", "0.05", "0.1", "0.2", "0.5", "1", "2",
+                "5", "10",
+            ],
+        );
+        // Nothing fits
+        assert_ticks(1.0, 100.0, 1.0, vec!["1"]);
+    }
+
+    #[test]
+    fn test_fallback() {
+        // Not above zero
+        assert_ticks(0.0, 1.0, 9.0, vec!["0.0", "0.5", "1.0"]);
+        // Within a power of 10
+        assert_ticks(6.0, 8.0, 3.0, vec!["7.0"]);
+    }
+}
@@ -1,12 +1,23 @@
 mod aligned_floats;
+mod aligned_integers;
+mod log_floats;
 mod span;
 mod timestamps;
+mod variants;
 
 pub use aligned_floats::AlignedFloats;
+pub use aligned_integers::AlignedIntegers;
+pub use log_floats::LogFloats;
 pub use span::{HorizontalSpan, TickFormatFn, VerticalSpan};
 pub use timestamps::{Period, Timestamps};
+pub use variants::Variants;
 
-use std::sync::Arc;
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 
 pub trait Generator: Send + Sync {
     type Tick;
@@ -31,6 +42,11 @@ pub trait Format {
 
     /// Formats a tick into a string according to the tick generator used.
     fn format(&self, value: &Self::Tick) -> String;
+
+    /// Returns true if the tick falls on a more significant boundary than the rest. For example, [Timestamps] emphasises midnight when showing hours or the start of a month when showing days. Default is false.
+    fn is_emphasised(&self, _value: &Self::Tick) -> bool {
+        false
+    }
 }
 
 #[derive(Clone)]
@@ -51,12 +67,12 @@ impl<Tick> GeneratedTicks<Tick> {
 
 impl<Tick: Send + Sync + 'static> GeneratedTicks<Tick> {
     pub fn none() -> GeneratedTicks<Tick> {
-        Self::new(NilState(std::marker::PhantomData), vec![])
+        Self::new(NilState(core::marker::PhantomData), vec![])
     }
 }
 
 // Dummy TickState that should never be called. Used with no ticks.
-struct NilState<Tick>(std::marker::PhantomData<Tick>);
+struct NilState<Tick>(core::marker::PhantomData<Tick>);
 
 impl<Tick> Format for NilState<Tick> {
     type Tick = Tick;
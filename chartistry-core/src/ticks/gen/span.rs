@@ -1,6 +1,6 @@
 use super::{Format, Span};
 use crate::Tick;
-use std::sync::Arc;
+use alloc::{string::String, sync::Arc};
 
 pub struct VerticalSpan {
     avail_height: f64,
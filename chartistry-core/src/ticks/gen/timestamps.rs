@@ -1,13 +1,34 @@
 use super::{Format, GeneratedTicks, Generator, Span};
+use crate::math;
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use chrono::{prelude::*, Duration, DurationRound, Months};
-use std::{borrow::Borrow, fmt::Display, ops::Add, sync::Arc};
+use core::{borrow::Borrow, fmt::Display, ops::Add};
+
+// Periods estimated to have more candidate ticks than this are skipped along with smaller periods. Far more than could be sampled to fit a chart. Stops small periods over large ranges (e.g., nanoseconds over a year) from blocking
+const MAX_CANDIDATES: f64 = 10_000.0;
 
 /// Generates timestamp ticks from a set of periods. Aligned to nice values (earlier periods).
 #[derive(Clone)]
 pub struct Timestamps<Tz> {
     format: Arc<dyn TimestampFormat<Tz> + Send + Sync>,
     periods: Vec<Period>,
-    tz: std::marker::PhantomData<Tz>,
+    relative: Option<RelativeTo>,
+    tz: core::marker::PhantomData<Tz>,
+}
+
+/// What relative timestamps are measured against.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum RelativeTo {
+    Latest,
+    #[cfg(feature = "std")]
+    Now,
 }
 
 trait TimestampFormat<Tz: TimeZone> {
@@ -44,7 +65,8 @@ struct State<Tz: TimeZone> {
     format: Arc<dyn TimestampFormat<Tz> + Send + Sync>,
     all_periods: Vec<Period>,
     period: Period,
-    tz: std::marker::PhantomData<Tz>,
+    anchor: Option<DateTime<Tz>>,
+    tz: core::marker::PhantomData<Tz>,
 }
 
 impl<Tz> Default for Timestamps<Tz>
@@ -71,7 +93,8 @@ where
         Self {
             format: Arc::new(ShortFormat),
             periods,
-            tz: std::marker::PhantomData,
+            relative: None,
+            tz: core::marker::PhantomData,
         }
     }
 
@@ -110,6 +133,19 @@ where
         self.format = Arc::new(f);
         self
     }
+
+    /// Labels ticks relative to the latest timestamp e.g., "-1h", "-30m" and "now". Ticks are aligned to the latest timestamp instead of calendar boundaries. Ideal for streaming dashboards where absolute dates are noise. Overrides any other format.
+    pub fn with_relative_to_latest(mut self) -> Self {
+        self.relative = Some(RelativeTo::Latest);
+        self
+    }
+
+    /// Labels ticks relative to the current time. The same as [Timestamps::with_relative_to_latest] except "now" is read from the clock when ticks are generated. Needs the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn with_relative_to_now(mut self) -> Self {
+        self.relative = Some(RelativeTo::Now);
+        self
+    }
 }
 
 impl<Tz> Generator for Timestamps<Tz>
@@ -130,18 +166,33 @@ where
             return GeneratedTicks::none();
         }
 
+        let anchor = self.relative.map(|relative| match relative {
+            RelativeTo::Latest => last.clone(),
+            #[cfg(feature = "std")]
+            RelativeTo::Now => Utc::now().with_timezone(&last.timezone()),
+        });
+
         let mut ticks = Vec::new();
-        let mut state = State::from_period(self, self.periods[0]);
+        let mut state = State::from_period(self, self.periods[0], anchor.clone());
 
         'outer: for &period in &self.periods {
+            // Bail before materialising too many ticks. Smaller periods would have more
+            if period.estimate_count(first, last) > MAX_CANDIDATES {
+                break;
+            }
             // Fetch all ticks for this period
-            let candidate = period
-                .iter_aligned_range(first.clone(), last.clone())
-                .collect::<Vec<_>>();
-            // Try to fit candidate ticks into previous ticks, sampling if necessary
-            for sample in 1..(candidate.len() + 1) {
+            let candidate = match &anchor {
+                Some(anchor) => period.relative_range(anchor, first, last),
+                None => period
+                    .iter_aligned_range(first.clone(), last.clone())
+                    .collect::<Vec<_>>(),
+            };
+            // Try to fit candidate ticks into previous ticks, sampling if necessary. Start from at most one tick per unit of space as anything denser won't fit
+            let min_sample = math::ceil(candidate.len() as f64 / span.length());
+            let min_sample = (min_sample as usize).clamp(1, candidate.len().max(1));
+            for sample in min_sample..(candidate.len() + 1) {
                 let sampled = Self::merge_ticks(&ticks, &candidate, sample);
-                state = State::from_period(self, period);
+                state = State::from_period(self, period, anchor.clone());
                 let used_width = span.consumed(&state, &sampled);
                 // Our sampled ticks fit
                 if used_width <= span.length() {
@@ -207,12 +258,13 @@ impl<Tz: TimeZone> Timestamps<Tz> {
 }
 
 impl<Tz: TimeZone> State<Tz> {
-    fn from_period(gen: &Timestamps<Tz>, period: Period) -> Self {
+    fn from_period(gen: &Timestamps<Tz>, period: Period, anchor: Option<DateTime<Tz>>) -> Self {
         Self {
             format: gen.format.clone(),
             all_periods: gen.periods.clone(),
             period,
-            tz: std::marker::PhantomData,
+            anchor,
+            tz: core::marker::PhantomData,
         }
     }
 }
@@ -221,6 +273,9 @@ impl<Tz: TimeZone> Format for State<Tz> {
     type Tick = DateTime<Tz>;
 
     fn format(&self, at: &Self::Tick) -> String {
+        if let Some(anchor) = &self.anchor {
+            return format_relative(&self.all_periods, anchor, at);
+        }
         let mut period = self.period;
         // If tick falls exactly on an earlier period, use that representation instead
         for earlier in &self.all_periods {
@@ -232,6 +287,34 @@ impl<Tz: TimeZone> Format for State<Tz> {
         // Use local formatter
         self.format.format(period, at)
     }
+
+    fn is_emphasised(&self, at: &Self::Tick) -> bool {
+        // Day boundaries and larger e.g., midnight on hourly ticks
+        (self.all_periods.iter())
+            .filter(|&&period| period >= Period::Day && period > self.period)
+            .any(|period| period.truncate_at(at.clone()).as_ref() == Some(at))
+    }
+}
+
+/// Formats a timestamp relative to an anchor using the largest period that it's a whole multiple of e.g., "-2h" rather than "-120m".
+fn format_relative<Tz: TimeZone>(
+    periods: &[Period],
+    anchor: &DateTime<Tz>,
+    at: &DateTime<Tz>,
+) -> String {
+    let (sign, from, to) = match at.cmp(anchor) {
+        core::cmp::Ordering::Equal => return "now".to_string(),
+        core::cmp::Ordering::Less => ("-", at, anchor),
+        core::cmp::Ordering::Greater => ("+", anchor, at),
+    };
+    // Note: periods are sorted largest first
+    for &period in periods {
+        if let Some(steps) = period.steps_between(from, to) {
+            return format!("{sign}{steps}{}", period.unit());
+        }
+    }
+    // Doesn't fall on a period, fallback to seconds
+    format!("{sign}{}s", (to.clone() - from.clone()).num_seconds())
 }
 
 struct ShortFormat;
@@ -305,6 +388,20 @@ impl Period {
         }
     }
 
+    fn unit(self) -> &'static str {
+        match self {
+            Period::Nanosecond => "ns",
+            Period::Microsecond => "us",
+            Period::Millisecond => "ms",
+            Period::Second => "s",
+            Period::Minute => "m",
+            Period::Hour => "h",
+            Period::Day => "d",
+            Period::Month => "M",
+            Period::Year => "Y",
+        }
+    }
+
     /// All available periods.
     pub const fn all() -> [Period; 9] {
         [
@@ -362,31 +459,130 @@ impl Period {
         }
     }
 
-    fn truncate_at<Tz: TimeZone>(self, at: DateTime<Tz>) -> Option<DateTime<Tz>> {
-        let duration = match self {
-            Period::Nanosecond => Duration::nanoseconds(1),
-            Period::Microsecond => Duration::microseconds(1),
-            Period::Millisecond => Duration::milliseconds(1),
-            Period::Second => Duration::seconds(1),
-            Period::Minute => Duration::minutes(1),
-            Period::Hour => Duration::hours(1),
-            Period::Day => Duration::days(1),
+    /// Returns the duration of fixed periods. None for variable periods (months and years).
+    fn fixed_duration(self) -> Option<Duration> {
+        match self {
+            Period::Nanosecond => Some(Duration::nanoseconds(1)),
+            Period::Microsecond => Some(Duration::microseconds(1)),
+            Period::Millisecond => Some(Duration::milliseconds(1)),
+            Period::Second => Some(Duration::seconds(1)),
+            Period::Minute => Some(Duration::minutes(1)),
+            Period::Hour => Some(Duration::hours(1)),
+            Period::Day => Some(Duration::days(1)),
+            Period::Month | Period::Year => None,
+        }
+    }
+
+    /// Estimates the number of periods from `from` to `to`. Approximates variable periods.
+    fn estimate_count<Tz: TimeZone>(self, from: &DateTime<Tz>, to: &DateTime<Tz>) -> f64 {
+        const DAY: f64 = 86_400.0;
+        let seconds = |duration: Duration| match duration.num_nanoseconds() {
+            Some(nanos) => nanos as f64 / 1e9,
+            None => duration.num_milliseconds() as f64 / 1e3,
+        };
+        let period = match self {
+            Period::Month => DAY * 30.44,
+            Period::Year => DAY * 365.25,
+            _ => self.fixed_duration().map_or(DAY, seconds),
+        };
+        seconds(to.clone() - from.clone()).abs() / period
+    }
+
+    /// Number of months in variable periods.
+    fn months(self) -> u32 {
+        if self == Period::Year {
+            12
+        } else {
+            1
+        }
+    }
 
-            // Variable periods. Can't use duration_trunc
-            Period::Month => {
-                return at
-                    .timezone()
-                    .with_ymd_and_hms(at.year(), at.month(), 1, 0, 0, 0)
-                    .latest();
+    /// Returns `anchor` moved back by `steps` periods. Negative steps move forward.
+    fn step_back<Tz: TimeZone>(self, anchor: &DateTime<Tz>, steps: i64) -> Option<DateTime<Tz>> {
+        match self.fixed_duration() {
+            Some(duration) => {
+                let nanos = duration.num_nanoseconds()?.checked_mul(steps)?;
+                anchor
+                    .clone()
+                    .checked_sub_signed(Duration::nanoseconds(nanos))
+            }
+            None => {
+                let months = steps.checked_mul(self.months() as i64)?;
+                let abs = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+                if months >= 0 {
+                    anchor.clone().checked_sub_months(abs)
+                } else {
+                    anchor.clone().checked_add_months(abs)
+                }
+            }
+        }
+    }
+
+    /// Returns the number of whole periods from `from` to `to`. None if `to` doesn't fall exactly on a period step.
+    fn steps_between<Tz: TimeZone>(self, from: &DateTime<Tz>, to: &DateTime<Tz>) -> Option<i64> {
+        let steps = match self.fixed_duration() {
+            Some(duration) => {
+                let diff = (to.clone() - from.clone()).num_nanoseconds()?;
+                let step = duration.num_nanoseconds()?;
+                (diff % step == 0).then_some(diff / step)?
+            }
+            None => {
+                let month_index = |at: &DateTime<Tz>| at.year() as i64 * 12 + at.month0() as i64;
+                let months = month_index(to) - month_index(from);
+                (months % self.months() as i64 == 0).then_some(months / self.months() as i64)?
+            }
+        };
+        (self.step_back(to, steps).as_ref() == Some(from)).then_some(steps)
+    }
+
+    /// Ticks from `from` to `to` (inclusive) spaced by this period and aligned to `anchor`.
+    fn relative_range<Tz: TimeZone>(
+        self,
+        anchor: &DateTime<Tz>,
+        from: &DateTime<Tz>,
+        to: &DateTime<Tz>,
+    ) -> Vec<DateTime<Tz>> {
+        // Estimate the first step at or before `to`. Errs on the side of too few steps
+        let first_step = match self.fixed_duration() {
+            Some(duration) => {
+                let diff = (anchor.clone() - to.clone()).num_nanoseconds();
+                let step = duration.num_nanoseconds();
+                match (diff, step) {
+                    (Some(diff), Some(step)) => diff.div_euclid(step),
+                    _ => return vec![],
+                }
             }
-            Period::Year => {
-                return at
-                    .timezone()
-                    .with_ymd_and_hms(at.year(), 1, 1, 0, 0, 0)
-                    .latest();
+            None => {
+                let month_index = |at: &DateTime<Tz>| at.year() as i64 * 12 + at.month0() as i64;
+                let months = month_index(anchor) - month_index(to) - 1;
+                months.div_euclid(self.months() as i64)
             }
         };
 
+        let mut ticks = Vec::new();
+        let mut steps = first_step;
+        while let Some(at) = self.step_back(anchor, steps) {
+            if at < *from {
+                break;
+            } else if at <= *to {
+                ticks.push(at);
+            }
+            steps += 1;
+        }
+        ticks.reverse();
+        ticks
+    }
+
+    pub(crate) fn truncate_at<Tz: TimeZone>(self, at: DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let Some(duration) = self.fixed_duration() else {
+            // Variable periods. Can't use duration_trunc
+            let month = if self == Period::Year { 1 } else { at.month() };
+            return at
+                .timezone()
+                .with_ymd_and_hms(at.year(), month, 1, 0, 0, 0)
+                .latest();
+        };
+
         // If at is zero (1970) then duration_trunc will fail but it's already aligned, so do nothing
         if Some(0) == at.timestamp_nanos_opt() {
             Some(at)
@@ -520,6 +716,107 @@ mod tests {
         assert_ticks(gen.generate(&first, &last, &mk_span(1000.0)), vec![]);
     }
 
+    #[test]
+    fn test_timestamps_relative() {
+        let gen = Timestamps::from_period(Period::Minute).with_relative_to_latest();
+        let first = Utc.with_ymd_and_hms(2024, 3, 5, 12, 27, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(2024, 3, 5, 12, 30, 20).unwrap();
+        assert_ticks(
+            gen.generate(&first, &last, &mk_span(1000.0)),
+            vec!["-3m", "-2m", "-1m", "now"],
+        );
+    }
+
+    #[test]
+    fn test_period_relative_range() {
+        let at = |h, m, s| Utc.with_ymd_and_hms(2024, 3, 5, h, m, s).unwrap();
+        assert_eq!(
+            Period::Minute.relative_range(&at(12, 30, 20), &at(12, 27, 0), &at(12, 30, 20)),
+            vec![
+                at(12, 27, 20),
+                at(12, 28, 20),
+                at(12, 29, 20),
+                at(12, 30, 20)
+            ]
+        );
+        // Months clamp to the end of shorter months
+        let ymd = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+        assert_eq!(
+            Period::Month.relative_range(&ymd(2024, 3, 31), &ymd(2023, 12, 1), &ymd(2024, 3, 31)),
+            vec![
+                ymd(2023, 12, 31),
+                ymd(2024, 1, 31),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let periods = [Period::Hour, Period::Minute, Period::Second];
+        let at = |h, m, s| Utc.with_ymd_and_hms(2024, 3, 5, h, m, s).unwrap();
+        let anchor = at(12, 0, 0);
+        assert_eq!(format_relative(&periods, &anchor, &at(10, 0, 0)), "-2h");
+        assert_eq!(format_relative(&periods, &anchor, &at(11, 30, 0)), "-30m");
+        assert_eq!(format_relative(&periods, &anchor, &at(10, 30, 0)), "-90m");
+        assert_eq!(format_relative(&periods, &anchor, &at(12, 0, 0)), "now");
+        assert_eq!(format_relative(&periods, &anchor, &at(12, 0, 30)), "+30s");
+        let periods = [Period::Year, Period::Month];
+        let ymd = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+        let anchor = ymd(2024, 3, 31);
+        assert_eq!(format_relative(&periods, &anchor, &ymd(2023, 3, 31)), "-1Y");
+        assert_eq!(format_relative(&periods, &anchor, &ymd(2024, 2, 29)), "-1M");
+    }
+
+    #[test]
+    fn test_timestamps_emphasised() {
+        let gen = Timestamps::from_periods(Period::all());
+        let first = Utc.with_ymd_and_hms(2024, 3, 4, 20, 0, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(2024, 3, 5, 4, 0, 0).unwrap();
+        let GeneratedTicks { ticks, state } = gen.generate(&first, &last, &mk_span(1000.0));
+        let emphasised = (ticks.iter())
+            .filter(|tick| state.is_emphasised(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            emphasised,
+            vec![&Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_estimate_count() {
+        let first = Utc.with_ymd_and_hms(2014, 1, 1, 0, 0, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(2015, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(Period::Day.estimate_count(&first, &last), 365.0);
+        assert_eq!(Period::Hour.estimate_count(&first, &last), 365.0 * 24.0);
+        assert!((Period::Month.estimate_count(&first, &last) - 12.0).abs() < 0.1);
+        assert!((Period::Year.estimate_count(&first, &last) - 1.0).abs() < 0.01);
+        assert_eq!(
+            Period::Second.estimate_count(&last, &first),
+            365.0 * 86_400.0
+        );
+        // Beyond nanosecond precision
+        let far = Utc.with_ymd_and_hms(2500, 1, 1, 0, 0, 0).unwrap();
+        assert!(Period::Nanosecond.estimate_count(&first, &far) > 1e18);
+    }
+
+    #[test]
+    fn test_huge_range() {
+        let first = Utc.with_ymd_and_hms(2014, 1, 1, 0, 0, 0).unwrap();
+        let last = Utc.with_ymd_and_hms(2015, 1, 1, 0, 0, 0).unwrap();
+        // Too many candidates to materialise
+        let gen = Timestamps::from_period(Period::Nanosecond);
+        assert_ticks(gen.generate(&first, &last, &mk_span(1000.0)), vec![]);
+        // Larger periods are still used
+        let last = Utc.with_ymd_and_hms(2016, 6, 1, 0, 0, 0).unwrap();
+        let gen = Timestamps::from_periods([Period::Year, Period::Nanosecond]);
+        assert_ticks(
+            gen.generate(&first, &last, &mk_span(1000.0)),
+            vec!["2014", "2015", "2016"],
+        );
+    }
+
     #[test]
     fn test_sample_ticks() {
         let f = Timestamps::<Utc>::sample_ticks::<u32>;
@@ -0,0 +1,190 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use crate::{math, Tick};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Generates ticks for ordered categories e.g., a C-like enum of severity levels. Each variant is placed at its index and labelled by its name. Variants are skipped evenly when they don't all fit.
+///
+/// Usually created by [ordered_tick](crate::ordered_tick).
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Variants<T: 'static> {
+    variants: &'static [T],
+    names: &'static [&'static str],
+}
+
+impl<T> Clone for Variants<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Variants<T> {}
+
+impl<T> Variants<T> {
+    /// Creates a new generator from variants in order and their names.
+    pub const fn new(variants: &'static [T], names: &'static [&'static str]) -> Self {
+        Self { variants, names }
+    }
+
+    /// Position of a variant i.e., its index. Returns `f64::NAN` if not found.
+    pub fn position(&self, value: &T) -> f64
+    where
+        T: PartialEq,
+    {
+        (self.variants.iter())
+            .position(|variant| variant == value)
+            .map_or(f64::NAN, |index| index as f64)
+    }
+
+    /// Variant nearest to a position. Returns `None` if out of bounds.
+    pub fn with_position(&self, position: f64) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.index(position)
+            .map(|index| self.variants[index].clone())
+    }
+
+    fn index(&self, position: f64) -> Option<usize> {
+        let index = math::round(position);
+        (index >= 0.0 && index < self.variants.len() as f64).then_some(index as usize)
+    }
+}
+
+impl<T: Tick> Generator for Variants<T> {
+    type Tick = T;
+
+    fn generate(
+        &self,
+        first: &Self::Tick,
+        last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let (first, last) = (first.position(), last.position());
+        let ticks = (self.variants.iter().enumerate())
+            .filter(|&(index, _)| first <= index as f64 && index as f64 <= last)
+            .map(|(_, variant)| variant.clone())
+            .collect::<Vec<_>>();
+        // Skip variants evenly when they don't fit
+        let step = math::ceil(span.consumed(self, &ticks) / span.length());
+        let step = if step.is_finite() && step > 1.0 {
+            step as usize
+        } else {
+            1
+        };
+        let ticks = ticks.into_iter().step_by(step).collect();
+        GeneratedTicks::new(*self, ticks)
+    }
+}
+
+impl<T: Tick> Format for Variants<T> {
+    type Tick = T;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        self.index(value.position())
+            .and_then(|index| self.names.get(index))
+            .map_or_else(|| "-".to_string(), |name| name.to_string())
+    }
+}
+
+/// Implements [Tick](crate::Tick) for a C-like enum of ordered categories e.g., severity levels. Variants are evenly spaced by their index and labelled by their name.
+///
+/// The enum must derive `Clone`, `PartialEq` and `PartialOrd`. List every variant in declaration order so positions agree with the enum's ordering.
+///
+/// ```rust
+/// # use chartistry_core::{ordered_tick, Tick};
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// enum Severity {
+///     Debug,
+///     Info,
+///     Warning,
+///     Error,
+/// }
+/// ordered_tick!(Severity { Debug, Info, Warning, Error });
+///
+/// assert_eq!(Severity::Warning.position(), 2.0);
+/// assert_eq!(Severity::Debug.with_position(3.0), Some(Severity::Error));
+/// ```
+#[macro_export]
+macro_rules! ordered_tick {
+    (@variants $ty:ident { $($variant:ident),+ }) => {
+        $crate::ticks::Variants::<$ty>::new(
+            &[$($ty::$variant),+],
+            &[$(stringify!($variant)),+],
+        )
+    };
+
+    ($ty:ident { $($variant:ident),+ $(,)? }) => {
+        impl $crate::ticks::__private::Sealed for $ty {}
+
+        impl $crate::Tick for $ty {
+            fn tick_label_generator() -> impl $crate::ticks::TickGen<Tick = Self> {
+                $crate::ordered_tick!(@variants $ty { $($variant),+ })
+            }
+
+            fn position(&self) -> f64 {
+                $crate::ordered_tick!(@variants $ty { $($variant),+ }).position(self)
+            }
+
+            fn with_position(&self, position: f64) -> Option<Self> {
+                $crate::ordered_tick!(@variants $ty { $($variant),+ }).with_position(position)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    enum Level {
+        Low,
+        Medium,
+        High,
+        Critical,
+    }
+    crate::ordered_tick!(Level {
+        Low,
+        Medium,
+        High,
+        Critical,
+    });
+
+    fn generate(first: Level, last: Level, width: f64) -> Vec<String> {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let ticks = Level::tick_label_generator().generate(&first, &last, &span);
+        (ticks.ticks.iter())
+            .map(|tick| ticks.state.format(tick))
+            .collect()
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(Level::Low.position(), 0.0);
+        assert_eq!(Level::Critical.position(), 3.0);
+        assert_eq!(Level::Low.with_position(1.4), Some(Level::Medium));
+        assert_eq!(Level::Low.with_position(-1.0), None);
+        assert_eq!(Level::Low.with_position(4.0), None);
+        assert_eq!(Level::Low.with_position(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        let all = vec!["Low", "Medium", "High", "Critical"];
+        assert_eq!(generate(Level::Low, Level::Critical, 100.0), all);
+        assert_eq!(
+            generate(Level::Medium, Level::High, 100.0),
+            vec!["Medium", "High"]
+        );
+        // Skip variants that don't fit
+        assert_eq!(
+            generate(Level::Low, Level::Critical, 20.0),
+            vec!["Low", "High"]
+        );
+    }
+}
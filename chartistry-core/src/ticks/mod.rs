@@ -0,0 +1,143 @@
+mod gen;
+
+pub use gen::{
+    AlignedFloats, AlignedIntegers, Format as TickFormat, GeneratedTicks, Generator as TickGen,
+    HorizontalSpan, LogFloats, Period, TickFormatFn, Timestamps, Variants, VerticalSpan,
+};
+
+use crate::math;
+use chrono::prelude::*;
+
+mod private {
+    pub trait Sealed {}
+}
+
+// Used by the ordered_tick! macro
+#[doc(hidden)]
+pub mod __private {
+    pub use super::private::Sealed;
+}
+
+/// A type that can be used as a tick on an axis. Try to rely on provided implementations.
+pub trait Tick: Clone + PartialEq + PartialOrd + Send + Sync + 'static + private::Sealed {
+    /// Default tick generator used in tick labels.
+    fn tick_label_generator() -> impl TickGen<Tick = Self>;
+
+    /// Default tick generator used in tooltips.
+    fn tooltip_generator() -> impl TickGen<Tick = Self> {
+        Self::tick_label_generator()
+    }
+
+    /// Maps the tick to a position on the axis. Must be uniform. May return `f64::NAN` for missing data.
+    fn position(&self) -> f64;
+
+    /// Inverse of [Tick::position]. Creates a new tick at the given position using `self` for any context needed e.g., a time zone. Returns `None` if the position can't be represented.
+    fn with_position(&self, position: f64) -> Option<Self>;
+
+    /// Start of the period containing this tick e.g., midnight for [Period::Day]. Used to group ticks when resampling. Returns `None` by default for ticks without periods.
+    fn period_start(&self, _period: Period) -> Option<Self> {
+        None
+    }
+}
+
+impl private::Sealed for f64 {}
+impl<Tz: TimeZone> private::Sealed for DateTime<Tz> {}
+
+impl Tick for f64 {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        AlignedFloats::default()
+    }
+
+    fn position(&self) -> f64 {
+        *self
+    }
+
+    fn with_position(&self, position: f64) -> Option<Self> {
+        Some(position)
+    }
+}
+
+macro_rules! impl_integer_tick {
+    ($($ty:ty),*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl Tick for $ty {
+                fn tick_label_generator() -> impl TickGen<Tick = Self> {
+                    AlignedIntegers::default()
+                }
+
+                fn position(&self) -> f64 {
+                    *self as f64
+                }
+
+                fn with_position(&self, position: f64) -> Option<Self> {
+                    let position = math::round(position);
+                    (position >= <$ty>::MIN as f64 && position <= <$ty>::MAX as f64)
+                        .then_some(position as $ty)
+                }
+            }
+        )*
+    };
+}
+impl_integer_tick!(i32, i64, u64);
+
+impl<Tz> Tick for DateTime<Tz>
+where
+    Tz: TimeZone + Send + Sync + 'static,
+    Tz::Offset: core::fmt::Display + Send + Sync,
+{
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        Timestamps::default()
+    }
+
+    fn tooltip_generator() -> impl TickGen<Tick = Self> {
+        Timestamps::default().with_long_format()
+    }
+
+    fn position(&self) -> f64 {
+        self.timestamp() as f64 + (self.timestamp_subsec_nanos() as f64 / 1e9)
+    }
+
+    fn with_position(&self, position: f64) -> Option<Self> {
+        if !position.is_finite() {
+            return None;
+        }
+        let secs = math::floor(position);
+        let nanos = math::round((position - secs) * 1e9).min(999_999_999.0) as u32;
+        DateTime::from_timestamp(secs as i64, nanos).map(|at| at.with_timezone(&self.timezone()))
+    }
+
+    fn period_start(&self, period: Period) -> Option<Self> {
+        period.truncate_at(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_position() {
+        assert_eq!(1.0_f64.with_position(42.5), Some(42.5));
+        let at = Utc.with_ymd_and_hms(2024, 5, 6, 7, 8, 9).unwrap();
+        assert_eq!(at.with_position(at.position()), Some(at));
+        let half = at.with_position(at.position() + 0.5).unwrap();
+        assert_eq!(half.timestamp_subsec_millis(), 500);
+        assert_eq!(at.with_position(f64::NAN), None);
+        assert_eq!(3_i32.with_position(2.6), Some(3));
+        assert_eq!(3_i64.with_position(-4.0), Some(-4));
+        assert_eq!(3_u64.with_position(-1.0), None);
+        assert_eq!(3_u64.with_position(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_period_start() {
+        let at = Utc.with_ymd_and_hms(2024, 5, 6, 7, 8, 9).unwrap();
+        let hour = Utc.with_ymd_and_hms(2024, 5, 6, 7, 0, 0).unwrap();
+        let month = Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap();
+        assert_eq!(at.period_start(Period::Hour), Some(hour));
+        assert_eq!(at.period_start(Period::Month), Some(month));
+        assert_eq!(1.5_f64.period_start(Period::Hour), None);
+    }
+}
@@ -158,6 +158,13 @@ impl EnvCalc {
 }
 
 impl KnownAspectRatio {
+    /// Width and height without layout. Inner ratios exclude the edges.
+    pub fn approx_size(&self) -> (f64, f64) {
+        match self {
+            Self::Inner(vars) | Self::Outer(vars) => (vars.width(), vars.height()),
+        }
+    }
+
     pub fn inner_width_signal(known: Memo<Self>, left: Memo<f64>, right: Memo<f64>) -> Memo<f64> {
         Memo::new(move |_| match known.get() {
             Self::Inner(vars) => vars.width(),
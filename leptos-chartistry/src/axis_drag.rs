@@ -0,0 +1,627 @@
+use crate::{
+    bounds::Bounds, edge::Edge, projection::Projection, series::Range, AxisScale, Tick, ViewState,
+};
+use leptos::prelude::*;
+
+/// Drag interactions on the axes. Dragging along the X axis pans the X range while dragging along the Y axis rescales the Y range around its centre. Pans and scales apply after each axis' [Scale](crate::Scale) e.g., zooming a log scale multiplies its range. Also handles touch gestures (pinch to zoom and two-finger pan) and zooming the inner chart area (mouse wheel to zoom, drag to pan). Stored in a [ViewState].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisDrag {
+    /// Offset applied to the X range in position space.
+    pan_x: RwSignal<f64>,
+    /// Scale factor applied to the X range.
+    scale_x: RwSignal<f64>,
+    /// Offset applied to the Y range in position space.
+    pan_y: RwSignal<f64>,
+    /// Scale factor applied to the Y range.
+    scale_y: RwSignal<f64>,
+    start: RwSignal<Option<DragStart>>,
+    pinch: RwSignal<Option<PinchStart>>,
+    pan: RwSignal<Option<PanStart>>,
+}
+
+/// Which axes are zoomed and panned. Used by [Chart](crate::Chart)'s `gestures` prop for touch gestures (pinch to zoom, two-finger pan) and `zoom` prop for the mouse (wheel to zoom, drag to pan).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum ZoomAxes {
+    /// Neither axis: touches and the mouse behave as usual on the page.
+    #[default]
+    None,
+    /// Only the X axis is zoomed and panned.
+    X,
+    /// Only the Y axis is zoomed and panned.
+    Y,
+    /// Both axes are zoomed and panned.
+    Both,
+}
+
+impl ZoomAxes {
+    fn applies_x(self) -> bool {
+        matches!(self, Self::X | Self::Both)
+    }
+
+    fn applies_y(self) -> bool {
+        matches!(self, Self::Y | Self::Both)
+    }
+}
+
+impl std::fmt::Display for ZoomAxes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::X => write!(f, "X"),
+            Self::Y => write!(f, "Y"),
+            Self::Both => write!(f, "Both"),
+        }
+    }
+}
+
+impl std::str::FromStr for ZoomAxes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            "both" => Ok(Self::Both),
+            _ => Err(format!("invalid ZoomAxes: `{}`", s)),
+        }
+    }
+}
+
+/// Limits how far the mouse wheel zooms the X axis e.g., never show fewer than 10 samples. See [Chart](crate::Chart)'s `zoom_limits` prop.
+///
+/// Zooming stops at a limit. Further wheel events (e.g., trackpad momentum) don't zoom or move the window.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let limits = ZoomLimits::new().with_min_samples(10).with_max_scale(1.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ZoomLimits {
+    /// Fewest X values (samples) to show when zoomed in. Default is `None` for no limit.
+    pub min_samples: Option<usize>,
+    /// Widest window as a multiple of the whole X range e.g., 1.0 to never zoom out past the data. Default is `None` for no limit.
+    pub max_scale: Option<f64>,
+}
+
+impl ZoomLimits {
+    /// Creates new zoom limits without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fewest X values (samples) to show when zoomed in.
+    pub fn with_min_samples(mut self, samples: usize) -> Self {
+        self.min_samples = Some(samples);
+        self
+    }
+
+    /// Sets the widest window as a multiple of the whole X range.
+    pub fn with_max_scale(mut self, scale: f64) -> Self {
+        self.max_scale = Some(scale);
+        self
+    }
+
+    /// Clamps a scale of the X range holding `len` samples. The minimum number of samples wins over the maximum scale.
+    fn clamp_scale(&self, scale: f64, len: usize) -> f64 {
+        let scale = self.max_scale.map_or(scale, |max| scale.min(max));
+        let min = (self.min_samples)
+            .filter(|_| len > 1)
+            .map(|samples| (samples.saturating_sub(1) as f64 / (len - 1) as f64).min(1.0));
+        min.map_or(scale, |min| scale.max(min))
+    }
+}
+
+/// Zoom limits applied to an X range holding `len` samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomClamp {
+    pub limits: ZoomLimits,
+    pub len: usize,
+}
+
+impl ZoomClamp {
+    fn clamp_scale(&self, scale: f64) -> f64 {
+        self.limits.clamp_scale(scale, self.len)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DragStart {
+    vertical: bool,
+    mouse: (f64, f64),
+    pan_x: f64,
+    scale_y: f64,
+    // Scaled position units per pixel at the start of the drag
+    x_per_px: f64,
+    inner_height: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PinchStart {
+    // Centre and spread (absolute distance) between the two touches
+    centre: (f64, f64),
+    spread: (f64, f64),
+    pan: (f64, f64),
+    scale: (f64, f64),
+    // Scaled positions of the touches' centre and of the inner chart area's centre
+    at: (f64, f64),
+    inner_centre: (f64, f64),
+    // Scaled position units per pixel at the start of the pinch
+    per_px: (f64, f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PanStart {
+    mouse: (f64, f64),
+    pan: (f64, f64),
+    // Scaled position units per pixel at the start of the drag
+    per_px: (f64, f64),
+    axes: ZoomAxes,
+}
+
+// Ratio to scale a range by for each step of the mouse wheel
+const WHEEL_ZOOM: f64 = 1.1;
+
+// Minimum spread (in pixels) between touches before zooming an axis. Avoids huge jumps when fingers are aligned
+const MIN_PINCH_SPREAD: f64 = 20.0;
+
+impl AxisDrag {
+    pub fn new(view: ViewState) -> Self {
+        Self {
+            pan_x: view.pan_x,
+            scale_x: view.scale_x,
+            pan_y: view.pan_y,
+            scale_y: view.scale_y,
+            start: RwSignal::default(),
+            pinch: RwSignal::default(),
+            pan: RwSignal::default(),
+        }
+    }
+
+    pub fn is_dragging(&self) -> Signal<bool> {
+        let (start, pan) = (self.start, self.pan);
+        Signal::derive(move || start.with(Option::is_some) || pan.with(Option::is_some))
+    }
+
+    /// Rescales and pans the X range on its axis scale.
+    pub fn apply_x<X: Tick>(
+        &self,
+        range_x: Memo<Range<X>>,
+        axis_scale: RwSignal<AxisScale>,
+    ) -> Memo<Range<X>> {
+        let (pan_x, scale_x) = (self.pan_x, self.scale_x);
+        Memo::new(move |_| {
+            let (pan_x, scale_x) = (pan_x.get(), scale_x.get());
+            let axis_scale = axis_scale.get();
+            (range_x.get()).map_positions(|min, max| {
+                transform((min, max), &axis_scale, |min, max| {
+                    let (min, max) = rescale(min, max, scale_x);
+                    pan(min, max, pan_x)
+                })
+            })
+        })
+    }
+
+    /// Rescales and pans the Y range on its axis scale.
+    pub fn apply_y<Y: Tick>(
+        &self,
+        range_y: Memo<Range<Y>>,
+        axis_scale: RwSignal<AxisScale>,
+    ) -> Memo<Range<Y>> {
+        let (pan_y, scale_y) = (self.pan_y, self.scale_y);
+        Memo::new(move |_| {
+            let (pan_y, scale_y) = (pan_y.get(), scale_y.get());
+            let axis_scale = axis_scale.get();
+            (range_y.get()).map_positions(|min, max| {
+                transform((min, max), &axis_scale, |min, max| {
+                    let (min, max) = rescale(min, max, scale_y);
+                    pan(min, max, pan_y)
+                })
+            })
+        })
+    }
+
+    /// Starts a drag on the given edge from the mouse position.
+    pub fn start(&self, edge: Edge, mouse: (f64, f64), proj: &Projection, inner: Bounds) {
+        let (left, _) = proj.svg_to_scaled(0.0, 0.0);
+        let (right, _) = proj.svg_to_scaled(1.0, 0.0);
+        self.start.set(Some(DragStart {
+            vertical: edge.is_vertical(),
+            mouse,
+            pan_x: self.pan_x.get_untracked(),
+            scale_y: self.scale_y.get_untracked(),
+            x_per_px: right - left,
+            inner_height: inner.height().max(1.0),
+        }));
+    }
+
+    /// Starts panning the inner chart area from the mouse position.
+    pub fn start_pan(&self, mouse: (f64, f64), axes: ZoomAxes, proj: &Projection) {
+        let origin = proj.svg_to_scaled(0.0, 0.0);
+        let unit = proj.svg_to_scaled(1.0, 1.0);
+        self.pan.set(Some(PanStart {
+            mouse,
+            pan: (self.pan_x.get_untracked(), self.pan_y.get_untracked()),
+            per_px: (unit.0 - origin.0, unit.1 - origin.1),
+            axes,
+        }));
+    }
+
+    /// Zooms in (wheel up) or out (wheel down) around the mouse position. The point under the mouse stays put. The X axis is kept within `clamp`.
+    pub fn zoom_at(
+        &self,
+        mouse: (f64, f64),
+        wheel_up: bool,
+        axes: ZoomAxes,
+        clamp: ZoomClamp,
+        proj: &Projection,
+        inner: Bounds,
+    ) {
+        let factor = if wheel_up {
+            1.0 / WHEEL_ZOOM
+        } else {
+            WHEEL_ZOOM
+        };
+        let at = proj.svg_to_scaled(mouse.0, mouse.1);
+        let centre = proj.svg_to_scaled(inner.centre_x(), inner.centre_y());
+        if axes.applies_x() {
+            let scale_x = self.scale_x.get_untracked();
+            let (pan, scale) = zoom_about(
+                (self.pan_x.get_untracked(), scale_x),
+                at.0,
+                centre.0,
+                clamp_factor(scale_x, factor, |scale| clamp.clamp_scale(scale)),
+            );
+            self.pan_x.set(pan);
+            self.scale_x.set(scale);
+        }
+        if axes.applies_y() {
+            let (pan, scale) = zoom_about(
+                (self.pan_y.get_untracked(), self.scale_y.get_untracked()),
+                at.1,
+                centre.1,
+                factor,
+            );
+            self.pan_y.set(pan);
+            self.scale_y.set(scale);
+        }
+    }
+
+    /// Keeps `zoom_x` in sync with the visible X range: `None` when the X axis isn't zoomed or panned. Setting it zooms the chart to that window of the `base` (unzoomed) range on the X axis scale.
+    pub fn sync_x<X: Tick>(
+        &self,
+        zoom_x: RwSignal<Option<(X, X)>>,
+        base: Memo<Range<X>>,
+        visible: Memo<Range<X>>,
+        axis_scale: RwSignal<AxisScale>,
+    ) {
+        let (pan_x, scale_x) = (self.pan_x, self.scale_x);
+        // Last value written by the chart. Avoids feeding it back
+        let written = StoredValue::new(None::<Option<(X, X)>>);
+        Effect::new(move |_| {
+            let zoomed = pan_x.get() != 0.0 || scale_x.get() != 1.0;
+            let range = zoomed
+                .then(|| visible.with(|range| range.range().map(|(a, b)| (a.clone(), b.clone()))))
+                .flatten();
+            written.set_value(Some(range.clone()));
+            if zoom_x.get_untracked() != range {
+                zoom_x.set(range);
+            }
+        });
+        Effect::new(move |_| {
+            let zoom = zoom_x.get();
+            if written.with_value(|written| written.as_ref() == Some(&zoom)) {
+                return;
+            }
+            let window = zoom.map(|(min, max)| (min.position(), max.position()));
+            let base = base.with_untracked(|range| range.positions());
+            let (pan, scale) = zoom_pan_scale(window, base, &axis_scale.get_untracked());
+            pan_x.set(pan);
+            scale_x.set(scale);
+        });
+    }
+
+    /// Updates the current drag (if any) with a new mouse position.
+    pub fn update(&self, mouse: (f64, f64)) {
+        if let Some(start) = self.pan.get_untracked() {
+            // Dragging moves the data with the mouse
+            let (dx, dy) = (mouse.0 - start.mouse.0, mouse.1 - start.mouse.1);
+            if start.axes.applies_x() {
+                self.pan_x.set(start.pan.0 - dx * start.per_px.0);
+            }
+            if start.axes.applies_y() {
+                self.pan_y.set(start.pan.1 - dy * start.per_px.1);
+            }
+        }
+        let Some(start) = self.start.get_untracked() else {
+            return;
+        };
+        if start.vertical {
+            // Dragging up zooms in
+            let dy = mouse.1 - start.mouse.1;
+            self.scale_y
+                .set(start.scale_y * (dy / start.inner_height).exp());
+        } else {
+            // Dragging right moves the data right
+            let dx = mouse.0 - start.mouse.0;
+            self.pan_x.set(start.pan_x - dx * start.x_per_px);
+        }
+    }
+
+    pub fn end(&self) {
+        self.start.set(None);
+        self.pan.set(None);
+    }
+
+    /// Updates a two-finger touch gesture. Starts a new gesture if there isn't one. Moving the touches apart zooms in around the point between them while moving them together pans.
+    pub fn pinch(
+        &self,
+        touches: [(f64, f64); 2],
+        gestures: ZoomAxes,
+        proj: &Projection,
+        inner: Bounds,
+    ) {
+        let [(x1, y1), (x2, y2)] = touches;
+        let centre = ((x1 + x2) / 2.0, (y1 + y2) / 2.0);
+        let spread = ((x1 - x2).abs(), (y1 - y2).abs());
+        let Some(start) = self.pinch.get_untracked() else {
+            let origin = proj.svg_to_scaled(0.0, 0.0);
+            let unit = proj.svg_to_scaled(1.0, 1.0);
+            self.pinch.set(Some(PinchStart {
+                centre,
+                spread,
+                pan: (self.pan_x.get_untracked(), self.pan_y.get_untracked()),
+                scale: (self.scale_x.get_untracked(), self.scale_y.get_untracked()),
+                at: proj.svg_to_scaled(centre.0, centre.1),
+                inner_centre: proj.svg_to_scaled(inner.centre_x(), inner.centre_y()),
+                per_px: (unit.0 - origin.0, unit.1 - origin.1),
+            }));
+            return;
+        };
+        let (dx, dy) = (centre.0 - start.centre.0, centre.1 - start.centre.1);
+        if gestures.applies_x() {
+            let ratio = pinch_ratio(start.spread.0, spread.0).unwrap_or(1.0);
+            let (pan, scale) = pinch_pan_scale(
+                (start.pan.0, start.scale.0),
+                start.at.0,
+                start.inner_centre.0,
+                ratio,
+                dx * start.per_px.0,
+            );
+            self.pan_x.set(pan);
+            self.scale_x.set(scale);
+        }
+        if gestures.applies_y() {
+            let ratio = pinch_ratio(start.spread.1, spread.1).unwrap_or(1.0);
+            let (pan, scale) = pinch_pan_scale(
+                (start.pan.1, start.scale.1),
+                start.at.1,
+                start.inner_centre.1,
+                ratio,
+                dy * start.per_px.1,
+            );
+            self.pan_y.set(pan);
+            self.scale_y.set(scale);
+        }
+    }
+
+    /// Ends a touch gesture.
+    pub fn end_pinch(&self) {
+        if self.pinch.get_untracked().is_some() {
+            self.pinch.set(None);
+        }
+    }
+
+    /// Resets the axis of the given edge.
+    pub fn reset(&self, edge: Edge) {
+        if edge.is_vertical() {
+            self.pan_y.set(0.0);
+            self.scale_y.set(1.0);
+        } else {
+            self.pan_x.set(0.0);
+            self.scale_x.set(1.0);
+        }
+    }
+}
+
+/// Applies `f` to (min, max) positions in the axis scale's space. Unchanged if either has no value on the scale.
+fn transform(
+    (min, max): (f64, f64),
+    axis_scale: &AxisScale,
+    f: impl FnOnce(f64, f64) -> (f64, f64),
+) -> (f64, f64) {
+    let (low, high) = (axis_scale.apply(min), axis_scale.apply(max));
+    if !(low.is_finite() && high.is_finite()) {
+        return (min, max);
+    }
+    let (low, high) = f(low, high);
+    (axis_scale.invert(low), axis_scale.invert(high))
+}
+
+fn pan(min: f64, max: f64, offset: f64) -> (f64, f64) {
+    (min + offset, max + offset)
+}
+
+/// Ratio to scale a range by as touches move from `start` to `now` pixels apart. Moving apart zooms in (a smaller range). None if the touches are too close on this axis.
+fn pinch_ratio(start: f64, now: f64) -> Option<f64> {
+    (start >= MIN_PINCH_SPREAD && now >= MIN_PINCH_SPREAD).then(|| start / now)
+}
+
+/// Pan and scale after zooming by `factor` around the position `at`. The visible range's `centre` moves towards `at` so it stays at the same place on screen.
+fn zoom_about((pan, scale): (f64, f64), at: f64, centre: f64, factor: f64) -> (f64, f64) {
+    (pan + (at - centre) * (1.0 - factor), scale * factor)
+}
+
+/// Pan and scale of a pinch: zooms by `ratio` around the touches' starting centre `at` then pans by how far the centre has `moved` (in scaled positions at the start). Moves are scaled by the zoom so the point under the touches follows them.
+fn pinch_pan_scale(start: (f64, f64), at: f64, centre: f64, ratio: f64, moved: f64) -> (f64, f64) {
+    let (pan, scale) = zoom_about(start, at, centre, ratio);
+    (pan - moved * ratio, scale)
+}
+
+/// Factor to zoom a `scale` by after clamping the result. One (no zoom) when already at a limit.
+fn clamp_factor(scale: f64, factor: f64, clamp: impl Fn(f64) -> f64) -> f64 {
+    if scale <= 0.0 {
+        return factor;
+    }
+    let clamped = clamp(scale * factor);
+    // Don't jump to a limit from the other side of it e.g., after setting the window directly
+    if (factor < 1.0 && clamped > scale) || (factor > 1.0 && clamped < scale) {
+        return 1.0;
+    }
+    clamped / scale
+}
+
+/// Pan and scale that show a `window` (min, max) of the `base` range.
+fn window_pan_scale(window: (f64, f64), base: (f64, f64)) -> (f64, f64) {
+    let width = base.1 - base.0;
+    let scale = if width == 0.0 {
+        1.0
+    } else {
+        (window.1 - window.0) / width
+    };
+    let pan = (window.0 + window.1) / 2.0 - (base.0 + base.1) / 2.0;
+    (pan, scale)
+}
+
+/// Pan and scale that show a `window` (min, max) of the `base` range in positions. Works on the axis scale. No zoom if either is missing or off the scale.
+fn zoom_pan_scale(
+    window: Option<(f64, f64)>,
+    base: Option<(f64, f64)>,
+    axis_scale: &AxisScale,
+) -> (f64, f64) {
+    let scaled = |(min, max): (f64, f64)| {
+        let (min, max) = (axis_scale.apply(min), axis_scale.apply(max));
+        (min.is_finite() && max.is_finite()).then_some((min, max))
+    };
+    match (window.and_then(scaled), base.and_then(scaled)) {
+        (Some(window), Some(base)) => window_pan_scale(window, base),
+        _ => (0.0, 1.0),
+    }
+}
+
+fn rescale(min: f64, max: f64, factor: f64) -> (f64, f64) {
+    let centre = (min + max) / 2.0;
+    let half = (max - min) / 2.0 * factor;
+    (centre - half, centre + half)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pan() {
+        assert_eq!(pan(1.0, 5.0, 0.0), (1.0, 5.0));
+        assert_eq!(pan(1.0, 5.0, -2.0), (-1.0, 3.0));
+    }
+
+    #[test]
+    fn test_pinch_ratio() {
+        assert_eq!(pinch_ratio(100.0, 200.0), Some(0.5));
+        assert_eq!(pinch_ratio(100.0, 50.0), Some(2.0));
+        assert_eq!(pinch_ratio(10.0, 200.0), None);
+        assert_eq!(pinch_ratio(100.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_zoom_about() {
+        // Range of 0 to 10: zoom in on 2 by half
+        let (offset, scale) = zoom_about((0.0, 1.0), 2.0, 5.0, 0.5);
+        assert_eq!((offset, scale), (-1.5, 0.5));
+        // The zoomed point keeps its place: 20% along the range
+        let (min, max) = rescale(0.0, 10.0, scale);
+        assert_eq!(pan(min, max, offset), (1.0, 6.0));
+    }
+
+    #[test]
+    fn test_pinch_pan_scale() {
+        // Without moving: the same as zooming about the touches
+        assert_eq!(
+            pinch_pan_scale((0.0, 1.0), 2.0, 5.0, 0.5, 0.0),
+            zoom_about((0.0, 1.0), 2.0, 5.0, 0.5)
+        );
+        // Moving pans by the zoomed distance
+        assert_eq!(pinch_pan_scale((0.0, 1.0), 2.0, 5.0, 0.5, 2.0), (-2.5, 0.5));
+        // No zoom just pans
+        assert_eq!(pinch_pan_scale((1.0, 2.0), 2.0, 5.0, 1.0, 3.0), (-2.0, 2.0));
+    }
+
+    #[test]
+    fn test_zoom_limits() {
+        let limits = ZoomLimits::new().with_min_samples(10).with_max_scale(1.0);
+        assert_eq!(limits.clamp_scale(0.5, 101), 0.5);
+        assert_eq!(limits.clamp_scale(0.01, 101), 0.09);
+        assert_eq!(limits.clamp_scale(2.0, 101), 1.0);
+        // Fewer samples than the minimum shows everything
+        assert_eq!(limits.clamp_scale(0.5, 5), 1.0);
+        assert_eq!(limits.clamp_scale(0.5, 1), 0.5);
+        assert_eq!(ZoomLimits::new().clamp_scale(0.01, 101), 0.01);
+    }
+
+    #[test]
+    fn test_clamp_factor() {
+        let clamp = |scale: f64| scale.clamp(0.25, 1.0);
+        assert_eq!(clamp_factor(1.0, 0.5, clamp), 0.5);
+        assert_eq!(clamp_factor(0.4, 0.5, clamp), 0.625);
+        // At a limit: no more zooming
+        assert_eq!(clamp_factor(0.25, 0.5, clamp), 1.0);
+        assert_eq!(clamp_factor(1.0, 2.0, clamp), 1.0);
+        // Past a limit: zooming back towards it is fine, further away is not
+        assert_eq!(clamp_factor(2.0, 0.5, clamp), 0.5);
+        assert_eq!(clamp_factor(2.0, 2.0, clamp), 1.0);
+    }
+
+    #[test]
+    fn test_window_pan_scale() {
+        assert_eq!(window_pan_scale((0.0, 10.0), (0.0, 10.0)), (0.0, 1.0));
+        assert_eq!(window_pan_scale((6.0, 8.0), (0.0, 10.0)), (2.0, 0.2));
+        assert_eq!(window_pan_scale((1.0, 3.0), (5.0, 5.0)), (-3.0, 1.0));
+    }
+
+    #[test]
+    fn test_zoom_pan_scale() {
+        let linear = AxisScale::default();
+        let base = Some((0.0, 10.0));
+        assert_eq!(zoom_pan_scale(Some((6.0, 8.0)), base, &linear), (2.0, 0.2));
+        assert_eq!(zoom_pan_scale(None, base, &linear), (0.0, 1.0));
+        assert_eq!(zoom_pan_scale(Some((6.0, 8.0)), None, &linear), (0.0, 1.0));
+        // Applying the pan and scale to the base shows the window on any scale
+        let log = AxisScale::new(crate::LogScale::default());
+        let base = (1.0, 10_000.0);
+        let (pan_by, scale) = zoom_pan_scale(Some((10.0, 100.0)), Some(base), &log);
+        let (min, max) = transform(base, &log, |min, max| {
+            let (min, max) = rescale(min, max, scale);
+            pan(min, max, pan_by)
+        });
+        assert!((min - 10.0).abs() < 1e-9 && (max - 100.0).abs() < 1e-9);
+        // Off the scale
+        assert_eq!(
+            zoom_pan_scale(Some((-1.0, 100.0)), Some(base), &log),
+            (0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let linear = AxisScale::default();
+        let zoom = |min, max| rescale(min, max, 0.5);
+        assert_eq!(transform((0.0, 10.0), &linear, zoom), (2.5, 7.5));
+        // Zooming a log scale keeps the ratio
+        let log = AxisScale::new(crate::LogScale::default());
+        let (min, max) = transform((1.0, 10_000.0), &log, zoom);
+        assert!((min - 10.0).abs() < 1e-9 && (max - 1000.0).abs() < 1e-9);
+        let (min, max) = transform((1.0, 100.0), &log, |min, max| pan(min, max, 1.0));
+        assert!((min - 10.0).abs() < 1e-9 && (max - 1000.0).abs() < 1e-9);
+        // No value on the scale
+        assert_eq!(transform((0.0, 10.0), &log, zoom), (0.0, 10.0));
+    }
+
+    #[test]
+    fn test_rescale() {
+        assert_eq!(rescale(0.0, 10.0, 1.0), (0.0, 10.0));
+        assert_eq!(rescale(0.0, 10.0, 0.5), (2.5, 7.5));
+        assert_eq!(rescale(0.0, 10.0, 2.0), (-5.0, 15.0));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{state::State, Tick, TickLabels};
+use leptos::prelude::*;
+
+/// Ticks generated for each axis of a chart. Lets apps render their own UI aligned with the chart e.g., a header row of dates above it.
+///
+/// Ticks are generated from the given [TickLabels]. Pass the same tick labels used by the chart's edges to get matching ticks. Positions are SVG coordinates relative to the chart's top-left corner.
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y: f64 }
+/// # #[component]
+/// # fn HeaderChart(data: Signal<Vec<MyData>>) -> impl IntoView {
+/// let x_ticks = TickLabels::aligned_floats();
+/// let ticks = AxisTicks::from_labels(x_ticks.clone(), TickLabels::aligned_floats());
+/// let header = ticks.x;
+/// view! {
+///     <div style="position: relative; height: 1em;">
+///         <For each=move || header.get() key=|(x, _)| x.to_string() let:tick>
+///             <span style="position: absolute;" style:left=format!("{}px", tick.1)>
+///                 {tick.0}
+///             </span>
+///         </For>
+///     </div>
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         bottom=x_ticks
+///         series=Series::new(|d: &MyData| d.x).line(|d: &MyData| d.y)
+///         data=data
+///         ticks=ticks
+///     />
+/// }
+/// # }
+/// ```
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub struct AxisTicks<X: Tick, Y: Tick> {
+    /// Generates the X ticks.
+    pub x_labels: TickLabels<X>,
+    /// Generates the Y ticks.
+    pub y_labels: TickLabels<Y>,
+    /// Generated X ticks and their horizontal position on the chart.
+    pub x: RwSignal<Vec<(X, f64)>>,
+    /// Generated Y ticks and their vertical position on the chart.
+    pub y: RwSignal<Vec<(Y, f64)>>,
+}
+
+impl<X: Tick, Y: Tick> Clone for AxisTicks<X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            x_labels: self.x_labels.clone(),
+            y_labels: self.y_labels.clone(),
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> Default for AxisTicks<X, Y> {
+    fn default() -> Self {
+        Self::from_labels(TickLabels::default(), TickLabels::default())
+    }
+}
+
+impl<X: Tick, Y: Tick> AxisTicks<X, Y> {
+    /// Creates new axis ticks using the default tick labels for X and Y.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates new axis ticks generated from the given tick labels.
+    pub fn from_labels(
+        x_labels: impl Into<TickLabels<X>>,
+        y_labels: impl Into<TickLabels<Y>>,
+    ) -> Self {
+        Self {
+            x_labels: x_labels.into(),
+            y_labels: y_labels.into(),
+            x: RwSignal::default(),
+            y: RwSignal::default(),
+        }
+    }
+
+    /// Keeps the ticks up to date with a chart's state.
+    pub(crate) fn track(&self, state: &State<X, Y>) {
+        let inner = state.layout.inner;
+        let proj = state.projection;
+        let avail_width = Signal::derive(move || inner.read().width());
+        let avail_height = Signal::derive(move || inner.read().height());
+        let gen_x = self.x_labels.generate_x(&state.pre, avail_width);
+        let gen_y = self.y_labels.generate_y(&state.pre, avail_height);
+        let (x, y) = (self.x, self.y);
+        Effect::new(move |_| {
+            let proj = proj.get();
+            let ticks = gen_x.read().ticks.clone();
+            x.set(
+                (ticks.into_iter())
+                    .map(|tick| {
+                        let pos = proj.position_to_svg(tick.position(), 0.0).0;
+                        (tick, pos)
+                    })
+                    .collect(),
+            );
+        });
+        Effect::new(move |_| {
+            let proj = proj.get();
+            let ticks = gen_y.read().ticks.clone();
+            y.set(
+                (ticks.into_iter())
+                    .map(|tick| {
+                        let pos = proj.position_to_svg(0.0, tick.position()).1;
+                        (tick, pos)
+                    })
+                    .collect(),
+            );
+        });
+    }
+}
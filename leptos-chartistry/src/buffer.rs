@@ -0,0 +1,128 @@
+use leptos::prelude::*;
+
+/// Bounded data for a [Chart](crate::Chart) e.g., a kiosk dashboard streaming data for days. Keeps the newest `capacity` items and drops the rest so memory stays bounded.
+///
+/// Once full, each push drops the oldest item so the chart always shows the newest `capacity` items. Pair with a [live window](crate::Series::with_live_window) to show a fixed span instead of a fixed count.
+///
+/// The chart rebuilds its data from every item on each push unless the series is [append only](crate::Series::with_append_only). Then only new items are processed and trimmed items are dropped from the front.
+///
+/// Pass to a chart's `data` prop:
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct Sample { at: f64, value: f64 }
+/// # #[component]
+/// # fn Kiosk() -> impl IntoView {
+/// let buffer = ChartBuffer::new(10_000);
+/// // e.g., from a websocket
+/// buffer.push(Sample { at: 1.0, value: 42.0 });
+/// view! {
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         series=Series::new(|s: &Sample| s.at)
+///             .line(|s: &Sample| s.value)
+///             .with_append_only(true)
+///         data=buffer
+///     />
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChartBuffer<T: Send + Sync + 'static> {
+    // Charts read data as a contiguous slice so the window slides along a Vec rather than wrapping around
+    data: RwSignal<Vec<T>>,
+    capacity: usize,
+}
+
+impl<T: Send + Sync + 'static> ChartBuffer<T> {
+    /// Creates an empty buffer keeping the newest `capacity` items. A capacity of zero is treated as one.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            data: RwSignal::new(Vec::with_capacity(capacity)),
+            capacity,
+        }
+    }
+
+    /// Number of newest items the buffer keeps.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of items currently held. Between zero and the capacity.
+    pub fn len(&self) -> usize {
+        self.data.with_untracked(Vec::len)
+    }
+
+    /// Returns true if the buffer holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends an item, dropping the oldest if full. Must be sorted after existing items.
+    pub fn push(&self, item: T) {
+        let capacity = self.capacity;
+        self.data.update(|data| {
+            data.push(item);
+            trim(data, capacity);
+        });
+    }
+
+    /// Appends items, dropping the oldest beyond the capacity. Must be sorted after existing items.
+    pub fn extend(&self, items: impl IntoIterator<Item = T>) {
+        let capacity = self.capacity;
+        self.data.update(|data| {
+            data.extend(items);
+            trim(data, capacity);
+        });
+    }
+
+    /// Removes all items.
+    pub fn clear(&self) {
+        self.data.update(Vec::clear);
+    }
+}
+
+impl<T: Send + Sync + 'static> Clone for ChartBuffer<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for ChartBuffer<T> {}
+
+impl<T: Send + Sync + 'static> From<ChartBuffer<T>> for Signal<Vec<T>> {
+    fn from(buffer: ChartBuffer<T>) -> Self {
+        buffer.data.into()
+    }
+}
+
+/// Drops the oldest items beyond `capacity`.
+fn trim<T>(data: &mut Vec<T>, capacity: usize) {
+    if data.len() > capacity {
+        data.drain(..data.len() - capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim() {
+        let mut data = (0..3).collect::<Vec<_>>();
+        trim(&mut data, 3);
+        assert_eq!(data, vec![0, 1, 2]);
+        // One in, one out
+        data.push(3);
+        trim(&mut data, 3);
+        assert_eq!(data, vec![1, 2, 3]);
+        data.push(4);
+        trim(&mut data, 3);
+        assert_eq!(data, vec![2, 3, 4]);
+        let mut data = (0..10).collect::<Vec<_>>();
+        trim(&mut data, 2);
+        assert_eq!(data, vec![8, 9]);
+    }
+}
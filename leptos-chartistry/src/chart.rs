@@ -1,20 +1,32 @@
 use crate::{
     aspect_ratio::KnownAspectRatio,
+    axis_drag::{AxisDrag, ZoomAxes, ZoomClamp, ZoomLimits},
+    axis_ticks::AxisTicks,
     debug::DebugRect,
-    inner::InnerLayout,
-    layout::{EdgeLayout, Layout},
+    diagnostics::{diagnose, Diagnostics},
+    export::{ChartHandle, ExportRef},
+    inner::{InnerLayout, UseInner},
+    layout::{overflow::EdgeOverflow, EdgeLayout, Layout},
     overlay::tooltip::Tooltip,
     projection::Projection,
-    series::{RenderData, UseData},
+    selection::{RenderSelection, Selection, SelectionMode},
+    series::{Range, RenderData, UseData},
+    shared::SharedRangeX,
+    snippets::SeriesSnippets,
     state::{PreState, State},
-    use_watched_node::{use_watched_node, UseWatchedNode},
-    AspectRatio, Padding, Series, Tick,
+    use_watched_node::{use_seen, use_watched_node, UseWatchedNode},
+    view_state::ViewState,
+    window_scroll::WindowScroll,
+    AspectRatio, AxisScale, Messages, Padding, PixelScale, Series, Tick, YAxis,
 };
-use leptos::{html::Div, prelude::*};
+use leptos::{either::Either, html::Div, prelude::*};
 
 pub const FONT_HEIGHT: f64 = 16.0;
 pub const FONT_WIDTH: f64 = 10.0;
 
+// Distance (in pixels) from a marker within which it can be dragged
+const EDIT_RADIUS: f64 = 8.0;
+
 /// Renders an SVG chart.
 ///
 /// Check the required and optional props list near the bottom for a quick overview.
@@ -103,15 +115,25 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     #[prop(into)]
     aspect_ratio: Signal<AspectRatio>,
 
-    /// The height of the font used in the chart. Passed to [SVG text](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/text). Default is 16.
+    /// The height of the font used in the chart. Passed to [SVG text](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/text). Default is 16 multiplied by `pixel_scale`.
     #[prop(into, optional)]
     font_height: Option<Signal<f64>>,
 
-    /// The width must be the exact width of a monospaced character in the font used. Along with font_height, it is used to calculate the dimensions of text. These dimensions are then fed into layout composition to render the chart. The default is 10.
+    /// The width must be the exact width of a monospaced character in the font used. Along with font_height, it is used to calculate the dimensions of text. These dimensions are then fed into layout composition to render the chart. The default is 10 multiplied by `pixel_scale`.
     #[prop(into, optional)]
     font_width: Option<Signal<f64>>,
 
-    /// Debug mode. If enabled shows lines around components and prints render info to the console. Useful for getting an idea of how the chart is rendering itself. Below is an example of how you might use it in development. Default is false.
+    /// Scales stroke widths, marker diameters and the default font size e.g., for high-DPI displays or exporting at 2x. See [PixelScale] for details. Default is no scaling.
+    #[prop(into, optional)]
+    pixel_scale: Signal<PixelScale>,
+
+    /// Font (height, width) used for layout and tick generation on the server and the first client render. Keeps server and hydrated markup identical. Once hydrated, the chart lays out again using `font_height`, `font_width` and `pixel_scale`. Default is `font_height` and `font_width` if given, otherwise the unscaled defaults (16, 10).
+    ///
+    /// Only charts with a known [AspectRatio] (not from the environment) are rendered on the server.
+    #[prop(into, optional)]
+    ssr_font_metrics: Option<Signal<(f64, f64)>>,
+
+    /// Debug mode. If enabled shows lines around components and prints render info to the console. Useful for getting an idea of how the chart is rendering itself. Also lists likely misconfigurations e.g., unsorted data or duplicate series names and logs them as warnings. Below is an example of how you might use it in development. Default is false.
     ///
     /// ```rust
     /// # use leptos::prelude::*;
@@ -145,6 +167,10 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     #[prop(into, optional)]
     padding: Option<Signal<Padding>>,
 
+    /// What happens to edge components when the chart is too small to fit them. See [EdgeOverflow] for details. Default is [EdgeOverflow::Squeeze].
+    #[prop(into, optional)]
+    edge_overflow: Signal<EdgeOverflow>,
+
     /// Top edge components. See [IntoEdge](crate::IntoEdge) for details. Default is none.
     #[prop(into, optional)]
     top: Vec<EdgeLayout<X>>,
@@ -158,25 +184,99 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     #[prop(into, optional)]
     left: Vec<EdgeLayout<Y>>,
 
-    /// Inner chart area components. Does not render lines -- use [Series] for that. See [IntoInner](crate::IntoInner) for details. Default is none.
+    /// Inner chart area components. Does not render lines -- use [Series] for that. Drawn behind the series except for annotations ([DataLabel](struct@crate::DataLabel) and [Callout](struct@crate::Callout)) which are drawn over them. See [IntoInner](crate::IntoInner) for details. Default is none.
     #[prop(into, optional)]
     inner: Vec<InnerLayout<X, Y>>,
     /// Tooltip to show on mouse hover. See [Tooltip](crate::Tooltip) for details. Default is hidden.
     #[prop(into, optional)]
     tooltip: Tooltip<X, Y>,
 
+    /// Called when the mouse hovers over a new data point in the inner chart area. See [DataEvent] for details. Default is none.
+    #[prop(into, optional)]
+    on_hover: Option<Callback<DataEvent<X, Y>>>,
+    /// Called when the inner chart area is clicked. Passes the nearest data point. See [DataEvent] for details. Default is none.
+    #[prop(into, optional)]
+    on_click: Option<Callback<DataEvent<X, Y>>>,
+    /// How dragging the inner chart area selects data points: a rectangle or lasso. Takes over dragging from scrolling the X window. See [SelectionMode] for details. Default is [SelectionMode::None].
+    #[prop(into, optional)]
+    selection: Signal<SelectionMode>,
+    /// Called when a selection ends. Passes every data point where a visible series is inside the selection e.g., for labelling or flagging outliers. Default is none.
+    #[prop(into, optional)]
+    on_select: Option<Callback<Vec<DataEvent<X, Y>>>>,
+    /// Called while dragging a marker of an [editable](crate::Line::with_editable) line. Passes the point's new Y value to write back to the data. See [EditEvent] for details. Default is none.
+    #[prop(into, optional)]
+    on_edit: Option<Callback<EditEvent<Y>>>,
+
     /// Series to render. Maps `T` to lines, bars, etc. See [Series] for details.
     #[prop(into)]
     series: Series<T, X, Y>,
     /// Data to render. Must be sorted.
+    ///
+    /// Accepts anything that converts into a `Signal<Vec<T>>` e.g., a plain `Vec<T>`, `ReadSignal`, `RwSignal` or `Memo`. For async data (resources, server functions), see [use_loading_data] which keeps the last loaded data and tracks a `loading` state:
+    ///
+    /// ```rust
+    /// # use leptos::prelude::*;
+    /// # use leptos_chartistry::*;
+    /// # #[derive(Clone)]
+    /// # struct MyData { x: f64, y: f64 }
+    /// # #[component]
+    /// # fn AsyncChart(fetched: ReadSignal<Option<Vec<MyData>>>) -> impl IntoView {
+    /// // e.g., `fetched` could be `move || resource.get()`
+    /// let (data, loading) = use_loading_data(move || fetched.get());
+    /// view! {
+    ///     <Chart
+    ///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+    ///         series=Series::new(|d: &MyData| d.x).line(|d: &MyData| d.y)
+    ///         data=data
+    ///         loading=loading
+    ///     />
+    /// }
+    /// # }
+    /// ```
     #[prop(into)]
     data: Signal<Vec<T>>,
+    /// Interaction state (zoom window, hidden series) to keep across data refreshes and rebuilds. See [ViewState] for details. Default is a new state owned by the chart.
+    #[prop(into, optional)]
+    view_state: Option<ViewState>,
+    /// Exposes the chart's generated ticks for rendering aligned UI outside of the chart. See [AxisTicks] for details. Default is none.
+    #[prop(into, optional)]
+    ticks: Option<AxisTicks<X, Y>>,
+    /// Exposes the chart's series for rendering their snippets outside of the chart e.g., a custom legend. See [SeriesSnippets] for details. Default is none.
+    #[prop(into, optional)]
+    snippets: Option<SeriesSnippets<X, Y>>,
+    /// Exposes the rendered chart for saving as an SVG or PNG image. See [ChartHandle] for details. Default is none.
+    #[prop(into, optional)]
+    export: Option<ChartHandle>,
+    /// Which axes touch gestures apply to: pinch to zoom around the point between the touches and two-finger pan. Single touches still scroll the page. See [ZoomAxes] for details. Default is [ZoomAxes::None].
+    #[prop(into, optional)]
+    gestures: Signal<ZoomAxes>,
+    /// Which axes the inner chart area zooms and pans with the mouse: the wheel zooms around the cursor and dragging pans. Ticks follow the zoomed window. Double click an axis' [tick labels](crate::TickLabels::with_drag) or call [ViewState::reset] to reset. Ignored by series with a [window](Series::window_x) which scroll instead. Default is [ZoomAxes::None].
+    #[prop(into, optional)]
+    zoom: Signal<ZoomAxes>,
+    /// Limits how far the mouse wheel zooms the X axis e.g., never show fewer than 10 samples. See [ZoomLimits] for details. Default is no limits.
+    #[prop(into, optional)]
+    zoom_limits: Signal<ZoomLimits>,
+    /// Visible X range when zoomed or panned. `None` shows the whole X range. Updated as the chart is zoomed (by the mouse, touch or axis drag) and zooms the chart when set e.g., to sync with your own state.
+    #[prop(into, optional)]
+    zoom_x: Option<RwSignal<Option<(X, X)>>>,
+    /// Whether data is loading. Shows a loading message until there's data and dims the chart while refreshing. Default is false.
+    #[prop(into, optional)]
+    loading: Signal<bool>,
+    /// Defers building the chart until it scrolls into view. Renders an empty placeholder of the chart's size until then. Useful for dashboards with many charts. Read once when the chart is created. Default is false.
+    #[prop(into, optional)]
+    lazy: Signal<bool>,
+    /// Built-in text e.g., the loading message. See [Messages] for localising charts. Default is the [Messages] provided as context, otherwise English.
+    #[prop(into, optional)]
+    messages: Option<Messages>,
 ) -> impl IntoView {
     let root = NodeRef::<Div>::new();
     let watch = use_watched_node(root);
+    let seen = use_seen(root, lazy.get_untracked());
+    let deferred = move || !seen.get();
 
-    // Aspect ratio signal
-    let have_dimensions = Memo::new(move |_| watch.bounds.get().is_some());
+    // Aspect ratio signal. Known ratios don't need to measure the page e.g., on the server
+    let have_dimensions =
+        Memo::new(move |_| watch.bounds.get().is_some() || !aspect_ratio.get().is_env());
     let width = Memo::new(move |_| watch.bounds.get().unwrap_or_default().width());
     let height = Memo::new(move |_| watch.bounds.get().unwrap_or_default().height());
     let calc = AspectRatio::known_signal(aspect_ratio, width, height);
@@ -189,8 +289,40 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     };
 
     let debug = Memo::new(move |_| debug.get());
-    let font_height = Memo::new(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
-    let font_width = Memo::new(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
+    // Server and first client render use fixed metrics. Effects only run on the client once hydrated
+    let hydrated = RwSignal::new(false);
+    Effect::new(move |_| hydrated.set(true));
+    let ssr_font_metrics = Memo::new(move |_| {
+        ssr_font_metrics.map(|m| m.get()).unwrap_or_else(|| {
+            (
+                font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT),
+                font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH),
+            )
+        })
+    });
+    let scale = Memo::new(move |_| {
+        if hydrated.get() {
+            pixel_scale.get().factor()
+        } else {
+            1.0
+        }
+    });
+    let font_height = Memo::new(move |_| {
+        if !hydrated.get() {
+            return ssr_font_metrics.get().0;
+        }
+        font_height
+            .map(|f| f.get())
+            .unwrap_or_else(|| FONT_HEIGHT * scale.get())
+    });
+    let font_width = Memo::new(move |_| {
+        if !hydrated.get() {
+            return ssr_font_metrics.get().1;
+        }
+        font_width
+            .map(|f| f.get())
+            .unwrap_or_else(|| FONT_WIDTH * scale.get())
+    });
     let padding = Memo::new(move |_| {
         padding
             .map(|p| p.get())
@@ -203,9 +335,100 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
     top.reverse();
     left.reverse();
 
+    let messages = messages
+        .or_else(use_context::<Messages>)
+        .unwrap_or_default();
+
+    // View state: hidden series and axis drag (pan X and rescale Y)
+    let view_state = view_state.unwrap_or_default();
+
     // Build data
-    let data = UseData::new(series, data);
-    let pre = PreState::new(debug.into(), font_height, font_width, padding.into(), data);
+    let mut data = UseData::new(series, data, view_state.hidden);
+    // Share X range with other charts e.g., in a grid
+    if let Some(shared) = use_context::<SharedRangeX<X>>() {
+        data.range_x = shared.share(data.range_x);
+    }
+    data.scroll_window(view_state.scroll_x);
+    data.live_window();
+    // Range sliders select part of the X range
+    for edge in top.iter().chain(&bottom) {
+        if let EdgeLayout::RangeSlider(slider) = edge {
+            data.range_x = slider.apply(data.range_x);
+        }
+    }
+    let window_scroll = WindowScroll::new(view_state.scroll_x, data.window_x, data.len);
+    let axis_drag = AxisDrag::new(view_state);
+    let unzoomed_x = data.range_x;
+    data.range_x = axis_drag.apply_x(data.range_x, data.scale_x);
+    if let Some(zoom_x) = zoom_x {
+        axis_drag.sync_x(zoom_x, unzoomed_x, data.range_x, data.scale_x);
+    }
+    data.index_series();
+    data.range_y = axis_drag.apply_y(data.range_y, data.scale_y);
+    let pre = PreState::new(
+        debug.into(),
+        font_height,
+        font_width,
+        padding.into(),
+        scale,
+        data,
+        axis_drag,
+        messages,
+    );
+
+    // Warn about common misconfigurations in debug mode
+    let diagnostics = {
+        let (positions_x, series) = (pre.data.positions_x(), pre.data.series);
+        let sorted_x = pre.data.sorted_x;
+        let label_axes = (left.iter().chain(&right))
+            .filter_map(|edge| match edge {
+                EdgeLayout::TickLabels(labels) => Some(labels.y_axis),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        Memo::new(move |_| {
+            // Avoid scanning X values unless asked
+            if !debug.get() || deferred() {
+                return Vec::new();
+            }
+            // Stacked bars sharing a name (and colour) have one legend entry
+            let series = (series.get().into_iter())
+                .filter(|series| series.in_legend())
+                .map(|series| (series.name.get(), series.colour().get(), series.y_axis()))
+                .collect::<Vec<_>>();
+            let secondary_labels =
+                (label_axes.iter()).any(|y_axis| y_axis.get() == YAxis::Secondary);
+            let size = have_dimensions.get().then(|| calc.get().approx_size());
+            let sorted_x = sorted_x.get();
+            positions_x.with(|xs| diagnose(xs, sorted_x, &series, secondary_labels, size))
+        })
+    };
+    Effect::new(move |_| {
+        for warning in diagnostics.get() {
+            log::warn!("{}", warning);
+        }
+    });
+
+    // Show loading until we have data. Dim while refreshing
+    let data_len = pre.data.len;
+    let loading_text = messages.loading;
+    let show_chart =
+        move || !deferred() && have_dimensions.get() && !(loading.get() && data_len.get() == 0);
+    // Placeholder until in view. Avoids reading data so it isn't processed early
+    let fallback = move || {
+        if deferred() {
+            let size = move || calc.get().approx_size();
+            Either::Left(view! {
+                <div
+                    class="_chartistry_placeholder"
+                    style:width=move || format!("{}px", size().0)
+                    style:height=move || format!("{}px", size().1) />
+            })
+        } else {
+            Either::Right(view!(<p>{loading_text}</p>))
+        }
+    };
+    let opacity = move || if loading.get() { "0.5" } else { "1" };
 
     view! {
         <div
@@ -213,47 +436,117 @@ pub fn Chart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
             class="_chartistry"
             style:width=env_size
             style:height=env_size
-            style="overflow: visible;">
+            style:opacity=opacity
+            style="overflow: visible;"
+            aria-busy=move || loading.get().to_string()>
             <DebugRect label="Chart" debug=debug />
-            <Show when=move || have_dimensions.get() fallback=|| view!(<p>"Loading..."</p>)>
+            <Show when=move || debug.get()>
+                <Diagnostics warnings=diagnostics />
+            </Show>
+            <Show when=show_chart fallback=fallback>
                 <RenderChart
                     watch=watch.clone()
                     pre_state=pre.clone()
                     aspect_ratio=calc
+                    edge_overflow=edge_overflow
                     top=top.clone()
                     right=right.clone()
                     bottom=bottom.clone()
                     left=left.clone()
                     inner=inner.clone()
                     tooltip=tooltip.clone()
+                    on_hover=on_hover
+                    on_click=on_click
+                    selection=selection
+                    on_select=on_select
+                    on_edit=on_edit
+                    ticks=ticks.clone()
+                    snippets=snippets
+                    export=export
+                    gestures=gestures
+                    zoom=zoom
+                    zoom_limits=zoom_limits
+                    window_scroll=window_scroll
                 />
             </Show>
         </div>
     }
 }
 
+/// Describes the data point nearest to the mouse. Passed to a [Chart]'s `on_hover` and `on_click` callbacks.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct DataEvent<X, Y> {
+    /// Index of the data point in the chart's data.
+    pub index: usize,
+    /// X value of the data point.
+    pub x: X,
+    /// Y values of the data point for each visible series by [stable ID](crate::UseY::key). In series order.
+    pub y: Vec<(String, Y)>,
+    /// Metadata of the data point. Only set if the series has [metadata](Series::with_meta).
+    pub meta: Option<String>,
+}
+
+/// Describes an edited data point. Passed to a [Chart]'s `on_edit` callback.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct EditEvent<Y> {
+    /// Name of the edited series.
+    pub series: String,
+    /// Stable ID of the edited series. See [UseY::key](crate::UseY::key).
+    pub key: String,
+    /// Index of the data point in the chart's data.
+    pub index: usize,
+    /// New Y value of the data point.
+    pub y: Y,
+}
+
 #[component]
 fn RenderChart<X: Tick, Y: Tick>(
     watch: UseWatchedNode,
     pre_state: PreState<X, Y>,
     aspect_ratio: Memo<KnownAspectRatio>,
+    edge_overflow: Signal<EdgeOverflow>,
     top: Vec<EdgeLayout<X>>,
     right: Vec<EdgeLayout<Y>>,
     bottom: Vec<EdgeLayout<X>>,
     left: Vec<EdgeLayout<Y>>,
     inner: Vec<InnerLayout<X, Y>>,
     tooltip: Tooltip<X, Y>,
+    on_hover: Option<Callback<DataEvent<X, Y>>>,
+    on_click: Option<Callback<DataEvent<X, Y>>>,
+    selection: Signal<SelectionMode>,
+    on_select: Option<Callback<Vec<DataEvent<X, Y>>>>,
+    on_edit: Option<Callback<EditEvent<Y>>>,
+    ticks: Option<AxisTicks<X, Y>>,
+    snippets: Option<SeriesSnippets<X, Y>>,
+    export: Option<ChartHandle>,
+    gestures: Signal<ZoomAxes>,
+    zoom: Signal<ZoomAxes>,
+    zoom_limits: Signal<ZoomLimits>,
+    window_scroll: WindowScroll,
 ) -> impl IntoView {
     let debug = pre_state.debug;
 
-    // Compose edges
-    let (layout, edges) = Layout::compose(&top, &right, &bottom, &left, aspect_ratio, &pre_state);
+    // Compose edges. May expand the ranges to nice ticks
+    let mut pre_state = pre_state;
+    let (layout, edges) = Layout::compose(
+        &top,
+        &right,
+        &bottom,
+        &left,
+        aspect_ratio,
+        edge_overflow,
+        &mut pre_state,
+    );
 
     // Finalise state
-    let projection = {
-        let range_x = pre_state.data.range_x;
-        let range_y = pre_state.data.range_y;
-        let includes_bars = pre_state.data.includes_bars;
+    let mk_projection = |range_y: Memo<Range<Y>>,
+                         scale_y: Signal<AxisScale>,
+                         y_break: Signal<Option<(f64, f64)>>| {
+        let (range_x, scale_x) = (pre_state.data.range_x, pre_state.data.scale_x);
+        let (includes_bars, reversed_y) = (pre_state.data.includes_bars, pre_state.data.reversed_y);
+        let font_height = pre_state.font_height;
         Memo::new(move |_| {
             let mut inner = layout.inner.get();
             // If we include bars, shrink the sides by half the width of X
@@ -262,10 +555,204 @@ fn RenderChart<X: Tick, Y: Tick>(
                 inner = inner.shrink(0.0, half, 0.0, half);
             }
 
-            Projection::new(inner, range_x.get().positions(), range_y.get().positions())
+            // Leave room for tick labels either side of a Y break
+            let gap = font_height.get();
+            Projection::with_scales(
+                inner,
+                range_x.get().positions(),
+                range_y.get().positions(),
+                scale_x.get(),
+                scale_y.get(),
+            )
+            .with_y_break(y_break.get(), gap)
+            .with_reversed_y(reversed_y.get())
         })
     };
-    let state = State::new(pre_state, &watch, layout, projection);
+    let projection = mk_projection(
+        pre_state.data.range_y,
+        pre_state.data.scale_y.into(),
+        pre_state.data.y_break.into(),
+    );
+    let projection_secondary = mk_projection(
+        pre_state.data.range_y_secondary,
+        Signal::default(),
+        Signal::default(),
+    );
+    let state = State::new(pre_state, &watch, layout, projection, projection_secondary);
+    if let Some(ticks) = ticks {
+        ticks.track(&state);
+    }
+    if let Some(snippets) = snippets {
+        snippets.track(&state);
+    }
+
+    // Events
+    let hover_inner = state.hover_inner;
+    let nearest_event = state.pre.data.nearest_event(state.hover_position_x);
+    if let Some(on_hover) = on_hover {
+        Effect::new(move |_| {
+            if hover_inner.get() {
+                if let Some(event) = nearest_event.get() {
+                    on_hover.run(event);
+                }
+            }
+        });
+    }
+    let on_click = move |_| {
+        if let (Some(on_click), true) = (on_click, hover_inner.get_untracked()) {
+            if let Some(event) = nearest_event.get_untracked() {
+                on_click.run(event);
+            }
+        }
+    };
+
+    // Follow the mouse while dragging an axis
+    let axis_drag = state.pre.axis_drag;
+    let is_dragging = axis_drag.is_dragging();
+    let mouse_chart = state.mouse_chart;
+    Effect::new(move |_| {
+        if is_dragging.get() {
+            axis_drag.update(mouse_chart.get());
+        }
+    });
+
+    // Pinch and pan with two fingers
+    let (touches, inner_bounds) = (watch.touches, state.layout.inner);
+    Effect::new(move |_| {
+        let gestures = gestures.get();
+        match touches.get().as_slice() {
+            [first, second, ..] if gestures != ZoomAxes::None => axis_drag.pinch(
+                [*first, *second],
+                gestures,
+                &projection.get_untracked(),
+                inner_bounds.get_untracked(),
+            ),
+            _ => axis_drag.end_pinch(),
+        }
+    });
+    let touch_action = move || match gestures.get() {
+        ZoomAxes::None => "auto",
+        _ => "pan-x pan-y",
+    };
+
+    // Select points by dragging the inner area
+    let selection = Selection::new(selection);
+    let is_selecting = selection.is_selecting();
+    Effect::new(move |_| {
+        if is_selecting.get() {
+            selection.update(mouse_chart.get());
+        }
+    });
+    // Drag markers of editable lines
+    let editing = RwSignal::new(None::<(usize, usize)>);
+    let data = state.pre.data.clone();
+    let series = state.pre.data.series;
+    Effect::new(move |_| {
+        let (Some((id, index)), Some(on_edit)) = (editing.get(), on_edit) else {
+            return;
+        };
+        let Some(series) = (series.get_untracked().into_iter()).find(|series| series.id == id)
+        else {
+            return;
+        };
+        let proj = match untrack(|| series.y_axis()) {
+            YAxis::Secondary => projection_secondary.get_untracked(),
+            _ => projection.get_untracked(),
+        };
+        let (_, pos_y) = proj.svg_to_position(0.0, mouse_chart.get().1);
+        if let Some(y) = data.y_with_position(id, index, pos_y) {
+            let (name, key) = (series.name.get_untracked(), series.key());
+            on_edit.run(EditEvent {
+                series: name,
+                key,
+                index,
+                y,
+            });
+        }
+    });
+
+    let data = state.pre.data.clone();
+    let inner_drag = state.inner_drag;
+    let end_drag = Callback::new(move |()| {
+        editing.set(None);
+        inner_drag.set(None);
+        axis_drag.end();
+        window_scroll.end();
+        if let Some(polygon) = selection.end() {
+            if let Some(on_select) = on_select {
+                on_select.run(data.select_events(
+                    &polygon,
+                    &projection.get_untracked(),
+                    &projection_secondary.get_untracked(),
+                ));
+            }
+        }
+    });
+
+    // Scroll the X window with the mouse wheel or by dragging the inner area
+    let x_width = state.layout.x_width;
+    let is_scrolling = window_scroll.is_dragging();
+    Effect::new(move |_| {
+        if is_scrolling.get() {
+            window_scroll.update(mouse_chart.get().0, x_width.get_untracked());
+        }
+    });
+    let (inner_bounds, data_len) = (state.layout.inner, state.pre.data.len);
+    let on_wheel = move |ev: leptos::ev::WheelEvent| {
+        if !hover_inner.get_untracked() {
+            return;
+        }
+        let delta = if ev.delta_y() != 0.0 {
+            ev.delta_y()
+        } else {
+            ev.delta_x()
+        };
+        if window_scroll.is_enabled() {
+            ev.prevent_default();
+            window_scroll.scroll_by(delta.signum() as isize);
+        } else if zoom.get_untracked() != ZoomAxes::None && delta != 0.0 {
+            ev.prevent_default();
+            let clamp = ZoomClamp {
+                limits: zoom_limits.get_untracked(),
+                len: data_len.get_untracked(),
+            };
+            axis_drag.zoom_at(
+                mouse_chart.get_untracked(),
+                delta < 0.0,
+                zoom.get_untracked(),
+                clamp,
+                &projection.get_untracked(),
+                inner_bounds.get_untracked(),
+            );
+        }
+    };
+    let data_edit = state.pre.data.clone();
+    let on_mousedown = move |ev: leptos::ev::MouseEvent| {
+        // Only drag with the main button
+        if ev.button() != 0 || !hover_inner.get_untracked() {
+            return;
+        }
+        let mouse = mouse_chart.get_untracked();
+        let edit_point = on_edit.and_then(|_| {
+            data_edit.nearest_editable(
+                mouse,
+                &projection.get_untracked(),
+                &projection_secondary.get_untracked(),
+                EDIT_RADIUS,
+            )
+        });
+        if let Some(point) = edit_point {
+            editing.set(Some(point));
+        } else if selection.is_enabled() {
+            selection.start(mouse_chart.get_untracked());
+        } else if window_scroll.is_enabled() {
+            window_scroll.start(mouse_chart.get_untracked().0);
+        } else if zoom.get_untracked() != ZoomAxes::None {
+            axis_drag.start_pan(mouse, zoom.get_untracked(), &projection.get_untracked());
+        } else {
+            inner_drag.set(Some(mouse));
+        }
+    };
 
     // Render edges
     let edges = edges
@@ -273,24 +760,38 @@ fn RenderChart<X: Tick, Y: Tick>(
         .map(|r| r.render(state.clone()))
         .collect_view();
 
-    // Inner
-    let inner = inner
+    // Inner. Annotations are drawn over the series
+    let (overlay, inner): (Vec<_>, Vec<_>) = InnerLayout::use_all(inner, &state)
         .into_iter()
-        .map(|opt| opt.into_use(&state).render(state.clone()))
-        .collect_view();
+        .partition(UseInner::is_overlay);
+    let render_inner = |inner: Vec<UseInner<X, Y>>| {
+        (inner.into_iter())
+            .map(|opt| opt.render(state.clone()))
+            .collect_view()
+    };
+    let (inner, overlay) = (render_inner(inner), render_inner(overlay));
 
     let outer = state.layout.outer;
     view! {
         <svg
+            node_ref=ExportRef(export)
             width=move || format!("{}px", outer.get().width())
             height=move || format!("{}px", outer.get().height())
             viewBox=move || outer.with(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
-            style="display: block; overflow: visible;">
+            style="display: block; overflow: visible;"
+            style:touch-action=touch_action
+            on:click=on_click
+            on:wheel=on_wheel
+            on:mousedown=on_mousedown
+            on:mouseup=move |_| end_drag.run(())
+            on:mouseleave=move |_| end_drag.run(())>
             <DebugRect label="RenderChart" debug=debug bounds=vec![outer.into()] />
             <CommonDefs />
             {inner}
             {edges}
             <RenderData state=state.clone() />
+            {overlay}
+            <RenderSelection selection=selection state=state.clone() />
         </svg>
         <Tooltip tooltip=tooltip state=state />
     }
@@ -0,0 +1,47 @@
+pub use chartistry_core::colours::*;
+
+use leptos::prelude::*;
+
+#[component]
+pub fn LinearGradientSvg(
+    #[prop(into)] id: String,
+    scheme: Signal<ColourScheme>,
+    range_y: Signal<Option<(f64, f64)>>,
+    #[prop(into, optional)] steps: Signal<Option<usize>>,
+    /// SVG Y coords (bottom, top) of the range. Shapes using the gradient share it instead of each spanning their own bounding box e.g., a line split into several paths.
+    #[prop(into, optional)]
+    svg_y: Signal<Option<(f64, f64)>>,
+) -> impl IntoView {
+    let stops = move || {
+        let (scheme, range_y) = (scheme.get(), range_y.get().unwrap_or_default());
+        match steps.get() {
+            Some(bands) => scheme.stepped_gradient_stops(range_y, bands),
+            None => scheme.gradient_stops(range_y),
+        }
+        .into_iter()
+        .map(|(percent, colour)| {
+            // Format as a percentage (0% - 100%)
+            let offset = format!("{:.2}%", percent * 100.0);
+            view! {
+                <stop offset=offset stop-color=colour.to_string() />
+            }
+        })
+        .collect_view()
+    };
+    let units = move || svg_y.get().map(|_| "userSpaceOnUse");
+    let y1 = move || {
+        svg_y
+            .get()
+            .map_or("100%".to_string(), |(bottom, _)| bottom.to_string())
+    };
+    let y2 = move || {
+        svg_y
+            .get()
+            .map_or("0%".to_string(), |(_, top)| top.to_string())
+    };
+    view! {
+        <linearGradient id=Some(id) gradientUnits=units x1="0%" y1=y1 x2="0%" y2=y2>
+            {stops}
+        </linearGradient>
+    }
+}
@@ -0,0 +1,37 @@
+/// Number type written to SVG attributes. Positions are calculated as `f64` but written using this type. With the `f32` feature this is single precision: plenty for screen coordinates and produces shorter path strings.
+#[cfg(feature = "f32")]
+pub type SvgNum = f32;
+/// Number type written to SVG attributes.
+#[cfg(not(feature = "f32"))]
+pub type SvgNum = f64;
+
+/// Converts a position to the number written to SVG attributes.
+#[cfg(feature = "f32")]
+pub fn svg_num(v: f64) -> SvgNum {
+    v as f32
+}
+
+/// Converts a position to the number written to SVG attributes.
+#[cfg(not(feature = "f32"))]
+pub fn svg_num(v: f64) -> SvgNum {
+    v
+}
+
+/// Formats an SVG point as "x,y".
+pub fn svg_point((x, y): (f64, f64)) -> String {
+    format!("{},{}", svg_num(x), svg_num(y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_point() {
+        assert_eq!(svg_point((1.0, 2.5)), "1,2.5");
+        #[cfg(feature = "f32")]
+        assert_eq!(svg_point((0.1, 1.0 / 3.0)), "0.1,0.33333334");
+        #[cfg(not(feature = "f32"))]
+        assert_eq!(svg_point((0.1, 1.0 / 3.0)), "0.1,0.3333333333333333");
+    }
+}
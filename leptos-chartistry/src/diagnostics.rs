@@ -0,0 +1,110 @@
+use crate::{colours::Colour, YAxis};
+use leptos::prelude::*;
+
+/// Finds common misconfigurations of a chart: unsorted or NaN X values, series sharing a name or colour, series on the secondary Y axis without tick labels showing it and a zero size. Takes X positions in data order, whether they should be sorted, each legend entry's (name, colour, Y axis), whether any tick labels show the secondary Y axis and the chart's size if known. Returns a warning for each issue found.
+pub(crate) fn diagnose(
+    xs: &[f64],
+    sorted_x: bool,
+    series: &[(String, Colour, YAxis)],
+    secondary_labels: bool,
+    size: Option<(f64, f64)>,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    // X values
+    let nans = xs.iter().filter(|x| x.is_nan()).count();
+    if nans > 0 {
+        warnings.push(format!(
+            "{nans} X values are NaN. Hovering and ranges assume every X value is a number."
+        ));
+    }
+    let unsorted = sorted_x
+        && (xs.iter().filter(|x| !x.is_nan()))
+            .zip(xs.iter().filter(|x| !x.is_nan()).skip(1))
+            .any(|(a, b)| b < a);
+    if unsorted {
+        warnings.push("Data is not sorted by X. Sort it before passing it to the chart.".into());
+    }
+
+    // Series
+    for (index, (name, colour, y_axis)) in series.iter().enumerate() {
+        let earlier = &series[..index];
+        if earlier.iter().any(|(other, _, _)| other == name) {
+            warnings.push(format!(
+                "More than one series is named `{name}`. Legends and tooltips can't tell them apart."
+            ));
+        }
+        if let Some((other, _, _)) = earlier.iter().find(|(_, other, _)| other == colour) {
+            warnings.push(format!(
+                "Series `{other}` and `{name}` share the colour {colour}. Add colours to the scheme or set one on the series."
+            ));
+        }
+        if *y_axis == YAxis::Secondary && !secondary_labels {
+            warnings.push(format!(
+                "Series `{name}` is on the secondary Y axis but no tick labels show it. Add `TickLabels::with_y_axis(YAxis::Secondary)` to an edge."
+            ));
+        }
+    }
+
+    // Size
+    if let Some((width, height)) = size.filter(|&(w, h)| !(w > 0.0 && h > 0.0)) {
+        warnings.push(format!(
+            "Chart has a zero size ({width} x {height}). Check its aspect ratio or the size of its container."
+        ));
+    }
+    warnings
+}
+
+/// Lists warnings from [diagnose] over the chart in debug mode.
+#[component]
+pub(crate) fn Diagnostics(warnings: Memo<Vec<String>>) -> impl IntoView {
+    view! {
+        <Show when=move || warnings.with(|warnings| !warnings.is_empty())>
+            <ul
+                class="_chartistry_diagnostics"
+                style="margin: 0; color: red; font-family: monospace;">
+                {move || warnings.get().into_iter().map(|warning| view!(<li>{warning}</li>)).collect_view()}
+            </ul>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Colour = Colour::from_rgb(0xFF, 0x00, 0x00);
+    const BLUE: Colour = Colour::from_rgb(0x00, 0x00, 0xFF);
+
+    fn series(name: &str, colour: Colour, y_axis: YAxis) -> (String, Colour, YAxis) {
+        (name.to_string(), colour, y_axis)
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let ok = [
+            series("a", RED, YAxis::Primary),
+            series("b", BLUE, YAxis::Primary),
+        ];
+        assert!(diagnose(&[1.0, 2.0, 2.0, 3.0], true, &ok, false, Some((100.0, 50.0))).is_empty());
+        assert!(diagnose(&[], true, &[], false, None).is_empty());
+        // X values
+        assert_eq!(
+            diagnose(&[1.0, f64::NAN, 3.0], true, &ok, false, None).len(),
+            1
+        );
+        assert_eq!(diagnose(&[1.0, 3.0, 2.0], true, &ok, false, None).len(), 1);
+        assert!(diagnose(&[1.0, 3.0, 2.0], false, &ok, false, None).is_empty());
+        // Series
+        let dupes = [
+            series("a", RED, YAxis::Primary),
+            series("a", RED, YAxis::Primary),
+        ];
+        assert_eq!(diagnose(&[], true, &dupes, false, None).len(), 2);
+        let secondary = [series("a", RED, YAxis::Secondary)];
+        assert_eq!(diagnose(&[], true, &secondary, false, None).len(), 1);
+        assert!(diagnose(&[], true, &secondary, true, None).is_empty());
+        // Size
+        assert_eq!(diagnose(&[], true, &[], false, Some((0.0, 50.0))).len(), 1);
+    }
+}
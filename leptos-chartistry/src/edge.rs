@@ -1,3 +1,4 @@
+use crate::bounds::Bounds;
 use std::str::FromStr;
 
 /// Identifies a rectangle edge.
@@ -26,6 +27,37 @@ impl Edge {
     pub fn is_vertical(&self) -> bool {
         !self.is_horizontal()
     }
+
+    /// Returns the edge on the other side of the rectangle.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Right => Self::Left,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Returns a strip of `size` along this edge of the outer bounds e.g., where an edge layout or inset legend is drawn. Clamped to the outer bounds.
+    pub fn inset(&self, outer: Bounds, size: f64) -> Bounds {
+        let (width, height) = (outer.width() - size, outer.height() - size);
+        match self {
+            Self::Top => outer.shrink(0.0, 0.0, height, 0.0),
+            Self::Right => outer.shrink(0.0, 0.0, 0.0, width),
+            Self::Bottom => outer.shrink(height, 0.0, 0.0, 0.0),
+            Self::Left => outer.shrink(0.0, width, 0.0, 0.0),
+        }
+    }
+
+    /// Shrinks the outer bounds by `size` from this edge only e.g., to leave a gap. Clamped to the outer bounds.
+    pub fn shrink(&self, outer: Bounds, size: f64) -> Bounds {
+        match self {
+            Self::Top => outer.shrink(size, 0.0, 0.0, 0.0),
+            Self::Right => outer.shrink(0.0, size, 0.0, 0.0),
+            Self::Bottom => outer.shrink(0.0, 0.0, size, 0.0),
+            Self::Left => outer.shrink(0.0, 0.0, 0.0, size),
+        }
+    }
 }
 
 impl std::fmt::Display for Edge {
@@ -52,3 +84,54 @@ impl FromStr for Edge {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inset() {
+        let outer = Bounds::new(100.0, 50.0);
+        assert_eq!(
+            Edge::Top.inset(outer, 10.0),
+            Bounds::from_points(0.0, 0.0, 100.0, 10.0)
+        );
+        assert_eq!(
+            Edge::Right.inset(outer, 10.0),
+            Bounds::from_points(90.0, 0.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            Edge::Bottom.inset(outer, 10.0),
+            Bounds::from_points(0.0, 40.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            Edge::Left.inset(outer, 10.0),
+            Bounds::from_points(0.0, 0.0, 10.0, 50.0)
+        );
+        // Clamped
+        assert_eq!(Edge::Top.inset(outer, 80.0), outer);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let outer = Bounds::new(100.0, 50.0);
+        assert_eq!(
+            Edge::Top.shrink(outer, 10.0),
+            Bounds::from_points(0.0, 10.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            Edge::Right.shrink(outer, 10.0),
+            Bounds::from_points(0.0, 0.0, 90.0, 50.0)
+        );
+        assert_eq!(
+            Edge::Bottom.shrink(outer, 10.0),
+            Bounds::from_points(0.0, 0.0, 100.0, 40.0)
+        );
+        assert_eq!(
+            Edge::Left.shrink(outer, 10.0),
+            Bounds::from_points(10.0, 0.0, 100.0, 50.0)
+        );
+        assert_eq!(Edge::Top.opposite(), Edge::Bottom);
+        assert_eq!(Edge::Left.opposite(), Edge::Right);
+    }
+}
@@ -0,0 +1,246 @@
+use js_sys::Array;
+use leptos::{prelude::*, svg::Svg, tachys::html::node_ref::NodeRefContainer};
+use std::time::Duration;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{
+    Blob, BlobPropertyBag, CanvasRenderingContext2d, Element, HtmlAnchorElement, HtmlCanvasElement,
+    HtmlImageElement, Url, XmlSerializer,
+};
+
+// Time before an SVG download's object URL is revoked. Revoking straight away can cancel the download
+const REVOKE_DELAY: Duration = Duration::from_secs(1);
+
+// Presentation properties copied onto each element. Enough for stylesheets that target the chart
+const INLINE_PROPERTIES: &[&str] = &[
+    "fill",
+    "fill-opacity",
+    "stroke",
+    "stroke-width",
+    "stroke-opacity",
+    "stroke-dasharray",
+    "opacity",
+    "visibility",
+    "font-family",
+    "font-size",
+    "font-weight",
+    "font-style",
+];
+
+/// Handle to a rendered chart for saving it as an image e.g., a "save as image" button on a dashboard.
+///
+/// Pass to a [Chart](crate::Chart) via its `export` prop. Once the chart renders, [ChartHandle::export_svg] returns a standalone SVG and the `download_*` methods trigger a browser download. Computed styles are inlined so the image matches what's on the page. Tooltips are not part of the image. Does nothing on the server.
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y: f64 }
+/// # #[component]
+/// # fn ExportChart(data: Signal<Vec<MyData>>) -> impl IntoView {
+/// let handle = ChartHandle::new();
+/// view! {
+///     <button on:click=move |_| handle.download_png("chart.png", 2.0)>"Save as PNG"</button>
+///     <button on:click=move |_| handle.download_svg("chart.svg")>"Save as SVG"</button>
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         series=Series::new(|d: &MyData| d.x).line(|d: &MyData| d.y)
+///         data=data
+///         export=handle
+///     />
+/// }
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct ChartHandle {
+    pub(crate) svg: NodeRef<Svg>,
+}
+
+impl ChartHandle {
+    /// Creates a new handle. Empty until passed to a rendered chart.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serialises the rendered chart as a standalone SVG document with computed styles inlined. Returns `None` if the chart hasn't rendered.
+    pub fn export_svg(&self) -> Option<String> {
+        let svg: Element = self.svg.get_untracked()?.into();
+        let copy = svg
+            .clone_node_with_deep(true)
+            .ok()?
+            .dyn_into::<Element>()
+            .ok()?;
+        inline_styles(&svg, &copy);
+        XmlSerializer::new().ok()?.serialize_to_string(&copy).ok()
+    }
+
+    /// Downloads the rendered chart as an SVG file. Does nothing if the chart hasn't rendered.
+    pub fn download_svg(&self, filename: &str) {
+        if let Some(url) = self.export_svg().and_then(|svg| svg_url(&svg)) {
+            download(&url, filename);
+            set_timeout(
+                move || {
+                    let _ = Url::revoke_object_url(&url);
+                },
+                REVOKE_DELAY,
+            );
+        }
+    }
+
+    /// Downloads the rendered chart as a PNG file. `scale` multiplies the chart's size e.g., 2.0 for high-DPI screens. Does nothing if the chart hasn't rendered.
+    ///
+    /// The image is drawn asynchronously so the download starts shortly after this call.
+    pub fn download_png(&self, filename: &str, scale: f64) {
+        let Some(svg) = self.svg.get_untracked() else {
+            return;
+        };
+        let rect = svg.get_bounding_client_rect();
+        let (width, height) = (
+            png_size(rect.width(), scale),
+            png_size(rect.height(), scale),
+        );
+        let Some(url) = self.export_svg().and_then(|svg| svg_url(&svg)) else {
+            return;
+        };
+        let Ok(image) = HtmlImageElement::new() else {
+            return;
+        };
+
+        // Draw the SVG on to a canvas once loaded
+        let filename = filename.to_string();
+        let (loaded, loaded_url) = (image.clone(), url.clone());
+        let onload = Closure::once_into_js(move || {
+            let _ = Url::revoke_object_url(&loaded_url);
+            let canvas = document()
+                .create_element("canvas")
+                .ok()
+                .and_then(|canvas| canvas.dyn_into::<HtmlCanvasElement>().ok());
+            let Some(canvas) = canvas else {
+                return;
+            };
+            canvas.set_width(width);
+            canvas.set_height(height);
+            let context = canvas
+                .get_context("2d")
+                .ok()
+                .flatten()
+                .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok());
+            let Some(context) = context else {
+                return;
+            };
+            let _ = context.draw_image_with_html_image_element_and_dw_and_dh(
+                &loaded,
+                0.0,
+                0.0,
+                width as f64,
+                height as f64,
+            );
+            if let Ok(png) = canvas.to_data_url_with_type("image/png") {
+                download(&png, &filename);
+            }
+        });
+        image.set_onload(Some(onload.unchecked_ref()));
+        image.set_src(&url);
+    }
+}
+
+/// Fills a chart's [ChartHandle] (if any) with its SVG element. Charts without a handle don't hold a node reference.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ExportRef(pub Option<ChartHandle>);
+
+impl NodeRefContainer<Svg> for ExportRef {
+    fn load(self, el: &Element) {
+        if let Some(handle) = self.0 {
+            handle.svg.load(el);
+        }
+    }
+}
+
+// Copies computed styles from the rendered elements on to their copies
+fn inline_styles(rendered: &Element, copy: &Element) {
+    let (Ok(rendered_all), Ok(copy_all)) = (
+        rendered.query_selector_all("*"),
+        copy.query_selector_all("*"),
+    ) else {
+        return;
+    };
+    let pairs = (0..rendered_all.length())
+        .filter_map(|i| Some((rendered_all.get(i)?, copy_all.get(i)?)))
+        .filter_map(|(r, c)| Some((r.dyn_into::<Element>().ok()?, c.dyn_into::<Element>().ok()?)));
+    for (rendered, copy) in std::iter::once((rendered.clone(), copy.clone())).chain(pairs) {
+        let Ok(Some(computed)) = window().get_computed_style(&rendered) else {
+            continue;
+        };
+        let properties = INLINE_PROPERTIES
+            .iter()
+            .filter_map(|&name| Some((name, computed.get_property_value(name).ok()?)))
+            .collect::<Vec<_>>();
+        let existing = copy.get_attribute("style").unwrap_or_default();
+        let _ = copy.set_attribute("style", &style_attr(&existing, &properties));
+    }
+}
+
+// Appends properties to an existing style attribute. Existing (inline) styles take precedence
+fn style_attr(existing: &str, properties: &[(&str, String)]) -> String {
+    let mut style = properties
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| format!("{name}: {value};"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let existing = existing.trim();
+    if !existing.is_empty() {
+        if !style.is_empty() {
+            style.push(' ');
+        }
+        style.push_str(existing);
+    }
+    style
+}
+
+fn png_size(size: f64, scale: f64) -> u32 {
+    (size * scale).round().max(1.0) as u32
+}
+
+fn svg_url(svg: &str) -> Option<String> {
+    let parts = Array::of1(&svg.into());
+    let options = BlobPropertyBag::new();
+    options.set_type("image/svg+xml;charset=utf-8");
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options).ok()?;
+    Url::create_object_url_with_blob(&blob).ok()
+}
+
+// Clicks a temporary link to download the URL
+fn download(url: &str, filename: &str) {
+    let link = document()
+        .create_element("a")
+        .ok()
+        .and_then(|link| link.dyn_into::<HtmlAnchorElement>().ok());
+    if let Some(link) = link {
+        link.set_href(url);
+        link.set_download(filename);
+        link.click();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_attr() {
+        let props = [("fill", "red".to_string()), ("stroke", String::new())];
+        assert_eq!(style_attr("", &props), "fill: red;");
+        assert_eq!(
+            style_attr(" opacity: 0.5; ", &props),
+            "fill: red; opacity: 0.5;"
+        );
+        assert_eq!(style_attr("opacity: 0.5;", &[]), "opacity: 0.5;");
+    }
+
+    #[test]
+    fn test_png_size() {
+        assert_eq!(png_size(300.4, 1.0), 300);
+        assert_eq!(png_size(300.0, 2.0), 600);
+        assert_eq!(png_size(0.0, 2.0), 1);
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{
+    colours::ColourScheme,
+    debug::DebugRect,
+    layout::tick_labels::{value_format, value_format_by},
+    overlay::tooltip::{announcement, live_region, place_left_of, viewport, TOOLTIP_STYLE},
+    series::stand_in_bar,
+    Tooltip, TooltipCtx, TooltipPlacement, TooltipValue, AXIS_MARKER_COLOUR, SERIES_COLOUR_SCHEME,
+};
+use leptos::{either::Either, html::Aside, portal::Portal, prelude::*};
+use std::sync::Arc;
+
+/// Default gap (in pixels) between funnel stages.
+pub const FUNNEL_GAP: f64 = 2.0;
+
+/// Describes a funnel: ordered stages with decreasing values e.g., visitors, sign ups then purchases. Drawn by [FunnelChart].
+///
+/// Each stage is a centred trapezoid as wide as its value at the top narrowing to the next stage's value at the bottom. Labels show the value and its share of the first stage.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Stage { name: String, users: f64 }
+/// let funnel = Funnel::new(|s: &Stage| s.name.clone(), |s: &Stage| s.users).with_gap(4.0);
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Funnel<T> {
+    get_label: Arc<dyn Fn(&T) -> String + Send + Sync>,
+    get_value: Arc<dyn Fn(&T) -> f64 + Send + Sync>,
+    /// Colour scheme of the stages. If there are more stages than colours, the colours will repeat. Default is [SERIES_COLOUR_SCHEME].
+    pub colours: RwSignal<ColourScheme>,
+    /// Gap (in pixels) between stages. Default is [FUNNEL_GAP].
+    pub gap: RwSignal<f64>,
+}
+
+impl<T> Funnel<T> {
+    /// Creates a new funnel. `get_label` names a stage and `get_value` returns its size. Stages are drawn in data order from the top. Negative and NaN values are drawn as zero.
+    pub fn new(
+        get_label: impl Fn(&T) -> String + Send + Sync + 'static,
+        get_value: impl Fn(&T) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            get_label: Arc::new(get_label),
+            get_value: Arc::new(get_value),
+            colours: RwSignal::new(SERIES_COLOUR_SCHEME.into()),
+            gap: RwSignal::new(FUNNEL_GAP),
+        }
+    }
+
+    /// Sets the colour scheme of the stages.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+
+    /// Sets the gap between stages.
+    pub fn with_gap(self, gap: impl Into<f64>) -> Self {
+        self.gap.set(gap.into());
+        self
+    }
+}
+
+/// Corners of each stage's trapezoid: top left, top right, bottom right and bottom left. Stages are stacked from the top of a `width` by `height` area with `gap` between them. The widest value spans the full width and the last stage keeps its width to the bottom.
+fn trapezoids(values: &[f64], width: f64, height: f64, gap: f64) -> Vec<[(f64, f64); 4]> {
+    let values = (values.iter())
+        .map(|&value| if value > 0.0 { value } else { 0.0 })
+        .collect::<Vec<_>>();
+    let max = values.iter().copied().fold(0.0, f64::max);
+    let count = values.len() as f64;
+    let stage_height = ((height - gap * (count - 1.0)) / count).max(0.0);
+    let half_width = |value: f64| {
+        if max > 0.0 {
+            value / max * width / 2.0
+        } else {
+            0.0
+        }
+    };
+    let centre = width / 2.0;
+    (values.iter().enumerate())
+        .map(|(index, &value)| {
+            let next = values.get(index + 1).copied().unwrap_or(value);
+            let (top, bottom) = (half_width(value), half_width(next));
+            let top_y = index as f64 * (stage_height + gap);
+            let bottom_y = top_y + stage_height;
+            [
+                (centre - top, top_y),
+                (centre + top, top_y),
+                (centre + bottom, bottom_y),
+                (centre - bottom, bottom_y),
+            ]
+        })
+        .collect()
+}
+
+/// Share of the first stage as a percentage e.g., "25.0%". Empty if the first stage is zero.
+fn share(value: f64, first: f64) -> String {
+    if first > 0.0 {
+        format!("{:.1}%", value / first * 100.0)
+    } else {
+        String::new()
+    }
+}
+
+/// Renders a [Funnel] in SVG. Each stage is labelled with its name, value and share of the first stage. Values are formatted like tick labels. Hovering a stage shows a [Tooltip].
+#[component]
+pub fn FunnelChart<T: Send + Sync + 'static>(
+    /// Describes the funnel.
+    funnel: Funnel<T>,
+    /// Stages of the funnel in order.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+    /// Width of the chart in pixels.
+    #[prop(into)]
+    width: Signal<f64>,
+    /// Height of the chart in pixels.
+    #[prop(into)]
+    height: Signal<f64>,
+    /// Debug mode. See [Chart](crate::Chart) for details.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+    /// Tooltip shown when hovering a stage. The stage's index is the X value and its name the X text. Its one value is the stage's, formatted by the tooltip's Y ticks and followed by its share of the first stage. Shows a [custom](Tooltip::custom) view and announces stages to screen readers like a chart's tooltip. Placements other than hidden show left of the cursor. Default is [Tooltip::left_cursor].
+    #[prop(into, optional)]
+    tooltip: Option<Tooltip<f64, f64>>,
+) -> impl IntoView {
+    let Funnel {
+        get_label,
+        get_value,
+        colours,
+        gap,
+    } = funnel;
+    let Tooltip {
+        placement,
+        cursor_distance,
+        y_ticks,
+        y_format,
+        portal,
+        custom,
+        live,
+        ..
+    } = tooltip.unwrap_or_else(Tooltip::left_cursor);
+    // Hovered stage and the mouse's page coords
+    let hovered = RwSignal::new(None::<usize>);
+    let mouse_page = RwSignal::new((0.0, 0.0));
+
+    let stages = Memo::new(move |_| {
+        data.with(|data| {
+            (data.iter())
+                .map(|datum| (get_label(datum), get_value(datum)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let render = move || {
+        let stages = stages.get();
+        let values = stages.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+        let first = values.first().copied().unwrap_or_default();
+        let format = value_format(&values);
+        let shapes = trapezoids(&values, width.get(), height.get(), gap.get());
+        let colours = colours.get();
+        (stages.into_iter().zip(shapes).enumerate())
+            .map(|(index, ((label, value), corners))| {
+                let points = (corners.iter())
+                    .map(|(x, y)| format!("{x},{y}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let colour = colours.by_index(index);
+                let text = format!("{label}: {} {}", format.format(&value), share(value, first))
+                    .trim_end()
+                    .to_string();
+                let [(_, top_y), _, _, (_, bottom_y)] = corners;
+                view! {
+                    <g class="_chartistry_funnel_stage">
+                        <polygon
+                            points=points
+                            fill=colour.to_string()
+                            on:mouseenter=move |_| hovered.set(Some(index))
+                            on:mousemove=move |ev| {
+                                mouse_page.set((ev.page_x() as f64, ev.page_y() as f64))
+                            }
+                            on:mouseleave=move |_| hovered.set(None) />
+                        <text
+                            x=width.get() / 2.0
+                            y=(top_y + bottom_y) / 2.0
+                            text-anchor="middle"
+                            dominant-baseline="middle">
+                            {text}
+                        </text>
+                    </g>
+                }
+            })
+            .collect_view()
+    };
+
+    // Hovered stage given to the tooltip
+    let ctx = Memo::new(move |_| {
+        let index = hovered.get()?;
+        stages.with(|stages| {
+            let (label, value) = stages.get(index).cloned()?;
+            let values = stages.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+            let colour = colours.get().by_index(index);
+            let series = stand_in_bar(index, label.clone(), colour);
+            let value_text = match y_format.get() {
+                Some(format) => (format)(&series, &value),
+                None => {
+                    let format = value_format_by(y_ticks.generator.get().as_ref(), &values);
+                    (y_ticks.format.get())(&value, format.as_ref())
+                }
+            };
+            let first = values.first().copied().unwrap_or_default();
+            let text = format!("{value_text} {}", share(value, first))
+                .trim_end()
+                .to_string();
+            Some(TooltipCtx {
+                x: Some(index as f64),
+                x_text: label,
+                meta: None,
+                values: vec![TooltipValue {
+                    series,
+                    colour,
+                    value: Some(value),
+                    text,
+                    greyed: false,
+                }],
+            })
+        })
+    });
+
+    // Tooltip position on the page: (left, top). Flips and shifts to stay within the viewport
+    let aside = NodeRef::<Aside>::new();
+    let position = Memo::new(move |_| {
+        let size = aside
+            .get()
+            .map(|aside| (aside.offset_width() as f64, aside.offset_height() as f64))
+            .unwrap_or_default();
+        place_left_of(mouse_page.get(), size, cursor_distance.get(), viewport())
+    });
+
+    let body = move || {
+        view! {
+            <Show when=move || placement.get() != TooltipPlacement::Hide && ctx.with(Option::is_some)>
+                <aside
+                    node_ref=aside
+                    class="_chartistry_tooltip"
+                    style=TOOLTIP_STYLE
+                    style:border=format!("1px solid {}", AXIS_MARKER_COLOUR)
+                    style:left=move || format!("{}px", position.get().0)
+                    style:top=move || format!("{}px", position.get().1)
+                    style:padding="0.25em 0.5em">
+                    {move || ctx.get().map(|ctx| match custom.get() {
+                        Some(custom) => Either::Left(custom(ctx)),
+                        None => Either::Right(view! {
+                            <h2 style="margin: 0; text-align: center; font-size: inherit;">
+                                {ctx.x_text}
+                            </h2>
+                            {ctx.values.into_iter().map(|value| view! {
+                                <p style="margin: 0; text-align: right;">
+                                    <span style:color=value.colour.to_string()>"■ "</span>
+                                    {value.text}
+                                </p>
+                            }).collect_view()}
+                        }),
+                    })}
+                </aside>
+            </Show>
+        }
+    };
+    let tooltip = move || {
+        if portal.get() {
+            Either::Left(view! { <Portal>{body}</Portal> })
+        } else {
+            Either::Right(body())
+        }
+    };
+
+    // Mirror the hovered stage into a live region
+    let live_text = move || {
+        if placement.get() == TooltipPlacement::Hide {
+            return String::new();
+        }
+        ctx.with(|ctx| {
+            ctx.as_ref().map_or_else(String::new, |ctx| {
+                let rows = (ctx.values.iter())
+                    .map(|value| (ctx.x_text.clone(), value.text.clone()))
+                    .collect::<Vec<_>>();
+                announcement("", &rows)
+            })
+        })
+    };
+
+    view! {
+        <svg
+            class="_chartistry_funnel"
+            width=move || width.get()
+            height=move || height.get()
+            viewBox=move || format!("0 0 {} {}", width.get(), height.get())
+            font-family="monospace">
+            <DebugRect label="Funnel" debug=debug />
+            {render}
+        </svg>
+        {tooltip}
+        {live_region(live, live_text)}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapezoids() {
+        let shapes = trapezoids(&[100.0, 50.0, f64::NAN], 200.0, 100.0, 5.0);
+        assert_eq!(
+            shapes,
+            vec![
+                [(0.0, 0.0), (200.0, 0.0), (150.0, 30.0), (50.0, 30.0)],
+                [(50.0, 35.0), (150.0, 35.0), (100.0, 65.0), (100.0, 65.0)],
+                [(100.0, 70.0), (100.0, 70.0), (100.0, 100.0), (100.0, 100.0)],
+            ]
+        );
+        assert!(trapezoids(&[], 200.0, 100.0, 5.0).is_empty());
+        // All zero
+        let shapes = trapezoids(&[0.0], 200.0, 100.0, 5.0);
+        assert_eq!(shapes[0][0], (100.0, 0.0));
+    }
+
+    #[test]
+    fn test_share() {
+        assert_eq!(share(25.0, 100.0), "25.0%");
+        assert_eq!(share(25.0, 0.0), "");
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    shared::{SharedLeftWidth, SharedRangeX},
+    AspectRatio, EdgeLayout, IntoEdge, Legend, Preset, PresetChart, Tick,
+};
+use leptos::prelude::*;
+
+/// Lays out a column of charts that share the same data. A common monitoring layout e.g., CPU, memory and network usage over the same time period.
+///
+/// Charts in the grid:
+/// - share the same X domain: the X range is the union of every chart's range,
+/// - align their left edges so that inner plot areas line up vertically,
+/// - share a single legend shown above the first chart. Any legends in the presets are removed. Charts are expected to use the same series names and colours e.g., one line per host.
+///
+/// # Example
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct Usage { x: f64, cpu: f64, mem: f64 }
+/// # fn example(data: Signal<Vec<Usage>>) -> impl IntoView {
+/// view! {
+///     <ChartGrid
+///         aspect_ratio=AspectRatio::from_outer_height(150.0, 4.0)
+///         legend=Legend::end()
+///         charts=vec![
+///             LineChart::new(|u: &Usage| u.x, [Line::new(|u: &Usage| u.cpu).with_name("cpu")]),
+///             LineChart::new(|u: &Usage| u.x, [Line::new(|u: &Usage| u.mem).with_name("mem")]),
+///         ]
+///         data=data
+///     />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn ChartGrid<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+    /// Determines the width and height of each chart. See [Chart](crate::Chart) for details.
+    #[prop(into)]
+    aspect_ratio: Signal<AspectRatio>,
+    /// Debug mode. See [Chart](crate::Chart) for details.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+    /// Optional legend shared by all charts. Shown above the first chart.
+    #[prop(into, optional)]
+    legend: Option<Legend>,
+    /// Charts to render, top to bottom.
+    charts: Vec<Preset<T, X, Y>>,
+    /// Data shared by all charts.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    provide_context(SharedLeftWidth::default());
+    provide_context(SharedRangeX::<X>::default());
+
+    let charts = charts
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut preset)| {
+            // Replace individual legends with the shared legend
+            let is_legend = |edge: &EdgeLayout<X>| matches!(edge, EdgeLayout::Legend(_));
+            preset.top.retain(|edge| !is_legend(edge));
+            preset.bottom.retain(|edge| !is_legend(edge));
+            if let (0, Some(legend)) = (index, legend.clone()) {
+                preset.top.insert(0, legend.into_edge());
+            }
+            view! {
+                <PresetChart aspect_ratio=aspect_ratio debug=debug preset=preset data=data />
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="_chartistry_grid" style="display: flex; flex-direction: column;">
+            {charts}
+        </div>
+    }
+}
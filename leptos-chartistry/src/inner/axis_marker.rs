@@ -35,6 +35,10 @@ pub enum AxisPlacement {
     HorizontalZero,
     /// Vertical zero line (if present).
     VerticalZero,
+    /// Vertical line at an X position (if present). See [AxisMarker::at_x].
+    AtX(f64),
+    /// Horizontal line at a Y position (if present) e.g., a reference value. See [AxisMarker::at_y].
+    AtY(f64),
 }
 
 impl AxisMarker {
@@ -72,6 +76,15 @@ impl AxisMarker {
         Self::new(AxisPlacement::VerticalZero)
     }
 
+    /// New axis marker on a vertical line at the given X value (if present).
+    pub fn at_x(x: impl Tick) -> Self {
+        Self::new(AxisPlacement::AtX(x.position()))
+    }
+    /// New axis marker on a horizontal line at the given Y value (if present) e.g., pH 7.
+    pub fn at_y(y: impl Tick) -> Self {
+        Self::new(AxisPlacement::AtY(y.position()))
+    }
+
     /// Sets the arrow visibility.
     pub fn with_arrow(self, arrow: impl Into<bool>) -> Self {
         self.arrow.set(arrow.into());
@@ -95,6 +108,8 @@ impl std::fmt::Display for AxisPlacement {
             P::Left => write!(f, "left"),
             P::HorizontalZero => write!(f, "horizontal zero"),
             P::VerticalZero => write!(f, "vertical zero"),
+            P::AtX(x) => write!(f, "at x {x}"),
+            P::AtY(y) => write!(f, "at y {y}"),
         }
     }
 }
@@ -104,7 +119,19 @@ impl FromStr for AxisPlacement {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use AxisPlacement::*;
-        match s.to_lowercase().as_str() {
+        let s = s.to_lowercase();
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("unknown axis placement: `{}`", s))
+        };
+        if let Some(x) = s.strip_prefix("at x") {
+            return parse(x).map(AtX);
+        } else if let Some(y) = s.strip_prefix("at y") {
+            return parse(y).map(AtY);
+        }
+        match s.as_str() {
             "top" => Ok(Top),
             "right" => Ok(Right),
             "bottom" => Ok(Bottom),
@@ -124,6 +151,7 @@ pub(super) fn AxisMarker<X: Tick, Y: Tick>(
     let debug = state.pre.debug;
     let zero = state.svg_zero;
     let inner = state.layout.inner;
+    let proj = state.projection;
 
     let pos = Memo::new(move |_| {
         let inner = inner.get();
@@ -141,6 +169,14 @@ pub(super) fn AxisMarker<X: Tick, Y: Tick>(
             AxisPlacement::Right => (right, bottom, right, top),
             AxisPlacement::HorizontalZero => (left, zero_y, right, zero_y),
             AxisPlacement::VerticalZero => (zero_x, bottom, zero_x, top),
+            AxisPlacement::AtX(x) => {
+                let (x, _) = proj.get().position_to_svg(x, 0.0);
+                (x, bottom, x, top)
+            }
+            AxisPlacement::AtY(y) => {
+                let (_, y) = proj.get().position_to_svg(0.0, y);
+                (left, y, right, y)
+            }
         };
         let in_bounds = inner.contains(x1, y1) && inner.contains(x2, y2);
         (in_bounds, coords)
@@ -162,11 +198,12 @@ pub(super) fn AxisMarker<X: Tick, Y: Tick>(
 
     let colour = marker.colour;
     let colour = move || colour.get().to_string();
+    let (width, scale) = (marker.width, state.pre.scale);
     view! {
         <g
             class="_chartistry_axis_marker"
             stroke=colour
-            stroke-width=marker.width>
+            stroke-width=move || width.get() * scale.get()>
             <Show when=move || in_bounds.get() >
                 <DebugRect label="axis_marker" debug=debug />
                 <line
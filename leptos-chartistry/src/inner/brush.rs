@@ -0,0 +1,170 @@
+use crate::{colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::prelude::*;
+
+/// Default colour for brushes.
+pub const BRUSH_COLOUR: Colour = Colour::from_rgb(0x12, 0xA5, 0xED);
+
+type OnSelect<X> = Callback<Option<(X, X)>>;
+
+// Drags narrower than this (in pixels) are clicks which clear the selection
+const MIN_BRUSH_WIDTH: f64 = 3.0;
+
+/// Builds a brush: drag across the inner chart area to select an X range e.g., to fetch higher resolution data for that window. Draws a translucent band over the selection. Click without dragging to clear it.
+///
+/// Only drags with the main mouse button that aren't taken by other drags of the inner chart area are used: a [Chart](crate::Chart)'s `selection`, `zoom` or a [window](crate::Series::window_x) take priority.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let brush = Brush::<f64>::new().with_on_select(|range| {
+///     if let Some((start, end)) = range {
+///         // Fetch data between start and end
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Brush<X: Tick> {
+    /// Selected X range. `None` when there is no selection. Set it to draw a selection or clear it.
+    pub selected: RwSignal<Option<(X, X)>>,
+    /// Colour of the selection. Default is [BRUSH_COLOUR].
+    pub colour: RwSignal<Colour>,
+    /// Opacity of the selection from 0.0 to 1.0. Default is 0.2.
+    pub opacity: RwSignal<f64>,
+    /// Called with the selected X range when a drag ends or `None` when cleared. Default is none.
+    pub on_select: RwSignal<Option<OnSelect<X>>>,
+}
+
+impl<X: Tick> Brush<X> {
+    /// Creates a new brush with no selection.
+    pub fn new() -> Self {
+        Self {
+            selected: RwSignal::default(),
+            colour: RwSignal::new(BRUSH_COLOUR),
+            opacity: RwSignal::new(0.2),
+            on_select: RwSignal::default(),
+        }
+    }
+
+    /// Sets the colour of the selection.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sets the opacity of the selection from 0.0 to 1.0.
+    pub fn with_opacity(self, opacity: f64) -> Self {
+        self.opacity.set(opacity);
+        self
+    }
+
+    /// Sets a callback for when the selection changes by dragging or clearing.
+    pub fn with_on_select(
+        self,
+        on_select: impl Fn(Option<(X, X)>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_select.set(Some(Callback::new(on_select)));
+        self
+    }
+
+    /// Clears the selection.
+    pub fn clear(&self) {
+        self.selected.set(None);
+    }
+}
+
+impl<X: Tick> Default for Brush<X> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Orders two X positions. Returns `None` if they're too close together to be a drag.
+fn brush_span(start: f64, end: f64, min_width: f64) -> Option<(f64, f64)> {
+    ((end - start).abs() >= min_width).then(|| (start.min(end), start.max(end)))
+}
+
+#[component]
+pub(super) fn Brush<X: Tick, Y: Tick>(brush: Brush<X>, state: State<X, Y>) -> impl IntoView {
+    let Brush {
+        selected,
+        colour,
+        opacity,
+        on_select,
+    } = brush;
+    let debug = state.pre.debug;
+    let (inner, proj) = (state.layout.inner, state.projection);
+    let (mouse_chart, inner_drag) = (state.mouse_chart, state.inner_drag);
+    let range_x = state.pre.data.range_x;
+
+    // SVG X of an in-progress drag. Started and ended by the chart
+    let start = Memo::new(move |_| inner_drag.get().map(|(x, _)| x));
+    Effect::new(move |prev: Option<Option<f64>>| {
+        let current = start.get();
+        // Select on drag end
+        let (Some(Some(start_x)), None) = (prev, current) else {
+            return current;
+        };
+        let inner = inner.get_untracked();
+        let end_x = mouse_chart.get_untracked().0;
+        let end_x = end_x.clamp(inner.left_x(), inner.right_x());
+        // Convert to X values using the range as a reference
+        let proj = proj.get_untracked();
+        let reference = range_x.with_untracked(|range| range.range().map(|(min, _)| min.clone()));
+        let range = brush_span(start_x, end_x, MIN_BRUSH_WIDTH)
+            .zip(reference)
+            .and_then(|((left, right), reference)| {
+                let left = reference.with_position(proj.svg_to_position(left, 0.0).0)?;
+                let right = reference.with_position(proj.svg_to_position(right, 0.0).0)?;
+                Some((left, right))
+            });
+        selected.set(range.clone());
+        if let Some(on_select) = on_select.get_untracked() {
+            on_select.run(range);
+        }
+        current
+    });
+
+    // Selection in SVG X coords: the drag in progress or the selected range
+    let span = Memo::new(move |_| {
+        if let Some(start) = start.get() {
+            let inner = inner.get();
+            let end = mouse_chart.get().0.clamp(inner.left_x(), inner.right_x());
+            return Some((start.min(end), start.max(end)));
+        }
+        let proj = proj.get();
+        selected.with(|selected| {
+            selected.as_ref().map(|(left, right)| {
+                let left = proj.position_to_svg(left.position(), 0.0).0;
+                let right = proj.position_to_svg(right.position(), 0.0).0;
+                (left.min(right), left.max(right))
+            })
+        })
+    });
+
+    view! {
+        <g class="_chartistry_brush" pointer-events="none">
+            <Show when=move || span.get().is_some()>
+                <DebugRect label="brush" debug=debug />
+                <rect
+                    x=move || span.get().map(|(left, _)| left)
+                    y=move || inner.get().top_y()
+                    width=move || span.get().map(|(left, right)| right - left)
+                    height=move || inner.get().height()
+                    fill=move || colour.get().to_string()
+                    fill-opacity=move || opacity.get().clamp(0.0, 1.0) />
+            </Show>
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brush_span() {
+        assert_eq!(brush_span(10.0, 40.0, 3.0), Some((10.0, 40.0)));
+        assert_eq!(brush_span(40.0, 10.0, 3.0), Some((10.0, 40.0)));
+        assert_eq!(brush_span(10.0, 12.0, 3.0), None);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::prelude::*;
+
+/// Default colour for callouts.
+pub const CALLOUT_COLOUR: Colour = Colour::from_rgb(0x44, 0x44, 0x44);
+
+// Length of the arrow head relative to the leader line width. See `marker_axis_arrow`
+const ARROW_LENGTH: f64 = 7.0;
+
+/// Builds a callout: a text box with a leader line pointing to a data point e.g., "pump failed here". The point is in data coordinates so the callout follows it as the chart is zoomed, scrolled or resized. The box is flipped or shifted to stay within the inner chart area and moved to another corner of the point to avoid callouts listed before it. Hidden when the point is outside the inner chart area.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let callout = Callout::new(12.0, 5.5, "pump failed").with_offset(-40.0, -30.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Callout {
+    /// X position of the point.
+    pub x: RwSignal<f64>,
+    /// Y position of the point.
+    pub y: RwSignal<f64>,
+    /// Text of the callout.
+    pub text: RwSignal<String>,
+    /// Offset in pixels from the point to the nearest corner of the text box. Default is up and to the right.
+    pub offset: RwSignal<(f64, f64)>,
+    /// Colour of the text, box border and leader line. Default is [CALLOUT_COLOUR].
+    pub colour: RwSignal<Colour>,
+    /// Width of the box border and leader line. Default is 1.0.
+    pub width: RwSignal<f64>,
+}
+
+impl Callout {
+    /// Creates a new callout pointing to the given X and Y values.
+    pub fn new(x: impl Tick, y: impl Tick, text: impl Into<String>) -> Self {
+        Self {
+            x: RwSignal::new(x.position()),
+            y: RwSignal::new(y.position()),
+            text: RwSignal::new(text.into()),
+            offset: RwSignal::new((30.0, -30.0)),
+            colour: RwSignal::new(CALLOUT_COLOUR),
+            width: RwSignal::new(1.0),
+        }
+    }
+
+    /// Sets the offset in pixels from the point to the text box. Negative X places the box to the left and negative Y above.
+    pub fn with_offset(self, x: f64, y: f64) -> Self {
+        self.offset.set((x, y));
+        self
+    }
+
+    /// Sets the colour of the text, box border and leader line.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+/// Places a box of `size` offset from a point. Flips the offset along an axis if the box would leave `inner` then shifts it to fit.
+fn place_box(
+    (x, y): (f64, f64),
+    (width, height): (f64, f64),
+    (dx, dy): (f64, f64),
+    inner: Bounds,
+) -> Bounds {
+    // Box extends away from the point along each offset
+    let start = |pos: f64, offset: f64, len: f64| {
+        if offset < 0.0 {
+            pos + offset - len
+        } else {
+            pos + offset
+        }
+    };
+    let fits = |start: f64, len: f64, min: f64, max: f64| start >= min && start + len <= max;
+    let mut left = start(x, dx, width);
+    if !fits(left, width, inner.left_x(), inner.right_x()) {
+        left = start(x, -dx, width);
+    }
+    let mut top = start(y, dy, height);
+    if !fits(top, height, inner.top_y(), inner.bottom_y()) {
+        top = start(y, -dy, height);
+    }
+    // Shift to fit. Prefers the top-left if it can't
+    let left = left.min(inner.right_x() - width).max(inner.left_x());
+    let top = top.min(inner.bottom_y() - height).max(inner.top_y());
+    Bounds::from_points(left, top, left + width, top + height)
+}
+
+/// Places a box like [place_box] but tries each corner of the point in turn to avoid the `placed` boxes. Falls back to the corner with the least overlap.
+fn place_box_avoiding(
+    point: (f64, f64),
+    size: (f64, f64),
+    (dx, dy): (f64, f64),
+    inner: Bounds,
+    placed: &[Bounds],
+) -> Bounds {
+    let overlap =
+        |rect: &Bounds| -> f64 { placed.iter().map(|other| overlap_area(rect, other)).sum() };
+    let candidates = [(dx, dy), (-dx, dy), (dx, -dy), (-dx, -dy)]
+        .map(|offset| place_box(point, size, offset, inner));
+    // Ties go to the earliest corner
+    (candidates.iter())
+        .min_by(|a, b| overlap(a).total_cmp(&overlap(b)))
+        .copied()
+        .unwrap_or(candidates[0])
+}
+
+/// Area of the intersection of two boxes.
+fn overlap_area(a: &Bounds, b: &Bounds) -> f64 {
+    let width = a.right_x().min(b.right_x()) - a.left_x().max(b.left_x());
+    let height = a.bottom_y().min(b.bottom_y()) - a.top_y().max(b.top_y());
+    width.max(0.0) * height.max(0.0)
+}
+
+/// Start of a leader line from a box to a point: the nearest point on the box's edge.
+fn leader_start(rect: Bounds, (x, y): (f64, f64)) -> (f64, f64) {
+    (
+        x.clamp(rect.left_x(), rect.right_x()),
+        y.clamp(rect.top_y(), rect.bottom_y()),
+    )
+}
+
+/// Moves `to` back towards `from` by `by` pixels. Leaves room for an arrow head.
+fn pull_back(from: (f64, f64), to: (f64, f64), by: f64) -> (f64, f64) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let len = dx.hypot(dy);
+    if len <= by {
+        return from;
+    }
+    let ratio = (len - by) / len;
+    (from.0 + dx * ratio, from.1 + dy * ratio)
+}
+
+#[derive(Clone)]
+pub struct UseCallout {
+    callout: Callout,
+    point: Memo<(f64, f64)>,
+    rect: Memo<Bounds>,
+    // Box while the callout is shown. Avoided by later callouts
+    placed: Memo<Option<Bounds>>,
+}
+
+impl Callout {
+    /// Places the callout avoiding the boxes of earlier callouts.
+    pub(super) fn use_callout<X: Tick, Y: Tick>(
+        self,
+        state: &State<X, Y>,
+        avoid: Vec<Memo<Option<Bounds>>>,
+    ) -> UseCallout {
+        let (x, y, text, offset) = (self.x, self.y, self.text, self.offset);
+        let font_height = state.pre.font_height;
+        let font_width = state.pre.font_width;
+        let inner = state.layout.inner;
+        let proj = state.projection;
+
+        let point = Memo::new(move |_| proj.get().position_to_svg(x.get(), y.get()));
+        let rect = Memo::new(move |_| {
+            let font_width = font_width.get();
+            let chars = text.with(|text| text.chars().count()) as f64;
+            let size = ((chars + 1.0) * font_width, font_height.get() + font_width);
+            let placed = avoid
+                .iter()
+                .filter_map(|rect| rect.get())
+                .collect::<Vec<_>>();
+            place_box_avoiding(point.get(), size, offset.get(), inner.get(), &placed)
+        });
+        let placed = Memo::new(move |_| {
+            let (x, y) = point.get();
+            inner.get().contains(x, y).then(|| rect.get())
+        });
+        UseCallout {
+            callout: self,
+            point,
+            rect,
+            placed,
+        }
+    }
+}
+
+impl UseCallout {
+    /// Box of the callout while it's shown.
+    pub(super) fn placed(&self) -> Memo<Option<Bounds>> {
+        self.placed
+    }
+}
+
+#[component]
+pub(super) fn Callout<X: Tick, Y: Tick>(callout: UseCallout, state: State<X, Y>) -> impl IntoView {
+    let UseCallout {
+        callout,
+        point,
+        rect,
+        placed,
+    } = callout;
+    let Callout {
+        text,
+        colour,
+        width,
+        ..
+    } = callout;
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let scale = state.pre.scale;
+    let in_bounds = Memo::new(move |_| placed.get().is_some());
+    let width = Signal::derive(move || width.get() * scale.get());
+    let leader = Memo::new(move |_| {
+        let point = point.get();
+        let start = leader_start(rect.get(), point);
+        (start, pull_back(start, point, ARROW_LENGTH * width.get()))
+    });
+    let colour = move || colour.get().to_string();
+
+    view! {
+        <g
+            class="_chartistry_callout"
+            font-family="monospace"
+            stroke=colour
+            stroke-width=width>
+            <Show when=move || in_bounds.get()>
+                <DebugRect label="callout" debug=debug bounds=vec![rect.into()] />
+                <line
+                    x1=move || leader.get().0 .0
+                    y1=move || leader.get().0 .1
+                    x2=move || leader.get().1 .0
+                    y2=move || leader.get().1 .1
+                    marker-end="url(#marker_axis_arrow)" />
+                <rect
+                    x=move || rect.get().left_x()
+                    y=move || rect.get().top_y()
+                    width=move || rect.get().width()
+                    height=move || rect.get().height()
+                    fill="#fff" />
+                <text
+                    x=move || rect.get().centre_x()
+                    y=move || rect.get().centre_y()
+                    style="white-space: pre;"
+                    stroke="none"
+                    fill=colour
+                    font-size=move || font_height.get()
+                    dominant-baseline="middle"
+                    text-anchor="middle">
+                    {text}
+                </text>
+            </Show>
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_place_box() {
+        let inner = Bounds::new(100.0, 100.0);
+        // Up and right of the point
+        assert_eq!(
+            place_box((50.0, 50.0), (20.0, 10.0), (10.0, -10.0), inner),
+            Bounds::from_points(60.0, 30.0, 80.0, 40.0)
+        );
+        // Flips left and down near the top-right corner
+        assert_eq!(
+            place_box((90.0, 5.0), (20.0, 10.0), (10.0, -10.0), inner),
+            Bounds::from_points(60.0, 15.0, 80.0, 25.0)
+        );
+        // Shifts when it can't fit either way
+        assert_eq!(
+            place_box((50.0, 50.0), (80.0, 10.0), (10.0, -10.0), inner),
+            Bounds::from_points(0.0, 30.0, 80.0, 40.0)
+        );
+    }
+
+    #[test]
+    fn test_place_box_avoiding() {
+        let inner = Bounds::new(100.0, 100.0);
+        // Nothing to avoid
+        assert_eq!(
+            place_box_avoiding((50.0, 50.0), (20.0, 10.0), (10.0, -10.0), inner, &[]),
+            Bounds::from_points(60.0, 30.0, 80.0, 40.0)
+        );
+        // Moves left of the point to avoid an earlier box
+        let placed = [Bounds::from_points(60.0, 30.0, 80.0, 40.0)];
+        assert_eq!(
+            place_box_avoiding((50.0, 50.0), (20.0, 10.0), (10.0, -10.0), inner, &placed),
+            Bounds::from_points(20.0, 30.0, 40.0, 40.0)
+        );
+        // Least overlap when every corner is taken
+        let placed = [
+            Bounds::from_points(60.0, 30.0, 80.0, 40.0),
+            Bounds::from_points(20.0, 30.0, 40.0, 40.0),
+            Bounds::from_points(60.0, 60.0, 80.0, 70.0),
+            Bounds::from_points(30.0, 60.0, 40.0, 70.0),
+        ];
+        assert_eq!(
+            place_box_avoiding((50.0, 50.0), (20.0, 10.0), (10.0, -10.0), inner, &placed),
+            Bounds::from_points(20.0, 60.0, 40.0, 70.0)
+        );
+    }
+
+    #[test]
+    fn test_overlap_area() {
+        let a = Bounds::from_points(0.0, 0.0, 10.0, 10.0);
+        assert_eq!(
+            overlap_area(&a, &Bounds::from_points(5.0, 5.0, 20.0, 20.0)),
+            25.0
+        );
+        assert_eq!(
+            overlap_area(&a, &Bounds::from_points(10.0, 0.0, 20.0, 10.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_leader() {
+        let rect = Bounds::from_points(60.0, 30.0, 80.0, 40.0);
+        assert_eq!(leader_start(rect, (50.0, 50.0)), (60.0, 40.0));
+        assert_eq!(pull_back((0.0, 0.0), (10.0, 0.0), 4.0), (6.0, 0.0));
+        assert_eq!(pull_back((0.0, 0.0), (3.0, 0.0), 4.0), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{colours::Colour, debug::DebugRect, state::State, Anchor, Tick};
+use leptos::prelude::*;
+
+/// Default colour for data labels.
+pub const DATA_LABEL_COLOUR: Colour = Colour::from_rgb(0x44, 0x44, 0x44);
+
+/// Builds a text label attached to a data point. Positioned in data coordinates (not pixels) so it stays with its point as the chart is zoomed, scrolled or resized. Hidden when the point is outside the inner chart area.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let label = DataLabel::new(12.0, 5.5, "peak");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct DataLabel {
+    /// X position of the labelled point.
+    pub x: RwSignal<f64>,
+    /// Y position of the labelled point.
+    pub y: RwSignal<f64>,
+    /// Text of the label.
+    pub text: RwSignal<String>,
+    /// Horizontal alignment of the text relative to the point. Default is [Anchor::Middle].
+    pub anchor: RwSignal<Anchor>,
+    /// Colour of the text. Default is [DATA_LABEL_COLOUR].
+    pub colour: RwSignal<Colour>,
+}
+
+impl DataLabel {
+    /// Creates a new label at the given X and Y values. Drawn just above the point.
+    pub fn new(x: impl Tick, y: impl Tick, text: impl Into<String>) -> Self {
+        Self {
+            x: RwSignal::new(x.position()),
+            y: RwSignal::new(y.position()),
+            text: RwSignal::new(text.into()),
+            anchor: RwSignal::new(Anchor::Middle),
+            colour: RwSignal::new(DATA_LABEL_COLOUR),
+        }
+    }
+
+    /// Sets the horizontal alignment of the text relative to the point.
+    pub fn with_anchor(self, anchor: impl Into<Anchor>) -> Self {
+        self.anchor.set(anchor.into());
+        self
+    }
+
+    /// Sets the colour of the text.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+#[component]
+pub(super) fn DataLabel<X: Tick, Y: Tick>(label: DataLabel, state: State<X, Y>) -> impl IntoView {
+    let DataLabel {
+        x,
+        y,
+        text,
+        anchor,
+        colour,
+    } = label;
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+
+    let pos = Memo::new(move |_| proj.get().position_to_svg(x.get(), y.get()));
+    let in_bounds = Memo::new(move |_| {
+        let (x, y) = pos.get();
+        inner.get().contains(x, y)
+    });
+
+    view! {
+        <g class="_chartistry_data_label" font-family="monospace">
+            <Show when=move || in_bounds.get()>
+                <DebugRect label="data_label" debug=debug />
+                <text
+                    x=move || pos.get().0
+                    y=move || pos.get().1 - font_height.get() / 4.0
+                    style="white-space: pre;"
+                    fill=move || colour.get().to_string()
+                    font-size=move || font_height.get()
+                    dominant-baseline="text-after-edge"
+                    text-anchor=move || anchor.get().to_string()>
+                    {text}
+                </text>
+            </Show>
+        </g>
+    }
+}
@@ -17,6 +17,12 @@ macro_rules! impl_grid_line {
             pub width: RwSignal<f64>,
             /// Colour of the grid line.
             pub colour: RwSignal<Colour>,
+            /// Dash pattern of the grid line as an SVG [stroke-dasharray](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dasharray) e.g., "2 4" for dots. Default is `None` for a solid line.
+            pub dash: RwSignal<Option<String>>,
+            /// Opacity of the grid line. Default is 1.0.
+            pub opacity: RwSignal<f64>,
+            /// Colour of emphasised grid lines e.g., midnight on hourly timestamps or the start of a month on daily timestamps. Default is `None` for no emphasis.
+            pub emphasis: RwSignal<Option<Colour>>,
             /// Ticks to align the grid line to.
             pub ticks: TickLabels<XY>,
         }
@@ -35,6 +41,24 @@ macro_rules! impl_grid_line {
                 self.colour.set(colour.into());
                 self
             }
+
+            /// Sets the dash pattern of the grid line e.g., "2 4" for a dotted line.
+            pub fn with_dash(self, dash: impl Into<String>) -> Self {
+                self.dash.set(Some(dash.into()));
+                self
+            }
+
+            /// Sets the opacity of the grid line.
+            pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+                self.opacity.set(opacity.into());
+                self
+            }
+
+            /// Sets the colour of emphasised grid lines. See [Self::emphasis] for details.
+            pub fn with_emphasis(self, colour: impl Into<Colour>) -> Self {
+                self.emphasis.set(Some(colour.into()));
+                self
+            }
         }
 
         impl<XY: Tick> Default for $name<XY> {
@@ -42,6 +66,9 @@ macro_rules! impl_grid_line {
                 Self {
                     width: RwSignal::new(1.0),
                     colour: RwSignal::new(GRID_LINE_COLOUR),
+                    dash: RwSignal::default(),
+                    opacity: RwSignal::new(1.0),
+                    emphasis: RwSignal::default(),
                     ticks: TickLabels::default(),
                 }
             }
@@ -57,6 +84,9 @@ macro_rules! impl_use_grid_line {
         pub struct $name<XY: Tick> {
             width: RwSignal<f64>,
             colour: RwSignal<Colour>,
+            dash: RwSignal<Option<String>>,
+            opacity: RwSignal<f64>,
+            emphasis: RwSignal<Option<Colour>>,
             ticks: Memo<GeneratedTicks<XY>>,
         }
 
@@ -65,6 +95,9 @@ macro_rules! impl_use_grid_line {
                 Self {
                     width: self.width,
                     colour: self.colour,
+                    dash: self.dash,
+                    opacity: self.opacity,
+                    emphasis: self.emphasis,
                     ticks: self.ticks,
                 }
             }
@@ -82,6 +115,9 @@ impl<X: Tick> XGridLine<X> {
         UseXGridLine {
             width: self.width,
             colour: self.colour,
+            dash: self.dash,
+            opacity: self.opacity,
+            emphasis: self.emphasis,
             ticks: self.ticks.generate_x(&state.pre, avail_width),
         }
     }
@@ -94,6 +130,9 @@ impl<Y: Tick> YGridLine<Y> {
         UseYGridLine {
             width: self.width,
             colour: self.colour,
+            dash: self.dash,
+            opacity: self.opacity,
+            emphasis: self.emphasis,
             ticks: self.ticks.generate_y(&state.pre, avail_height),
         }
     }
@@ -107,15 +146,19 @@ pub(super) fn XGridLine<X: Tick, Y: Tick>(
     let debug = state.pre.debug;
     let inner = state.layout.inner;
     let proj = state.projection;
-    let colour = line.colour;
+    let (colour, dash, opacity) = (line.colour, line.dash, line.opacity);
+    let emphasis = line.emphasis;
+    let (width, scale) = (line.width, state.pre.scale);
 
     let lines = move || {
         for_ticks(line.ticks, proj, true)
             .into_iter()
-            .map(|(x, label)| {
+            .map(|(x, label, emphasised)| {
+                let stroke = move || emphasis.get().filter(|_| emphasised).map(|c| c.to_string());
                 view! {
                     <DebugRect label=format!("grid_line_x/{}", label) debug=debug />
                     <line
+                        stroke=stroke
                         x1=x
                         y1=move || inner.get().top_y()
                         x2=x
@@ -129,7 +172,9 @@ pub(super) fn XGridLine<X: Tick, Y: Tick>(
         <g
             class="_chartistry_grid_line_x"
             stroke=move || colour.get().to_string()
-            stroke-width=line.width>
+            stroke-width=move || width.get() * scale.get()
+            stroke-dasharray=move || dash.get()
+            stroke-opacity=move || opacity.get().clamp(0.0, 1.0)>
             <DebugRect label="grid_line_x" debug=debug />
             {lines}
         </g>
@@ -144,15 +189,19 @@ pub(super) fn YGridLine<X: Tick, Y: Tick>(
     let debug = state.pre.debug;
     let inner = state.layout.inner;
     let proj = state.projection;
-    let colour = line.colour;
+    let (colour, dash, opacity) = (line.colour, line.dash, line.opacity);
+    let emphasis = line.emphasis;
+    let (width, scale) = (line.width, state.pre.scale);
 
     let lines = move || {
         for_ticks(line.ticks, proj, false)
             .into_iter()
-            .map(|(y, label)| {
+            .map(|(y, label, emphasised)| {
+                let stroke = move || emphasis.get().filter(|_| emphasised).map(|c| c.to_string());
                 view! {
                     <DebugRect label=format!("grid_line_y/{}", label) debug=debug />
                     <line
+                        stroke=stroke
                         x1=move || inner.get().left_x()
                         y1=y
                         x2=move || inner.get().right_x()
@@ -166,7 +215,9 @@ pub(super) fn YGridLine<X: Tick, Y: Tick>(
         <g
             class="_chartistry_grid_line_y"
             stroke=move || colour.get().to_string()
-            stroke-width=line.width>
+            stroke-width=move || width.get() * scale.get()
+            stroke-dasharray=move || dash.get()
+            stroke-opacity=move || opacity.get().clamp(0.0, 1.0)>
             <DebugRect label="grid_line_y" debug=debug />
             {lines}
         </g>
@@ -177,7 +228,7 @@ fn for_ticks<XY: Tick>(
     ticks: Memo<GeneratedTicks<XY>>,
     proj: Memo<Projection>,
     is_x: bool,
-) -> Vec<(f64, String)> {
+) -> Vec<(f64, String, bool)> {
     ticks.with(move |ticks| {
         let proj = proj.get();
         ticks
@@ -185,13 +236,14 @@ fn for_ticks<XY: Tick>(
             .iter()
             .map(|tick| {
                 let label = ticks.state.format(tick);
+                let emphasised = ticks.state.is_emphasised(tick);
                 let tick = tick.position();
                 let tick = if is_x {
                     proj.position_to_svg(tick, 0.0).0
                 } else {
                     proj.position_to_svg(0.0, tick).1
                 };
-                (tick, label)
+                (tick, label, emphasised)
             })
             .collect::<Vec<_>>()
     })
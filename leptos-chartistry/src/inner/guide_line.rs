@@ -1,4 +1,6 @@
-use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use crate::{
+    bounds::Bounds, colours::Colour, debug::DebugRect, edge::Edge, state::State, Tick, TickLabels,
+};
 use leptos::prelude::*;
 use std::str::FromStr;
 
@@ -17,6 +19,8 @@ macro_rules! impl_guide_line {
             pub width: RwSignal<f64>,
             /// Colour of the guide line.
             pub colour: RwSignal<Colour>,
+            /// Whether to label the guide line with its value at the edge of the inner chart. Creates a crosshair effect when combined with both X and Y guide lines.
+            pub label: RwSignal<bool>,
         }
 
         impl $name {
@@ -25,6 +29,7 @@ macro_rules! impl_guide_line {
                     align: RwSignal::new(align.into()),
                     width: RwSignal::new(1.0),
                     colour: RwSignal::new(GUIDE_LINE_COLOUR),
+                    label: RwSignal::new(false),
                 }
             }
 
@@ -43,6 +48,12 @@ macro_rules! impl_guide_line {
                 self.colour.set(colour.into());
                 self
             }
+
+            /// Sets whether to label the guide line with its value.
+            pub fn with_label(self, label: impl Into<bool>) -> Self {
+                self.label.set(label.into());
+                self
+            }
         }
 
         impl Default for $name {
@@ -132,8 +143,39 @@ pub(super) fn XGuideLine<X: Tick, Y: Tick>(
         Bounds::from_points(x, inner.top_y(), x, inner.bottom_y())
     });
 
+    // Label: nearest data or mouse position
+    let label = {
+        let range_x = state.pre.data.range_x;
+        let hover_position_x = state.hover_position_x;
+        let nearest_data_x = state.pre.data.nearest_data_x(state.hover_position_x);
+        let ticks = TickLabels::from_generator(X::tooltip_generator());
+        let format = ticks.format;
+        let avail_width = Signal::derive(move || inner.read().width());
+        let ticks = ticks.generate_x(&state.pre, avail_width);
+        Signal::derive(move || {
+            let x_value = match line.align.get() {
+                AlignOver::Data => nearest_data_x.get(),
+                AlignOver::Mouse => range_x.with(|range| {
+                    let (first, _) = range.range()?;
+                    first.with_position(hover_position_x.get())
+                }),
+            };
+            x_value
+                .map(|x_value| (format.get())(&x_value, ticks.read().state.as_ref()))
+                .unwrap_or_default()
+        })
+    };
+
     view! {
-        <GuideLine id="x" width=line.width colour=line.colour state=state pos=pos />
+        <GuideLine id="x" width=line.width colour=line.colour state=state.clone() pos=pos />
+        <GuideLabel
+            id="x"
+            edge=Edge::Bottom
+            show=line.label
+            colour=line.colour
+            label=label
+            state=state
+            pos=pos />
     }
 }
 
@@ -151,8 +193,38 @@ pub(super) fn YGuideLine<X: Tick, Y: Tick>(
         let inner = inner.get();
         Bounds::from_points(inner.left_x(), mouse_y, inner.right_x(), mouse_y)
     });
+
+    // Label: mouse position
+    let label = {
+        let range_y = state.pre.data.range_y;
+        let proj = state.projection;
+        let ticks = TickLabels::from_generator(Y::tooltip_generator());
+        let format = ticks.format;
+        let avail_height = Signal::derive(move || inner.read().height());
+        let ticks = ticks.generate_y(&state.pre, avail_height);
+        Signal::derive(move || {
+            let (mouse_x, mouse_y) = mouse_chart.get();
+            let position_y = proj.get().svg_to_position(mouse_x, mouse_y).1;
+            range_y
+                .with(|range| {
+                    let (first, _) = range.range()?;
+                    first.with_position(position_y)
+                })
+                .map(|y_value| (format.get())(&y_value, ticks.read().state.as_ref()))
+                .unwrap_or_default()
+        })
+    };
+
     view! {
-        <GuideLine id="y" width=line.width colour=line.colour state=state pos=pos />
+        <GuideLine id="y" width=line.width colour=line.colour state=state.clone() pos=pos />
+        <GuideLabel
+            id="y"
+            edge=Edge::Left
+            show=line.label
+            colour=line.colour
+            label=label
+            state=state
+            pos=pos />
     }
 }
 
@@ -166,6 +238,7 @@ fn GuideLine<X: Tick, Y: Tick>(
 ) -> impl IntoView {
     let debug = state.pre.debug;
     let hover_inner = state.hover_inner;
+    let scale = state.pre.scale;
 
     let x1 = Memo::new(move |_| pos.get().left_x());
     let y1 = Memo::new(move |_| pos.get().top_y());
@@ -181,7 +254,7 @@ fn GuideLine<X: Tick, Y: Tick>(
         <g
             class=format!("_chartistry_{}_guide_line", id)
             stroke=move || colour.get().to_string()
-            stroke-width=width>
+            stroke-width=move || width.get() * scale.get()>
             <Show when=move || hover_inner.get() && have_data.get() >
                 <DebugRect label=format!("{}_guide_line", id) debug=debug />
                 <line
@@ -194,3 +267,67 @@ fn GuideLine<X: Tick, Y: Tick>(
         </g>
     }
 }
+
+/// Renders a guide line's value on the inner chart's edge (bottom for X, left for Y).
+#[component]
+fn GuideLabel<X: Tick, Y: Tick>(
+    id: &'static str,
+    edge: Edge,
+    show: RwSignal<bool>,
+    colour: RwSignal<Colour>,
+    label: Signal<String>,
+    state: State<X, Y>,
+    pos: Signal<Bounds>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let padding = state.pre.padding;
+    let hover_inner = state.hover_inner;
+
+    // Don't render if the guide line is NaN or there's nothing to show
+    let have_label = Signal::derive(move || {
+        let pos = pos.get();
+        !(pos.left_x().is_nan() || pos.top_y().is_nan() || label.with(|label| label.is_empty()))
+    });
+
+    let bounds = Memo::new(move |_| {
+        if !have_label.get() {
+            return Bounds::default();
+        }
+        let pos = pos.get();
+        let padding = padding.get();
+        let chars = label.with(|label| label.chars().count()) as f64;
+        let width = font_width.get() * chars + padding.width();
+        let height = font_height.get() + padding.height();
+        let (left, top) = match edge {
+            Edge::Top | Edge::Bottom => (pos.left_x() - width / 2.0, pos.bottom_y() - height),
+            Edge::Left | Edge::Right => (pos.left_x(), pos.top_y() - height / 2.0),
+        };
+        Bounds::from_points(left, top, left + width, top + height)
+    });
+
+    view! {
+        <Show when=move || show.get() && hover_inner.get() && have_label.get()>
+            <g class=format!("_chartistry_{}_guide_label", id) font-family="monospace">
+                <DebugRect label=format!("{}_guide_label", id) debug=debug bounds=vec![bounds.into()] />
+                <rect
+                    x=move || bounds.get().left_x()
+                    y=move || bounds.get().top_y()
+                    width=move || bounds.get().width()
+                    height=move || bounds.get().height()
+                    fill=move || colour.get().to_string() />
+                <text
+                    x=move || bounds.get().centre_x()
+                    y=move || bounds.get().centre_y()
+                    style="white-space: pre;"
+                    fill="#fff"
+                    font-size=move || font_height.get()
+                    dominant-baseline="middle"
+                    text-anchor="middle">
+                    {label}
+                </text>
+            </g>
+        </Show>
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{edge::Edge, state::State, Anchor, Legend, Tick};
+use crate::{bounds::Bounds, edge::Edge, state::State, Anchor, Legend, Tick};
 use leptos::prelude::*;
 
 /// Builds an inset legend for the chart [series](crate::Series). Differs from [Legend](struct@Legend) by being placed inside the chart area.
@@ -7,18 +7,36 @@ use leptos::prelude::*;
 pub struct InsetLegend {
     /// Edge of the chart area to place the legend.
     pub edge: RwSignal<Edge>,
-    /// Legend to display. Relies on the internal `anchor` signal. See [Legend](struct@Legend) for details.
+    /// Legend to display. Its `anchor` is used unless [auto](Self::auto) is set. See [Legend](struct@Legend) for details.
     pub legend: Legend,
+    /// Place the legend in the corner covering the fewest data points. Overrides the edge and anchor. Default is false.
+    pub auto: RwSignal<bool>,
 }
 
+// Corners tried by auto placement in order of preference
+const AUTO_CORNERS: [(Edge, Anchor); 4] = [
+    (Edge::Top, Anchor::End),
+    (Edge::Top, Anchor::Start),
+    (Edge::Bottom, Anchor::End),
+    (Edge::Bottom, Anchor::Start),
+];
+
 impl InsetLegend {
     fn new(edge: Edge, anchor: Anchor) -> Self {
         Self {
             edge: RwSignal::new(edge),
             legend: Legend::new(anchor),
+            auto: RwSignal::new(false),
         }
     }
 
+    /// Creates a new inset legend placed in the corner of the chart area covering the fewest data points. Moves as the data changes so the legend doesn't hide the interesting part of the series.
+    pub fn auto() -> Self {
+        let legend = Self::top_right();
+        legend.auto.set(true);
+        legend
+    }
+
     /// Creates a new inset legend placed at the top-left corner of the chart area.
     pub fn top_left() -> Self {
         Self::new(Edge::Top, Anchor::Start)
@@ -58,27 +76,100 @@ pub(super) fn InsetLegend<X: Tick, Y: Tick>(
     legend: InsetLegend,
     state: State<X, Y>,
 ) -> impl IntoView {
-    let InsetLegend { edge, legend } = legend;
+    let InsetLegend { edge, legend, auto } = legend;
     let inner = state.layout.inner;
     let width = Legend::width(&state.pre);
     let height = legend.fixed_height(&state.pre);
+
+    // Pick the corner with the least data
+    let positions = state.pre.data.svg_positions(state.projection);
+    let placement = Memo::new(move |_| {
+        if !auto.get() {
+            return (edge.get(), legend.anchor.get());
+        }
+        let (inner, width, height) = (inner.get(), width.get(), height.get());
+        let corners = AUTO_CORNERS.map(|(edge, anchor)| corner(inner, edge, anchor, width, height));
+        let best = positions.with(|positions| least_covered(&corners, positions));
+        AUTO_CORNERS[best]
+    });
+    let edge = Signal::derive(move || placement.get().0);
+    let anchor = Signal::derive(move || placement.get().1);
+
     let bounds = Memo::new(move |_| {
         let inner = inner.get();
         let height = height.get();
         let width = width.get();
         // Build legend bounds as an inset of the chart bounds
-        let (top, right, bottom, left) = match edge.get() {
-            Edge::Top => (0.0, 0.0, inner.height() - height, 0.0),
-            Edge::Bottom => (inner.height() - height, 0.0, 0.0, 0.0),
-            Edge::Left => (0.0, inner.width() - width, 0.0, 0.0),
-            Edge::Right => (0.0, 0.0, 0.0, inner.width() - width),
-        };
-        inner.shrink(top, right, bottom, left)
+        let edge = edge.get();
+        edge.inset(inner, if edge.is_horizontal() { height } else { width })
     });
 
     view! {
         <g class="_chartistry_legend_inset">
-            <Legend legend=legend edge=edge bounds=bounds state=state />
+            <Legend legend=legend edge=edge bounds=bounds state=state anchor=anchor />
         </g>
     }
 }
+
+/// Bounds of a legend placed in a corner of the inner chart area.
+fn corner(inner: Bounds, edge: Edge, anchor: Anchor, width: f64, height: f64) -> Bounds {
+    let (spare_x, spare_y) = (
+        (inner.width() - width).max(0.0),
+        (inner.height() - height).max(0.0),
+    );
+    let (top, bottom) = match edge {
+        Edge::Bottom => (spare_y, 0.0),
+        _ => (0.0, spare_y),
+    };
+    let (left, right) = match anchor {
+        Anchor::Start => (0.0, spare_x),
+        Anchor::Middle => (spare_x / 2.0, spare_x / 2.0),
+        Anchor::End => (spare_x, 0.0),
+    };
+    inner.shrink(top, right, bottom, left)
+}
+
+/// Index of the area containing the fewest points. Ties go to the first area.
+fn least_covered(areas: &[Bounds], points: &[(f64, f64)]) -> usize {
+    (areas.iter().enumerate())
+        .min_by_key(|(_, area)| {
+            (points.iter())
+                .filter(|&&(x, y)| area.contains(x, y))
+                .count()
+        })
+        .map_or(0, |(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corner() {
+        let inner = Bounds::from_points(0.0, 0.0, 100.0, 50.0);
+        assert_eq!(
+            corner(inner, Edge::Top, Anchor::End, 20.0, 10.0),
+            Bounds::from_points(80.0, 0.0, 100.0, 10.0)
+        );
+        assert_eq!(
+            corner(inner, Edge::Bottom, Anchor::Start, 20.0, 10.0),
+            Bounds::from_points(0.0, 40.0, 20.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn test_least_covered() {
+        let areas = [
+            Bounds::from_points(0.0, 0.0, 10.0, 10.0),
+            Bounds::from_points(10.0, 0.0, 20.0, 10.0),
+        ];
+        assert_eq!(least_covered(&areas, &[]), 0);
+        assert_eq!(least_covered(&areas, &[(5.0, 5.0)]), 1);
+        assert_eq!(
+            least_covered(&areas, &[(5.0, 5.0), (15.0, 5.0), (16.0, 5.0)]),
+            0
+        );
+        // Missing points are ignored
+        assert_eq!(least_covered(&areas, &[(f64::NAN, 5.0), (15.0, 5.0)]), 0);
+    }
+}
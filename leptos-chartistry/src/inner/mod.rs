@@ -1,14 +1,26 @@
 pub mod axis_marker;
+pub mod brush;
+pub mod callout;
+pub mod data_label;
 pub mod grid_line;
 pub mod guide_line;
 pub mod legend;
+pub mod reference_line;
+pub mod region;
+pub mod watermark;
 
 use crate::{state::State, Tick};
 use axis_marker::AxisMarker;
+use brush::Brush;
+use callout::Callout;
+use data_label::DataLabel;
 use grid_line::{XGridLine, YGridLine};
 use guide_line::{XGuideLine, YGuideLine};
 use legend::InsetLegend;
-use leptos::{either::EitherOf6, prelude::*};
+use leptos::{either::EitherOf14, prelude::*};
+use reference_line::{XReferenceLine, YReferenceLine};
+use region::{XRegion, YRegion};
+use watermark::Watermark;
 
 /// Inner layout options for a [Chart](crate::Chart). See [IntoInner](trait@IntoInner) for details.
 #[derive(Clone)]
@@ -26,6 +38,22 @@ pub enum InnerLayout<X: Tick, Y: Tick> {
     YGuideLine(guide_line::YGuideLine),
     /// Inset legend. See [InsetLegend](struct@legend::InsetLegend) for details.
     Legend(legend::InsetLegend),
+    /// Text label at a data point. See [DataLabel](struct@data_label::DataLabel) for details.
+    DataLabel(data_label::DataLabel),
+    /// Text box pointing to a data point. See [Callout](struct@callout::Callout) for details.
+    Callout(callout::Callout),
+    /// Background image or text. See [Watermark](struct@watermark::Watermark) for details.
+    Watermark(watermark::Watermark),
+    /// Drag to select an X range. See [Brush](struct@brush::Brush) for details.
+    Brush(brush::Brush<X>),
+    /// Vertical line at a fixed X value. See [XReferenceLine](struct@reference_line::XReferenceLine) for details.
+    XReferenceLine(reference_line::XReferenceLine),
+    /// Horizontal line at a fixed Y value. See [YReferenceLine](struct@reference_line::YReferenceLine) for details.
+    YReferenceLine(reference_line::YReferenceLine),
+    /// Shaded band between two X values. See [XRegion](struct@region::XRegion) for details.
+    XRegion(region::XRegion),
+    /// Shaded band between two Y values. See [YRegion](struct@region::YRegion) for details.
+    YRegion(region::YRegion),
 }
 
 /// Convert a type (e.g., a [guide line](struct@guide_line::XGuideLine)) into an inner layout for use in a [Chart](crate::Chart).
@@ -41,9 +69,32 @@ pub enum UseInner<X: Tick, Y: Tick> {
     XGuideLine(guide_line::UseXGuideLine),
     YGuideLine(guide_line::UseYGuideLine),
     Legend(legend::InsetLegend),
+    DataLabel(data_label::DataLabel),
+    Callout(callout::UseCallout),
+    Watermark(watermark::Watermark),
+    Brush(brush::Brush<X>),
+    XReferenceLine(reference_line::XReferenceLine),
+    YReferenceLine(reference_line::YReferenceLine),
+    XRegion(region::XRegion),
+    YRegion(region::YRegion),
 }
 
 impl<X: Tick, Y: Tick> InnerLayout<X, Y> {
+    /// Prepares a list of inner layouts for rendering. Callouts avoid those listed before them.
+    pub(super) fn use_all(inner: Vec<Self>, state: &State<X, Y>) -> Vec<UseInner<X, Y>> {
+        let mut callouts = Vec::new();
+        (inner.into_iter())
+            .map(|inner| match inner {
+                Self::Callout(inner) => {
+                    let callout = inner.use_callout(state, callouts.clone());
+                    callouts.push(callout.placed());
+                    UseInner::Callout(callout)
+                }
+                inner => inner.into_use(state),
+            })
+            .collect()
+    }
+
     pub(super) fn into_use(self, state: &State<X, Y>) -> UseInner<X, Y> {
         match self {
             Self::AxisMarker(inner) => UseInner::AxisMarker(inner),
@@ -52,31 +103,68 @@ impl<X: Tick, Y: Tick> InnerLayout<X, Y> {
             Self::XGuideLine(inner) => UseInner::XGuideLine(inner.use_horizontal()),
             Self::YGuideLine(inner) => UseInner::YGuideLine(inner.use_vertical()),
             Self::Legend(inner) => UseInner::Legend(inner),
+            Self::DataLabel(inner) => UseInner::DataLabel(inner),
+            Self::Callout(inner) => UseInner::Callout(inner.use_callout(state, Vec::new())),
+            Self::Watermark(inner) => UseInner::Watermark(inner),
+            Self::Brush(inner) => UseInner::Brush(inner),
+            Self::XReferenceLine(inner) => UseInner::XReferenceLine(inner),
+            Self::YReferenceLine(inner) => UseInner::YReferenceLine(inner),
+            Self::XRegion(inner) => UseInner::XRegion(inner),
+            Self::YRegion(inner) => UseInner::YRegion(inner),
         }
     }
 }
 
 impl<X: Tick, Y: Tick> UseInner<X, Y> {
+    /// Whether to render on top of the series e.g., annotations of data points.
+    pub(super) fn is_overlay(&self) -> bool {
+        matches!(self, Self::DataLabel(_) | Self::Callout(_))
+    }
+
     pub(super) fn render(self, state: State<X, Y>) -> impl IntoView {
         match self {
-            Self::AxisMarker(inner) => EitherOf6::A(view! {
+            Self::AxisMarker(inner) => EitherOf14::A(view! {
                 <AxisMarker marker=inner state=state />
             }),
-            Self::XGridLine(inner) => EitherOf6::B(view! {
+            Self::XGridLine(inner) => EitherOf14::B(view! {
                 <XGridLine line=inner state=state />
             }),
-            Self::YGridLine(inner) => EitherOf6::C(view! {
+            Self::YGridLine(inner) => EitherOf14::C(view! {
                 <YGridLine line=inner state=state />
             }),
-            Self::XGuideLine(inner) => EitherOf6::D(view! {
+            Self::XGuideLine(inner) => EitherOf14::D(view! {
                 <XGuideLine line=inner state=state />
             }),
-            Self::YGuideLine(inner) => EitherOf6::E(view! {
+            Self::YGuideLine(inner) => EitherOf14::E(view! {
                 <YGuideLine line=inner state=state />
             }),
-            Self::Legend(inner) => EitherOf6::F(view! {
+            Self::Legend(inner) => EitherOf14::F(view! {
                 <InsetLegend legend=inner state=state />
             }),
+            Self::DataLabel(inner) => EitherOf14::G(view! {
+                <DataLabel label=inner state=state />
+            }),
+            Self::Callout(inner) => EitherOf14::H(view! {
+                <Callout callout=inner state=state />
+            }),
+            Self::Watermark(inner) => EitherOf14::I(view! {
+                <Watermark watermark=inner state=state />
+            }),
+            Self::Brush(inner) => EitherOf14::J(view! {
+                <Brush brush=inner state=state />
+            }),
+            Self::XReferenceLine(inner) => EitherOf14::K(view! {
+                <XReferenceLine line=inner state=state />
+            }),
+            Self::YReferenceLine(inner) => EitherOf14::L(view! {
+                <YReferenceLine line=inner state=state />
+            }),
+            Self::XRegion(inner) => EitherOf14::M(view! {
+                <XRegion region=inner state=state />
+            }),
+            Self::YRegion(inner) => EitherOf14::N(view! {
+                <YRegion region=inner state=state />
+            }),
         }
     }
 }
@@ -108,3 +196,11 @@ impl_into_inner!(grid_line::YGridLine<Y>, YGridLine);
 impl_into_inner!(guide_line::XGuideLine, XGuideLine);
 impl_into_inner!(guide_line::YGuideLine, YGuideLine);
 impl_into_inner!(legend::InsetLegend, Legend);
+impl_into_inner!(data_label::DataLabel, DataLabel);
+impl_into_inner!(callout::Callout, Callout);
+impl_into_inner!(watermark::Watermark, Watermark);
+impl_into_inner!(brush::Brush<X>, Brush);
+impl_into_inner!(reference_line::XReferenceLine, XReferenceLine);
+impl_into_inner!(reference_line::YReferenceLine, YReferenceLine);
+impl_into_inner!(region::XRegion, XRegion);
+impl_into_inner!(region::YRegion, YRegion);
@@ -0,0 +1,245 @@
+use crate::{colours::Colour, debug::DebugRect, state::State, Tick, YAxis};
+use leptos::prelude::*;
+
+/// Default colour for reference lines.
+pub const REFERENCE_LINE_COLOUR: Colour = Colour::from_rgb(0xD3, 0x2F, 0x2F);
+
+macro_rules! impl_reference_line {
+    ($name:ident, $axis:literal, $example:literal { $($(#[$meta:meta])* $field:ident: $ty:ty = $default:expr),* }) => {
+        #[doc = concat!("Builds a reference line at a fixed ", $axis, " value e.g., ", $example, ". Positioned in data coordinates so it stays put as the chart is zoomed or scrolled. Hidden when outside the inner chart area.")]
+        #[derive(Clone, Debug, PartialEq)]
+        #[non_exhaustive]
+        pub struct $name {
+            #[doc = concat!($axis, " position of the line.")]
+            pub position: RwSignal<f64>,
+            /// Colour of the line and label. Default is [REFERENCE_LINE_COLOUR].
+            pub colour: RwSignal<Colour>,
+            /// Width of the line. Default is 1.0.
+            pub width: RwSignal<f64>,
+            /// SVG dash array of the line e.g., `"4 4"`. Empty for a solid line. Default is `"4 4"`.
+            pub dash: RwSignal<String>,
+            /// Optional text drawn beside the line. Default is `None`.
+            pub label: RwSignal<Option<String>>,
+            $($(#[$meta])* pub $field: $ty,)*
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new reference line at the given ", $axis, " value.")]
+            pub fn new(position: impl Tick) -> Self {
+                Self {
+                    position: RwSignal::new(position.position()),
+                    colour: RwSignal::new(REFERENCE_LINE_COLOUR),
+                    width: RwSignal::new(1.0),
+                    dash: RwSignal::new("4 4".to_string()),
+                    label: RwSignal::default(),
+                    $($field: $default,)*
+                }
+            }
+
+            /// Sets the colour of the line and label.
+            pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+                self.colour.set(colour.into());
+                self
+            }
+
+            /// Sets the width of the line.
+            pub fn with_width(self, width: impl Into<f64>) -> Self {
+                self.width.set(width.into());
+                self
+            }
+
+            /// Sets the SVG dash array of the line. Use an empty string for a solid line.
+            pub fn with_dash(self, dash: impl Into<String>) -> Self {
+                self.dash.set(dash.into());
+                self
+            }
+
+            /// Sets the text drawn beside the line.
+            pub fn with_label(self, label: impl Into<String>) -> Self {
+                self.label.set(Some(label.into()));
+                self
+            }
+        }
+    };
+}
+
+impl_reference_line!(XReferenceLine, "X", "a deployment timestamp" {});
+impl_reference_line!(YReferenceLine, "Y", "an alert threshold at pH 7.5" {
+    /// Y axis the position is on e.g., [YAxis::Secondary] for a threshold of a series on a right-hand scale. Default is [YAxis::Primary].
+    y_axis: RwSignal<YAxis> = RwSignal::default()
+});
+
+impl YReferenceLine {
+    /// Sets the Y axis the position is on. See [YReferenceLine::y_axis] for details.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+}
+
+#[component]
+pub(super) fn XReferenceLine<X: Tick, Y: Tick>(
+    line: XReferenceLine,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let inner = state.layout.inner;
+    let proj = state.projection;
+    let position = line.position;
+    // Vertical line from the bottom to the top of the inner chart
+    let x = Memo::new(move |_| proj.get().position_to_svg(position.get(), 0.0).0);
+    let in_bounds = Memo::new(move |_| {
+        let inner = inner.get();
+        let x = x.get();
+        inner.left_x() <= x && x <= inner.right_x()
+    });
+    let coords = Signal::derive(move || {
+        let inner = inner.get();
+        let x = x.get();
+        (x, inner.bottom_y(), x, inner.top_y())
+    });
+    // Label right of the line at the top
+    let label_pos = Signal::derive(move || {
+        let x = x.get();
+        (x, inner.get().top_y())
+    });
+    view! {
+        <ReferenceLine
+            kind="_chartistry_x_reference_line"
+            line=line.into()
+            state=state
+            in_bounds=in_bounds
+            coords=coords
+            label_pos=label_pos
+            label_anchor="start"
+            label_baseline="text-before-edge"
+            label_nudge=1.0
+        />
+    }
+}
+
+#[component]
+pub(super) fn YReferenceLine<X: Tick, Y: Tick>(
+    line: YReferenceLine,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let inner = state.layout.inner;
+    let (proj, proj_secondary) = (state.projection, state.projection_secondary);
+    let (position, y_axis) = (line.position, line.y_axis);
+    let proj = Memo::new(move |_| match y_axis.get() {
+        YAxis::Secondary => proj_secondary.get(),
+        _ => proj.get(),
+    });
+    // Horizontal line from the left to the right of the inner chart
+    let y = Memo::new(move |_| proj.get().position_to_svg(0.0, position.get()).1);
+    let in_bounds = Memo::new(move |_| {
+        let inner = inner.get();
+        let y = y.get();
+        inner.top_y() <= y && y <= inner.bottom_y()
+    });
+    let coords = Signal::derive(move || {
+        let inner = inner.get();
+        let y = y.get();
+        (inner.left_x(), y, inner.right_x(), y)
+    });
+    // Label above the line on the right
+    let label_pos = Signal::derive(move || (inner.get().right_x(), y.get()));
+    view! {
+        <ReferenceLine
+            kind="_chartistry_y_reference_line"
+            line=line.into()
+            state=state
+            in_bounds=in_bounds
+            coords=coords
+            label_pos=label_pos
+            label_anchor="end"
+            label_baseline="text-after-edge"
+            label_nudge=-1.0
+        />
+    }
+}
+
+// Common style of X and Y reference lines
+struct LineStyle {
+    colour: RwSignal<Colour>,
+    width: RwSignal<f64>,
+    dash: RwSignal<String>,
+    label: RwSignal<Option<String>>,
+}
+
+impl From<XReferenceLine> for LineStyle {
+    fn from(line: XReferenceLine) -> Self {
+        Self {
+            colour: line.colour,
+            width: line.width,
+            dash: line.dash,
+            label: line.label,
+        }
+    }
+}
+
+impl From<YReferenceLine> for LineStyle {
+    fn from(line: YReferenceLine) -> Self {
+        Self {
+            colour: line.colour,
+            width: line.width,
+            dash: line.dash,
+            label: line.label,
+        }
+    }
+}
+
+#[component]
+fn ReferenceLine<X: Tick, Y: Tick>(
+    kind: &'static str,
+    line: LineStyle,
+    state: State<X, Y>,
+    in_bounds: Memo<bool>,
+    coords: Signal<(f64, f64, f64, f64)>,
+    label_pos: Signal<(f64, f64)>,
+    label_anchor: &'static str,
+    label_baseline: &'static str,
+    // Direction to nudge the label away from the line: 1.0 for right or -1.0 for left
+    label_nudge: f64,
+) -> impl IntoView {
+    let LineStyle {
+        colour,
+        width,
+        dash,
+        label,
+    } = line;
+    let debug = state.pre.debug;
+    let (font_height, font_width) = (state.pre.font_height, state.pre.font_width);
+    let scale = state.pre.scale;
+    let colour = move || colour.get().to_string();
+    // Nudge the label away from the line
+    let label_x = move || label_pos.get().0 + label_nudge * font_width.get() / 2.0;
+    view! {
+        <g class=kind>
+            <Show when=move || in_bounds.get()>
+                <DebugRect label=kind debug=debug />
+                <line
+                    x1=move || coords.get().0
+                    y1=move || coords.get().1
+                    x2=move || coords.get().2
+                    y2=move || coords.get().3
+                    stroke=colour
+                    stroke-width=move || width.get() * scale.get()
+                    stroke-dasharray=move || dash.get()
+                />
+                {move || label.get().map(|label| view! {
+                    <text
+                        x=label_x
+                        y=move || label_pos.get().1
+                        style="white-space: pre;"
+                        font-family="monospace"
+                        font-size=move || font_height.get()
+                        fill=colour
+                        text-anchor=label_anchor
+                        dominant-baseline=label_baseline>
+                        {label}
+                    </text>
+                })}
+            </Show>
+        </g>
+    }
+}
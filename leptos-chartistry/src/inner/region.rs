@@ -0,0 +1,141 @@
+use crate::{colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::prelude::*;
+
+/// Default colour for shaded regions.
+pub const REGION_COLOUR: Colour = Colour::from_rgb(0x9A, 0x9A, 0x9A);
+
+macro_rules! impl_region {
+    ($name:ident, $axis:literal, $example:literal) => {
+        #[doc = concat!("Builds a shaded band between two ", $axis, " values e.g., ", $example, ". Drawn behind the series and positioned in data coordinates so it moves with the data when zoomed or scrolled. Clipped to the inner chart area.")]
+        #[derive(Clone, Debug, PartialEq)]
+        #[non_exhaustive]
+        pub struct $name {
+            #[doc = concat!("One edge of the band as an ", $axis, " position.")]
+            pub from: RwSignal<f64>,
+            #[doc = concat!("Other edge of the band as an ", $axis, " position.")]
+            pub to: RwSignal<f64>,
+            /// Fill colour of the band. Default is [REGION_COLOUR].
+            pub colour: RwSignal<Colour>,
+            /// Fill opacity of the band from 0 to 1. Default is 0.2.
+            pub opacity: RwSignal<f64>,
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new region between two ", $axis, " values. Order doesn't matter.")]
+            pub fn new<XY: Tick>(from: XY, to: XY) -> Self {
+                Self {
+                    from: RwSignal::new(from.position()),
+                    to: RwSignal::new(to.position()),
+                    colour: RwSignal::new(REGION_COLOUR),
+                    opacity: RwSignal::new(0.2),
+                }
+            }
+
+            /// Sets the fill colour of the band.
+            pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+                self.colour.set(colour.into());
+                self
+            }
+
+            /// Sets the fill opacity of the band.
+            pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+                self.opacity.set(opacity.into());
+                self
+            }
+        }
+    };
+}
+
+impl_region!(XRegion, "X", "night time");
+impl_region!(YRegion, "Y", "an acceptable range of values");
+
+/// Orders two SVG coordinates and clamps them between `low` and `high`. Returns None if nothing is left e.g., the span is outside.
+fn clamp_span(a: f64, b: f64, low: f64, high: f64) -> Option<(f64, f64)> {
+    let (start, end) = (a.min(b).max(low), a.max(b).min(high));
+    (start < end).then_some((start, end))
+}
+
+#[component]
+pub(super) fn XRegion<X: Tick, Y: Tick>(region: XRegion, state: State<X, Y>) -> impl IntoView {
+    let XRegion {
+        from,
+        to,
+        colour,
+        opacity,
+    } = region;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+    let rect = Signal::derive(move || {
+        let inner = inner.get();
+        let proj = proj.get();
+        let (from, _) = proj.position_to_svg(from.get(), 0.0);
+        let (to, _) = proj.position_to_svg(to.get(), 0.0);
+        let (left, right) = clamp_span(from, to, inner.left_x(), inner.right_x())?;
+        Some((left, inner.top_y(), right - left, inner.height()))
+    });
+    view! {
+        <Region kind="_chartistry_x_region" rect=rect colour=colour opacity=opacity state=state />
+    }
+}
+
+#[component]
+pub(super) fn YRegion<X: Tick, Y: Tick>(region: YRegion, state: State<X, Y>) -> impl IntoView {
+    let YRegion {
+        from,
+        to,
+        colour,
+        opacity,
+    } = region;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+    let rect = Signal::derive(move || {
+        let inner = inner.get();
+        let proj = proj.get();
+        let (_, from) = proj.position_to_svg(0.0, from.get());
+        let (_, to) = proj.position_to_svg(0.0, to.get());
+        let (top, bottom) = clamp_span(from, to, inner.top_y(), inner.bottom_y())?;
+        Some((inner.left_x(), top, inner.width(), bottom - top))
+    });
+    view! {
+        <Region kind="_chartistry_y_region" rect=rect colour=colour opacity=opacity state=state />
+    }
+}
+
+#[component]
+fn Region<X: Tick, Y: Tick>(
+    kind: &'static str,
+    rect: Signal<Option<(f64, f64, f64, f64)>>,
+    colour: RwSignal<Colour>,
+    opacity: RwSignal<f64>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    view! {
+        <g class=kind>
+            {move || rect.get().map(|(x, y, width, height)| view! {
+                <DebugRect label=kind debug=debug />
+                <rect
+                    x=x
+                    y=y
+                    width=width
+                    height=height
+                    fill=move || colour.get().to_string()
+                    fill-opacity=move || opacity.get()
+                />
+            })}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_span() {
+        assert_eq!(clamp_span(30.0, 10.0, 0.0, 100.0), Some((10.0, 30.0)));
+        assert_eq!(clamp_span(-10.0, 50.0, 0.0, 100.0), Some((0.0, 50.0)));
+        assert_eq!(clamp_span(110.0, 120.0, 0.0, 100.0), None);
+        assert_eq!(clamp_span(10.0, 10.0, 0.0, 100.0), None);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::{either::Either, prelude::*};
+
+/// Default colour for text watermarks.
+pub const WATERMARK_COLOUR: Colour = Colour::from_rgb(0x9A, 0x9A, 0x9A);
+
+/// Builds a background image or text watermark (e.g., a logo or "DRAFT") drawn below the series. Clipped to the inner chart area.
+///
+/// Inner layouts are drawn in order so place watermarks first to keep them below grid lines.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let draft = Watermark::text("DRAFT").with_opacity(0.15);
+/// let logo = Watermark::image("/logo.svg")
+///     .with_position(WatermarkPosition::Pixels(Bounds::from_points(10.0, 10.0, 90.0, 40.0)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Watermark {
+    /// What to draw.
+    pub content: RwSignal<WatermarkContent>,
+    /// Where to draw it. Default is [WatermarkPosition::Fill].
+    pub position: RwSignal<WatermarkPosition>,
+    /// Opacity from 0.0 to 1.0. Default is 0.2.
+    pub opacity: RwSignal<f64>,
+    /// Colour of a text watermark. Default is [WATERMARK_COLOUR].
+    pub colour: RwSignal<Colour>,
+}
+
+/// Content of a [Watermark].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum WatermarkContent {
+    /// Image URL e.g., an SVG logo or a data URL. Scaled to fit while keeping its aspect ratio.
+    Image(String),
+    /// Text scaled to fit.
+    Text(String),
+}
+
+/// Where a [Watermark] is drawn.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum WatermarkPosition {
+    /// Fills the inner chart area.
+    #[default]
+    Fill,
+    /// Pixel bounds relative to the top-left of the inner chart area.
+    Pixels(Bounds),
+    /// Data coordinates between two X and two Y positions. Moves with the data when zoomed or scrolled.
+    Data {
+        /// X range of the watermark.
+        x: (f64, f64),
+        /// Y range of the watermark.
+        y: (f64, f64),
+    },
+}
+
+impl Watermark {
+    fn new(content: WatermarkContent) -> Self {
+        Self {
+            content: RwSignal::new(content),
+            position: RwSignal::default(),
+            opacity: RwSignal::new(0.2),
+            colour: RwSignal::new(WATERMARK_COLOUR),
+        }
+    }
+
+    /// Creates a new image watermark from a URL.
+    pub fn image(href: impl Into<String>) -> Self {
+        Self::new(WatermarkContent::Image(href.into()))
+    }
+
+    /// Creates a new text watermark.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(WatermarkContent::Text(text.into()))
+    }
+
+    /// Sets where the watermark is drawn.
+    pub fn with_position(self, position: impl Into<WatermarkPosition>) -> Self {
+        self.position.set(position.into());
+        self
+    }
+
+    /// Sets the opacity of the watermark.
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+
+    /// Sets the colour of a text watermark.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+/// Font size that fits `chars` monospace characters within `bounds`. Uses the ratio of the chart's font width to height.
+fn fit_font_size(bounds: Bounds, chars: usize, font_ratio: f64) -> f64 {
+    let by_width = bounds.width() / (chars.max(1) as f64 * font_ratio);
+    bounds.height().min(by_width)
+}
+
+#[component]
+pub(super) fn Watermark<X: Tick, Y: Tick>(
+    watermark: Watermark,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let Watermark {
+        content,
+        position,
+        opacity,
+        colour,
+    } = watermark;
+    let debug = state.pre.debug;
+    let (font_height, font_width) = (state.pre.font_height, state.pre.font_width);
+    let inner = state.layout.inner;
+    let proj = state.projection;
+
+    let bounds = Memo::new(move |_| {
+        let inner = inner.get();
+        match position.get() {
+            WatermarkPosition::Fill => inner,
+            WatermarkPosition::Pixels(b) => Bounds::from_points(
+                inner.left_x() + b.left_x(),
+                inner.top_y() + b.top_y(),
+                inner.left_x() + b.right_x(),
+                inner.top_y() + b.bottom_y(),
+            ),
+            WatermarkPosition::Data { x, y } => {
+                let proj = proj.get();
+                let (x1, y1) = proj.position_to_svg(x.0, y.0);
+                let (x2, y2) = proj.position_to_svg(x.1, y.1);
+                if [x1, y1, x2, y2].iter().any(|v| v.is_nan()) {
+                    return Bounds::default();
+                }
+                Bounds::from_points(x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2))
+            }
+        }
+    });
+    let view_box = move || {
+        inner.with(|inner| {
+            format!(
+                "{} {} {} {}",
+                inner.left_x(),
+                inner.top_y(),
+                inner.width(),
+                inner.height()
+            )
+        })
+    };
+
+    let content = move || match content.get() {
+        WatermarkContent::Image(href) => Either::Left(view! {
+            <image
+                href=href
+                x=move || bounds.get().left_x()
+                y=move || bounds.get().top_y()
+                width=move || bounds.get().width()
+                height=move || bounds.get().height()
+                preserveAspectRatio="xMidYMid meet" />
+        }),
+        WatermarkContent::Text(text) => {
+            let chars = text.chars().count();
+            let font_size = move || {
+                let ratio = font_width.get() / font_height.get();
+                fit_font_size(bounds.get(), chars, ratio)
+            };
+            Either::Right(view! {
+                <text
+                    x=move || bounds.get().centre_x()
+                    y=move || bounds.get().centre_y()
+                    style="white-space: pre;"
+                    font-family="monospace"
+                    font-size=font_size
+                    fill=move || colour.get().to_string()
+                    dominant-baseline="middle"
+                    text-anchor="middle">
+                    {text}
+                </text>
+            })
+        }
+    };
+
+    view! {
+        <svg
+            class="_chartistry_watermark"
+            x=move || inner.get().left_x()
+            y=move || inner.get().top_y()
+            width=move || inner.get().width()
+            height=move || inner.get().height()
+            viewBox=view_box
+            overflow="hidden"
+            pointer-events="none"
+            opacity=move || opacity.get().clamp(0.0, 1.0)>
+            <DebugRect label="watermark" debug=debug bounds=vec![bounds.into()] />
+            {content}
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_font_size() {
+        let bounds = Bounds::new(100.0, 40.0);
+        // Limited by height
+        assert_eq!(fit_font_size(bounds, 4, 0.5), 40.0);
+        // Limited by width: 10 chars at half the font size
+        assert_eq!(fit_font_size(bounds, 10, 0.5), 20.0);
+        // Empty text is limited by height
+        assert_eq!(fit_font_size(bounds, 0, 0.5), 40.0);
+    }
+}
@@ -1,12 +1,15 @@
-use super::{EdgeLayout, UseLayout};
+use super::{overflow::EdgeOverflow, EdgeLayout, UseLayout};
 use crate::{
     aspect_ratio::KnownAspectRatio,
     bounds::Bounds,
     edge::Edge,
+    shared::SharedLeftWidth,
     state::{PreState, State},
-    Tick,
+    ticks::GeneratedTicks,
+    Tick, YAxis,
 };
 use leptos::prelude::*;
+use std::cmp::Reverse;
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -25,11 +28,71 @@ pub struct DeferredRender {
     edge: Edge,
     bounds: Memo<Bounds>,
     layout: UseLayout,
+    // Fitted size vs. the size the component asked for
+    size: Signal<f64>,
+    natural: Signal<f64>,
 }
 
 impl DeferredRender {
     pub fn render<X: Tick, Y: Tick>(self, state: State<X, Y>) -> impl IntoView {
-        self.layout.render(self.edge, self.bounds, state)
+        let DeferredRender {
+            edge,
+            bounds,
+            layout,
+            size,
+            natural,
+        } = self;
+        let outer = state.layout.outer;
+        // Clip truncated components to their bounds. Allow overflow along the edge
+        let clip = Memo::new(move |_| {
+            let bounds = bounds.get();
+            let outer = outer.get();
+            if edge.is_horizontal() {
+                Bounds::from_points(
+                    outer.left_x(),
+                    bounds.top_y(),
+                    outer.right_x(),
+                    bounds.bottom_y(),
+                )
+            } else {
+                Bounds::from_points(
+                    bounds.left_x(),
+                    outer.top_y(),
+                    bounds.right_x(),
+                    outer.bottom_y(),
+                )
+            }
+        });
+        let view_box = move || {
+            let clip = clip.get();
+            format!(
+                "{} {} {} {}",
+                clip.left_x(),
+                clip.top_y(),
+                clip.width(),
+                clip.height()
+            )
+        };
+        let overflow = move || {
+            if size.get() < natural.get() {
+                "hidden"
+            } else {
+                "visible"
+            }
+        };
+        view! {
+            <Show when=move || { size.get() > 0.0 }>
+                <svg
+                    x=move || clip.get().left_x()
+                    y=move || clip.get().top_y()
+                    width=move || clip.get().width()
+                    height=move || clip.get().height()
+                    viewBox=view_box
+                    overflow=overflow>
+                    {layout.clone().render(edge, bounds, state.clone())}
+                </svg>
+            </Show>
+        }
     }
 }
 
@@ -42,9 +105,14 @@ impl Layout {
     ///
     /// This function is long but procedural. General process:
     ///  - Constrain the layout using fixed height from top / bottom edges.
+    ///  - Hide or truncate edges that overflow the chart (if requested).
     ///  - Calculate the inner height.
+    ///  - Expand the Y range to nice ticks if requested.
+    ///  - Align the secondary Y range with the primary ticks if requested.
     ///  - Process the left / right components using inner height.
+    ///  - Align the left edge with other charts (e.g., in a grid) if requested.
     ///  - Calculate the inner width.
+    ///  - Expand the X range to nice ticks if requested.
     ///  - Process top / bottom components using inner width.
     ///  - Calculate the bounds: outer, inner, edges, edge components. Adhere to aspect ratio.
     ///  - Return state (Layout) and a deferred renderer (ComposedLayout).
@@ -55,23 +123,88 @@ impl Layout {
         bottom: &[EdgeLayout<X>],
         left: &[EdgeLayout<Y>],
         aspect_ratio: Memo<KnownAspectRatio>,
-        state: &PreState<X, Y>,
+        overflow: Signal<EdgeOverflow>,
+        state: &mut PreState<X, Y>,
     ) -> (Layout, Vec<DeferredRender>) {
+        // Space available to edges on outer charts
+        let avail_edges = move |horizontal: bool| {
+            Memo::new(move |_| match aspect_ratio.get() {
+                KnownAspectRatio::Inner(_) => f64::INFINITY,
+                KnownAspectRatio::Outer(vars) => {
+                    let (min_width, min_height) = overflow.get().min_inner();
+                    if horizontal {
+                        vars.height() - min_height
+                    } else {
+                        vars.width() - min_width
+                    }
+                }
+            })
+        };
+
         // Horizontal options
-        let top_heights = collect_heights(top, state);
+        let top_natural = collect_heights(top, state);
+        let bottom_natural = collect_heights(bottom, state);
+        let (top_heights, bottom_heights) = fit_sizes(
+            overflow,
+            avail_edges(true),
+            (priorities(top), top_natural.clone()),
+            (priorities(bottom), bottom_natural.clone()),
+        );
         let top_height = sum_sizes(top_heights.clone());
-        let bottom_heights = collect_heights(bottom, state);
         let bottom_height = sum_sizes(bottom_heights.clone());
         let inner_height =
             KnownAspectRatio::inner_height_signal(aspect_ratio, top_height, bottom_height);
 
+        // Nice domains expand the Y range using ticks generated for the inner height
+        for edge in left.iter().chain(right) {
+            if let EdgeLayout::TickLabels(labels) = edge {
+                let range = labels.nice_range_y(state, inner_height);
+                match labels.y_axis.get_untracked() {
+                    YAxis::Secondary => state.data.range_y_secondary = range,
+                    _ => state.data.range_y = range,
+                }
+            }
+        }
+
+        // Secondary Y labels can align with the primary ticks so their grid lines coincide
+        let primary_ticks = left.iter().chain(right).find_map(|edge| match edge {
+            EdgeLayout::TickLabels(labels) if labels.y_axis.get_untracked() != YAxis::Secondary => {
+                Some(labels.generate_y(state, inner_height.into()))
+            }
+            _ => None,
+        });
+        if let Some(primary_ticks) = primary_ticks {
+            for edge in left.iter().chain(right) {
+                if let EdgeLayout::TickLabels(labels) = edge {
+                    if labels.y_axis.get_untracked() == YAxis::Secondary {
+                        state.data.range_y_secondary = labels.align_range_y(state, primary_ticks);
+                    }
+                }
+            }
+        }
+
         // Vertical options
-        let (left_widths, left) = use_vertical(left, state, inner_height);
+        let (left_priority, right_priority) = (priorities(left), priorities(right));
+        let (left_natural, left) = use_vertical(left, state, inner_height, primary_ticks);
+        let (right_natural, right) = use_vertical(right, state, inner_height, primary_ticks);
+        let (left_widths, right_widths) = fit_sizes(
+            overflow,
+            avail_edges(false),
+            (left_priority, left_natural.clone()),
+            (right_priority, right_natural.clone()),
+        );
         let left_width = sum_sizes(left_widths.clone());
-        let (right_widths, right) = use_vertical(right, state, inner_height);
+        let left_width = use_context::<SharedLeftWidth>()
+            .map(|shared| shared.align(left_width))
+            .unwrap_or(left_width);
         let right_width = sum_sizes(right_widths.clone());
         let avail_width =
             KnownAspectRatio::inner_width_signal(aspect_ratio, left_width, right_width);
+        for edge in top.iter().chain(bottom) {
+            if let EdgeLayout::TickLabels(labels) = edge {
+                state.data.range_x = labels.nice_range_x(state, avail_width);
+            }
+        }
 
         // Bounds
         let outer = Memo::new(move |_| {
@@ -109,53 +242,83 @@ impl Layout {
         });
 
         // Find the width of each X
-        let data_len = state.data.len;
-        let x_width = Memo::new(move |_| inner.get().width() / data_len.get() as f64);
+        let len_x = state.data.len_x;
+        let x_width = Memo::new(move |_| inner.get().width() / len_x.get().max(1) as f64);
 
         // State signals
         let layout = Layout {
             outer,
-            top: option_bounds(Edge::Top, top_bounds, top_heights),
-            right: option_bounds(Edge::Right, right_bounds, right_widths),
-            bottom: option_bounds(Edge::Bottom, bottom_bounds, bottom_heights),
-            left: option_bounds(Edge::Left, left_bounds, left_widths),
+            top: option_bounds(Edge::Top, top_bounds, top_heights.clone()),
+            right: option_bounds(Edge::Right, right_bounds, right_widths.clone()),
+            bottom: option_bounds(Edge::Bottom, bottom_bounds, bottom_heights.clone()),
+            left: option_bounds(Edge::Left, left_bounds, left_widths.clone()),
             inner,
             x_width,
         };
 
-        let vertical = |edge, bounds: &[Memo<Bounds>], items: Vec<_>| {
-            items
-                .into_iter()
-                .enumerate()
-                .map(move |(index, opt)| (edge, bounds[index], opt))
-                .collect::<Vec<_>>()
+        // Sizes of each component: (fitted, natural)
+        let sizes = |fitted: Vec<Signal<f64>>, natural: Vec<Signal<f64>>| {
+            fitted.into_iter().zip(natural).collect::<Vec<_>>()
         };
-        let horizontal = |edge: Edge, bounds: &[Memo<Bounds>], items: &[EdgeLayout<X>]| {
+        let vertical = |edge, bounds: &[Memo<Bounds>], sizes: Vec<_>, items: Vec<_>| {
             items
-                .iter()
+                .into_iter()
+                .zip(sizes)
                 .enumerate()
-                .map(|(index, opt)| {
-                    (
-                        edge,
-                        bounds[index],
-                        opt.to_horizontal_use(state, avail_width),
-                    )
-                })
+                .map(move |(index, (opt, sizes))| (edge, bounds[index], opt, sizes))
                 .collect::<Vec<_>>()
         };
+        let horizontal =
+            |edge: Edge, bounds: &[Memo<Bounds>], sizes: Vec<_>, items: &[EdgeLayout<X>]| {
+                items
+                    .iter()
+                    .zip(sizes)
+                    .enumerate()
+                    .map(|(index, (opt, sizes))| {
+                        (
+                            edge,
+                            bounds[index],
+                            opt.to_horizontal_use(state, avail_width),
+                            sizes,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            };
 
         // Chain edges together for a deferred render
-        let deferred = vertical(Edge::Left, &layout.left, left)
-            .into_iter()
-            .chain(vertical(Edge::Right, &layout.right, right))
-            .chain(horizontal(Edge::Top, &layout.top, top))
-            .chain(horizontal(Edge::Bottom, &layout.bottom, bottom))
-            .map(|(edge, bounds, layout)| DeferredRender {
-                edge,
-                bounds,
-                layout,
-            })
-            .collect::<Vec<_>>();
+        let deferred = vertical(
+            Edge::Left,
+            &layout.left,
+            sizes(left_widths, left_natural),
+            left,
+        )
+        .into_iter()
+        .chain(vertical(
+            Edge::Right,
+            &layout.right,
+            sizes(right_widths, right_natural),
+            right,
+        ))
+        .chain(horizontal(
+            Edge::Top,
+            &layout.top,
+            sizes(top_heights, top_natural),
+            top,
+        ))
+        .chain(horizontal(
+            Edge::Bottom,
+            &layout.bottom,
+            sizes(bottom_heights, bottom_natural),
+            bottom,
+        ))
+        .map(|(edge, bounds, layout, (size, natural))| DeferredRender {
+            edge,
+            bounds,
+            layout,
+            size,
+            natural,
+        })
+        .collect::<Vec<_>>();
 
         (layout, deferred)
     }
@@ -175,16 +338,53 @@ fn use_vertical<X: Tick, Y: Tick>(
     items: &[EdgeLayout<Y>],
     state: &PreState<X, Y>,
     avail_height: Memo<f64>,
+    primary_ticks: Option<Memo<GeneratedTicks<Y>>>,
 ) -> (Vec<Signal<f64>>, Vec<UseLayout>) {
     items
         .iter()
         .map(|c| {
-            let vert = c.to_vertical_use(state, avail_height);
+            let vert = match (c, primary_ticks) {
+                (EdgeLayout::TickLabels(labels), Some(primary))
+                    if labels.y_axis.get_untracked() == YAxis::Secondary =>
+                {
+                    labels.to_aligned_vertical_use(state, avail_height, primary)
+                }
+                _ => c.to_vertical_use(state, avail_height),
+            };
             (vert.width, vert.layout)
         })
         .unzip()
 }
 
+fn priorities<XY: Tick>(items: &[EdgeLayout<XY>]) -> Vec<RwSignal<usize>> {
+    items.iter().map(|c| c.priority()).collect()
+}
+
+/// Fits the sizes of two opposing edges (e.g., top and bottom) into the available space. Components are ranked by their priority then distance from the inner chart area.
+fn fit_sizes(
+    overflow: Signal<EdgeOverflow>,
+    avail: Memo<f64>,
+    a: (Vec<RwSignal<usize>>, Vec<Signal<f64>>),
+    b: (Vec<RwSignal<usize>>, Vec<Signal<f64>>),
+) -> (Vec<Signal<f64>>, Vec<Signal<f64>>) {
+    let (a_len, b_len) = (a.1.len(), b.1.len());
+    let fitted = Memo::new(move |_| {
+        let rank = |(distance, (priority, size)): (usize, (&RwSignal<usize>, &Signal<f64>))| {
+            ((priority.get(), Reverse(distance)), size.get())
+        };
+        let sizes = (a.0.iter().zip(&a.1).enumerate().map(rank))
+            .chain(b.0.iter().zip(&b.1).enumerate().map(rank))
+            .collect::<Vec<_>>();
+        overflow.get().fit(&sizes, avail.get())
+    });
+    let split = |range: std::ops::Range<usize>| {
+        range
+            .map(|index| Signal::derive(move || fitted.with(|fitted| fitted[index])))
+            .collect::<Vec<_>>()
+    };
+    (split(0..a_len), split(a_len..a_len + b_len))
+}
+
 fn sum_sizes(sizes: Vec<Signal<f64>>) -> Memo<f64> {
     Memo::new(move |_| sizes.iter().map(|opt| opt.get()).sum::<f64>())
 }
@@ -15,12 +15,36 @@ use leptos::{either::Either, prelude::*};
 pub struct Legend {
     /// Anchor of the legend.
     pub anchor: RwSignal<Anchor>,
+    /// Priority when the chart is too small to fit its edges. Lower priorities are hidden or truncated first. Default is 1 (before tick labels). See [EdgeOverflow](crate::EdgeOverflow).
+    pub priority: RwSignal<usize>,
+    /// Whether clicking an entry hides or shows its series by adding or removing its [stable ID](crate::UseY::key) from [ViewState::hidden](crate::ViewState::hidden). Hidden entries are greyed out. Default is false.
+    pub toggle: RwSignal<bool>,
+    /// Called when a legend entry is clicked. Default is none.
+    pub on_click: RwSignal<Option<Callback<LegendEvent>>>,
+    /// Called when the mouse enters (`Some`) or leaves (`None`) a legend entry. Default is none.
+    pub on_hover: RwSignal<Option<Callback<Option<LegendEvent>>>>,
+}
+
+/// Describes a legend entry. Passed to a [Legend]'s `on_click` and `on_hover` callbacks.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct LegendEvent {
+    /// Unique identifier of the series within the chart.
+    pub id: usize,
+    /// Stable ID of the series. See [UseY::key](crate::UseY::key).
+    pub key: String,
+    /// Name of the series.
+    pub name: String,
 }
 
 impl Legend {
     pub(crate) fn new(anchor: Anchor) -> Self {
         Self {
             anchor: RwSignal::new(anchor),
+            priority: RwSignal::new(1),
+            toggle: RwSignal::default(),
+            on_click: RwSignal::default(),
+            on_hover: RwSignal::default(),
         }
     }
 
@@ -37,11 +61,38 @@ impl Legend {
         Self::new(Anchor::End)
     }
 
+    /// Sets the priority of the legend when the chart is too small to fit its edges.
+    pub fn with_priority(self, priority: usize) -> Self {
+        self.priority.set(priority);
+        self
+    }
+
+    /// Sets whether clicking an entry hides or shows its series. See [Legend::toggle] for details.
+    pub fn with_toggle(self, toggle: impl Into<bool>) -> Self {
+        self.toggle.set(toggle.into());
+        self
+    }
+
+    /// Sets a callback for when a legend entry is clicked e.g., to navigate to or filter by a series.
+    pub fn with_on_click(self, on_click: impl Fn(LegendEvent) + Send + Sync + 'static) -> Self {
+        self.on_click.set(Some(Callback::new(on_click)));
+        self
+    }
+
+    /// Sets a callback for when the mouse enters or leaves a legend entry. Passes `None` on leave.
+    pub fn with_on_hover(
+        self,
+        on_hover: impl Fn(Option<LegendEvent>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_hover.set(Some(Callback::new(on_hover)));
+        self
+    }
+
     pub(crate) fn width<X: Tick, Y: Tick>(state: &PreState<X, Y>) -> Signal<f64> {
         let font_height = state.font_height;
         let font_width = state.font_width;
         let padding = state.padding;
-        let series = state.data.series;
+        let series = legend_series(state.data.series);
         let snippet_bounds = UseY::snippet_width(font_height, font_width);
         Signal::derive(move || {
             let font_width = font_width.get();
@@ -82,20 +133,23 @@ pub(crate) fn Legend<X: Tick, Y: Tick>(
     #[prop(into)] edge: Signal<Edge>,
     bounds: Memo<Bounds>,
     state: State<X, Y>,
+    /// Overrides the legend's anchor e.g., for an auto placed inset legend.
+    #[prop(optional, into)]
+    anchor: Option<Signal<Anchor>>,
 ) -> impl IntoView {
-    let anchor = legend.anchor;
+    let anchor = anchor.unwrap_or_else(|| legend.anchor.into());
     let debug = state.pre.debug;
     let font_height = state.pre.font_height;
     let padding = state.pre.padding;
-    let series = state.pre.data.series;
+    let series = legend_series(state.pre.data.series);
 
     // Don't apply padding on the edges of our axis i.e., maximise the space we extend over
     let padding = Memo::new(move |_| {
         let padding = padding.get();
         if edge.get().is_horizontal() {
-            Padding::sides(padding.top, 0.0, padding.bottom, 0.0)
+            Padding::sides(padding.top(), 0.0, padding.bottom(), 0.0)
         } else {
-            Padding::sides(0.0, padding.right, 0.0, padding.left)
+            Padding::sides(0.0, padding.right(), 0.0, padding.left())
         }
     });
     let inner = Signal::derive(move || padding.get().apply(bounds.get()));
@@ -104,11 +158,11 @@ pub(crate) fn Legend<X: Tick, Y: Tick>(
         let edge = edge.get();
         let body = if edge.is_horizontal() {
             Either::Left(view! {
-                <HorizontalBody series=series state=state.clone() />
+                <HorizontalBody legend=legend.clone() series=series state=state.clone() />
             })
         } else {
             Either::Right(view! {
-                <VerticalBody series=series state=state.clone() />
+                <VerticalBody legend=legend.clone() series=series state=state.clone() />
             })
         };
         view! {
@@ -142,12 +196,27 @@ pub(crate) fn Legend<X: Tick, Y: Tick>(
     }
 }
 
+/// Series listed in the legend.
+fn legend_series(series: Memo<Vec<UseY>>) -> Memo<Vec<UseY>> {
+    Memo::new(move |_| {
+        let mut series = series.get();
+        series.retain(UseY::in_legend);
+        series
+    })
+}
+
 #[component]
-fn VerticalBody<X: Tick, Y: Tick>(series: Memo<Vec<UseY>>, state: State<X, Y>) -> impl IntoView {
+fn VerticalBody<X: Tick, Y: Tick>(
+    legend: Legend,
+    series: Memo<Vec<UseY>>,
+    state: State<X, Y>,
+) -> impl IntoView {
     let padding = move || {
         let p = state.pre.padding.get();
-        format!("0 {}px 0 {}px", p.right, p.left)
+        format!("0 {}px 0 {}px", p.right(), p.left())
     };
+    let hidden = state.pre.data.hidden;
+    let state = StoredValue::new(state);
     view! {
         <For
             each=move || series.get()
@@ -155,7 +224,9 @@ fn VerticalBody<X: Tick, Y: Tick>(series: Memo<Vec<UseY>>, state: State<X, Y>) -
             let:series>
             <tr>
                 <td style:padding=padding>
-                    <Snippet series=series state=state.clone() />
+                    <Entry legend=legend.clone() series=series.clone() hidden=hidden>
+                        <Snippet series=series state=state.get_value() />
+                    </Entry>
                 </td>
             </tr>
         </For>
@@ -163,13 +234,19 @@ fn VerticalBody<X: Tick, Y: Tick>(series: Memo<Vec<UseY>>, state: State<X, Y>) -
 }
 
 #[component]
-fn HorizontalBody<X: Tick, Y: Tick>(series: Memo<Vec<UseY>>, state: State<X, Y>) -> impl IntoView {
+fn HorizontalBody<X: Tick, Y: Tick>(
+    legend: Legend,
+    series: Memo<Vec<UseY>>,
+    state: State<X, Y>,
+) -> impl IntoView {
     let padding_left = move |i| {
         (i != 0)
-            .then_some(state.pre.padding.get().left)
+            .then_some(state.pre.padding.get().left())
             .map(|p| format!("{}px", p))
             .unwrap_or_default()
     };
+    let hidden = state.pre.data.hidden;
+    let state = StoredValue::new(state);
     view! {
         <tr>
             <For
@@ -177,9 +254,74 @@ fn HorizontalBody<X: Tick, Y: Tick>(series: Memo<Vec<UseY>>, state: State<X, Y>)
                 key=|(_, series)| series.id
                 let:series>
                 <td style:padding-left=move || padding_left(series.0)>
-                    <Snippet series=series.1 state=state.clone() />
+                    <Entry legend=legend.clone() series=series.1.clone() hidden=hidden>
+                        <Snippet series=series.1 state=state.get_value() />
+                    </Entry>
                 </td>
             </For>
         </tr>
     }
 }
+
+/// Legend entry. Toggles the series in `hidden` and calls the legend's callbacks.
+#[component]
+fn Entry(
+    legend: Legend,
+    series: UseY,
+    hidden: RwSignal<Vec<String>>,
+    children: Children,
+) -> impl IntoView {
+    let Legend {
+        toggle,
+        on_click,
+        on_hover,
+        ..
+    } = legend;
+    let (id, name, key) = (series.id, series.name, StoredValue::new(series.key()));
+    let is_hidden = {
+        let series = series.clone();
+        Memo::new(move |_| hidden.with(|hidden| series.is_hidden_by(hidden)))
+    };
+    let event = move || LegendEvent {
+        id,
+        key: key.get_value(),
+        name: name.get_untracked(),
+    };
+    let hover = move |entered: bool| {
+        if let Some(on_hover) = on_hover.get_untracked() {
+            on_hover.run(entered.then(event));
+        }
+    };
+    let cursor = move || {
+        if toggle.get() || on_click.read().is_some() {
+            "pointer"
+        } else {
+            "auto"
+        }
+    };
+    view! {
+        <div
+            class="_chartistry_legend_entry"
+            style:cursor=cursor
+            style:opacity=move || if is_hidden.get() { "0.4" } else { "1" }
+            on:click=move |_| {
+                if toggle.get_untracked() {
+                    if is_hidden.get_untracked() {
+                        let name = name.get_untracked();
+                        hidden.update(|hidden| {
+                            hidden.retain(|hidden| *hidden != name && key.with_value(|key| hidden != key))
+                        });
+                    } else {
+                        hidden.update(|hidden| hidden.push(key.get_value()));
+                    }
+                }
+                if let Some(on_click) = on_click.get_untracked() {
+                    on_click.run(event());
+                }
+            }
+            on:mouseenter=move |_| hover(true)
+            on:mouseleave=move |_| hover(false)>
+            {children()}
+        </div>
+    }
+}
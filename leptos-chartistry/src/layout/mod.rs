@@ -1,5 +1,7 @@
 mod compose;
 pub mod legend;
+pub mod overflow;
+pub mod range_slider;
 pub mod rotated_label;
 pub mod tick_labels;
 
@@ -11,7 +13,7 @@ use crate::{
     state::{PreState, State},
     Tick,
 };
-use leptos::{either::EitherOf3, prelude::*};
+use leptos::{either::EitherOf4, prelude::*};
 
 /// All possible layout options for an edge of a [Chart](crate::Chart). See [IntoEdge](trait@IntoEdge) for details.
 #[derive(Clone)]
@@ -23,6 +25,8 @@ pub enum EdgeLayout<XY: Tick> {
     RotatedLabel(rotated_label::RotatedLabel),
     /// Tick labels. See [tick_labels](struct@tick_labels::TickLabels) for details.
     TickLabels(tick_labels::TickLabels<XY>),
+    /// Range slider. See [range_slider](struct@range_slider::RangeSlider) for details.
+    RangeSlider(range_slider::RangeSlider<XY>),
 }
 
 struct UseVerticalLayout {
@@ -35,6 +39,7 @@ enum UseLayout {
     Legend(legend::Legend),
     RotatedLabel(rotated_label::RotatedLabel),
     TickLabels(tick_labels::UseTickLabels),
+    RangeSlider(range_slider::UseRangeSlider),
 }
 
 impl UseLayout {
@@ -45,15 +50,29 @@ impl UseLayout {
         state: State<X, Y>,
     ) -> impl IntoView {
         match self {
-            Self::Legend(inner) => EitherOf3::A(view! {
+            Self::Legend(inner) => EitherOf4::A(view! {
                 <legend::Legend legend=inner edge=edge bounds=bounds state=state />
             }),
-            Self::RotatedLabel(inner) => EitherOf3::B(view! {
+            Self::RotatedLabel(inner) => EitherOf4::B(view! {
                 <rotated_label::RotatedLabel label=inner edge=edge bounds=bounds state=state />
             }),
-            Self::TickLabels(inner) => EitherOf3::C(view! {
+            Self::TickLabels(inner) => EitherOf4::C(view! {
                 <tick_labels::TickLabels ticks=inner edge=edge bounds=bounds state=state />
             }),
+            Self::RangeSlider(inner) => EitherOf4::D(view! {
+                <range_slider::RangeSlider slider=inner edge=edge bounds=bounds state=state />
+            }),
+        }
+    }
+}
+
+impl<XY: Tick> EdgeLayout<XY> {
+    fn priority(&self) -> RwSignal<usize> {
+        match self {
+            Self::Legend(inner) => inner.priority,
+            Self::RotatedLabel(inner) => inner.priority,
+            Self::TickLabels(inner) => inner.priority,
+            Self::RangeSlider(inner) => inner.priority,
         }
     }
 }
@@ -64,6 +83,7 @@ impl<X: Tick> EdgeLayout<X> {
             Self::Legend(inner) => inner.fixed_height(state),
             Self::RotatedLabel(inner) => inner.fixed_height(state),
             Self::TickLabels(inner) => inner.fixed_height(state),
+            Self::RangeSlider(inner) => inner.fixed_height(state),
         }
     }
 
@@ -76,6 +96,7 @@ impl<X: Tick> EdgeLayout<X> {
             Self::Legend(inner) => inner.to_horizontal_use(),
             Self::RotatedLabel(inner) => inner.to_horizontal_use(),
             Self::TickLabels(inner) => inner.to_horizontal_use(state, avail_width),
+            Self::RangeSlider(inner) => inner.to_horizontal_use(state),
         }
     }
 }
@@ -90,6 +111,7 @@ impl<Y: Tick> EdgeLayout<Y> {
             Self::Legend(inner) => inner.to_vertical_use(state),
             Self::RotatedLabel(inner) => inner.to_vertical_use(state),
             Self::TickLabels(inner) => inner.to_vertical_use(state, avail_height),
+            Self::RangeSlider(inner) => inner.to_vertical_use(),
         }
     }
 }
@@ -124,3 +146,4 @@ macro_rules! impl_into_edge {
 impl_into_edge!(legend::Legend, Legend);
 impl_into_edge!(rotated_label::RotatedLabel, RotatedLabel);
 impl_into_edge!(tick_labels::TickLabels<XY>, TickLabels);
+impl_into_edge!(range_slider::RangeSlider<XY>, RangeSlider);
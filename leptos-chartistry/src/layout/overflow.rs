@@ -0,0 +1,115 @@
+/// Describes what happens to edge components (legends, labels, etc.) when a chart is too small to fit them. Only applies to charts with outer (or environment) dimensions as an inner aspect ratio always fits.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum EdgeOverflow {
+    /// Edges squeeze the inner chart area which can shrink to nothing. This is the default.
+    #[default]
+    Squeeze,
+    /// Hide edge components until the inner chart area has a minimum width and height. The lowest priority components are hidden first, then those furthest from the inner chart.
+    Hide {
+        /// Minimum width of the inner chart area.
+        min_width: f64,
+        /// Minimum height of the inner chart area.
+        min_height: f64,
+    },
+    /// Truncate edge components so that the inner chart area has a minimum width and height. The highest priority components (then those nearest the inner chart) are kept whole while others are cut off.
+    Truncate {
+        /// Minimum width of the inner chart area.
+        min_width: f64,
+        /// Minimum height of the inner chart area.
+        min_height: f64,
+    },
+}
+
+impl EdgeOverflow {
+    /// Hide edge components to keep a minimum inner chart area. See [EdgeOverflow::Hide].
+    pub fn hide(min_width: f64, min_height: f64) -> Self {
+        Self::Hide {
+            min_width,
+            min_height,
+        }
+    }
+
+    /// Truncate edge components to keep a minimum inner chart area. See [EdgeOverflow::Truncate].
+    pub fn truncate(min_width: f64, min_height: f64) -> Self {
+        Self::Truncate {
+            min_width,
+            min_height,
+        }
+    }
+
+    /// Minimum inner (width, height). Zero when squeezing.
+    pub(super) fn min_inner(&self) -> (f64, f64) {
+        match *self {
+            Self::Squeeze => (0.0, 0.0),
+            Self::Hide {
+                min_width,
+                min_height,
+            }
+            | Self::Truncate {
+                min_width,
+                min_height,
+            } => (min_width, min_height),
+        }
+    }
+
+    /// Fits edge component sizes into the available space. Each size has a key: lower keys are hidden or truncated first. Returns the fitted sizes in the same order.
+    pub(super) fn fit<K: Ord>(&self, sizes: &[(K, f64)], avail: f64) -> Vec<f64> {
+        let mut fitted = sizes.iter().map(|&(_, size)| size).collect::<Vec<_>>();
+        let total = fitted.iter().sum::<f64>();
+        if total <= avail {
+            return fitted;
+        }
+        // Order by key, highest (kept) first
+        let mut order = (0..sizes.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| sizes[b].0.cmp(&sizes[a].0));
+        match self {
+            Self::Squeeze => {}
+            Self::Hide { .. } => {
+                let mut total = total;
+                for &index in order.iter().rev() {
+                    if total <= avail {
+                        break;
+                    }
+                    total -= fitted[index];
+                    fitted[index] = 0.0;
+                }
+            }
+            Self::Truncate { .. } => {
+                let mut remaining = avail.max(0.0);
+                for &index in &order {
+                    fitted[index] = fitted[index].min(remaining);
+                    remaining -= fitted[index];
+                }
+            }
+        }
+        fitted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZES: &[(usize, f64)] = &[(2, 10.0), (1, 20.0), (2, 5.0), (0, 8.0)];
+
+    #[test]
+    fn test_fit() {
+        // Fits
+        let hide = EdgeOverflow::hide(0.0, 0.0);
+        assert_eq!(hide.fit(SIZES, 43.0), vec![10.0, 20.0, 5.0, 8.0]);
+        // Squeeze ignores space
+        assert_eq!(
+            EdgeOverflow::Squeeze.fit(SIZES, 0.0),
+            vec![10.0, 20.0, 5.0, 8.0]
+        );
+        // Hide lowest key first
+        assert_eq!(hide.fit(SIZES, 40.0), vec![10.0, 20.0, 5.0, 0.0]);
+        assert_eq!(hide.fit(SIZES, 30.0), vec![10.0, 0.0, 5.0, 0.0]);
+        assert_eq!(hide.fit(SIZES, -1.0), vec![0.0, 0.0, 0.0, 0.0]);
+        // Truncate lowest key first
+        let truncate = EdgeOverflow::truncate(0.0, 0.0);
+        assert_eq!(truncate.fit(SIZES, 40.0), vec![10.0, 20.0, 5.0, 5.0]);
+        assert_eq!(truncate.fit(SIZES, 12.0), vec![10.0, 0.0, 2.0, 0.0]);
+    }
+}
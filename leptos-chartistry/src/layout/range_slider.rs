@@ -0,0 +1,255 @@
+use super::{UseLayout, UseVerticalLayout};
+use crate::{
+    bounds::Bounds,
+    colours::Colour,
+    debug::DebugRect,
+    edge::Edge,
+    series::Range,
+    state::{PreState, State},
+    Tick, AXIS_MARKER_COLOUR,
+};
+use leptos::{ev, prelude::*};
+
+/// Builds a two-thumb slider for selecting an X range. Placed on the top or bottom edge. The track spans the X range of all data and the chart only shows the selected range.
+///
+/// Bound to a pair of `min_x` and `max_x` signals which can be shared with the rest of an app e.g., a series' [min_x](crate::Series::min_x) and [max_x](crate::Series::max_x). A `None` thumb sits at the end of the track.
+///
+/// Not supported on the left or right edges.
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub struct RangeSlider<X: Tick> {
+    /// Start of the selected range. `None` selects from the start of the data.
+    pub min_x: RwSignal<Option<X>>,
+    /// End of the selected range. `None` selects to the end of the data.
+    pub max_x: RwSignal<Option<X>>,
+    /// Colour of the track and selection.
+    pub colour: RwSignal<Colour>,
+    /// Priority when the chart is too small to fit its edges. Lower priorities are hidden or truncated first. Default is 2 (alongside tick labels). See [EdgeOverflow](crate::EdgeOverflow).
+    pub priority: RwSignal<usize>,
+}
+
+#[derive(Clone)]
+pub struct UseRangeSlider {
+    colour: RwSignal<Colour>,
+    // Positions of the full range and the (min, max) thumbs
+    full: Signal<Option<(f64, f64)>>,
+    thumbs: Signal<Option<(f64, f64)>>,
+    // Sets a thumb (is min?) to a position
+    set_thumb: Callback<(bool, f64)>,
+}
+
+impl<X: Tick> Clone for RangeSlider<X> {
+    fn clone(&self) -> Self {
+        Self {
+            min_x: self.min_x,
+            max_x: self.max_x,
+            colour: self.colour,
+            priority: self.priority,
+        }
+    }
+}
+
+impl<X: Tick> Default for RangeSlider<X> {
+    fn default() -> Self {
+        Self::new(RwSignal::default(), RwSignal::default())
+    }
+}
+
+impl<X: Tick> RangeSlider<X> {
+    /// Creates a new range slider bound to the given signals.
+    pub fn new(min_x: RwSignal<Option<X>>, max_x: RwSignal<Option<X>>) -> Self {
+        Self {
+            min_x,
+            max_x,
+            colour: RwSignal::new(AXIS_MARKER_COLOUR),
+            priority: RwSignal::new(2),
+        }
+    }
+
+    /// Sets the colour of the track and selection.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sets the priority of the slider when the chart is too small to fit its edges.
+    pub fn with_priority(self, priority: usize) -> Self {
+        self.priority.set(priority);
+        self
+    }
+
+    /// Restricts an X range to the selected range.
+    pub(crate) fn apply(&self, range_x: Memo<Range<X>>) -> Memo<Range<X>> {
+        let (min_x, max_x) = (self.min_x, self.max_x);
+        Memo::new(move |_| range_x.get().restrict(min_x.get(), max_x.get()))
+    }
+
+    pub(super) fn fixed_height<Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
+        let font_height = state.font_height;
+        let padding = state.padding;
+        Signal::derive(move || font_height.get() + padding.get().height())
+    }
+
+    pub(super) fn to_horizontal_use<Y: Tick>(&self, state: &PreState<X, Y>) -> UseLayout {
+        let (min_x, max_x) = (self.min_x, self.max_x);
+        let full_range_x = state.data.full_range_x;
+        let full = Signal::derive(move || full_range_x.read().positions());
+        let thumbs = Signal::derive(move || {
+            let (first, last) = full.get()?;
+            let min = min_x.read().as_ref().map_or(first, |x| x.position());
+            let max = max_x.read().as_ref().map_or(last, |x| x.position());
+            Some((min.clamp(first, last), max.clamp(first, last)))
+        });
+        let set_thumb = Callback::new(move |(is_min, position): (bool, f64)| {
+            let Some(tick) = full_range_x.with_untracked(|range| {
+                range
+                    .range()
+                    .and_then(|(first, _)| first.with_position(position))
+            }) else {
+                return;
+            };
+            if is_min {
+                min_x.set(Some(tick));
+            } else {
+                max_x.set(Some(tick));
+            }
+        });
+        UseLayout::RangeSlider(UseRangeSlider {
+            colour: self.colour,
+            full,
+            thumbs,
+            set_thumb,
+        })
+    }
+}
+
+impl<Y: Tick> RangeSlider<Y> {
+    pub(super) fn to_vertical_use(&self) -> UseVerticalLayout {
+        // Not supported: take up no space
+        UseVerticalLayout {
+            width: Signal::derive(|| 0.0),
+            layout: UseLayout::RangeSlider(UseRangeSlider {
+                colour: self.colour,
+                full: Signal::derive(|| None),
+                thumbs: Signal::derive(|| None),
+                set_thumb: Callback::new(|_| {}),
+            }),
+        }
+    }
+}
+
+/// Maps a position between two ranges e.g., from data positions to SVG.
+fn map_between(value: f64, from: (f64, f64), to: (f64, f64)) -> f64 {
+    let span = from.1 - from.0;
+    if span == 0.0 {
+        return to.0;
+    }
+    to.0 + (value - from.0) / span * (to.1 - to.0)
+}
+
+#[component]
+pub(super) fn RangeSlider<X: Tick, Y: Tick>(
+    slider: UseRangeSlider,
+    edge: Edge,
+    bounds: Memo<Bounds>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let UseRangeSlider {
+        colour,
+        full,
+        thumbs,
+        set_thumb,
+    } = slider;
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let inner = state.layout.inner;
+    let mouse_chart = state.mouse_chart;
+
+    // SVG track (left, right) and thumbs (min, max)
+    let track = Memo::new(move |_| inner.with(|inner| (inner.left_x(), inner.right_x())));
+    let thumbs_svg = Memo::new(move |_| {
+        let (full, (min, max)) = (full.get()?, thumbs.get()?);
+        let track = track.get();
+        Some((map_between(min, full, track), map_between(max, full, track)))
+    });
+    let centre_y = move || bounds.get().centre_y();
+    let radius = move || font_height.get() / 3.0;
+
+    // Drag a thumb (is min?)
+    let dragging = RwSignal::new(None::<bool>);
+    Effect::new(move |_| {
+        let Some(is_min) = dragging.get() else {
+            return;
+        };
+        let (x, _) = mouse_chart.get();
+        if let (Some(full), Some((min, max))) = (full.get_untracked(), thumbs.get_untracked()) {
+            let position = map_between(x, track.get_untracked(), full);
+            // Thumbs can't cross
+            let position = if is_min {
+                position.clamp(full.0, max)
+            } else {
+                position.clamp(min, full.1)
+            };
+            set_thumb.run((is_min, position));
+        }
+    });
+    let handle = window_event_listener(ev::mouseup, move |_| dragging.set(None));
+    on_cleanup(move || handle.remove());
+
+    let thumb = move |is_min: bool| {
+        let cx = move || {
+            thumbs_svg
+                .get()
+                .map(|(min, max)| if is_min { min } else { max })
+        };
+        view! {
+            <circle
+                cx=cx
+                cy=centre_y
+                r=radius
+                fill="white"
+                stroke=move || colour.get().to_string()
+                stroke-width=2
+                style="cursor: ew-resize;"
+                on:mousedown=move |ev| {
+                    ev.stop_propagation();
+                    dragging.set(Some(is_min));
+                } />
+        }
+    };
+
+    view! {
+        <g class="_chartistry_range_slider">
+            <DebugRect label="range_slider" debug=debug bounds=vec![bounds.into()] />
+            <Show when=move || edge.is_horizontal() && thumbs_svg.get().is_some()>
+                <line
+                    x1=move || track.get().0
+                    y1=centre_y
+                    x2=move || track.get().1
+                    y2=centre_y
+                    stroke=move || colour.get().to_string()
+                    stroke-width=2 />
+                <rect
+                    x=move || thumbs_svg.get().map(|(min, _)| min)
+                    y=move || centre_y() - radius() / 2.0
+                    width=move || thumbs_svg.get().map(|(min, max)| max - min)
+                    height=radius
+                    fill=move || colour.get().to_string() />
+                {thumb(true)}
+                {thumb(false)}
+            </Show>
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_between() {
+        assert_eq!(map_between(5.0, (0.0, 10.0), (100.0, 200.0)), 150.0);
+        assert_eq!(map_between(150.0, (100.0, 200.0), (0.0, 10.0)), 5.0);
+        assert_eq!(map_between(3.0, (1.0, 1.0), (100.0, 200.0)), 100.0);
+    }
+}
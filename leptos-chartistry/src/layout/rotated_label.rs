@@ -23,7 +23,7 @@ pub enum Anchor {
 
 /// Builds a rotated label to match the orientation of the axis it's placed on.
 ///
-/// Warning: does not wrap text. Extra text will not be clipped.
+/// Text is split into lines on `\n` and optionally wrapped at word boundaries (see [RotatedLabel::with_wrap]). The layout reserves space for every line. Extra text along a line will not be clipped.
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct RotatedLabel {
@@ -31,6 +31,14 @@ pub struct RotatedLabel {
     pub text: RwSignal<String>,
     /// Anchor of the label.
     pub anchor: RwSignal<Anchor>,
+    /// Priority when the chart is too small to fit its edges. Lower priorities are hidden or truncated first. Default is 3 (after legends and tick labels). See [EdgeOverflow](crate::EdgeOverflow).
+    pub priority: RwSignal<usize>,
+    /// Wraps text at word boundaries to lines of at most this many characters. Default is `None` which only breaks lines on `\n`.
+    pub wrap: RwSignal<Option<usize>>,
+    /// Font height of the label e.g., for a big title. Default is `None` which uses the chart's `font_height`.
+    pub font_height: RwSignal<Option<f64>>,
+    /// Width of a monospaced character of the label's font. Default is `None` which uses the chart's `font_width`.
+    pub font_width: RwSignal<Option<f64>>,
 }
 
 impl RotatedLabel {
@@ -38,6 +46,10 @@ impl RotatedLabel {
         Self {
             text: RwSignal::new(text),
             anchor: RwSignal::new(anchor),
+            priority: RwSignal::new(3),
+            wrap: RwSignal::default(),
+            font_height: RwSignal::default(),
+            font_width: RwSignal::default(),
         }
     }
 
@@ -54,15 +66,36 @@ impl RotatedLabel {
         Self::new(Anchor::End, text.into())
     }
 
+    /// Sets the priority of the label when the chart is too small to fit its edges.
+    pub fn with_priority(self, priority: usize) -> Self {
+        self.priority.set(priority);
+        self
+    }
+
+    /// Sets the maximum number of characters per line. Longer text is wrapped at word boundaries.
+    pub fn with_wrap(self, max_chars: usize) -> Self {
+        self.wrap.set(Some(max_chars));
+        self
+    }
+
+    /// Sets the font height and monospaced character width of the label. Overrides the chart's font for this label only and is used to measure its layout.
+    pub fn with_font(self, height: impl Into<f64>, width: impl Into<f64>) -> Self {
+        self.font_height.set(Some(height.into()));
+        self.font_width.set(Some(width.into()));
+        self
+    }
+
     fn size<X: Tick, Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
-        let text = self.text;
+        let state = &state.with_font(self.font_height, self.font_width);
+        let (text, wrap) = (self.text, self.wrap);
         let font_height = state.font_height;
         let padding = state.padding;
         Signal::derive(move || {
-            if text.with(|t| t.is_empty()) {
+            let lines = text.with(|text| wrap_lines(text, wrap.get()).len());
+            if lines == 0 {
                 0.0
             } else {
-                font_height.get() + padding.get().height()
+                font_height.get() * lines as f64 + padding.get().height()
             }
         })
     }
@@ -92,7 +125,7 @@ impl Anchor {
         self.to_string()
     }
 
-    fn map_points(&self, left: f64, middle: f64, right: f64) -> f64 {
+    pub(super) fn map_points(&self, left: f64, middle: f64, right: f64) -> f64 {
         match self {
             Anchor::Start => left,
             Anchor::Middle => middle,
@@ -132,6 +165,33 @@ impl std::fmt::Display for Anchor {
     }
 }
 
+/// Splits text into lines on `\n` then wraps each at word boundaries to at most `max_chars`. Words longer than `max_chars` get their own line. Empty text has no lines.
+fn wrap_lines(text: &str, max_chars: Option<usize>) -> Vec<String> {
+    if text.is_empty() {
+        return vec![];
+    }
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let Some(max_chars) = max_chars else {
+            lines.push(paragraph.to_string());
+            continue;
+        };
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let line_len = line.chars().count();
+            if line_len > 0 && line_len + 1 + word.chars().count() > max_chars {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
 #[component]
 pub(super) fn RotatedLabel<X: Tick, Y: Tick>(
     label: RotatedLabel,
@@ -139,7 +199,15 @@ pub(super) fn RotatedLabel<X: Tick, Y: Tick>(
     bounds: Memo<Bounds>,
     state: State<X, Y>,
 ) -> impl IntoView {
-    let RotatedLabel { text, anchor } = label;
+    let RotatedLabel {
+        text,
+        anchor,
+        wrap,
+        font_height,
+        font_width,
+        ..
+    } = label;
+    let state = state.with_font(font_height, font_width);
     let debug = state.pre.debug;
     let font_height = state.pre.font_height;
     let padding = state.pre.padding;
@@ -159,6 +227,24 @@ pub(super) fn RotatedLabel<X: Tick, Y: Tick>(
         }
     });
 
+    // One tspan per line centred on the label's position
+    let lines = move || {
+        let lines = text.with(|text| wrap_lines(text, wrap.get()));
+        let first_dy = -(lines.len().saturating_sub(1) as f64) / 2.0;
+        (lines.into_iter().enumerate())
+            .map(|(index, line)| {
+                let dy = if index == 0 { first_dy } else { 1.0 };
+                view! {
+                    <tspan
+                        x=move || position.with(|(_, x, _)| x.to_string())
+                        dy=move || format!("{}px", dy * font_height.get())>
+                        {line}
+                    </tspan>
+                }
+            })
+            .collect_view()
+    };
+
     view! {
         <g
             class="_chartistry_rotated_label"
@@ -171,8 +257,31 @@ pub(super) fn RotatedLabel<X: Tick, Y: Tick>(
                 dominant-baseline="middle"
                 text-anchor=move || anchor.get().to_svg_attr()
                 font-size=move || format!("{}px", font_height.get())>
-                {text}
+                {lines}
             </text>
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_lines() {
+        assert_eq!(wrap_lines("", None), Vec::<String>::new());
+        assert_eq!(wrap_lines("one line", None), vec!["one line"]);
+        assert_eq!(wrap_lines("two\nlines", None), vec!["two", "lines"]);
+        assert_eq!(
+            wrap_lines("the quick brown fox", Some(10)),
+            vec!["the quick", "brown fox"]
+        );
+        // Long words aren't split
+        assert_eq!(
+            wrap_lines("a extraordinarily b", Some(5)),
+            vec!["a", "extraordinarily", "b"]
+        );
+        // Explicit breaks are kept when wrapping
+        assert_eq!(wrap_lines("a b\nc", Some(10)), vec!["a b", "c"]);
+    }
+}
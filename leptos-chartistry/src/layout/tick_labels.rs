@@ -1,14 +1,16 @@
-use super::{UseLayout, UseVerticalLayout};
+use super::{rotated_label::Anchor, UseLayout, UseVerticalLayout};
 use crate::{
     bounds::Bounds,
     debug::DebugRect,
     edge::Edge,
+    padding::Padding,
+    series::Range,
     state::{PreState, State},
     ticks::{
         AlignedFloats, GeneratedTicks, HorizontalSpan, TickFormat, TickFormatFn, TickGen,
         Timestamps, VerticalSpan,
     },
-    Tick,
+    AxisScale, Tick, YAxis,
 };
 use chrono::prelude::*;
 use leptos::prelude::*;
@@ -28,11 +30,42 @@ pub struct TickLabels<XY: Tick> {
     pub format: RwSignal<Arc<TickFormatFn<XY>>>,
     /// Tick generator for the labels.
     pub generator: RwSignal<Arc<dyn TickGen<Tick = XY> + Send + Sync>>,
+    /// Alignment of each label relative to its tick along the axis. [Anchor::Start] begins the label at the tick (right of or below it), [Anchor::End] ends the label at the tick. Default is [Anchor::Middle] which centres the label on the tick.
+    pub align: RwSignal<Anchor>,
+    /// Padding inside each label. Default is `None` which uses the chart's padding.
+    pub padding: RwSignal<Option<Padding>>,
+    /// Gap between the labels and the inner chart area (axis). Default is zero.
+    pub gap: RwSignal<f64>,
+    /// Enables dragging on the labels to change the axis range. Dragging along the X axis pans while dragging along the Y axis rescales. Double click to reset. Default is false.
+    pub drag: RwSignal<bool>,
+    /// Priority when the chart is too small to fit its edges. Lower priorities are hidden or truncated first. Default is 2 (after legends, before labels). See [EdgeOverflow](crate::EdgeOverflow).
+    pub priority: RwSignal<usize>,
+    /// Called with the tick when a label is clicked. Default is none.
+    pub on_click: RwSignal<Option<Callback<XY>>>,
+    /// Font height of the labels e.g., small tick labels next to a big title. Default is `None` which uses the chart's `font_height`.
+    pub font_height: RwSignal<Option<f64>>,
+    /// Width of a monospaced character of the labels' font. Default is `None` which uses the chart's `font_width`.
+    pub font_width: RwSignal<Option<f64>>,
+    /// Expands the axis range outward to the nearest ticks so the first and last ticks land on the chart border. Default is false.
+    pub nice_domain: RwSignal<bool>,
+    /// Y axis the labels show when on the left or right edge e.g., [YAxis::Secondary] for a right-hand scale. Dragging always rescales the primary axis. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+    /// Aligns the labels of the [secondary Y axis](YAxis::Secondary) with the primary Y axis' tick labels so their grid lines coincide. The secondary range is expanded so nice values land on the primary ticks. Ignored without primary Y tick labels. Default is false.
+    pub align_ticks: RwSignal<bool>,
 }
 
 #[derive(Clone)]
 pub struct UseTickLabels {
     ticks: Signal<Vec<(f64, String)>>,
+    align: RwSignal<Anchor>,
+    padding: Signal<Padding>,
+    gap: RwSignal<f64>,
+    drag: RwSignal<bool>,
+    clickable: Signal<bool>,
+    on_click: Callback<usize>,
+    font_height: RwSignal<Option<f64>>,
+    font_width: RwSignal<Option<f64>>,
+    y_axis: RwSignal<YAxis>,
 }
 
 impl<XY: Tick> Clone for TickLabels<XY> {
@@ -41,6 +74,17 @@ impl<XY: Tick> Clone for TickLabels<XY> {
             min_chars: self.min_chars,
             format: self.format,
             generator: self.generator,
+            align: self.align,
+            padding: self.padding,
+            gap: self.gap,
+            drag: self.drag,
+            priority: self.priority,
+            on_click: self.on_click,
+            font_height: self.font_height,
+            font_width: self.font_width,
+            nice_domain: self.nice_domain,
+            y_axis: self.y_axis,
+            align_ticks: self.align_ticks,
         }
     }
 }
@@ -56,6 +100,15 @@ impl TickLabels<f64> {
     pub fn aligned_floats() -> Self {
         Self::from_generator(AlignedFloats::default())
     }
+
+    /// Appends a unit (e.g., "B/s") to each label with a metric prefix picked for the axis range e.g., "1.2 GB/s". Replaces the tick generator. See [AlignedFloats::with_unit] for details.
+    ///
+    /// Use [Tooltip::with_y_unit](crate::Tooltip::with_y_unit) for the tooltip to follow suit.
+    pub fn with_unit(self, unit: impl Into<String>) -> Self {
+        let gen = AlignedFloats::default().with_unit(unit);
+        self.generator.set(Arc::new(gen));
+        self
+    }
 }
 
 impl<Tz> TickLabels<DateTime<Tz>>
@@ -70,12 +123,23 @@ where
 }
 
 impl<XY: Tick> TickLabels<XY> {
-    /// Creates a new tick label generator from a tick generator. Use [AlignedFloats] or [Timestamps] for available generators.
+    /// Creates a new tick label generator from a tick generator. Use [AlignedFloats], [AlignedIntegers](crate::AlignedIntegers) or [Timestamps] for available generators.
     pub fn from_generator(gen: impl TickGen<Tick = XY> + 'static) -> Self {
         Self {
             min_chars: RwSignal::default(),
             format: RwSignal::new(HorizontalSpan::identity_format()),
             generator: RwSignal::new(Arc::new(gen)),
+            align: RwSignal::new(Anchor::Middle),
+            padding: RwSignal::default(),
+            gap: RwSignal::default(),
+            drag: RwSignal::default(),
+            priority: RwSignal::new(2),
+            on_click: RwSignal::default(),
+            font_height: RwSignal::default(),
+            font_width: RwSignal::default(),
+            nice_domain: RwSignal::default(),
+            y_axis: RwSignal::default(),
+            align_ticks: RwSignal::default(),
         }
     }
 
@@ -85,6 +149,42 @@ impl<XY: Tick> TickLabels<XY> {
         self
     }
 
+    /// Sets the alignment of each label relative to its tick. See [TickLabels::align] for details.
+    pub fn with_align(self, align: impl Into<Anchor>) -> Self {
+        self.align.set(align.into());
+        self
+    }
+
+    /// Sets the padding inside each label. Overrides the chart's padding.
+    pub fn with_padding(self, padding: impl Into<Padding>) -> Self {
+        self.padding.set(Some(padding.into()));
+        self
+    }
+
+    /// Sets the gap between the labels and the inner chart area (axis).
+    pub fn with_gap(self, gap: impl Into<f64>) -> Self {
+        self.gap.set(gap.into());
+        self
+    }
+
+    /// Enables dragging on the labels to pan (X axis) or rescale (Y axis) the chart. See [TickLabels::drag] for details.
+    pub fn with_drag(self, drag: bool) -> Self {
+        self.drag.set(drag);
+        self
+    }
+
+    /// Sets the priority of the labels when the chart is too small to fit its edges.
+    pub fn with_priority(self, priority: usize) -> Self {
+        self.priority.set(priority);
+        self
+    }
+
+    /// Sets a callback for when a label is clicked e.g., to drill into a day or filter by a category.
+    pub fn with_on_click(self, on_click: impl Fn(XY) + Send + Sync + 'static) -> Self {
+        self.on_click.set(Some(Callback::new(on_click)));
+        self
+    }
+
     /// Sets the format function for the tick labels.
     ///
     /// This is a function that takes a `Tick` and a formatter and returns a `String`. It gives an opportunity to customise tick label format. The formatter is the resulting state of the tick generator and does the default aciton. For example if aligned floats decides to use "1000s" then the formatter will use that.
@@ -96,11 +196,70 @@ impl<XY: Tick> TickLabels<XY> {
         self
     }
 
+    /// Sets the font height and monospaced character width of the labels. Overrides the chart's font for these labels only and is used to measure their layout and tick density.
+    pub fn with_font(self, height: impl Into<f64>, width: impl Into<f64>) -> Self {
+        self.font_height.set(Some(height.into()));
+        self.font_width.set(Some(width.into()));
+        self
+    }
+
+    /// Expands the axis range to the nearest ticks so the outer ticks land on the chart border. See [TickLabels::nice_domain] for details.
+    pub fn with_nice_domain(self, nice_domain: bool) -> Self {
+        self.nice_domain.set(nice_domain);
+        self
+    }
+
+    /// Sets the Y axis the labels show. See [TickLabels::y_axis] for details.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+
+    /// Aligns the secondary Y axis' ticks with the primary axis. See [TickLabels::align_ticks] for details.
+    pub fn with_align_ticks(self, align_ticks: bool) -> Self {
+        self.align_ticks.set(align_ticks);
+        self
+    }
+
+    // Label padding: falls back to the chart's padding
+    fn use_padding<X: Tick, Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<Padding> {
+        let padding = self.padding;
+        let fallback = state.padding;
+        Signal::derive(move || padding.get().unwrap_or_else(|| fallback.get()))
+    }
+
+    fn to_use(
+        &self,
+        padding: Signal<Padding>,
+        gen: Memo<GeneratedTicks<XY>>,
+        ticks: Signal<Vec<(f64, String)>>,
+    ) -> UseTickLabels {
+        let on_click = self.on_click;
+        UseTickLabels {
+            ticks,
+            align: self.align,
+            padding,
+            gap: self.gap,
+            drag: self.drag,
+            clickable: Signal::derive(move || on_click.read().is_some()),
+            // Look up the tick by its index
+            on_click: Callback::new(move |index: usize| {
+                let tick = gen.with_untracked(|gen| gen.ticks.get(index).cloned());
+                if let (Some(on_click), Some(tick)) = (on_click.get_untracked(), tick) {
+                    on_click.run(tick);
+                }
+            }),
+            font_height: self.font_height,
+            font_width: self.font_width,
+            y_axis: self.y_axis,
+        }
+    }
+
     fn map_ticks(&self, gen: Memo<GeneratedTicks<XY>>) -> Signal<Vec<(f64, String)>> {
         let format = self.format;
         Signal::derive(move || {
             let format = format.get();
-            gen.with(|GeneratedTicks { ticks, state }| {
+            gen.with(|GeneratedTicks { ticks, state, .. }| {
                 ticks
                     .iter()
                     .map(|tick| (tick.position(), (format)(tick, state.as_ref())))
@@ -126,13 +285,15 @@ impl<X: Tick> TickLabels<X> {
         state: &PreState<X, Y>,
         avail_width: Signal<f64>,
     ) -> Memo<GeneratedTicks<X>> {
+        let state = &state.with_font(self.font_height, self.font_width);
         let font_width = state.font_width;
-        let padding = state.padding;
-        let range_x = state.data.range_x;
+        let padding = self.use_padding(state);
+        let (range_x, scale_x) = (state.data.range_x, state.data.scale_x);
         let TickLabels {
             min_chars,
             format,
             generator,
+            ..
         } = self.clone();
         Memo::new(move |_| {
             range_x
@@ -146,16 +307,38 @@ impl<X: Tick> TickLabels<X> {
                         avail_width.get(),
                         format.get(),
                     );
-                    generator.get().generate(first, last, &span)
+                    let mut gen = generator.get().generate(first, last, &span);
+                    // Drop ticks without a value on the scale e.g., zero on a log scale
+                    let scale_x = scale_x.get();
+                    gen.ticks
+                        .retain(|tick| scale_x.apply(tick.position()).is_finite());
+                    gen
                 })
                 .unwrap_or_else(GeneratedTicks::none)
         })
     }
 
+    /// Expands the X range to the nearest ticks if [TickLabels::nice_domain] is set.
+    pub(super) fn nice_range_x<Y: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        avail_width: Memo<f64>,
+    ) -> Memo<Range<X>> {
+        let gen = self.generate_x(state, avail_width.into());
+        nice_range(
+            self.nice_domain,
+            state.data.range_x,
+            gen,
+            state.data.scale_x.into(),
+        )
+    }
+
     pub(super) fn fixed_height<Y: Tick>(&self, state: &PreState<X, Y>) -> Signal<f64> {
+        let state = &state.with_font(self.font_height, self.font_width);
         let font_height = state.font_height;
-        let padding = state.padding;
-        Signal::derive(move || font_height.get() + padding.get().height())
+        let padding = self.use_padding(state);
+        let gap = self.gap;
+        Signal::derive(move || font_height.get() + padding.get().height() + gap.get())
     }
 
     pub(super) fn to_horizontal_use<Y: Tick>(
@@ -163,9 +346,9 @@ impl<X: Tick> TickLabels<X> {
         state: &PreState<X, Y>,
         avail_width: Memo<f64>,
     ) -> UseLayout {
-        UseLayout::TickLabels(UseTickLabels {
-            ticks: self.map_ticks(self.generate_x(state, avail_width.into())),
-        })
+        let gen = self.generate_x(state, avail_width.into());
+        let ticks = self.map_ticks(gen);
+        UseLayout::TickLabels(self.to_use(self.use_padding(state), gen, ticks))
     }
 }
 
@@ -175,45 +358,286 @@ impl<Y: Tick> TickLabels<Y> {
         state: &PreState<X, Y>,
         avail_height: Signal<f64>,
     ) -> Memo<GeneratedTicks<Y>> {
+        let state = &state.with_font(self.font_height, self.font_width);
         let font_height = state.font_height;
-        let padding = state.padding;
-        let range_y = state.data.range_y;
+        let padding = self.use_padding(state);
+        let range_y = self.range_y(state);
+        let y_break = self.y_break(state);
+        let scale_y = self.scale_y(state);
         let generator = self.generator;
         Memo::new(move |_| {
             range_y
                 .get()
                 .range()
                 .map(|(first, last)| {
+                    let y_break = y_break.get();
+                    // Keep the same density over what's left of a broken axis
+                    let stretch = break_stretch(first.position(), last.position(), y_break);
                     let span = VerticalSpan::new(
                         font_height.get() + padding.get().height(),
-                        avail_height.get(),
+                        avail_height.get() * stretch,
                     );
-                    generator.get().generate(first, last, &span)
+                    let mut gen = generator.get().generate(first, last, &span);
+                    let scale_y = scale_y.get();
+                    gen.ticks
+                        .retain(|tick| scale_y.apply(tick.position()).is_finite());
+                    if let Some((low, high)) = y_break {
+                        gen.ticks.retain(|tick| {
+                            let pos = tick.position();
+                            pos <= low || pos >= high
+                        });
+                    }
+                    gen
                 })
                 .unwrap_or_else(GeneratedTicks::none)
         })
     }
 
+    /// Expands the Y range to the nearest ticks if [TickLabels::nice_domain] is set.
+    pub(super) fn nice_range_y<X: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        avail_height: Memo<f64>,
+    ) -> Memo<Range<Y>> {
+        let gen = self.generate_y(state, avail_height.into());
+        nice_range(
+            self.nice_domain,
+            self.range_y(state),
+            gen,
+            self.scale_y(state).into(),
+        )
+    }
+
+    // Range of the labels' Y axis
+    fn range_y<X: Tick>(&self, state: &PreState<X, Y>) -> Memo<Range<Y>> {
+        let y_axis = self.y_axis;
+        let (primary, secondary) = (state.data.range_y, state.data.range_y_secondary);
+        Memo::new(move |_| match y_axis.get() {
+            YAxis::Secondary => secondary.get(),
+            _ => primary.get(),
+        })
+    }
+
+    // Scale of the labels' Y axis. Only the main axis has one
+    fn scale_y<X: Tick>(&self, state: &PreState<X, Y>) -> Memo<AxisScale> {
+        let y_axis = self.y_axis;
+        let scale_y = state.data.scale_y;
+        Memo::new(move |_| match y_axis.get() {
+            YAxis::Secondary => AxisScale::default(),
+            _ => scale_y.get(),
+        })
+    }
+
+    /// Fits the secondary Y range to the `primary` ticks if [TickLabels::align_ticks] is set.
+    pub(super) fn align_range_y<X: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        primary: Memo<GeneratedTicks<Y>>,
+    ) -> Memo<Range<Y>> {
+        let align = self.align_ticks;
+        let (range_y, secondary) = (state.data.range_y, self.range_y(state));
+        Memo::new(move |_| {
+            let range = secondary.get();
+            let Some(primary_range) = range_y.with(Range::positions) else {
+                return range;
+            };
+            if !align.get() {
+                return range;
+            }
+            let ticks =
+                primary.with(|gen| gen.ticks.iter().map(Tick::position).collect::<Vec<_>>());
+            range.map_positions(|min, max| align_positions(primary_range, &ticks, (min, max)))
+        })
+    }
+
+    // Y break of the labels' Y axis. Only the main axis has one and reversing drops it
+    fn y_break<X: Tick>(&self, state: &PreState<X, Y>) -> Memo<Option<(f64, f64)>> {
+        let y_axis = self.y_axis;
+        let (y_break, reversed_y) = (state.data.y_break, state.data.reversed_y);
+        Memo::new(move |_| match y_axis.get() {
+            YAxis::Secondary => None,
+            _ if reversed_y.get() => None,
+            _ => y_break.get(),
+        })
+    }
+
     pub(super) fn to_vertical_use<X: Tick>(
         &self,
         state: &PreState<X, Y>,
         avail_height: Memo<f64>,
     ) -> UseVerticalLayout {
-        let ticks = self.map_ticks(self.generate_y(state, avail_height.into()));
+        let gen = self.generate_y(state, avail_height.into());
+        self.use_vertical_ticks(state, gen)
+    }
+
+    /// Secondary Y labels placed on the `primary` ticks if [TickLabels::align_ticks] is set. Expects the secondary range from [TickLabels::align_range_y].
+    pub(super) fn to_aligned_vertical_use<X: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        avail_height: Memo<f64>,
+        primary: Memo<GeneratedTicks<Y>>,
+    ) -> UseVerticalLayout {
+        // Our own ticks give the label format
+        let own = self.generate_y(state, avail_height.into());
+        let align = self.align_ticks;
+        let (range_y, secondary) = (state.data.range_y, self.range_y(state));
+        let gen = Memo::new(move |_| {
+            let mut gen = own.get();
+            if !align.get() {
+                return gen;
+            }
+            let ranges = range_y
+                .with(Range::positions)
+                .zip(secondary.with(Range::positions));
+            let context = secondary.with(|range| range.range().map(|(min, _)| min.clone()));
+            if let (Some((from, to)), Some(context)) = (ranges, context) {
+                gen.ticks = primary.with(|primary| {
+                    (primary.ticks.iter())
+                        .filter_map(|tick| {
+                            context.with_position(map_position(tick.position(), from, to))
+                        })
+                        .collect()
+                });
+            }
+            gen
+        });
+        self.use_vertical_ticks(state, gen)
+    }
+
+    fn use_vertical_ticks<X: Tick>(
+        &self,
+        state: &PreState<X, Y>,
+        gen: Memo<GeneratedTicks<Y>>,
+    ) -> UseVerticalLayout {
+        let ticks = self.map_ticks(gen);
+        let padding = self.use_padding(state);
+        let state = &state.with_font(self.font_height, self.font_width);
         UseVerticalLayout {
-            width: mk_width(self.min_chars, state, ticks),
-            layout: UseLayout::TickLabels(UseTickLabels { ticks }),
+            width: mk_width(self.min_chars, self.gap, padding, state, ticks),
+            layout: UseLayout::TickLabels(self.to_use(padding, gen, ticks)),
+        }
+    }
+}
+
+// Ticks to fit between the smallest and largest value when picking the precision of value labels. Gives about four significant figures of the largest value
+const VALUE_LABEL_TICKS: f64 = 1000.0;
+
+/// Tick format for labelling values away from an axis e.g., funnel stages. Precise enough to tell the values apart. Missing values (NaN) are formatted as "-".
+pub(crate) fn value_format(values: &[f64]) -> Arc<dyn TickFormat<Tick = f64> + Send + Sync> {
+    value_format_by(&f64::tooltip_generator(), values)
+}
+
+/// Like [value_format] but from a given tick generator e.g., a tooltip's Y ticks with a unit.
+pub(crate) fn value_format_by(
+    gen: &dyn TickGen<Tick = f64>,
+    values: &[f64],
+) -> Arc<dyn TickFormat<Tick = f64> + Send + Sync> {
+    let (min, max) = (values.iter())
+        .filter(|value| value.is_finite())
+        .fold((0.0, 0.0), |(min, max), &value| {
+            (f64::min(min, value), f64::max(max, value))
+        });
+    let span = VerticalSpan::new(1.0, VALUE_LABEL_TICKS);
+    gen.generate(&min, &max, &span).state
+}
+
+fn nice_range<XY: Tick>(
+    nice_domain: RwSignal<bool>,
+    range: Memo<Range<XY>>,
+    gen: Memo<GeneratedTicks<XY>>,
+    scale: Signal<AxisScale>,
+) -> Memo<Range<XY>> {
+    Memo::new(move |_| {
+        let range = range.get();
+        if !nice_domain.get() {
+            return range;
+        }
+        // Step in scaled positions so e.g., log axes extend by a ratio
+        let scale = scale.get();
+        let ticks = gen.with(|gen| {
+            (gen.ticks.iter())
+                .map(|tick| scale.apply(tick.position()))
+                .collect::<Vec<_>>()
+        });
+        range.map_positions(|min, max| {
+            let (min, max) = nice_positions(scale.apply(min), scale.apply(max), &ticks);
+            (scale.invert(min), scale.invert(max))
+        })
+    })
+}
+
+/// How much taller a Y axis from `min` to `max` would be without its break. Ticks are generated over the whole range so their spacing matches what's left after the break is cut out.
+fn break_stretch(min: f64, max: f64, y_break: Option<(f64, f64)>) -> f64 {
+    let Some((low, high)) = y_break.filter(|&(low, high)| min < low && high < max) else {
+        return 1.0;
+    };
+    (max - min) / (max - min - (high - low))
+}
+
+/// Expands `min` and `max` outward by whole steps from the ticks within them. Each end steps by the gap between its two outermost ticks so uneven ticks (e.g., months) follow the ticks nearest them. Needs at least two ticks otherwise the range is unchanged.
+fn nice_positions(min: f64, max: f64, ticks: &[f64]) -> (f64, f64) {
+    let ([first, second, ..], [.., second_last, last]) = (ticks, ticks) else {
+        return (min, max);
+    };
+    // Whole steps needed to cover `by`
+    let steps = |by: f64, step: f64| {
+        (step.is_finite() && step > 0.0).then(|| (by / step).ceil().max(0.0) * step)
+    };
+    let min = steps(first - min, second - first).map_or(min, |by| first - by);
+    let max = steps(max - last, last - second_last).map_or(max, |by| last + by);
+    (min, max)
+}
+
+/// Expands the secondary range (`min`, `max`) so that nice values land on the primary `ticks` of the `primary` range. Needs at least two ticks otherwise the range is unchanged.
+fn align_positions(primary: (f64, f64), ticks: &[f64], (min, max): (f64, f64)) -> (f64, f64) {
+    let [first, second, ..] = ticks else {
+        return (min, max);
+    };
+    let step = second - first;
+    // The primary range in tick steps from the first tick
+    let (lo, hi) = ((primary.0 - first) / step, (primary.1 - first) / step);
+    if !(step.is_finite() && step > 0.0 && hi > lo && max > min) {
+        return (min, max);
+    }
+    let mut sec_step = nice_step((max - min) / (hi - lo));
+    // A bigger step might be needed once the first tick is rounded down
+    for _ in 0..8 {
+        let start = ((min - lo * sec_step) / sec_step).floor() * sec_step;
+        if start + hi * sec_step >= max {
+            return (start + lo * sec_step, start + hi * sec_step);
         }
+        sec_step = nice_step(sec_step * 1.01);
     }
+    (min, max)
+}
+
+/// Smallest nice step (1, 2, 2.5 or 5 times a power of ten) of at least `raw`.
+fn nice_step(raw: f64) -> f64 {
+    let magnitude = 10_f64.powi(raw.log10().floor() as i32);
+    [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .map(|mult| mult * magnitude)
+        .find(|&step| step >= raw)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Maps a position between two ranges.
+fn map_position(position: f64, from: (f64, f64), to: (f64, f64)) -> f64 {
+    let width = from.1 - from.0;
+    if width == 0.0 {
+        return to.0;
+    }
+    to.0 + (position - from.0) / width * (to.1 - to.0)
 }
 
 fn mk_width<X: Tick, Y: Tick>(
     min_chars: RwSignal<usize>,
+    gap: RwSignal<f64>,
+    padding: Signal<Padding>,
     state: &PreState<X, Y>,
     ticks: Signal<Vec<(f64, String)>>,
 ) -> Signal<f64> {
     let font_width = state.font_width;
-    let padding = state.padding;
     Signal::derive(move || {
         let longest_chars = ticks.with(|ticks| {
             ticks
@@ -223,7 +647,7 @@ fn mk_width<X: Tick, Y: Tick>(
                 .unwrap_or_default()
                 .max(min_chars.get())
         }) as f64;
-        font_width.get() * longest_chars + padding.get().width()
+        font_width.get() * longest_chars + padding.get().width() + gap.get()
     })
 }
 
@@ -252,9 +676,26 @@ pub(super) fn TickLabels<X: Tick, Y: Tick>(
     bounds: Memo<Bounds>,
     state: State<X, Y>,
 ) -> impl IntoView {
+    let UseTickLabels {
+        ticks,
+        align,
+        padding,
+        gap,
+        drag,
+        clickable,
+        on_click,
+        font_height,
+        font_width,
+        y_axis,
+    } = ticks;
+    // Drag the primary axis
+    let projection = state.projection;
+    let state = (state.with_font(font_height, font_width)).with_y_axis(y_axis.get_untracked());
+    let axis_drag = state.pre.axis_drag;
+    let (inner, mouse_chart) = (state.layout.inner, state.mouse_chart);
     let ticks = move || {
         // Align vertical labels
-        let ticks = ticks.ticks.get();
+        let ticks = ticks.get();
         let ticks = if edge.is_vertical() {
             let (pos, labels): (Vec<f64>, Vec<String>) = ticks.into_iter().unzip();
             let labels = align_tick_labels(labels);
@@ -264,15 +705,64 @@ pub(super) fn TickLabels<X: Tick, Y: Tick>(
         };
         ticks
             .into_iter()
-            .map(|tick| {
+            .enumerate()
+            .map(|(index, tick)| {
                 view! {
-                    <TickLabel edge=edge outer=bounds state=state.clone() tick=tick />
+                    <TickLabel
+                        edge=edge
+                        outer=bounds
+                        align=align
+                        padding=padding
+                        gap=gap
+                        state=state.clone()
+                        tick=tick
+                        clickable=clickable
+                        index=index
+                        on_click=on_click />
                 }
             })
             .collect_view()
     };
+
+    // Axis drag
+    let on_mousedown = move |_| {
+        if drag.get_untracked() {
+            let proj = projection.get_untracked();
+            axis_drag.start(
+                edge,
+                mouse_chart.get_untracked(),
+                &proj,
+                inner.get_untracked(),
+            );
+        }
+    };
+    let on_dblclick = move |_| {
+        if drag.get_untracked() {
+            axis_drag.reset(edge);
+        }
+    };
+    let cursor = move || match (drag.get(), edge.is_vertical()) {
+        (false, _) => "auto",
+        (true, true) => "ns-resize",
+        (true, false) => "ew-resize",
+    };
+
     view! {
-        <g class="_chartistry_tick_labels">
+        <g
+            class="_chartistry_tick_labels"
+            style:cursor=cursor
+            style:user-select="none"
+            on:mousedown=on_mousedown
+            on:dblclick=on_dblclick>
+            // Catch drags between labels
+            <Show when=move || drag.get()>
+                <rect
+                    x=move || bounds.get().left_x()
+                    y=move || bounds.get().top_y()
+                    width=move || bounds.get().width()
+                    height=move || bounds.get().height()
+                    fill="transparent" />
+            </Show>
             {ticks}
         </g>
     }
@@ -282,13 +772,18 @@ pub(super) fn TickLabels<X: Tick, Y: Tick>(
 fn TickLabel<X: Tick, Y: Tick>(
     edge: Edge,
     outer: Memo<Bounds>,
+    align: RwSignal<Anchor>,
+    padding: Signal<Padding>,
+    gap: RwSignal<f64>,
     state: State<X, Y>,
     tick: (f64, String),
+    index: usize,
+    clickable: Signal<bool>,
+    on_click: Callback<usize>,
 ) -> impl IntoView {
     let debug = state.pre.debug;
     let font_height = state.pre.font_height;
     let font_width = state.pre.font_width;
-    let padding = state.pre.padding;
     let projection = state.projection;
 
     let (position, label) = tick;
@@ -300,17 +795,20 @@ fn TickLabel<X: Tick, Y: Tick>(
         let height = font_height.get() + padding.height();
 
         let proj = projection.get();
-        let outer = outer.get();
+        let align = align.get();
+        // Leave a gap between the label and the inner chart
+        let gap = gap.get();
+        let outer = edge.opposite().shrink(outer.get(), gap);
         match edge {
             Edge::Top | Edge::Bottom => {
                 let (x, _) = proj.position_to_svg(position, 0.0);
-                let x = x - width / 2.0;
+                let x = align.map_points(x, x - width / 2.0, x - width);
                 Bounds::from_points(x, outer.top_y(), x + width, outer.bottom_y())
             }
 
             Edge::Left | Edge::Right => {
                 let (_, y) = proj.position_to_svg(0.0, position);
-                let y = y - height / 2.0;
+                let y = align.map_points(y, y - height / 2.0, y - height);
                 Bounds::from_points(outer.left_x(), y, outer.right_x(), y + height)
             }
         }
@@ -337,7 +835,9 @@ fn TickLabel<X: Tick, Y: Tick>(
     view! {
         <g
             class="_chartistry_tick_label"
-            font-family="monospace">
+            font-family="monospace"
+            style:cursor=move || if clickable.get() { "pointer" } else { "inherit" }
+            on:click=move |_| on_click.run(index)>
             <DebugRect label="tick" debug=debug bounds=vec![bounds, content.into()] />
             <text
                 x=move || text_position.get().1
@@ -351,3 +851,90 @@ fn TickLabel<X: Tick, Y: Tick>(
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_positions() {
+        // Primary 0-100 with ticks every 20. Secondary 3-47 becomes 0-50 with ticks every 10
+        let ticks = [0.0, 20.0, 40.0, 60.0, 80.0, 100.0];
+        assert_eq!(
+            align_positions((0.0, 100.0), &ticks, (3.0, 47.0)),
+            (0.0, 50.0)
+        );
+        // Rounding down the first tick needs a bigger step
+        assert_eq!(
+            align_positions((0.0, 100.0), &ticks, (9.0, 54.0)),
+            (0.0, 100.0)
+        );
+        // Primary range beyond its ticks
+        assert_eq!(
+            align_positions((-10.0, 90.0), &ticks[..5], (0.0, 4.0)),
+            (-0.5, 4.5)
+        );
+        // Unchanged without enough ticks or an empty range
+        assert_eq!(
+            align_positions((0.0, 100.0), &[50.0], (3.0, 47.0)),
+            (3.0, 47.0)
+        );
+        assert_eq!(
+            align_positions((0.0, 100.0), &ticks, (5.0, 5.0)),
+            (5.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_nice_step() {
+        assert_eq!(nice_step(0.7), 1.0);
+        assert_eq!(nice_step(1.0), 1.0);
+        assert_eq!(nice_step(2.2), 2.5);
+        assert_eq!(nice_step(30.0), 50.0);
+        assert_eq!(nice_step(0.06), 0.1);
+    }
+
+    #[test]
+    fn test_map_position() {
+        assert_eq!(map_position(25.0, (0.0, 100.0), (0.0, 50.0)), 12.5);
+        assert_eq!(map_position(25.0, (5.0, 5.0), (0.0, 50.0)), 0.0);
+    }
+
+    #[test]
+    fn test_nice_positions() {
+        // Expands to the next tick either side
+        assert_eq!(
+            nice_positions(3.0, 97.0, &[20.0, 40.0, 60.0, 80.0]),
+            (0.0, 100.0)
+        );
+        // Ticks on the border are unchanged
+        assert_eq!(nice_positions(0.0, 80.0, &[0.0, 40.0, 80.0]), (0.0, 80.0));
+        // Negative ranges
+        assert_eq!(nice_positions(-7.0, 7.0, &[-5.0, 0.0, 5.0]), (-10.0, 10.0));
+        // Each end steps by its own tick gap
+        assert_eq!(
+            nice_positions(3.0, 97.0, &[10.0, 20.0, 60.0, 90.0]),
+            (0.0, 120.0)
+        );
+        // Too few ticks to find a step
+        assert_eq!(nice_positions(3.0, 97.0, &[50.0]), (3.0, 97.0));
+        assert_eq!(nice_positions(3.0, 97.0, &[]), (3.0, 97.0));
+    }
+
+    #[test]
+    fn test_value_format() {
+        let format = value_format(&[1234.0, 500.0, f64::NAN]);
+        assert_eq!(format.format(&1234.0), "1234");
+        assert_eq!(format.format(&500.0), "500");
+        assert_eq!(format.format(&f64::NAN), "-");
+        assert_eq!(value_format(&[0.5, 0.25]).format(&0.25), "0.2500");
+    }
+
+    #[test]
+    fn test_break_stretch() {
+        assert_eq!(break_stretch(0.0, 110.0, Some((10.0, 100.0))), 5.5);
+        assert_eq!(break_stretch(0.0, 110.0, None), 1.0);
+        // Outside the range
+        assert_eq!(break_stretch(0.0, 110.0, Some((10.0, 200.0))), 1.0);
+    }
+}
@@ -3,6 +3,11 @@
 //!
 //! All charts are built using the [Chart] fn. If you understand this function, you understand this library.
 //!
+//! ## Feature flags
+//!
+//! - `ssr`: enables server-side rendering. Charts with a known aspect ratio render on the server using fixed font metrics so the hydrated markup matches. See [Chart]'s `ssr_font_metrics` prop.
+//! - `f32`: writes SVG path and polygon coordinates using single precision. Produces shorter paths and less string building on large charts which helps wasm size and performance. Layout and projection still use `f64`.
+//!
 //! ## Examples
 //!
 //! - See the [demo for Chartistry in action](https://feral-dot-io.github.io/leptos-chartistry/).
@@ -52,42 +57,98 @@
 //! ```
 
 mod aspect_ratio;
-mod bounds;
+mod axis_drag;
+mod axis_ticks;
+mod buffer;
 mod chart;
 mod colours;
+mod coord;
 mod debug;
+mod diagnostics;
 mod edge;
+mod export;
+mod funnel;
+mod grid;
 mod inner;
 mod layout;
+mod loading;
+mod messages;
 mod overlay;
-mod padding;
-mod projection;
+mod pixel_scale;
+mod presets;
+mod sankey;
+mod selection;
 mod series;
+mod shared;
+mod snippets;
 mod state;
-mod ticks;
 mod use_watched_node;
+mod view_state;
+mod wind_rose;
+mod window_scroll;
+
+use chartistry_core::{bounds, padding, projection, scale, ticks};
 
 pub use aspect_ratio::AspectRatio;
-pub use chart::Chart;
+pub use axis_drag::{ZoomAxes, ZoomLimits};
+pub use axis_ticks::AxisTicks;
+pub use bounds::Bounds;
+pub use buffer::ChartBuffer;
+pub use chart::{Chart, DataEvent, EditEvent};
+pub use chartistry_core::ordered_tick;
 pub use colours::{Colour, ColourScheme, DivergingGradient, SequentialGradient};
 pub use edge::Edge;
+pub use export::ChartHandle;
+pub use funnel::{Funnel, FunnelChart, FUNNEL_GAP};
+pub use grid::ChartGrid;
 pub use inner::{
     axis_marker::{AxisMarker, AxisPlacement, AXIS_MARKER_COLOUR},
+    brush::{Brush, BRUSH_COLOUR},
+    callout::{Callout, CALLOUT_COLOUR},
+    data_label::{DataLabel, DATA_LABEL_COLOUR},
     grid_line::{XGridLine, YGridLine, GRID_LINE_COLOUR},
     guide_line::{AlignOver, XGuideLine, YGuideLine, GUIDE_LINE_COLOUR},
     legend::InsetLegend,
+    reference_line::{XReferenceLine, YReferenceLine, REFERENCE_LINE_COLOUR},
+    region::{XRegion, YRegion, REGION_COLOUR},
+    watermark::{Watermark, WatermarkContent, WatermarkPosition, WATERMARK_COLOUR},
     InnerLayout, IntoInner, IntoInner as _,
 };
 pub use layout::{
-    legend::Legend,
+    legend::{Legend, LegendEvent},
+    overflow::EdgeOverflow,
+    range_slider::RangeSlider,
     rotated_label::{Anchor, RotatedLabel},
     tick_labels::TickLabels,
     EdgeLayout, IntoEdge, IntoEdge as _,
 };
-pub use overlay::tooltip::{Tooltip, TooltipPlacement, TooltipSortBy, TOOLTIP_CURSOR_DISTANCE};
+pub use loading::use_loading_data;
+pub use messages::Messages;
+pub use overlay::tooltip::{
+    MissingDisplay, MissingFormatFn, Tooltip, TooltipCtx, TooltipPlacement, TooltipSortBy,
+    TooltipValue, TooltipViewFn, YFormatFn, TOOLTIP_CURSOR_DISTANCE,
+};
 pub use padding::Padding;
+pub use pixel_scale::PixelScale;
+pub use presets::{
+    BarChart, DepthProfileChart, Financial, FinancialChart, LineChart, Preset, PresetChart,
+    TimeSeriesChart,
+};
+pub use sankey::{Sankey, SankeyChart, SANKEY_GAP, SANKEY_NODE_WIDTH};
+pub use scale::{AxisScale, CustomScale, LinearScale, LogScale, PowerScale, Scale, SymLogScale};
+pub use selection::SelectionMode;
+pub use series::UseY;
 pub use series::{
-    Bar, BarPlacement, Interpolation, Line, Marker, MarkerShape, Series, Stack, Step, BAR_GAP,
-    BAR_GAP_INNER, DIVERGING_GRADIENT, LINEAR_GRADIENT, SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME,
+    Aggregate, Bar, BarPlacement, BarStack, BarWidth, Candlestick, Difference, Interpolation, Line,
+    Marker, MarkerShape, Resample, Series, Stack, Step, YAxis, BAR_GAP, BAR_GAP_INNER,
+    CANDLESTICK_FALLING_COLOUR, CANDLESTICK_RISING_COLOUR, DIFFERENCE_ABOVE_COLOUR,
+    DIFFERENCE_BELOW_COLOUR, DIVERGING_GRADIENT, LINEAR_GRADIENT, SERIES_COLOUR_SCHEME,
+    STACK_COLOUR_SCHEME, STACK_TOTAL_COLOUR, Y_BREAK_COLOUR,
+};
+pub use shared::SharedLeftWidth;
+pub use snippets::{SeriesSnippet, SeriesSnippets, SeriesTaster};
+pub use ticks::{
+    AlignedFloats, AlignedIntegers, LogFloats, Period, Tick, TickFormat, Timestamps, Variants,
 };
-pub use ticks::{AlignedFloats, Period, Tick, TickFormat, Timestamps};
+pub use view_state::ViewState;
+pub use wind_rose::{WindRose, WindRoseChart, WIND_ROSE_COLOUR_SCHEME};
@@ -0,0 +1,30 @@
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Adapts optional data (e.g., from a resource or server function) into data and loading signals for a [Chart](crate::Chart). Returns `(data, loading)`.
+///
+/// The source returns `None` while loading. The last loaded data is kept until new data arrives so that refreshing (e.g., polling) doesn't blank the chart. Both signals are derived from the source so they're also correct when rendered on the server.
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # #[derive(Clone)]
+/// # struct MyData { x: f64, y: f64 }
+/// # fn example(fetched: ReadSignal<Option<Vec<MyData>>>) {
+/// let (data, loading) = use_loading_data(move || fetched.get());
+/// # }
+/// ```
+pub fn use_loading_data<T: Clone + Send + Sync + 'static>(
+    source: impl Fn() -> Option<Vec<T>> + Send + Sync + 'static,
+) -> (Signal<Vec<T>>, Signal<bool>) {
+    let source = Arc::new(source);
+    let data = {
+        let source = source.clone();
+        Memo::new_owning(move |prev: Option<Vec<T>>| match source() {
+            Some(loaded) => (loaded, true),
+            None => (prev.unwrap_or_default(), false),
+        })
+    };
+    let loading = Memo::new(move |_| source().is_none());
+    (data.into(), loading.into())
+}
@@ -0,0 +1,54 @@
+use leptos::prelude::*;
+
+/// Built-in text shown by a chart. Replace these to localise charts without forking e.g., for non-English apps. Everything else (labels, series names, tick formats) is already set by you.
+///
+/// Pass to a [Chart](crate::Chart) using the `messages` prop or provide it once as context for every chart below a component:
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # #[component]
+/// # fn App() -> impl IntoView {
+/// provide_context(Messages::default().with_loading("Chargement…"));
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Messages {
+    /// Placeholder for a missing value e.g., in a tooltip. Default is "-".
+    pub missing: RwSignal<String>,
+    /// Shown until a chart has data or dimensions to render. Default is "Loading...".
+    pub loading: RwSignal<String>,
+    /// Shown in a tooltip when there's no data to hover over. Default is "no data".
+    pub no_data: RwSignal<String>,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self {
+            missing: RwSignal::new("-".to_string()),
+            loading: RwSignal::new("Loading...".to_string()),
+            no_data: RwSignal::new("no data".to_string()),
+        }
+    }
+}
+
+impl Messages {
+    /// Sets the placeholder for a missing value.
+    pub fn with_missing(self, missing: impl Into<String>) -> Self {
+        self.missing.set(missing.into());
+        self
+    }
+
+    /// Sets the loading message.
+    pub fn with_loading(self, loading: impl Into<String>) -> Self {
+        self.loading.set(loading.into());
+        self
+    }
+
+    /// Sets the tooltip's message when there's no data.
+    pub fn with_no_data(self, no_data: impl Into<String>) -> Self {
+        self.no_data.set(no_data.into());
+        self
+    }
+}
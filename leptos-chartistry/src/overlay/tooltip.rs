@@ -1,15 +1,33 @@
 use crate::{
+    colours::Colour,
     debug::DebugRect,
+    projection::Projection,
     series::{Snippet, UseY},
     state::State,
-    Tick, TickLabels, AXIS_MARKER_COLOUR,
+    ticks::{AlignedFloats, TickFormat, TickFormatFn},
+    Tick, TickLabels, YAxis, AXIS_MARKER_COLOUR,
+};
+use leptos::{
+    either::Either, html::Aside, leptos_dom::helpers::set_timeout, portal::Portal, prelude::*,
+};
+use std::{
+    cmp::{Ordering, Reverse},
+    sync::Arc,
+    time::Duration,
 };
-use leptos::prelude::*;
-use std::cmp::{Ordering, Reverse};
 
 /// Default gap distance from cursor to tooltip when shown.
 pub const TOOLTIP_CURSOR_DISTANCE: f64 = 10.0;
 
+// Minimum time between screen reader announcements
+const LIVE_THROTTLE: Duration = Duration::from_millis(500);
+
+// Style of the tooltip box. Placed absolutely in page coordinates
+pub(crate) const TOOLTIP_STYLE: &str = "position: absolute; z-index: 1; width: max-content; height: max-content; background-color: #fff; white-space: pre; font-family: monospace;";
+
+// Hides the live region visually while keeping it available to screen readers
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; overflow: hidden; clip: rect(0 0 0 0); white-space: nowrap;";
+
 /// Builds a mouse tooltip that shows X and Y values for the nearest data. Drawn in HTML as an overlay.
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
@@ -22,13 +40,83 @@ pub struct Tooltip<X: Tick, Y: Tick> {
     pub cursor_distance: RwSignal<f64>,
     /// If true, skips Y values that are `f64::NAN`.
     pub skip_missing: RwSignal<bool>,
+    /// How missing Y values (`None` from [Line::new_optional](crate::Line::new_optional) or `f64::NAN`) are shown. Default is [MissingDisplay::Dash].
+    pub missing: RwSignal<MissingDisplay>,
+    /// Custom format of missing Y values. Overrides the text of [missing](Self::missing). See [Tooltip::with_missing_format] for details.
+    pub missing_format: RwSignal<Option<Arc<MissingFormatFn<X, Y>>>>,
     /// Whether to show X ticks. Default is true.
     // TODO: move to TickLabels
     pub show_x_ticks: RwSignal<bool>,
     /// X axis formatter.
     pub x_ticks: TickLabels<X>,
+    /// Extra formats of the X value. Each is shown on its own line below the X value. See [Tooltip::with_x_format] for details.
+    pub x_formats: RwSignal<Vec<Arc<TickFormatFn<X>>>>,
     /// Y axis formatter.
     pub y_ticks: TickLabels<Y>,
+    /// Custom format of Y values by series. Overrides [y_ticks](Self::y_ticks). See [Tooltip::with_y_format] for details.
+    pub y_format: RwSignal<Option<Arc<YFormatFn<Y>>>>,
+    /// Renders the tooltip in a portal on the document body instead of inside the chart. Avoids clipping by ancestors with `overflow: hidden`. Default is false.
+    pub portal: RwSignal<bool>,
+    /// Custom view of the tooltip's contents. Replaces the X value and Y value table. See [Tooltip::custom] for details.
+    pub custom: RwSignal<Option<Arc<TooltipViewFn<X, Y>>>>,
+    /// Mirrors the tooltip's values into a visually hidden `aria-live="polite"` region so screen readers announce them as the hovered X changes. Throttled to avoid a flood of announcements. Default is true.
+    pub live: RwSignal<bool>,
+}
+
+/// Formats a missing Y value given the series' last known (X, Y) value before the hovered X, if any.
+pub type MissingFormatFn<X, Y> = dyn Fn(Option<(&X, &Y)>) -> String + Send + Sync;
+
+/// Formats a series' Y value. Given the series and its value at the hovered X.
+pub type YFormatFn<Y> = dyn Fn(&UseY, &Y) -> String + Send + Sync;
+
+/// Renders the contents of a tooltip from the hovered values. See [Tooltip::custom].
+pub type TooltipViewFn<X, Y> = dyn Fn(TooltipCtx<X, Y>) -> AnyView + Send + Sync;
+
+/// Hovered values given to a [custom tooltip](Tooltip::custom).
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TooltipCtx<X, Y> {
+    /// Hovered X value. `None` without data.
+    pub x: Option<X>,
+    /// Hovered X value formatted by the tooltip's X ticks.
+    pub x_text: String,
+    /// Metadata of the hovered point if set. See [Series::with_meta](crate::Series::with_meta).
+    pub meta: Option<String>,
+    /// Each series' value at the hovered X. Sorted and filtered as the built-in table would be.
+    pub values: Vec<TooltipValue<Y>>,
+}
+
+/// A series' value at the hovered X. See [TooltipCtx].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct TooltipValue<Y> {
+    /// The series e.g., for its name.
+    pub series: UseY,
+    /// Colour of the series.
+    pub colour: Colour,
+    /// Y value. `None` if missing.
+    pub value: Option<Y>,
+    /// Y value formatted as the built-in table would show it. Includes missing values.
+    pub text: String,
+    /// Whether the built-in table would grey out the row e.g., a missing value.
+    pub greyed: bool,
+}
+
+/// How the tooltip shows a missing Y value (`f64::NAN`).
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum MissingDisplay {
+    /// Shows the missing placeholder. See [Messages](crate::Messages).
+    #[default]
+    Dash,
+    /// Hides the row. Same as [Tooltip::skip_missing].
+    Hide,
+    /// Shows the given text e.g., "no data".
+    Text(String),
+    /// Shows the missing placeholder in a greyed out row.
+    Greyed,
+    /// Shows the series' last known value before the hovered X followed by its X value in a greyed out row e.g., "42 (12:05)".
+    LastKnown,
 }
 
 /// Where the tooltip is place when shown.
@@ -40,6 +128,10 @@ pub enum TooltipPlacement {
     Hide,
     /// Shows the tooltip to the left of the cursor.
     LeftCursor,
+    /// Shows the tooltip above the top of the hovered bar group. Falls back to [LeftCursor](Self::LeftCursor) on charts without bars.
+    BarTop,
+    /// Shows a small badge with each series' value beside its point at the hovered X, instead of one combined box. Useful for comparing many series.
+    Badges,
 }
 
 /// How the tooltip Y value table is sorted.
@@ -84,6 +176,50 @@ impl<X: Tick, Y: Tick> Tooltip<X, Y> {
         Self::from_placement(TooltipPlacement::LeftCursor)
     }
 
+    /// Creates a new tooltip above the hovered bar group. Uses default X and Y ticks.
+    pub fn bar_top() -> Self {
+        Self::from_placement(TooltipPlacement::BarTop)
+    }
+
+    /// Creates a new tooltip that shows a badge beside each series. Uses default X and Y ticks.
+    pub fn badges() -> Self {
+        Self::from_placement(TooltipPlacement::Badges)
+    }
+
+    /// Creates a new tooltip left of the cursor with your own view of the hovered values e.g., a branded card with links or icons. Shown in the same box as the built-in tooltip. Uses default X and Y ticks.
+    ///
+    /// ```rust
+    /// # use leptos::prelude::*;
+    /// # use leptos_chartistry::*;
+    /// let tooltip = Tooltip::<f64, f64>::custom(|ctx: TooltipCtx<f64, f64>| {
+    ///     view! {
+    ///         <h3>{ctx.x_text}</h3>
+    ///         <ul>
+    ///             {ctx.values.into_iter().map(|value| view! {
+    ///                 <li style:color=value.colour.to_string()>
+    ///                     {value.series.name.get()} ": " {value.text}
+    ///                 </li>
+    ///             }).collect_view()}
+    ///         </ul>
+    ///     }
+    /// });
+    /// ```
+    pub fn custom<IV: IntoView + 'static>(
+        view: impl Fn(TooltipCtx<X, Y>) -> IV + Send + Sync + 'static,
+    ) -> Self {
+        Self::left_cursor().with_custom(view)
+    }
+
+    /// Sets a custom view of the tooltip's contents. See [Tooltip::custom] for details.
+    pub fn with_custom<IV: IntoView + 'static>(
+        self,
+        view: impl Fn(TooltipCtx<X, Y>) -> IV + Send + Sync + 'static,
+    ) -> Self {
+        self.custom
+            .set(Some(Arc::new(move |ctx| view(ctx).into_any())));
+        self
+    }
+
     /// Sets the sort order of the Y value table.
     pub fn with_sort_by(self, sort_by: impl Into<TooltipSortBy>) -> Self {
         self.sort_by.set(sort_by.into());
@@ -102,11 +238,98 @@ impl<X: Tick, Y: Tick> Tooltip<X, Y> {
         self
     }
 
+    /// Sets how missing Y values are shown.
+    pub fn with_missing(self, missing: impl Into<MissingDisplay>) -> Self {
+        self.missing.set(missing.into());
+        self
+    }
+
+    /// Sets a custom format for missing Y values. Given the series' last known (X, Y) value before the hovered X, if any. Rows are still hidden or greyed out according to [missing](Self::missing).
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// let tooltip = Tooltip::<f64, f64>::left_cursor()
+    ///     .with_missing(MissingDisplay::Greyed)
+    ///     .with_missing_format(|last| match last {
+    ///         Some((x, y)) => format!("{y} (at {x})"),
+    ///         None => "no data".to_string(),
+    ///     });
+    /// ```
+    pub fn with_missing_format(
+        self,
+        format: impl Fn(Option<(&X, &Y)>) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.missing_format.set(Some(Arc::new(format)));
+        self
+    }
+
     /// Sets whether to show X ticks.
     pub fn show_x_ticks(self, show_x_ticks: impl Into<bool>) -> Self {
         self.show_x_ticks.set(show_x_ticks.into());
         self
     }
+
+    /// Adds an extra format of the X value shown on its own line e.g., a relative time "3m ago" below a timestamp. Can be called multiple times.
+    ///
+    /// Takes the same arguments as [TickLabels::with_format]: the hovered X value and the formatter from the X tick generator.
+    pub fn with_x_format(
+        self,
+        format: impl Fn(&X, &dyn TickFormat<Tick = X>) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.x_formats
+            .update(|formats| formats.push(Arc::new(format)));
+        self
+    }
+
+    /// Replaces the format of the X value. An alias of setting the format of [x_ticks](Self::x_ticks) with [TickLabels::with_format] and takes the same arguments: the hovered X value and the formatter from the X tick generator.
+    pub fn with_x_value_format(
+        self,
+        format: impl Fn(&X, &dyn TickFormat<Tick = X>) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.x_ticks.format.set(Arc::new(format));
+        self
+    }
+
+    /// Sets a custom format of Y values e.g., to add units, change precision by series or localise numbers. Given the series and its value. Also formats extra values such as a candlestick's open, high and low. Missing values are shown according to [missing](Self::missing).
+    ///
+    /// ```rust
+    /// # use leptos::prelude::*;
+    /// # use leptos_chartistry::*;
+    /// let tooltip = Tooltip::<f64, f64>::left_cursor().with_y_format(|series, y| {
+    ///     match series.name.get().as_str() {
+    ///         "pH" => format!("{y:.2} pH"),
+    ///         _ => format!("{y:.1} °C"),
+    ///     }
+    /// });
+    /// ```
+    pub fn with_y_format(
+        self,
+        format: impl Fn(&UseY, &Y) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.y_format.set(Some(Arc::new(format)));
+        self
+    }
+
+    /// Sets whether to render the tooltip in a portal on the document body. See [Tooltip::portal] for details.
+    pub fn with_portal(self, portal: impl Into<bool>) -> Self {
+        self.portal.set(portal.into());
+        self
+    }
+
+    /// Sets whether the tooltip's values are announced to screen readers. See [Tooltip::live] for details.
+    pub fn with_live(self, live: impl Into<bool>) -> Self {
+        self.live.set(live.into());
+        self
+    }
+}
+
+impl<X: Tick> Tooltip<X, f64> {
+    /// Appends a unit (e.g., "B/s") to Y values with a metric prefix picked for the Y range e.g., "1.2 GB/s". See [TickLabels::with_unit] for details.
+    pub fn with_y_unit(self, unit: impl Into<String>) -> Self {
+        let gen = AlignedFloats::default().with_unit(unit);
+        self.y_ticks.generator.set(Arc::new(gen));
+        self
+    }
 }
 
 impl<X: Tick, Y: Tick> Default for Tooltip<X, Y> {
@@ -116,9 +339,16 @@ impl<X: Tick, Y: Tick> Default for Tooltip<X, Y> {
             sort_by: RwSignal::default(),
             cursor_distance: RwSignal::new(TOOLTIP_CURSOR_DISTANCE),
             skip_missing: RwSignal::new(false),
+            missing: RwSignal::default(),
+            missing_format: RwSignal::default(),
             show_x_ticks: RwSignal::new(true),
             x_ticks: TickLabels::default(),
             y_ticks: TickLabels::default(),
+            y_format: RwSignal::default(),
+            x_formats: RwSignal::default(),
+            portal: RwSignal::new(false),
+            custom: RwSignal::default(),
+            live: RwSignal::new(true),
         }
     }
 }
@@ -159,6 +389,8 @@ impl std::fmt::Display for TooltipPlacement {
         match self {
             TooltipPlacement::Hide => write!(f, "Hide"),
             TooltipPlacement::LeftCursor => write!(f, "Left cursor"),
+            TooltipPlacement::BarTop => write!(f, "Bar top"),
+            TooltipPlacement::Badges => write!(f, "Badges"),
         }
     }
 }
@@ -170,6 +402,8 @@ impl std::str::FromStr for TooltipPlacement {
         match s.to_lowercase().as_str() {
             "hide" => Ok(TooltipPlacement::Hide),
             "left cursor" => Ok(TooltipPlacement::LeftCursor),
+            "bar top" => Ok(TooltipPlacement::BarTop),
+            "badges" => Ok(TooltipPlacement::Badges),
             _ => Err(format!("invalid TooltipPlacement: `{}`", s)),
         }
     }
@@ -207,35 +441,64 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         placement,
         sort_by,
         skip_missing,
+        missing,
+        missing_format,
         cursor_distance,
         show_x_ticks,
         x_ticks,
         y_ticks,
+        y_format,
+        x_formats,
+        portal,
+        custom,
+        live,
     } = tooltip;
     let debug = state.pre.debug;
+    let (missing_text, no_data) = (state.pre.messages.missing, state.pre.messages.no_data);
     let font_height = state.pre.font_height;
     let font_width = state.pre.font_width;
     let padding = state.pre.padding;
     let inner = state.layout.inner;
 
-    let x_body = {
-        let nearest_data_x = state.pre.data.nearest_data_x(state.hover_position_x);
-        let x_format = x_ticks.format;
+    let nearest_data_x = state.pre.data.nearest_data_x(state.hover_position_x);
+    let x_format = x_ticks.format;
+    let x_ticks = {
         let avail_width = Signal::derive(move || inner.read().width());
-        let x_ticks = x_ticks.generate_x(&state.pre, avail_width);
-        move || {
-            // Hide ticks?
-            if !show_x_ticks.get() {
-                return "".to_string();
-            }
-            let x_format = x_format.get();
-            nearest_data_x.read().as_ref().map_or_else(
-                || "no data".to_string(),
-                |x_value| (x_format)(x_value, x_ticks.read().state.as_ref()),
-            )
+        x_ticks.generate_x(&state.pre, avail_width)
+    };
+    let format_x_value =
+        move |x_value: &X| (x_format.get())(x_value, x_ticks.read().state.as_ref());
+    let x_body = move || {
+        // Hide ticks?
+        if !show_x_ticks.get() {
+            return "".to_string();
         }
+        nearest_data_x
+            .read()
+            .as_ref()
+            .map_or_else(|| no_data.get(), format_x_value)
     };
 
+    // Extra X formats
+    let x_extra = move || {
+        if !show_x_ticks.get() {
+            return vec![];
+        }
+        let ticks = x_ticks.read();
+        nearest_data_x
+            .read()
+            .as_ref()
+            .map_or_else(Vec::new, |x_value| {
+                x_formats
+                    .get()
+                    .iter()
+                    .map(|format| (format)(x_value, ticks.state.as_ref()))
+                    .collect::<Vec<_>>()
+            })
+    };
+
+    let nearest_meta = state.pre.data.nearest_meta(state.hover_position_x);
+
     let format_y_value = {
         let avail_height = Signal::derive(move || inner.read().height());
         let y_format = y_ticks.format;
@@ -243,7 +506,7 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         move |y_value: Option<Y>| {
             let y_format = y_format.get();
             y_value.as_ref().map_or_else(
-                || "-".to_string(),
+                || missing_text.get(),
                 |y_value| (y_format)(y_value, y_ticks.read().state.as_ref()),
             )
         }
@@ -254,10 +517,10 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         Memo::new(move |_| {
             let mut y_values = nearest_data_y.get();
             // Skip missing?
-            if skip_missing.get() {
+            if skip_missing.get() || missing.get() == MissingDisplay::Hide {
                 y_values = y_values
                     .into_iter()
-                    .filter(|(_, y_value)| y_value.is_some())
+                    .filter(|(_, y_value)| !is_missing(y_value))
                     .collect::<Vec<_>>()
             }
             // Sort values
@@ -266,23 +529,227 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         })
     };
 
+    // Y values by series. Uses the custom format if set
+    let format_series_y = move |line: &UseY, y_value: Option<Y>| match (y_format.get(), y_value) {
+        (Some(format), Some(y_value)) => (format)(line, &y_value),
+        (_, y_value) => format_y_value(y_value),
+    };
+
+    // Missing values: (text, greyed out?)
+    let format_missing = {
+        let last_known = state.pre.data.nearest_last_known_y(state.hover_position_x);
+        move |line: &UseY| {
+            let display = missing.get();
+            let greyed = matches!(display, MissingDisplay::Greyed | MissingDisplay::LastKnown);
+            let last = || last_known.with(|last| last.get(&line.id).cloned());
+            let text = match (missing_format.get(), display) {
+                (Some(format), _) => (format)(last().as_ref().map(|(x, y)| (x, y))),
+                (None, MissingDisplay::Text(text)) => text,
+                (None, MissingDisplay::LastKnown) => last().map_or_else(
+                    || missing_text.get(),
+                    |(x, y)| {
+                        format!(
+                            "{} ({})",
+                            format_series_y(line, Some(y)),
+                            format_x_value(&x)
+                        )
+                    },
+                ),
+                (None, _) => missing_text.get(),
+            };
+            (text, greyed)
+        }
+    };
+
+    let nearest_data_extra = state.pre.data.nearest_data_extra(state.hover_position_x);
     let nearest_data_y = move || {
+        let extras = nearest_data_extra.get();
         nearest_y_values
             .get()
             .into_iter()
-            .map(|(line, y_value)| {
-                let y_value = format_y_value(y_value);
-                (line, y_value)
+            .map(|(series, value)| {
+                let (text, greyed) = if is_missing(&value) {
+                    format_missing(&series)
+                } else {
+                    // Extra values e.g., a candlestick's open, high and low
+                    let extra = (extras
+                        .get(&series.id)
+                        .cloned()
+                        .unwrap_or_default()
+                        .into_iter())
+                    .map(|y| format_series_y(&series, Some(y)))
+                    .collect::<Vec<_>>();
+                    let text = series.format_extra(format_series_y(&series, value.clone()), extra);
+                    (text, false)
+                };
+                TooltipValue {
+                    colour: series.colour().get(),
+                    series,
+                    value,
+                    text,
+                    greyed,
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Hovered values for a custom view
+    let ctx = move || TooltipCtx {
+        x: nearest_data_x.get(),
+        x_text: x_body(),
+        meta: nearest_meta.get(),
+        values: nearest_data_y(),
+    };
+
+    // Mirror values into a live region
+    let live_text = {
+        let hover_inner = state.hover_inner;
+        move || {
+            if hover_inner.get() && placement.get() != TooltipPlacement::Hide {
+                let rows = (nearest_data_y().into_iter())
+                    .map(|value| (value.series.name.get(), value.text))
+                    .collect::<Vec<_>>();
+                announcement(&x_body(), &rows)
+            } else {
+                String::new()
+            }
+        }
+    };
+
+    // Top of the hovered bar group in SVG coords
+    let bar_top = {
+        let includes_bars = state.pre.data.includes_bars;
+        let nearest_positions = state.pre.data.nearest_positions(state.hover_position_x);
+        let nearest_extra = state
+            .pre
+            .data
+            .nearest_extra_positions(state.hover_position_x);
+        let (proj, proj_secondary) = (state.projection, state.projection_secondary);
+        let inner = state.layout.inner;
+        Memo::new(move |_| {
+            if !includes_bars.get() {
+                return None;
+            }
+            let (positions, extras) = (nearest_positions.get(), nearest_extra.get());
+            let (proj, proj_secondary, inner) = (proj.get(), proj_secondary.get(), inner.get());
+            nearest_y_values.with(|y_values| {
+                let ends = (y_values.iter())
+                    .filter(|(series, _)| series.is_bar_like())
+                    .filter_map(|(series, _)| {
+                        // Stacked bars are at their stacked position
+                        let &position = positions.get(&series.id)?;
+                        let proj = match series.y_axis() {
+                            YAxis::Secondary => &proj_secondary,
+                            _ => &proj,
+                        };
+                        let extra = extras.get(&series.id).map_or(&[][..], Vec::as_slice);
+                        let bottom_y = (series.bar())
+                            .filter(|bar| !bar.stacked)
+                            .map(|bar| bar.bottom_y(proj, inner));
+                        Some(bar_ends(proj, position, extra, bottom_y))
+                    })
+                    .flatten();
+                bar_group_top(ends)
+            })
+        })
+    };
+
+    // Tooltip position on the page: (left, top). Flips and shifts to stay within the viewport
+    let aside = NodeRef::<Aside>::new();
+    let position = {
+        let (mouse_page, mouse_chart) = (state.mouse_page, state.mouse_chart);
+        Memo::new(move |_| {
+            let (page_x, page_y) = mouse_page.get();
+            let distance = cursor_distance.get();
+            let size = aside
+                .get()
+                .map(|aside| (aside.offset_width() as f64, aside.offset_height() as f64))
+                .unwrap_or_default();
+            let viewport = viewport();
+            match (placement.get(), bar_top.get()) {
+                (TooltipPlacement::BarTop, Some((x, y))) => {
+                    // Chart origin on the page
+                    let (chart_x, chart_y) = mouse_chart.get();
+                    let (origin_x, origin_y) = (page_x - chart_x, page_y - chart_y);
+                    place_above((origin_x + x, origin_y + y), size, distance, viewport)
+                }
+                _ => place_left_of((page_x, page_y), size, distance, viewport),
+            }
+        })
+    };
+
+    // Badges on the page: (series, value, greyed, (left, top)). Skips series without a position e.g., missing values
+    let badges = {
+        let nearest_positions = state.pre.data.nearest_positions(state.hover_position_x);
+        let (proj, proj_secondary) = (state.projection, state.projection_secondary);
+        let (mouse_page, mouse_chart) = (state.mouse_page, state.mouse_chart);
+        move || {
+            let positions = nearest_positions.get();
+            let (page_x, page_y) = mouse_page.get();
+            let (chart_x, chart_y) = mouse_chart.get();
+            let (origin_x, origin_y) = (page_x - chart_x, page_y - chart_y);
+            nearest_data_y()
+                .into_iter()
+                .filter_map(
+                    |TooltipValue {
+                         series,
+                         text,
+                         greyed,
+                         ..
+                     }| {
+                        let &(x, y) = positions.get(&series.id)?;
+                        if x.is_nan() || y.is_nan() {
+                            return None;
+                        }
+                        let proj = match series.y_axis() {
+                            YAxis::Secondary => proj_secondary.get(),
+                            _ => proj.get(),
+                        };
+                        let (x, y) = proj.position_to_svg(x, y);
+                        Some((series, text, greyed, (origin_x + x, origin_y + y)))
+                    },
+                )
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // Interleave group subheadings with series rows
+    let rows = move || {
+        let rows = nearest_data_y()
+            .into_iter()
+            .map(|value| {
+                let group = value.series.tooltip_group();
+                (group, (value.series, value.text, value.greyed))
+            })
+            .collect::<Vec<_>>();
+        group_rows(rows)
+            .into_iter()
+            .flat_map(|(group, rows)| {
+                (group.map(TooltipRow::Group).into_iter()).chain(rows.into_iter().map(
+                    |(series, y_value, greyed)| {
+                        TooltipRow::Series(Box::new(series), y_value, greyed)
+                    },
+                ))
             })
             .collect::<Vec<_>>()
     };
 
     let series_tr = {
-        let state = state.clone();
-        move |(series, y_value): (UseY, String)| {
-            view! {
-                <tr>
-                    <td><Snippet series=series state=state.clone() /></td>
+        let state = StoredValue::new(state.clone());
+        move |row: TooltipRow| match row {
+            TooltipRow::Group(group) => Either::Left(view! {
+                <tr class="_chartistry_tooltip_group">
+                    <th
+                        colspan="2"
+                        style="text-align: left; font-weight: bold;"
+                        style:padding-top=move || format!("{}px", font_height.get() / 2.0)>
+                        {group}
+                    </th>
+                </tr>
+            }),
+            TooltipRow::Series(series, y_value, greyed) => Either::Right(view! {
+                <tr style:opacity=if greyed { "0.5" } else { "1" }>
+                    <td><Snippet series=*series state=state.get_value() /></td>
                     <td
                         style="white-space: pre; font-family: monospace; text-align: right;"
                         style:padding-top=move || format!("{}px", font_height.get() / 4.0)
@@ -290,37 +757,370 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
                         {y_value}
                     </td>
                 </tr>
-            }
+            }),
         }
     };
 
-    view! {
-        <Show when=move || state.hover_inner.get() && placement.get() != TooltipPlacement::Hide>
+    let body = move || {
+        let state = state.clone();
+        view! {
+        <Show when=move || state.hover_inner.get() && placement.get() == TooltipPlacement::Badges>
+            {move || badges().into_iter().map(|(series, y_value, greyed, (left, top))| view! {
+                <div
+                    class="_chartistry_tooltip_badge"
+                    style="position: absolute; z-index: 1; width: max-content; transform: translateY(-50%); background-color: #fff; white-space: pre; font-family: monospace;"
+                    style:border=move || format!("1px solid {}", series.colour().get())
+                    style:opacity=if greyed { "0.5" } else { "1" }
+                    style:left=move || format!("{}px", left + cursor_distance.get())
+                    style:top=format!("{}px", top)
+                    style:padding=move || format!("0 {}px", font_width.get() / 2.0)
+                    style:font-size=move || format!("{}px", font_height.get())>
+                    {y_value}
+                </div>
+            }).collect_view()}
+        </Show>
+        <Show when=move || state.hover_inner.get() && !matches!(placement.get(), TooltipPlacement::Hide | TooltipPlacement::Badges)>
             <DebugRect label="tooltip" debug=debug />
             <aside
+                node_ref=aside
                 class="_chartistry_tooltip"
-                style="position: absolute; z-index: 1; width: max-content; height: max-content; transform: translateY(-50%); background-color: #fff; white-space: pre; font-family: monospace;"
+                style=TOOLTIP_STYLE
                 style:border=format!("1px solid {}", AXIS_MARKER_COLOUR)
-                style:top=move || format!("calc({}px)", state.mouse_page.get().1)
-                style:right=move || format!("calc(100% - {}px + {}px)", state.mouse_page.get().0, cursor_distance.get())
+                style:left=move || format!("{}px", position.get().0)
+                style:top=move || format!("{}px", position.get().1)
                 style:padding=move || padding.get().to_css_style()>
-                <h2
-                    style="margin: 0; text-align: center;"
-                    style:font-size=move || format!("{}px", font_height.get())>
-                    {x_body}
-                </h2>
-                <table
-                    style="border-collapse: collapse; border-spacing: 0; margin: 0 0 0 auto; padding: 0;"
-                    style:font-size=move || format!("{}px", font_height.get())>
-                    <tbody>
-                        <For
-                            each=nearest_data_y
-                            key=|(series, y_value)| (series.id, y_value.to_owned())
-                            children=series_tr.clone()
-                        />
-                    </tbody>
-                </table>
+                <Show when=move || custom.with(Option::is_some)>
+                    {move || custom.get().map(|custom| custom(ctx()))}
+                </Show>
+                <Show when=move || custom.with(Option::is_none)>
+                    <h2
+                        style="margin: 0; text-align: center;"
+                        style:font-size=move || format!("{}px", font_height.get())>
+                        {x_body}
+                    </h2>
+                    {move || x_extra().into_iter().map(|x_value| view! {
+                        <p
+                            class="_chartistry_tooltip_x"
+                            style="margin: 0; text-align: center;"
+                            style:font-size=move || format!("{}px", font_height.get())>
+                            {x_value}
+                        </p>
+                    }).collect_view()}
+                    {move || nearest_meta.get().map(|meta| view! {
+                        <p
+                            class="_chartistry_tooltip_meta"
+                            style="margin: 0; text-align: center;"
+                            style:font-size=move || format!("{}px", font_height.get())>
+                            {meta}
+                        </p>
+                    })}
+                    <table
+                        style="border-collapse: collapse; border-spacing: 0; margin: 0 0 0 auto; padding: 0;"
+                        style:font-size=move || format!("{}px", font_height.get())>
+                        <tbody>
+                            <For
+                                each=rows
+                                key=TooltipRow::key
+                                children=series_tr
+                            />
+                        </tbody>
+                    </table>
+                </Show>
             </aside>
         </Show>
+        }
+    };
+
+    let tooltip = move || {
+        if portal.get() {
+            let body = body.clone();
+            Either::Left(view! { <Portal>{body.clone()}</Portal> })
+        } else {
+            Either::Right(body())
+        }
+    };
+    view! {
+        {tooltip}
+        {live_region(live, live_text)}
+    }
+}
+
+/// Mirrors text into a visually hidden `aria-live="polite"` region so screen readers announce it. Announces the latest text at most once per throttle period. Shared by charts and other tooltips e.g., [FunnelChart](crate::FunnelChart).
+pub(crate) fn live_region(
+    live: RwSignal<bool>,
+    text: impl Fn() -> String + 'static,
+) -> impl IntoView {
+    let announced = RwSignal::new(String::new());
+    let latest = StoredValue::new(String::new());
+    let pending = StoredValue::new(false);
+    Effect::new(move |_| {
+        if !live.get() {
+            return;
+        }
+        latest.set_value(text());
+        if !pending.get_value() {
+            pending.set_value(true);
+            set_timeout(
+                move || {
+                    if let Some(text) = latest.try_get_value() {
+                        pending.set_value(false);
+                        announced.set(text);
+                    }
+                },
+                LIVE_THROTTLE,
+            );
+        }
+    });
+    view! {
+        <Show when=move || live.get()>
+            <div
+                class="_chartistry_tooltip_live"
+                aria-live="polite"
+                aria-atomic="true"
+                style=VISUALLY_HIDDEN>
+                {move || announced.get()}
+            </div>
+        </Show>
+    }
+}
+
+/// A row of the tooltip's Y value table.
+enum TooltipRow {
+    /// Subheading of a group of series. See [Line::tooltip_group](crate::Line::tooltip_group).
+    Group(String),
+    /// Series with its formatted Y value and whether it's greyed out.
+    Series(Box<UseY>, String, bool),
+}
+
+impl TooltipRow {
+    fn key(&self) -> (Option<usize>, String, bool) {
+        match self {
+            Self::Group(group) => (None, group.to_owned(), false),
+            Self::Series(series, y_value, greyed) => (Some(series.id), y_value.to_owned(), *greyed),
+        }
+    }
+}
+
+/// Groups rows by their group keeping the existing order within each group. Ungrouped rows come first followed by groups in order of first appearance.
+fn group_rows<T>(rows: Vec<(Option<String>, T)>) -> Vec<(Option<String>, Vec<T>)> {
+    let mut groups: Vec<(Option<String>, Vec<T>)> = vec![(None, vec![])];
+    for (group, row) in rows {
+        match groups.iter_mut().find(|(existing, _)| *existing == group) {
+            Some((_, rows)) => rows.push(row),
+            None => groups.push((group, vec![row])),
+        }
+    }
+    groups.retain(|(_, rows)| !rows.is_empty());
+    groups
+}
+
+/// Text read out by screen readers: the X value followed by each series' name and Y value e.g., "Monday: apples 3, pears 5".
+pub(crate) fn announcement(x_value: &str, rows: &[(String, String)]) -> String {
+    let rows = (rows.iter())
+        .map(|(name, y_value)| format!("{} {}", name, y_value.trim()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    match (x_value.is_empty(), rows.is_empty()) {
+        (true, _) => rows,
+        (false, true) => x_value.to_string(),
+        (false, false) => format!("{}: {}", x_value, rows),
+    }
+}
+
+/// Returns true if a Y value is missing (`None` or `f64::NAN`).
+fn is_missing<Y: Tick>(y_value: &Option<Y>) -> bool {
+    y_value.as_ref().is_none_or(|y| y.position().is_nan())
+}
+
+/// Visible area of the page: (left, top, right, bottom) in page coordinates.
+pub(crate) fn viewport() -> (f64, f64, f64, f64) {
+    let (left, top) = (
+        window().scroll_x().unwrap_or_default(),
+        window().scroll_y().unwrap_or_default(),
+    );
+    let (width, height) = document()
+        .document_element()
+        .map(|el| (el.client_width() as f64, el.client_height() as f64))
+        .unwrap_or_default();
+    (left, top, left + width, top + height)
+}
+
+/// Clamps the start of a span of `len` to fit within `min` and `max`. Prefers `min` if it can't fit.
+fn clamp_start(start: f64, len: f64, min: f64, max: f64) -> f64 {
+    start.min(max - len).max(min)
+}
+
+/// Places a box of `size` left of an anchor, vertically centred. Flips to the right if there's no room on the left. Returns (left, top).
+pub(crate) fn place_left_of(
+    (x, y): (f64, f64),
+    (width, height): (f64, f64),
+    distance: f64,
+    (min_x, min_y, max_x, max_y): (f64, f64, f64, f64),
+) -> (f64, f64) {
+    let mut left = x - distance - width;
+    if left < min_x {
+        left = x + distance;
+    }
+    let left = clamp_start(left, width, min_x, max_x);
+    let top = clamp_start(y - height / 2.0, height, min_y, max_y);
+    (left, top)
+}
+
+/// Ends of a bar in SVG coords: its (stacked) position, extra positions (e.g., a stacked bar's bottom or a candlestick's open, high and low) and the SVG Y of an unstacked bar's bottom. Empty if the bar is missing.
+fn bar_ends(
+    proj: &Projection,
+    (x, y): (f64, f64),
+    extra: &[f64],
+    bottom_y: Option<f64>,
+) -> Vec<(f64, f64)> {
+    if x.is_nan() || y.is_nan() {
+        return vec![];
+    }
+    let (svg_x, svg_y) = proj.position_to_svg(x, y);
+    (std::iter::once(svg_y))
+        .chain(extra.iter().map(|&y| proj.position_to_svg(x, y).1))
+        .chain(bottom_y)
+        .filter(|y| !y.is_nan())
+        .map(|y| (svg_x, y))
+        .collect()
+}
+
+/// Highest of the bar ends in SVG coords. SVG Y increases downwards so a reversed Y axis puts a bar's bottom on top.
+fn bar_group_top(ends: impl IntoIterator<Item = (f64, f64)>) -> Option<(f64, f64)> {
+    ends.into_iter().min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Places a box of `size` above an anchor, horizontally centred. Flips below if there's no room above. Returns (left, top).
+fn place_above(
+    (x, y): (f64, f64),
+    (width, height): (f64, f64),
+    distance: f64,
+    (min_x, min_y, max_x, max_y): (f64, f64, f64, f64),
+) -> (f64, f64) {
+    let left = clamp_start(x - width / 2.0, width, min_x, max_x);
+    let mut top = y - distance - height;
+    if top < min_y {
+        top = y + distance;
+    }
+    let top = clamp_start(top, height, min_y, max_y);
+    (left, top)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: (f64, f64, f64, f64) = (0.0, 100.0, 400.0, 400.0);
+
+    #[test]
+    fn test_announcement() {
+        let rows = [
+            ("apples".to_string(), " 3".to_string()),
+            ("pears".to_string(), "5".to_string()),
+        ];
+        assert_eq!(announcement("Monday", &rows), "Monday: apples 3, pears 5");
+        assert_eq!(announcement("", &rows), "apples 3, pears 5");
+        assert_eq!(announcement("Monday", &[]), "Monday");
+    }
+
+    #[test]
+    fn test_group_rows() {
+        let group = |name: &str| Some(name.to_string());
+        let rows = vec![
+            (group("b"), 1),
+            (None, 2),
+            (group("a"), 3),
+            (group("b"), 4),
+            (None, 5),
+        ];
+        assert_eq!(
+            group_rows(rows),
+            vec![
+                (None, vec![2, 5]),
+                (group("b"), vec![1, 4]),
+                (group("a"), vec![3]),
+            ]
+        );
+        assert_eq!(group_rows::<usize>(vec![]), vec![]);
+        assert_eq!(group_rows(vec![(None, 1)]), vec![(None, vec![1])]);
+    }
+
+    #[test]
+    fn test_bar_group_top() {
+        use crate::bounds::Bounds;
+        let proj = Projection::new(
+            Bounds::new(100.0, 100.0),
+            Some((0.0, 2.0)),
+            Some((0.0, 10.0)),
+        );
+        let zero_y = proj.position_to_svg(0.0, 0.0).1;
+        // Stack of 3 with 2 on top: anchors on the stacked top at 5 not either value
+        let stack = [
+            bar_ends(&proj, (1.0, 3.0), &[0.0], None),
+            bar_ends(&proj, (1.0, 5.0), &[3.0], None),
+        ];
+        assert_eq!(bar_group_top(stack.concat()), Some((50.0, 50.0)));
+        // Unstacked bar beside a missing bar
+        let bars = [
+            bar_ends(&proj, (1.0, 4.0), &[], Some(zero_y)),
+            bar_ends(&proj, (1.0, f64::NAN), &[], Some(zero_y)),
+        ];
+        assert_eq!(bar_group_top(bars.concat()), Some((50.0, 60.0)));
+        // Candlestick anchors on its high rather than its close
+        let candle = bar_ends(&proj, (1.0, 4.0), &[2.0, 8.0, 1.0], None);
+        assert_eq!(bar_group_top(candle), Some((50.0, 20.0)));
+        assert_eq!(bar_group_top(vec![]), None);
+        // Reversed Y: bars hang down from their bottom
+        let proj = proj.with_reversed_y(true);
+        let zero_y = proj.position_to_svg(0.0, 0.0).1;
+        let stack = [
+            bar_ends(&proj, (1.0, 3.0), &[0.0], None),
+            bar_ends(&proj, (1.0, 5.0), &[3.0], None),
+        ];
+        assert_eq!(bar_group_top(stack.concat()), Some((50.0, 0.0)));
+        let bar = bar_ends(&proj, (1.0, 4.0), &[], Some(zero_y));
+        assert_eq!(bar_group_top(bar), Some((50.0, 0.0)));
+    }
+
+    #[test]
+    fn test_place_left_of() {
+        // Room on the left
+        assert_eq!(
+            place_left_of((200.0, 200.0), (50.0, 40.0), 10.0, VIEWPORT),
+            (140.0, 180.0)
+        );
+        // Flips right
+        assert_eq!(
+            place_left_of((30.0, 200.0), (50.0, 40.0), 10.0, VIEWPORT),
+            (40.0, 180.0)
+        );
+        // Flips but shifts to fit on narrow screens
+        assert_eq!(
+            place_left_of((100.0, 200.0), (350.0, 40.0), 10.0, VIEWPORT),
+            (50.0, 180.0)
+        );
+        // Shifts down from the top of the viewport
+        assert_eq!(
+            place_left_of((200.0, 110.0), (50.0, 40.0), 10.0, VIEWPORT),
+            (140.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_place_above() {
+        assert_eq!(
+            place_above((200.0, 200.0), (50.0, 40.0), 10.0, VIEWPORT),
+            (175.0, 150.0)
+        );
+        // Flips below
+        assert_eq!(
+            place_above((200.0, 120.0), (50.0, 40.0), 10.0, VIEWPORT),
+            (175.0, 130.0)
+        );
+        // Shifts from the right edge
+        assert_eq!(
+            place_above((390.0, 200.0), (50.0, 40.0), 10.0, VIEWPORT),
+            (350.0, 150.0)
+        );
     }
 }
@@ -0,0 +1,63 @@
+/// Scales stroke widths, marker diameters and default font sizes so that charts look right on high-DPI displays or when exported at 2x. See [Chart](crate::Chart)'s `pixel_scale` prop.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum PixelScale {
+    /// Follow the browser's device pixel ratio. Falls back to 1 when there's no window e.g., SSR.
+    Auto,
+    /// Fixed scale factor. Default is 1 (no scaling).
+    Manual(f64),
+}
+
+impl Default for PixelScale {
+    fn default() -> Self {
+        Self::Manual(1.0)
+    }
+}
+
+impl From<f64> for PixelScale {
+    fn from(factor: f64) -> Self {
+        Self::Manual(factor)
+    }
+}
+
+impl PixelScale {
+    /// Scale factor to multiply pixel sizes by. Invalid factors (zero, negative or not finite) use 1.
+    pub(crate) fn factor(self) -> f64 {
+        let factor = match self {
+            Self::Auto => device_pixel_ratio(),
+            Self::Manual(factor) => factor,
+        };
+        if factor.is_finite() && factor > 0.0 {
+            factor
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn device_pixel_ratio() -> f64 {
+    web_sys::window()
+        .map(|window| window.device_pixel_ratio())
+        .unwrap_or(1.0)
+}
+
+#[cfg(feature = "ssr")]
+fn device_pixel_ratio() -> f64 {
+    1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factor() {
+        assert_eq!(PixelScale::default().factor(), 1.0);
+        assert_eq!(PixelScale::Manual(2.0).factor(), 2.0);
+        assert_eq!(PixelScale::from(1.5).factor(), 1.5);
+        assert_eq!(PixelScale::Manual(0.0).factor(), 1.0);
+        assert_eq!(PixelScale::Manual(-2.0).factor(), 1.0);
+        assert_eq!(PixelScale::Manual(f64::NAN).factor(), 1.0);
+    }
+}
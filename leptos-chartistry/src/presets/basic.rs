@@ -0,0 +1,250 @@
+use crate::{
+    AspectRatio, AxisMarker, Bar, Chart, EdgeLayout, InnerLayout, IntoEdge, IntoInner, Legend,
+    Line, RotatedLabel, Series, Tick, TickLabels, Tooltip, XGridLine, XGuideLine, YGridLine,
+    YGuideLine,
+};
+use chrono::prelude::*;
+use leptos::prelude::*;
+
+/// A chart's series and layout bundled together. Created by [LineChart], [BarChart], [TimeSeriesChart] or [DepthProfileChart] and rendered with [PresetChart].
+///
+/// Fields mirror the props of [Chart] and can be changed before rendering.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64 }
+/// let preset = LineChart::new(
+///     |d: &MyData| d.x,
+///     [
+///         Line::new(|d: &MyData| d.y1).with_name("butterflies"),
+///         Line::new(|d: &MyData| d.y2).with_name("dragonflies"),
+///     ],
+/// )
+/// .with_title("My garden");
+/// ```
+#[non_exhaustive]
+pub struct Preset<T: Send + Sync + 'static, X: Tick, Y: Tick> {
+    /// Series to render.
+    pub series: Series<T, X, Y>,
+    /// Top edge layout.
+    pub top: Vec<EdgeLayout<X>>,
+    /// Right edge layout.
+    pub right: Vec<EdgeLayout<Y>>,
+    /// Bottom edge layout.
+    pub bottom: Vec<EdgeLayout<X>>,
+    /// Left edge layout.
+    pub left: Vec<EdgeLayout<Y>>,
+    /// Inner layout.
+    pub inner: Vec<InnerLayout<X, Y>>,
+    /// Tooltip shown on hover.
+    pub tooltip: Tooltip<X, Y>,
+}
+
+/// Preset for a line chart. Adds X and Y tick labels, a legend, grid lines, guide lines and a tooltip.
+pub struct LineChart;
+
+/// Preset for a bar chart. Adds X and Y tick labels, a legend, horizontal grid lines and a tooltip.
+pub struct BarChart;
+
+/// Preset for a line chart over time. Like [LineChart] but with timestamp tick labels and a labelled X guide line.
+pub struct TimeSeriesChart;
+
+/// Preset for a depth profile e.g., temperature against depth in the ocean. Depth is on the Y axis and increases downwards with X tick labels along the top. Adds a legend, grid lines, a labelled depth guide line and a tooltip.
+///
+/// Lines join points in data order so order your data by depth to draw each profile from the surface down. X isn't expected to be sorted (see [Series::sorted_x]) and the tooltip shows the point nearest the mouse along X.
+///
+/// There's a single X axis, labelled along the top. Lines share the series' X getter so measurements on different scales (e.g., temperature and salinity) need a chart each.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Sample { temperature: f64, depth: f64 }
+/// let preset = DepthProfileChart::new(
+///     |s: &Sample| s.temperature,
+///     [Line::new(|s: &Sample| s.depth).with_name("Depth")],
+/// )
+/// .with_y_label("Depth (m)");
+/// ```
+pub struct DepthProfileChart;
+
+impl<T: Send + Sync + 'static, X: Tick, Y: Tick> Preset<T, X, Y> {
+    /// Creates a preset from a series with no layout. Use [LineChart], [BarChart] or [TimeSeriesChart] for sensible defaults.
+    pub fn new(series: Series<T, X, Y>) -> Self {
+        Self {
+            series,
+            top: Vec::new(),
+            right: Vec::new(),
+            bottom: Vec::new(),
+            left: Vec::new(),
+            inner: Vec::new(),
+            tooltip: Tooltip::default(),
+        }
+    }
+
+    /// Adds a title above the chart.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.top.insert(0, RotatedLabel::middle(title).into_edge());
+        self
+    }
+
+    /// Adds a label next to the Y axis.
+    pub fn with_y_label(mut self, label: impl Into<String>) -> Self {
+        self.left.insert(0, RotatedLabel::middle(label).into_edge());
+        self
+    }
+
+    /// Sets the tooltip.
+    pub fn with_tooltip(mut self, tooltip: impl Into<Tooltip<X, Y>>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+}
+
+impl LineChart {
+    /// Creates a line chart. Use `get_x` to extract the X value from your struct and `lines` for the Y values.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+        get_x: impl Fn(&T) -> X + Send + Sync + 'static,
+        lines: impl IntoIterator<Item = impl Into<Line<T, Y>>>,
+    ) -> Preset<T, X, Y> {
+        Preset {
+            left: vec![TickLabels::default().into_edge()],
+            bottom: vec![TickLabels::default().into_edge(), Legend::end().into_edge()],
+            inner: vec![
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+                YGridLine::default().into_inner(),
+                YGuideLine::over_mouse().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ],
+            tooltip: Tooltip::left_cursor(),
+            ..Preset::new(Series::new(get_x).lines(lines))
+        }
+    }
+}
+
+impl BarChart {
+    /// Creates a bar chart. Use `get_x` to extract the X value from your struct and `bars` for the Y values.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+        get_x: impl Fn(&T) -> X + Send + Sync + 'static,
+        bars: impl IntoIterator<Item = impl Into<Bar<T, Y>>>,
+    ) -> Preset<T, X, Y> {
+        Preset {
+            left: vec![TickLabels::default().into_edge()],
+            bottom: vec![TickLabels::default().into_edge(), Legend::end().into_edge()],
+            inner: vec![
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ],
+            tooltip: Tooltip::left_cursor(),
+            ..Preset::new(Series::new(get_x).bars(bars))
+        }
+    }
+}
+
+impl TimeSeriesChart {
+    /// Creates a line chart over time. Use `get_x` to extract the timestamp from your struct and `lines` for the Y values.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<T, Tz, Y>(
+        get_x: impl Fn(&T) -> DateTime<Tz> + Send + Sync + 'static,
+        lines: impl IntoIterator<Item = impl Into<Line<T, Y>>>,
+    ) -> Preset<T, DateTime<Tz>, Y>
+    where
+        T: Send + Sync + 'static,
+        Tz: TimeZone + Send + Sync + 'static,
+        Tz::Offset: std::fmt::Display + Send + Sync,
+        Y: Tick,
+    {
+        Preset {
+            left: vec![TickLabels::default().into_edge()],
+            bottom: vec![
+                TickLabels::timestamps().into_edge(),
+                Legend::end().into_edge(),
+            ],
+            inner: vec![
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+                YGridLine::default().into_inner(),
+                YGuideLine::over_mouse().into_inner(),
+                XGuideLine::over_data().with_label(true).into_inner(),
+            ],
+            tooltip: Tooltip::left_cursor(),
+            ..Preset::new(Series::new(get_x).lines(lines))
+        }
+    }
+}
+
+impl DepthProfileChart {
+    /// Creates a depth profile. Use `get_x` to extract the measurement from your struct and `lines` for the depths.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+        get_x: impl Fn(&T) -> X + Send + Sync + 'static,
+        lines: impl IntoIterator<Item = impl Into<Line<T, Y>>>,
+    ) -> Preset<T, X, Y> {
+        Preset {
+            top: vec![TickLabels::default().into_edge()],
+            left: vec![TickLabels::default().into_edge()],
+            bottom: vec![Legend::end().into_edge()],
+            inner: vec![
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::top_edge().into_inner(),
+                XGridLine::default().into_inner(),
+                YGridLine::default().into_inner(),
+                YGuideLine::over_mouse().with_label(true).into_inner(),
+            ],
+            tooltip: Tooltip::left_cursor(),
+            ..Preset::new(
+                Series::new(get_x)
+                    .lines(lines)
+                    .with_reversed_y(true)
+                    .with_sorted_x(false),
+            )
+        }
+    }
+}
+
+/// Renders a [Preset]. See [Chart] for details on the props.
+#[component]
+pub fn PresetChart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+    /// Determines the width and height of the chart. See [Chart] for details.
+    #[prop(into)]
+    aspect_ratio: Signal<AspectRatio>,
+    /// Debug mode. See [Chart] for details.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+    /// The preset to render.
+    #[prop(into)]
+    preset: Preset<T, X, Y>,
+    /// Data to render.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let Preset {
+        series,
+        top,
+        right,
+        bottom,
+        left,
+        inner,
+        tooltip,
+    } = preset;
+    view! {
+        <Chart
+            aspect_ratio=aspect_ratio
+            debug=debug
+            top=top
+            right=right
+            bottom=bottom
+            left=left
+            inner=inner
+            tooltip=tooltip
+            series=series
+            data=data
+        />
+    }
+}
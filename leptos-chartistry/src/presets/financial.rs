@@ -0,0 +1,130 @@
+use crate::{
+    AspectRatio, AxisMarker, Bar, Candlestick, Chart, IntoEdge, IntoInner, Series, Tick,
+    TickLabels, Tooltip, XGuideLine, YAxis, YGuideLine,
+};
+use leptos::prelude::*;
+
+/// Builds a financial chart: candlesticks with optional volume bars, a labelled crosshair and an OHLC tooltip. Render with [FinancialChart].
+///
+/// Volume is drawn as bars on the [secondary Y axis](YAxis::Secondary) with its tick labels on the right. Price and volume share the chart, X values and tooltip.
+///
+/// # Example
+/// ```rust
+/// # use chrono::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct Price { at: DateTime<Utc>, open: f64, high: f64, low: f64, close: f64, volume: f64 }
+/// let financial = Financial::new(
+///     |p: &Price| p.at,
+///     Candlestick::new(
+///         |p: &Price| p.open,
+///         |p: &Price| p.high,
+///         |p: &Price| p.low,
+///         |p: &Price| p.close,
+///     ),
+/// )
+/// .with_volume(Bar::new(|p: &Price| p.volume).with_name("Volume"));
+/// ```
+#[non_exhaustive]
+pub struct Financial<T: Send + Sync + 'static, X: Tick, Y: Tick> {
+    /// Price and volume series. Contains the candlestick and any volume bars.
+    pub series: Series<T, X, Y>,
+    /// X tick labels.
+    pub x_ticks: TickLabels<X>,
+    /// Price tick labels on the left.
+    pub y_ticks: TickLabels<Y>,
+    /// Volume tick labels on the right. Only shown with volume.
+    pub volume_ticks: TickLabels<Y>,
+    /// Tooltip showing price and volume.
+    pub tooltip: Tooltip<X, Y>,
+    // Whether volume bars have been added
+    volume: bool,
+}
+
+impl<T: Send + Sync + 'static, X: Tick, Y: Tick> Financial<T, X, Y> {
+    /// Creates a new financial chart from an X value getter and a candlestick.
+    pub fn new(
+        get_x: impl Fn(&T) -> X + Send + Sync + 'static,
+        candlestick: impl Into<Candlestick<T, Y>>,
+    ) -> Self {
+        Self {
+            series: Series::new(get_x).candlestick(candlestick),
+            x_ticks: TickLabels::default(),
+            y_ticks: TickLabels::default(),
+            volume_ticks: TickLabels::default().with_y_axis(YAxis::Secondary),
+            tooltip: Tooltip::left_cursor(),
+            volume: false,
+        }
+    }
+
+    /// Adds volume bars plotted against the secondary Y axis.
+    pub fn with_volume(mut self, volume: impl Into<Bar<T, Y>>) -> Self {
+        let volume = volume.into().with_y_axis(YAxis::Secondary);
+        self.series = self.series.bar(volume);
+        self.volume = true;
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static, X: Tick, Y: Tick> Clone for Financial<T, X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            series: self.series.clone(),
+            x_ticks: self.x_ticks.clone(),
+            y_ticks: self.y_ticks.clone(),
+            volume_ticks: self.volume_ticks.clone(),
+            tooltip: self.tooltip.clone(),
+            volume: self.volume,
+        }
+    }
+}
+
+/// Renders a [Financial] preset. The chart is drawn with a labelled crosshair and an OHLC tooltip. If set, volume bars share the chart with their tick labels on the right.
+#[component]
+pub fn FinancialChart<T: Send + Sync + 'static, X: Tick, Y: Tick>(
+    /// Aspect ratio of the chart.
+    #[prop(into)]
+    aspect_ratio: Signal<AspectRatio>,
+    /// Debug mode. See [Chart] for details.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+    /// The financial preset to render.
+    financial: Financial<T, X, Y>,
+    /// Data to render.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let Financial {
+        series,
+        x_ticks,
+        y_ticks,
+        volume_ticks,
+        tooltip,
+        volume,
+    } = financial;
+    let right = if volume {
+        vec![volume_ticks.into_edge()]
+    } else {
+        vec![]
+    };
+
+    view! {
+        <div class="_chartistry_financial">
+            <Chart
+                aspect_ratio=aspect_ratio
+                debug=debug
+                left=y_ticks
+                right=right
+                bottom=x_ticks
+                inner=[
+                    AxisMarker::left_edge().into_inner(),
+                    AxisMarker::bottom_edge().into_inner(),
+                    XGuideLine::over_data().with_label(true).into_inner(),
+                    YGuideLine::over_mouse().with_label(true).into_inner(),
+                ]
+                tooltip=tooltip
+                series=series
+                data=data
+            />
+        </div>
+    }
+}
@@ -0,0 +1,6 @@
+//! Presets bundle series, layout and tooltip options into larger compositions. They're intended as a starting point and to show how the building blocks fit together: each preset is a builder with public fields that can be adjusted before rendering.
+mod basic;
+mod financial;
+
+pub use basic::{BarChart, DepthProfileChart, LineChart, Preset, PresetChart, TimeSeriesChart};
+pub use financial::{Financial, FinancialChart};
@@ -0,0 +1,357 @@
+use crate::{
+    colours::ColourScheme, debug::DebugRect, layout::tick_labels::value_format,
+    SERIES_COLOUR_SCHEME,
+};
+use leptos::prelude::*;
+use std::{collections::HashMap, sync::Arc};
+
+/// Default gap (in pixels) between nodes in a column of a [Sankey].
+pub const SANKEY_GAP: f64 = 8.0;
+
+/// Default width (in pixels) of a [Sankey] node.
+pub const SANKEY_NODE_WIDTH: f64 = 12.0;
+
+type GetLabel<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Describes a two column flow diagram: sources on the left flowing into targets on the right e.g., where traffic went from each referrer. Drawn by [SankeyChart].
+///
+/// Each datum is a flow from a source to a target with a weight. Nodes are as tall as their total flow and links as thick as their weight. Nodes take colours from the scheme, sources then targets. Links are coloured by their source.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Visit { referrer: String, page: String, count: f64 }
+/// let sankey = Sankey::new(
+///     |v: &Visit| v.referrer.clone(),
+///     |v: &Visit| v.page.clone(),
+///     |v: &Visit| v.count,
+/// );
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Sankey<T> {
+    get_source: GetLabel<T>,
+    get_target: GetLabel<T>,
+    get_weight: Arc<dyn Fn(&T) -> f64 + Send + Sync>,
+    /// Colour scheme of the nodes: sources first, then targets. If there are more nodes than colours, the colours will repeat. Default is [SERIES_COLOUR_SCHEME].
+    pub colours: RwSignal<ColourScheme>,
+    /// Gap (in pixels) between nodes in a column. Default is [SANKEY_GAP].
+    pub gap: RwSignal<f64>,
+    /// Width (in pixels) of nodes. Default is [SANKEY_NODE_WIDTH].
+    pub node_width: RwSignal<f64>,
+}
+
+impl<T> Sankey<T> {
+    /// Creates a new flow diagram. `get_source` and `get_target` name either end of a flow and `get_weight` its size. Nodes are ordered by first appearance. Flows without a positive weight are left out.
+    pub fn new(
+        get_source: impl Fn(&T) -> String + Send + Sync + 'static,
+        get_target: impl Fn(&T) -> String + Send + Sync + 'static,
+        get_weight: impl Fn(&T) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            get_source: Arc::new(get_source),
+            get_target: Arc::new(get_target),
+            get_weight: Arc::new(get_weight),
+            colours: RwSignal::new(SERIES_COLOUR_SCHEME.into()),
+            gap: RwSignal::new(SANKEY_GAP),
+            node_width: RwSignal::new(SANKEY_NODE_WIDTH),
+        }
+    }
+
+    /// Sets the colour scheme of the nodes.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+
+    /// Sets the gap between nodes in a column.
+    pub fn with_gap(self, gap: impl Into<f64>) -> Self {
+        self.gap.set(gap.into());
+        self
+    }
+
+    /// Sets the width of nodes.
+    pub fn with_node_width(self, node_width: impl Into<f64>) -> Self {
+        self.node_width.set(node_width.into());
+        self
+    }
+}
+
+/// A source or target drawn as a bar.
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    label: String,
+    total: f64,
+    top: f64,
+    height: f64,
+}
+
+/// A flow between a source and target drawn as a band.
+#[derive(Clone, Debug, PartialEq)]
+struct Link {
+    source: usize,
+    target: usize,
+    weight: f64,
+    // Top of the band at either end
+    source_y: f64,
+    target_y: f64,
+    thickness: f64,
+}
+
+/// Lays out (source, target, weight) flows in a `height` tall area with `gap` between nodes. Both columns share one scale so the busier column fills the height.
+fn layout(
+    flows: &[(String, String, f64)],
+    height: f64,
+    gap: f64,
+) -> (Vec<Node>, Vec<Node>, Vec<Link>) {
+    // Nodes by first appearance. Indexed by label
+    let index_of =
+        |nodes: &mut Vec<Node>, indexes: &mut HashMap<String, usize>, label: &str, weight: f64| {
+            let index = *indexes.entry(label.to_string()).or_insert_with(|| {
+                nodes.push(Node {
+                    label: label.to_string(),
+                    total: 0.0,
+                    top: 0.0,
+                    height: 0.0,
+                });
+                nodes.len() - 1
+            });
+            nodes[index].total += weight;
+            index
+        };
+    let (mut sources, mut targets) = (Vec::new(), Vec::new());
+    let (mut source_indexes, mut target_indexes) = (HashMap::new(), HashMap::new());
+    let flows = (flows.iter())
+        .filter(|(_, _, weight)| *weight > 0.0)
+        .map(|(source, target, weight)| {
+            let source = index_of(&mut sources, &mut source_indexes, source, *weight);
+            let target = index_of(&mut targets, &mut target_indexes, target, *weight);
+            (source, target, *weight)
+        })
+        .collect::<Vec<_>>();
+
+    // Shared scale: pixels per unit of weight
+    let total = flows.iter().map(|(_, _, weight)| weight).sum::<f64>();
+    let fit = |nodes: &[Node]| (height - gap * (nodes.len() as f64 - 1.0)).max(0.0) / total;
+    let scale = if total > 0.0 {
+        fit(&sources).min(fit(&targets))
+    } else {
+        0.0
+    };
+    let stack = |nodes: &mut [Node]| {
+        let mut top = 0.0;
+        for node in nodes {
+            node.top = top;
+            node.height = node.total * scale;
+            top += node.height + gap;
+        }
+    };
+    stack(&mut sources);
+    stack(&mut targets);
+
+    // Links fill their nodes from the top in order
+    let mut source_used = vec![0.0; sources.len()];
+    let mut target_used = vec![0.0; targets.len()];
+    let links = (flows.into_iter())
+        .map(|(source, target, weight)| {
+            let thickness = weight * scale;
+            let link = Link {
+                source,
+                target,
+                weight,
+                source_y: sources[source].top + source_used[source],
+                target_y: targets[target].top + target_used[target],
+                thickness,
+            };
+            source_used[source] += thickness;
+            target_used[target] += thickness;
+            link
+        })
+        .collect();
+    (sources, targets, links)
+}
+
+/// SVG path of a band from `(x1, y1)` to `(x2, y2)` (top edges) with the given thickness. Edges are S curves.
+fn band(x1: f64, y1: f64, x2: f64, y2: f64, thickness: f64) -> String {
+    let mid = (x1 + x2) / 2.0;
+    let (b1, b2) = (y1 + thickness, y2 + thickness);
+    format!(
+        "M {x1},{y1} C {mid},{y1} {mid},{y2} {x2},{y2} L {x2},{b2} C {mid},{b2} {mid},{b1} {x1},{b1} Z"
+    )
+}
+
+/// Renders a [Sankey] in SVG. Nodes are labelled with their name and total. Hovering a link shows its source, target and weight.
+#[component]
+pub fn SankeyChart<T: Send + Sync + 'static>(
+    /// Describes the flow diagram.
+    sankey: Sankey<T>,
+    /// Flows to render.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+    /// Width of the chart in pixels.
+    #[prop(into)]
+    width: Signal<f64>,
+    /// Height of the chart in pixels.
+    #[prop(into)]
+    height: Signal<f64>,
+    /// Debug mode. See [Chart](crate::Chart) for details.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+) -> impl IntoView {
+    let Sankey {
+        get_source,
+        get_target,
+        get_weight,
+        colours,
+        gap,
+        node_width,
+    } = sankey;
+
+    let flows = Memo::new(move |_| {
+        data.with(|data| {
+            (data.iter())
+                .map(|datum| (get_source(datum), get_target(datum), get_weight(datum)))
+                .collect::<Vec<_>>()
+        })
+    });
+    let laid_out = Memo::new(move |_| flows.with(|flows| layout(flows, height.get(), gap.get())));
+    // Weights and totals are formatted like tick labels
+    let format = Signal::derive(move || {
+        let (sources, _, links) = laid_out.get();
+        let values = (links.iter().map(|link| link.weight))
+            .chain(sources.iter().map(|node| node.total))
+            .collect::<Vec<_>>();
+        value_format(&values)
+    });
+
+    let links = move || {
+        let (sources, targets, links) = laid_out.get();
+        let (width, node_width) = (width.get(), node_width.get());
+        let (colours, format) = (colours.get(), format.get());
+        (links.into_iter())
+            .map(|link| {
+                let d = band(
+                    node_width,
+                    link.source_y,
+                    width - node_width,
+                    link.target_y,
+                    link.thickness,
+                );
+                let title = format!(
+                    "{} → {}: {}",
+                    sources[link.source].label,
+                    targets[link.target].label,
+                    format.format(&link.weight)
+                );
+                view! {
+                    <path d=d fill=colours.by_index(link.source).to_string() fill-opacity="0.5">
+                        <title>{title}</title>
+                    </path>
+                }
+            })
+            .collect_view()
+    };
+
+    let nodes = move || {
+        let (sources, targets, _) = laid_out.get();
+        let (width, node_width) = (width.get(), node_width.get());
+        let (colours, format) = (colours.get(), format.get());
+        // (node, left x, colour, label x, label anchor)
+        let source_count = sources.len();
+        let sources = (sources.into_iter().enumerate()).map(|(index, node)| {
+            let colour = colours.by_index(index).to_string();
+            (node, 0.0, colour, node_width * 1.5, "start")
+        });
+        let targets = (targets.into_iter().enumerate()).map(|(index, node)| {
+            let colour = colours.by_index(source_count + index).to_string();
+            (
+                node,
+                width - node_width,
+                colour,
+                width - node_width * 1.5,
+                "end",
+            )
+        });
+        (sources.chain(targets))
+            .map(|(node, x, colour, label_x, anchor)| {
+                let label = format!("{} ({})", node.label, format.format(&node.total));
+                view! {
+                    <rect x=x y=node.top width=node_width height=node.height fill=colour />
+                    <text
+                        x=label_x
+                        y=node.top + node.height / 2.0
+                        text-anchor=anchor
+                        dominant-baseline="middle">
+                        {label}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <svg
+            class="_chartistry_sankey"
+            width=move || width.get()
+            height=move || height.get()
+            viewBox=move || format!("0 0 {} {}", width.get(), height.get())
+            font-family="monospace">
+            <DebugRect label="Sankey" debug=debug />
+            <g class="_chartistry_sankey_links">{links}</g>
+            <g class="_chartistry_sankey_nodes">{nodes}</g>
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flow(source: &str, target: &str, weight: f64) -> (String, String, f64) {
+        (source.to_string(), target.to_string(), weight)
+    }
+
+    #[test]
+    fn test_layout() {
+        let flows = [
+            flow("search", "home", 30.0),
+            flow("social", "home", 10.0),
+            flow("search", "blog", 20.0),
+            flow("social", "blog", f64::NAN),
+            flow("email", "blog", 0.0),
+        ];
+        let (sources, targets, links) = layout(&flows, 110.0, 10.0);
+        // Two nodes a column: 100px for 60 units
+        let tops = |nodes: &[Node]| nodes.iter().map(|n| (n.top, n.height)).collect::<Vec<_>>();
+        let scale = 100.0 / 60.0;
+        assert_eq!(
+            tops(&sources),
+            vec![(0.0, 50.0 * scale), (50.0 * scale + 10.0, 10.0 * scale)]
+        );
+        assert_eq!(
+            tops(&targets),
+            vec![(0.0, 40.0 * scale), (40.0 * scale + 10.0, 20.0 * scale)]
+        );
+        // Links stack within their nodes
+        let ends = (links.iter())
+            .map(|l| (l.source, l.target, l.source_y, l.target_y))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ends,
+            vec![
+                (0, 0, 0.0, 0.0),
+                (1, 0, 50.0 * scale + 10.0, 30.0 * scale),
+                (0, 1, 30.0 * scale, 40.0 * scale + 10.0),
+            ]
+        );
+        // Empty
+        assert_eq!(layout(&[], 100.0, 10.0), (vec![], vec![], vec![]));
+    }
+
+    #[test]
+    fn test_band() {
+        assert_eq!(
+            band(0.0, 0.0, 10.0, 20.0, 5.0),
+            "M 0,0 C 5,0 5,20 10,20 L 10,25 C 5,25 5,5 0,5 Z"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{coord::svg_point, debug::DebugRect, state::State, Tick};
+use leptos::prelude::*;
+
+/// How dragging the inner chart area selects data points. See [Chart](crate::Chart)'s `on_select` prop.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum SelectionMode {
+    /// No selection: dragging scrolls the X window (if any).
+    #[default]
+    None,
+    /// Drag out a rectangle.
+    Rectangle,
+    /// Draw a freehand lasso around points.
+    Lasso,
+}
+
+impl std::fmt::Display for SelectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Rectangle => write!(f, "Rectangle"),
+            Self::Lasso => write!(f, "Lasso"),
+        }
+    }
+}
+
+impl std::str::FromStr for SelectionMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "rectangle" => Ok(Self::Rectangle),
+            "lasso" => Ok(Self::Lasso),
+            _ => Err(format!("invalid SelectionMode: `{}`", s)),
+        }
+    }
+}
+
+/// An in-progress selection drawn by dragging the inner chart area. Points are in SVG coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Selection {
+    mode: Signal<SelectionMode>,
+    points: RwSignal<Option<Vec<(f64, f64)>>>,
+}
+
+// Minimum distance (in pixels) between lasso points. Avoids huge polygons from mouse events
+const MIN_LASSO_STEP: f64 = 3.0;
+
+impl Selection {
+    pub fn new(mode: Signal<SelectionMode>) -> Self {
+        Self {
+            mode,
+            points: RwSignal::default(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.mode.get_untracked() != SelectionMode::None
+    }
+
+    pub fn is_selecting(&self) -> Signal<bool> {
+        let points = self.points;
+        Signal::derive(move || points.with(|points| points.is_some()))
+    }
+
+    /// Starts a selection from the mouse position.
+    pub fn start(&self, mouse: (f64, f64)) {
+        if self.is_enabled() {
+            self.points.set(Some(vec![mouse]));
+        }
+    }
+
+    /// Updates the current selection (if any) with a new mouse position.
+    pub fn update(&self, mouse: (f64, f64)) {
+        let mode = self.mode.get_untracked();
+        self.points.update(|points| {
+            let Some(points) = points else {
+                return;
+            };
+            match mode {
+                SelectionMode::Rectangle => {
+                    points.truncate(1);
+                    points.push(mouse);
+                }
+                SelectionMode::Lasso => {
+                    let far_enough = points
+                        .last()
+                        .is_none_or(|&(x, y)| (mouse.0 - x).hypot(mouse.1 - y) >= MIN_LASSO_STEP);
+                    if far_enough {
+                        points.push(mouse);
+                    }
+                }
+                SelectionMode::None => {}
+            }
+        });
+    }
+
+    /// Ends the current selection returning its polygon (if any).
+    pub fn end(&self) -> Option<Vec<(f64, f64)>> {
+        let points = self.points.get_untracked()?;
+        self.points.set(None);
+        let polygon = to_polygon(self.mode.get_untracked(), points);
+        (polygon.len() >= 3).then_some(polygon)
+    }
+
+    fn polygon(&self) -> Signal<Option<Vec<(f64, f64)>>> {
+        let (mode, points) = (self.mode, self.points);
+        Signal::derive(move || {
+            let points = points.get()?;
+            Some(to_polygon(mode.get(), points))
+        })
+    }
+}
+
+/// Converts the dragged points to a polygon. A rectangle is described by its opposite corners.
+fn to_polygon(mode: SelectionMode, points: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    match (mode, points.as_slice()) {
+        (SelectionMode::Rectangle, &[(x1, y1), (x2, y2)]) => {
+            vec![(x1, y1), (x2, y1), (x2, y2), (x1, y2)]
+        }
+        (SelectionMode::Rectangle, _) => vec![],
+        _ => points,
+    }
+}
+
+/// Whether a point is inside a polygon using the even-odd rule. Test in the space the polygon was drawn in (SVG) as other scales bend its edges.
+pub(crate) fn point_in_polygon((x, y): (f64, f64), polygon: &[(f64, f64)]) -> bool {
+    let mut inside = false;
+    let mut prev = match polygon.last() {
+        Some(&last) => last,
+        None => return false,
+    };
+    for &(px, py) in polygon {
+        let (qx, qy) = prev;
+        if (py > y) != (qy > y) && x < (qx - px) * (y - py) / (qy - py) + px {
+            inside = !inside;
+        }
+        prev = (px, py);
+    }
+    inside
+}
+
+#[component]
+pub(crate) fn RenderSelection<X: Tick, Y: Tick>(
+    selection: Selection,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let polygon = selection.polygon();
+    let svg_points = move || {
+        polygon.get().map(|polygon| {
+            polygon
+                .into_iter()
+                .map(svg_point)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    };
+    view! {
+        <g class="_chartistry_selection">
+            <DebugRect label="selection" debug=debug />
+            <Show when=move || polygon.with(|polygon| polygon.is_some())>
+                <polygon
+                    points=svg_points
+                    fill="currentColor"
+                    fill-opacity="0.1"
+                    stroke="currentColor"
+                    stroke-opacity="0.5"
+                    stroke-dasharray="4 2"
+                    pointer-events="none"
+                />
+            </Show>
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_polygon() {
+        let corners = vec![(1.0, 2.0), (3.0, 4.0)];
+        assert_eq!(
+            to_polygon(SelectionMode::Rectangle, corners.clone()),
+            vec![(1.0, 2.0), (3.0, 2.0), (3.0, 4.0), (1.0, 4.0)]
+        );
+        assert_eq!(
+            to_polygon(SelectionMode::Rectangle, vec![(1.0, 2.0)]),
+            vec![]
+        );
+        assert_eq!(to_polygon(SelectionMode::Lasso, corners.clone()), corners);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let square = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        assert!(point_in_polygon((5.0, 5.0), &square));
+        assert!(!point_in_polygon((15.0, 5.0), &square));
+        assert!(!point_in_polygon((5.0, -1.0), &square));
+        assert!(!point_in_polygon((f64::NAN, 5.0), &square));
+        assert!(!point_in_polygon((5.0, 5.0), &[]));
+        // Concave: a "U" shape excludes its notch
+        let u = [
+            (0.0, 0.0),
+            (3.0, 0.0),
+            (3.0, 5.0),
+            (6.0, 5.0),
+            (6.0, 0.0),
+            (9.0, 0.0),
+            (9.0, 9.0),
+            (0.0, 9.0),
+        ];
+        assert!(point_in_polygon((1.0, 1.0), &u));
+        assert!(!point_in_polygon((4.5, 2.0), &u));
+        assert!(point_in_polygon((4.5, 7.0), &u));
+    }
+}
@@ -1,5 +1,5 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseBar, SeriesAcc, UseY};
-use crate::{state::State, Colour, Tick};
+use super::{ApplyUseSeries, GetYValue, IntoUseBar, SeriesAcc, StackPart, UseY, YAxis};
+use crate::{bounds::Bounds, projection::Projection, state::State, Colour, Tick};
 use leptos::prelude::*;
 use std::sync::Arc;
 
@@ -25,18 +25,133 @@ pub struct Bar<T, Y> {
     get_y: Arc<dyn GetYValue<T, Y>>,
     /// Set the name of the bar as used in the legend and tooltip.
     pub name: RwSignal<String>,
+    /// Stable ID of the bar. See [UseY::key](crate::UseY::key).
+    pub id: RwSignal<Option<String>>,
+    /// Tooltip section to list the bar under. See [Line::tooltip_group](crate::Line::tooltip_group).
+    pub tooltip_group: RwSignal<Option<String>>,
     /// Set the colour of the bar. If not set, the next colour in the series will be used. Default is `None`.
     pub colour: RwSignal<Option<Colour>>,
+    /// Colour of bars with a value below zero e.g., red for losses. The legend shows both colours. Default is `None` which uses [colour](Self::colour) for all bars.
+    pub negative_colour: RwSignal<Option<Colour>>,
     /// Sets where the bar's bottom is placed. Defaults to the zero line.
     pub placement: RwSignal<BarPlacement>,
+    /// Y axis the bar is plotted against e.g., volume on a right-hand scale. Bars on the [secondary axis](YAxis::Secondary) placed at the zero line include zero in its range. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
     /// Set the gap between group bars. Clamped to 0.0 and 1.0. Defaults to 0.1.
     ///
     /// The gap is the ratio of the available width for an X value. For example if the chart has a width of 200px and 5 items (`T`) that leaves 40px per item. So a gap of 0.1 (10%) would leave 4px between each item with 2px on either side.
     pub gap: RwSignal<f64>,
     /// Set the gap inside a group of bars. Clamped to 0.0 and 1.0. Defaults to 0.05.
     ///
-    /// The group gap is the ratio of the available width for a single bar in a group of bars (for a single X value). Carrying on the example from [gap](Self::gap) a group gap of 0.05 (5%) and two bars would result in 1px (40 / 2 * 0.05). This group gap becomes the space inbetween each bar.
+    /// The group gap is the ratio of the available width for a single bar in a group of bars (for a single X value). Carrying on the example from [gap](Self::gap) a group gap of 0.05 (5%) and two bars would result in 1px (40 / 2 * 0.05). This group gap becomes the space inbetween each bar. Bars sharing a [group](Self::group) key share one slot and use [inner_gap](Self::inner_gap) between them instead.
     pub group_gap: RwSignal<f64>,
+    /// How the width available to each bar is found. Default is [BarWidth::Uniform].
+    pub width: RwSignal<BarWidth>,
+    /// Explicit group key. Bars sharing a key are placed side-by-side in a shared slot at each X value, separated by [inner_gap](Self::inner_gap). Slots are ordered by the first bar added with their key. Default is `None` which gives the bar its own slot.
+    pub group: RwSignal<Option<String>>,
+    /// Set the gap between bars sharing a [group](Self::group) slot. Clamped to 0.0 and 1.0. The ratio of each bar's width in the slot. Defaults to 0.0.
+    pub inner_gap: RwSignal<f64>,
+}
+
+/// Draws bars on top of each other in a single slot at each X value. Add several stacks to a series to place them side-by-side: each slot holds a stack e.g., a stack per region of each product's sales over time.
+///
+/// Positive values stack upwards from zero and negative values downwards. Hidden bars are left out of the stack.
+///
+/// Bars sharing a name across stacks share a colour and a single legend entry. Toggling the entry hides them all.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, north_a: f64, north_b: f64, south_a: f64, south_b: f64 }
+/// let series = Series::new(|data: &MyData| data.x)
+///     .bar_stack(
+///         BarStack::new()
+///             .with_name("North")
+///             .bar(Bar::new(|data: &MyData| data.north_a).with_name("Apples"))
+///             .bar(Bar::new(|data: &MyData| data.north_b).with_name("Bananas")),
+///     )
+///     .bar_stack(
+///         BarStack::new()
+///             .with_name("South")
+///             .bar(Bar::new(|data: &MyData| data.south_a).with_name("Apples"))
+///             .bar(Bar::new(|data: &MyData| data.south_b).with_name("Bananas")),
+///     );
+/// ```
+#[non_exhaustive]
+pub struct BarStack<T, Y> {
+    bars: Vec<Bar<T, Y>>,
+    // Tooltip section of the stack's bars
+    name: Option<String>,
+}
+
+impl<T, Y> BarStack<T, Y> {
+    /// Create a new empty stack of bars.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a bar to the top of the stack. Only its colour, name and tooltip options are used: placement, gaps, width and Y axis follow the stack's bottom bar.
+    pub fn bar(mut self, bar: impl Into<Bar<T, Y>>) -> Self {
+        self.bars.push(bar.into());
+        self
+    }
+
+    /// Names the stack. Used as the tooltip section of its bars unless they set their own [tooltip_group](Bar::tooltip_group).
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Gets the current number of bars in the stack.
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    /// Returns true if there are no bars in the stack.
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty()
+    }
+}
+
+impl<T, Y> Clone for BarStack<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            bars: self.bars.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl<T, Y> Default for BarStack<T, Y> {
+    fn default() -> Self {
+        Self {
+            bars: Vec::new(),
+            name: None,
+        }
+    }
+}
+
+impl<T, Y, I: IntoIterator<Item = Bar<T, Y>>> From<I> for BarStack<T, Y> {
+    fn from(bars: I) -> Self {
+        let mut stack = Self::default();
+        for bar in bars {
+            stack = stack.bar(bar);
+        }
+        stack
+    }
+}
+
+/// How the width available to a bar (before [gaps](Bar::gap)) is found.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum BarWidth {
+    /// Divides the inner chart width equally between X values. Assumes X values are evenly spaced.
+    #[default]
+    Uniform,
+    /// Uses the smallest gap to each bar's neighbouring X values. Suits irregularly spaced X values e.g., timestamps with missing days.
+    Gaps,
+    /// A fixed span of X positions (see [Tick::position]) e.g., `86_400.0` seconds for a day with timestamps.
+    Span(f64),
 }
 
 /// The location of where the bar extends from.
@@ -53,10 +168,35 @@ pub enum BarPlacement {
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseBar {
     group_id: usize,
-    colour: Signal<Colour>,
-    placement: RwSignal<BarPlacement>,
+    pub(crate) colour: Signal<Colour>,
+    pub(crate) negative_colour: RwSignal<Option<Colour>>,
+    pub(crate) placement: RwSignal<BarPlacement>,
+    pub(crate) y_axis: RwSignal<YAxis>,
     gap: RwSignal<f64>,
     group_gap: RwSignal<f64>,
+    width: RwSignal<BarWidth>,
+    group: RwSignal<Option<String>>,
+    inner_gap: RwSignal<f64>,
+    // Whether the bar is drawn from the top of the bar below it in a [BarStack]
+    pub(crate) stacked: bool,
+    // Whether the bar has a legend entry. False when an earlier stacked bar of the same name has it
+    legend: bool,
+}
+
+impl UseBar {
+    /// Whether the bar is listed in the legend. Bars sharing a name across [stacks](BarStack) are listed once.
+    pub(crate) fn in_legend(&self) -> bool {
+        self.legend
+    }
+
+    /// SVG Y an unstacked bar extends from: the zero line or the chart's edge.
+    pub(crate) fn bottom_y(&self, proj: &Projection, inner: Bounds) -> f64 {
+        match self.placement.get() {
+            BarPlacement::Zero => proj.position_to_svg(0.0, 0.0).1,
+            BarPlacement::Edge if proj.is_reversed_y() => inner.top_y(),
+            BarPlacement::Edge => inner.bottom_y(),
+        }
+    }
 }
 
 impl<T, Y> Bar<T, Y> {
@@ -70,10 +210,17 @@ impl<T, Y> Bar<T, Y> {
         Self {
             get_y: Arc::new(get_y),
             name: RwSignal::default(),
+            id: RwSignal::default(),
+            tooltip_group: RwSignal::default(),
             colour: RwSignal::default(),
+            negative_colour: RwSignal::default(),
             placement: RwSignal::default(),
+            y_axis: RwSignal::default(),
             gap: RwSignal::new(BAR_GAP),
             group_gap: RwSignal::new(BAR_GAP_INNER),
+            width: RwSignal::default(),
+            group: RwSignal::default(),
+            inner_gap: RwSignal::default(),
         }
     }
 
@@ -83,18 +230,42 @@ impl<T, Y> Bar<T, Y> {
         self
     }
 
+    /// Set a stable ID for the bar. See [Bar::id] for details.
+    pub fn with_id(self, id: impl Into<String>) -> Self {
+        self.id.set(Some(id.into()));
+        self
+    }
+
+    /// Set the tooltip section to list the bar under. See [Bar::tooltip_group] for details.
+    pub fn with_tooltip_group(self, group: impl Into<String>) -> Self {
+        self.tooltip_group.set(Some(group.into()));
+        self
+    }
+
     /// Set the colour of the bar. If not set, the next colour in the series will be used.
     pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
         self.colour.set(colour.into());
         self
     }
 
+    /// Set the colour of bars with a value below zero.
+    pub fn with_negative_colour(self, colour: impl Into<Colour>) -> Self {
+        self.negative_colour.set(Some(colour.into()));
+        self
+    }
+
     /// Set the placement of the bar.
     pub fn with_placement(self, placement: impl Into<BarPlacement>) -> Self {
         self.placement.set(placement.into());
         self
     }
 
+    /// Plot the bar against a Y axis e.g., [YAxis::Secondary] for a right-hand scale. See [Bar::y_axis] for details.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+
     /// Set the gap between a group of bars. Clamped to 0.0 and 1.0. Defaults to 0.1.
     pub fn with_gap(self, gap: f64) -> Self {
         self.gap.set(gap);
@@ -106,6 +277,24 @@ impl<T, Y> Bar<T, Y> {
         self.group_gap.set(group_gap);
         self
     }
+
+    /// Set the group key of the bar. Bars with the same key share a slot at each X value. See [Bar::group] for details.
+    pub fn with_group(self, group: impl Into<String>) -> Self {
+        self.group.set(Some(group.into()));
+        self
+    }
+
+    /// Set the gap between bars sharing a group slot. Clamped to 0.0 and 1.0. Defaults to 0.0.
+    pub fn with_inner_gap(self, inner_gap: f64) -> Self {
+        self.inner_gap.set(inner_gap);
+        self
+    }
+
+    /// Set how the width available to each bar is found e.g., [BarWidth::Gaps] for irregular timestamps.
+    pub fn with_width(self, width: impl Into<BarWidth>) -> Self {
+        self.width.set(width.into());
+        self
+    }
 }
 
 impl<T, Y> Clone for Bar<T, Y> {
@@ -113,10 +302,17 @@ impl<T, Y> Clone for Bar<T, Y> {
         Self {
             get_y: self.get_y.clone(),
             placement: self.placement,
+            y_axis: self.y_axis,
             gap: self.gap,
             group_gap: self.group_gap,
+            width: self.width,
+            group: self.group,
+            inner_gap: self.inner_gap,
             name: self.name,
+            id: self.id,
+            tooltip_group: self.tooltip_group,
             colour: self.colour,
+            negative_colour: self.negative_colour,
         }
     }
 }
@@ -141,21 +337,211 @@ impl<T, Y> IntoUseBar<T, Y> for Bar<T, Y> {
         group_id: usize,
         colour: Memo<Colour>,
     ) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let bar = self.to_use_y(id, self.use_bar(group_id, colour));
+        (bar, self.get_y.clone())
+    }
+}
+
+impl<T, Y> Bar<T, Y> {
+    fn use_bar(&self, group_id: usize, colour: Memo<Colour>) -> UseBar {
         let override_colour = self.colour;
         let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
-        let bar = UseY::new_bar(
-            id,
-            self.name,
-            UseBar {
-                group_id,
-                colour,
-                placement: self.placement,
-                gap: self.gap,
-                group_gap: self.group_gap,
-            },
-        );
-        (bar, self.get_y.clone())
+        UseBar {
+            group_id,
+            colour,
+            negative_colour: self.negative_colour,
+            placement: self.placement,
+            y_axis: self.y_axis,
+            gap: self.gap,
+            group_gap: self.group_gap,
+            width: self.width,
+            group: self.group,
+            inner_gap: self.inner_gap,
+            stacked: false,
+            legend: true,
+        }
+    }
+
+    fn to_use_y(&self, id: usize, bar: UseBar) -> UseY {
+        UseY::new_bar(id, self.name, self.id, self.tooltip_group.into(), bar)
+    }
+}
+
+impl<T: 'static> ApplyUseSeries<T, f64> for BarStack<T, f64> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, f64>) {
+        // One slot for the whole stack
+        let group_id = series.next_group_id();
+        // Stacked on the bottom bar's axis
+        let y_axis = (self.bars.first()).map_or_else(RwSignal::default, |bar| bar.y_axis);
+        let mut previous = Vec::with_capacity(self.bars.len());
+        for bar in self.bars.clone() {
+            // Share colour with bars of the same name in other stacks. Hiding the name hides them all
+            let name = bar.name.get_untracked();
+            let shared = (series.stacked_bars.get(&name).copied()).filter(|_| !name.is_empty());
+            let (colour, legend) = match shared {
+                Some(colour) => (colour, false),
+                None => {
+                    let colour = series.next_colour();
+                    (series.stacked_bars).insert(name, colour);
+                    (colour, true)
+                }
+            };
+            let stacked = StackedBar {
+                bar,
+                legend,
+                y_axis,
+                stack_name: self.name.clone(),
+                previous: previous.clone(),
+            };
+            _ = series.push_bar_to(group_id, colour, stacked);
+            previous.extend(series.last_stack_part());
+        }
+    }
+}
+
+// Bar drawn on top of the bars below it in a stack
+struct StackedBar<T, Y> {
+    bar: Bar<T, Y>,
+    legend: bool,
+    // Y axis of the stack's bottom bar
+    y_axis: RwSignal<YAxis>,
+    // Tooltip section when the bar doesn't set its own
+    stack_name: Option<String>,
+    previous: Vec<StackPart<T, Y>>,
+}
+
+struct UseStackedBar<T, Y> {
+    bar: Arc<dyn GetYValue<T, Y>>,
+    previous: Vec<StackPart<T, Y>>,
+}
+
+impl<T: 'static> IntoUseBar<T, f64> for StackedBar<T, f64> {
+    fn into_use_bar(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+    ) -> (UseY, Arc<dyn GetYValue<T, f64>>) {
+        let use_bar = UseBar {
+            stacked: true,
+            legend: self.legend,
+            y_axis: self.y_axis,
+            ..self.bar.use_bar(group_id, colour)
+        };
+        let (tooltip_group, stack_name) = (self.bar.tooltip_group, self.stack_name);
+        let tooltip_group =
+            Signal::derive(move || tooltip_group.get().or_else(|| stack_name.clone()));
+        let bar = UseY::new_bar(id, self.bar.name, self.bar.id, tooltip_group, use_bar);
+        let get_y = Arc::new(UseStackedBar {
+            bar: self.bar.get_y.clone(),
+            previous: self.previous,
+        });
+        (bar, get_y)
+    }
+}
+
+impl<T> UseStackedBar<T, f64> {
+    // Bar's value with missing values as zero so they don't break the stack above
+    fn own_value(&self, t: &T) -> Option<f64> {
+        let value = self.bar.value(t)?;
+        Some(if value.is_nan() { 0.0 } else { value })
+    }
+
+    // Top of the shown bars below with the same sign: positive values stack upwards from zero and negative values downwards
+    fn base(&self, t: &T) -> f64 {
+        let negative = self.own_value(t).unwrap_or_default() < 0.0;
+        (self.previous.iter())
+            .filter_map(|part| part.value(t))
+            .filter(|v| !v.is_nan() && (*v < 0.0) == negative)
+            .sum()
+    }
+}
+
+impl<T> GetYValue<T, f64> for UseStackedBar<T, f64> {
+    fn value(&self, t: &T) -> Option<f64> {
+        self.bar.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<f64> {
+        // Missing values leave a gap but don't break the stack above
+        let value = self.own_value(t)?;
+        Some(self.base(t) + value)
+    }
+
+    // The bar's bottom
+    fn extra_values(&self, t: &T) -> Vec<f64> {
+        vec![self.base(t)]
+    }
+}
+
+/// Stand-in series of a lone bar e.g., a [Funnel](crate::Funnel) stage given to a [Tooltip](crate::Tooltip). Only its ID, name and colour are meaningful.
+pub(crate) fn stand_in_bar(id: usize, name: String, colour: Colour) -> UseY {
+    let bar = Bar::<(), f64>::new(|_| 0.0).with_name(name);
+    let use_bar = bar.use_bar(0, Memo::new(move |_| colour));
+    bar.to_use_y(id, use_bar)
+}
+
+/// Width available to each bar given their SVG X coords. `uniform` is the width of an evenly spaced X value and `px_per_position` converts X positions to SVG.
+fn bar_widths(xs: &[f64], width: BarWidth, uniform: f64, px_per_position: f64) -> Vec<f64> {
+    match width {
+        BarWidth::Uniform => vec![uniform; xs.len()],
+        BarWidth::Span(span) => vec![(span * px_per_position).abs(); xs.len()],
+        BarWidth::Gaps => (0..xs.len())
+            .map(|index| {
+                let before = index.checked_sub(1).map(|prev| xs[index] - xs[prev]);
+                let after = xs.get(index + 1).map(|next| next - xs[index]);
+                match (before, after) {
+                    (Some(before), Some(after)) => before.min(after),
+                    (Some(gap), None) | (None, Some(gap)) => gap,
+                    (None, None) => uniform,
+                }
+            })
+            .collect(),
+    }
+}
+
+// Where a bar sits at each X value
+#[derive(Clone, Debug, PartialEq)]
+struct BarSlot {
+    slot: usize,
+    slots: usize,
+    // Position and number of bars within the slot
+    index: usize,
+    len: usize,
+}
+
+/// Finds the slot of a bar given every bar's (group ID, group key) in order. Bars sharing a key share a slot. Bars without a key get their own. Bars sharing a group ID (a [BarStack]) count once.
+fn bar_slot(bars: &[(usize, Option<String>)], group_id: usize) -> BarSlot {
+    let mut slots: Vec<(Option<&String>, Vec<usize>)> = Vec::new();
+    for (id, key) in bars {
+        if slots.iter().any(|(_, ids)| ids.contains(id)) {
+            continue;
+        }
+        match slots
+            .iter_mut()
+            .find(|(k, _)| k.is_some() && *k == key.as_ref())
+        {
+            Some((_, ids)) => ids.push(*id),
+            None => slots.push((key.as_ref(), vec![*id])),
+        }
     }
+    let count = slots.len();
+    (slots.into_iter().enumerate())
+        .find_map(|(slot, (_, ids))| {
+            let index = ids.iter().position(|&id| id == group_id)?;
+            Some(BarSlot {
+                slot,
+                slots: count,
+                index,
+                len: ids.len(),
+            })
+        })
+        .unwrap_or(BarSlot {
+            slot: 0,
+            slots: 1,
+            index: 0,
+            len: 1,
+        })
 }
 
 #[component]
@@ -163,47 +549,86 @@ pub fn RenderBar<X: Tick, Y: Tick>(
     bar: UseBar,
     state: State<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
+    #[prop(optional)] extras: Signal<Vec<Vec<f64>>>,
 ) -> impl IntoView {
-    let bars = Memo::new(move |_| {
-        state
-            .pre
-            .data
-            .series
-            .get()
-            .iter()
-            .filter_map(|series| series.bar().map(|bar| (series.clone(), bar.clone())))
-            .collect::<Vec<_>>()
-            .len()
+    let slot = Memo::new(move |_| {
+        let mut bars = state.pre.data.series.with(|series| {
+            series
+                .iter()
+                .filter_map(|series| series.bar().map(|bar| (bar.group_id, bar.group.get())))
+                .collect::<Vec<_>>()
+        });
+        // Slots follow the order bars were added
+        bars.sort_by_key(|&(group_id, _)| group_id);
+        bar_slot(&bars, bar.group_id)
     });
 
+    let colour = bar.colour;
     let rects = move || {
         positions.with(|positions| {
             // Find the bottom Y position of each bar
-            let bottom_y = match bar.placement.get() {
-                BarPlacement::Zero => state.svg_zero.get().1,
-                BarPlacement::Edge => state.layout.inner.get().bottom_y(),
-            };
+            let zero_y = state.svg_zero.get().1;
+            let proj = state.projection.get();
+            let reversed_y = proj.is_reversed_y();
+            let bottom_y = bar.bottom_y(&proj, state.layout.inner.get());
 
             // Find width of each X position
             // Note: this should possibly be on Layout
+            let px_per_position =
+                proj.position_to_svg(1.0, 0.0).0 - proj.position_to_svg(0.0, 0.0).0;
+            let xs = positions.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+            let widths = bar_widths(
+                &xs,
+                bar.width.get(),
+                state.layout.x_width.get(),
+                px_per_position,
+            );
             let gap = bar.gap.get().clamp(0.0, 1.0);
-            let width = state.layout.x_width.get() * (1.0 - gap);
-            // Find width of each group in an X position
             let group_gap = bar.group_gap.get().clamp(0.0, 1.0);
-            let group_width = width / bars.get() as f64;
-            let group_width_inner = group_width * (1.0 - group_gap);
-            let group_gap = group_width * group_gap;
+            let inner_gap = bar.inner_gap.get().clamp(0.0, 1.0);
+            let slot = slot.get();
+            let negative_colour = bar.negative_colour.get();
+            // Stacked bars start from the top of the bar below
+            let bases = extras.with(|extras| {
+                (0..positions.len())
+                    .map(|index| {
+                        (extras.get(index))
+                            .and_then(|extra| extra.first().copied())
+                            .filter(|_| bar.stacked)
+                    })
+                    .collect::<Vec<_>>()
+            });
 
-            let offset = group_gap / 2.0 - width / 2.0;
             positions
                 .iter()
-                .map(|&(x, y)| {
+                .zip(widths)
+                .zip(bases)
+                .map(|((&(x, y), width), base)| {
+                    let width = width * (1.0 - gap);
+                    // Find width of each slot in an X position
+                    let slot_width = width / slot.slots as f64;
+                    let slot_left = x - width / 2.0
+                        + slot_width * slot.slot as f64
+                        + slot_width * group_gap / 2.0;
+                    // Split the slot between bars sharing it
+                    let bar_width = slot_width * (1.0 - group_gap) / slot.len as f64;
+                    let bar_left =
+                        slot_left + bar_width * slot.index as f64 + bar_width * inner_gap / 2.0;
+                    // SVG Y increases downwards so negative values are below their base (the zero line or the stack below) unless Y is reversed
+                    let fill = negative_colour
+                        .filter(|_| (y > base.unwrap_or(zero_y)) != reversed_y)
+                        .map(|colour| colour.to_string());
+                    let (y, height) = match base {
+                        Some(base) => (y.min(base), (base - y).abs()),
+                        None => (y, bottom_y - y),
+                    };
                     view! {
                         <rect
-                            x=x + group_width * bar.group_id as f64 + offset
+                            x=bar_left
                             y=y
-                            width=group_width_inner
-                            height=bottom_y - y />
+                            width=bar_width * (1.0 - inner_gap)
+                            height=height
+                            fill=fill />
                     }
                 })
                 .collect::<Vec<_>>()
@@ -212,8 +637,120 @@ pub fn RenderBar<X: Tick, Y: Tick>(
     view! {
         <g
             class="_chartistry_bar"
-            fill=move || bar.colour.get().to_string()>
+            fill=move || colour.get().to_string()>
+            {rects}
+        </g>
+    }
+}
+
+/// Invisible hit areas for each bar group spanning the full height of the inner chart. Hovering or clicking anywhere in a bar's column selects it. The hovered column is highlighted.
+#[component]
+pub fn BarHitAreas<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
+    let positions_x = state.pre.data.positions_x();
+    let hovered_x = state.pre.data.nearest_position_x(state.hover_position_x);
+    let hover_inner = state.hover_inner;
+    let (inner, x_width, proj) = (state.layout.inner, state.layout.x_width, state.projection);
+
+    let rects = move || {
+        let inner = inner.get();
+        let width = x_width.get();
+        let proj = proj.get();
+        let hovered = hovered_x.get().filter(|_| hover_inner.get());
+        positions_x.with(|positions| {
+            positions
+                .iter()
+                .map(|&position| {
+                    let (x, _) = proj.position_to_svg(position, 0.0);
+                    let opacity = if hovered == Some(position) { 0.05 } else { 0.0 };
+                    view! {
+                        <rect
+                            x=x - width / 2.0
+                            y=inner.top_y()
+                            width=width
+                            height=inner.height()
+                            fill-opacity=opacity />
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+    view! {
+        <g class="_chartistry_bar_hit_areas" fill="currentColor">
             {rects}
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_widths() {
+        let xs = [0.0, 10.0, 15.0, 35.0];
+        assert_eq!(bar_widths(&xs, BarWidth::Uniform, 8.0, 2.0), vec![8.0; 4]);
+        assert_eq!(
+            bar_widths(&xs, BarWidth::Gaps, 8.0, 2.0),
+            vec![10.0, 5.0, 5.0, 20.0]
+        );
+        assert_eq!(bar_widths(&[3.0], BarWidth::Gaps, 8.0, 2.0), vec![8.0]);
+        assert_eq!(
+            bar_widths(&xs[..2], BarWidth::Span(3.0), 8.0, 2.0),
+            vec![6.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn test_bar_slot() {
+        let slot = |slot, slots, index, len| BarSlot {
+            slot,
+            slots,
+            index,
+            len,
+        };
+        let a = Some("a".to_string());
+        let bars = [(0, a.clone()), (1, None), (2, a.clone()), (3, None)];
+        assert_eq!(bar_slot(&bars, 0), slot(0, 3, 0, 2));
+        assert_eq!(bar_slot(&bars, 1), slot(1, 3, 0, 1));
+        assert_eq!(bar_slot(&bars, 2), slot(0, 3, 1, 2));
+        assert_eq!(bar_slot(&bars, 3), slot(2, 3, 0, 1));
+        // Without keys each bar has its own slot
+        let bars = [(0, None), (1, None)];
+        assert_eq!(bar_slot(&bars, 1), slot(1, 2, 0, 1));
+        // Stacks take one slot
+        let bars = [(0, None), (0, None), (1, None), (1, None)];
+        assert_eq!(bar_slot(&bars, 0), slot(0, 2, 0, 1));
+        assert_eq!(bar_slot(&bars, 1), slot(1, 2, 0, 1));
+    }
+
+    #[test]
+    fn test_stacked_bar() {
+        let get_y = |y: f64| Arc::new(move |_: &()| y) as Arc<dyn GetYValue<(), f64>>;
+        let part = |y: f64, hidden: bool| StackPart {
+            id: 0,
+            get_y: get_y(y),
+            hidden: Signal::derive(move || hidden),
+        };
+        let previous = vec![
+            part(1.0, false),
+            part(f64::NAN, false),
+            part(-4.0, false),
+            part(2.0, false),
+            part(8.0, true),
+        ];
+        let bar = UseStackedBar {
+            bar: get_y(3.0),
+            previous: previous.clone(),
+        };
+        assert_eq!(bar.value(&()), Some(3.0));
+        assert_eq!(bar.stacked_value(&()), Some(6.0));
+        assert_eq!(bar.extra_values(&()), vec![3.0]);
+        // Negative values stack downwards from zero
+        let bar = UseStackedBar {
+            bar: get_y(-1.0),
+            previous,
+        };
+        assert_eq!(bar.stacked_value(&()), Some(-5.0));
+        assert_eq!(bar.extra_values(&()), vec![-4.0]);
+    }
+}
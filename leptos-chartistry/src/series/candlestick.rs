@@ -0,0 +1,266 @@
+use super::{ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY};
+use crate::{Colour, Tick};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default colour for a candlestick that closes higher than it opened.
+pub const CANDLESTICK_RISING_COLOUR: Colour = Colour::from_rgb(0x71, 0xc6, 0x14);
+/// Default colour for a candlestick that closes lower than it opened.
+pub const CANDLESTICK_FALLING_COLOUR: Colour = Colour::from_rgb(0xF5, 0x32, 0x5B);
+
+/// Draws a candlestick for open, high, low and close (OHLC) values. Typically used for financial data.
+///
+/// The body of the candle runs from the open to the close value and the wick from the high to the low. Candles are coloured by direction: [rising](Self::rising) when the close is above the open and [falling](Self::falling) otherwise. The tooltip shows all four values.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Price { x: f64, open: f64, high: f64, low: f64, close: f64 }
+/// let series = Series::new(|p: &Price| p.x)
+///     .candlestick(Candlestick::new(
+///         |p: &Price| p.open,
+///         |p: &Price| p.high,
+///         |p: &Price| p.low,
+///         |p: &Price| p.close,
+///     ).with_name("ACME"));
+/// ```
+#[non_exhaustive]
+pub struct Candlestick<T, Y> {
+    get_ohlc: Arc<GetOhlc<T, Y>>,
+    /// Name of the candlestick. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Stable ID of the candlestick. See [UseY::key](crate::UseY::key).
+    pub id: RwSignal<Option<String>>,
+    /// Tooltip section to list the candlestick under. See [Line::tooltip_group](crate::Line::tooltip_group).
+    pub tooltip_group: RwSignal<Option<String>>,
+    /// Colour of a candle that closes higher than it opened.
+    pub rising: RwSignal<Colour>,
+    /// Colour of a candle that closes lower than (or equal to) its open.
+    pub falling: RwSignal<Colour>,
+    /// Gap between candles. Clamped to 0.0 and 1.0. Defaults to 0.1. See [Bar::gap](crate::Bar::gap) for details.
+    pub gap: RwSignal<f64>,
+    /// Width of the wick (high to low) line. Defaults to 1.0.
+    pub wick_width: RwSignal<f64>,
+}
+
+type GetValue<T, Y> = Box<dyn Fn(&T) -> Y + Send + Sync>;
+
+struct GetOhlc<T, Y> {
+    open: GetValue<T, Y>,
+    high: GetValue<T, Y>,
+    low: GetValue<T, Y>,
+    close: GetValue<T, Y>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseCandlestick {
+    pub(crate) rising: RwSignal<Colour>,
+    falling: RwSignal<Colour>,
+    gap: RwSignal<f64>,
+    wick_width: RwSignal<f64>,
+}
+
+impl<T, Y> Candlestick<T, Y> {
+    /// Create a new candlestick from getters for the open, high, low and close values.
+    pub fn new(
+        open: impl Fn(&T) -> Y + Send + Sync + 'static,
+        high: impl Fn(&T) -> Y + Send + Sync + 'static,
+        low: impl Fn(&T) -> Y + Send + Sync + 'static,
+        close: impl Fn(&T) -> Y + Send + Sync + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_ohlc: Arc::new(GetOhlc {
+                open: Box::new(open),
+                high: Box::new(high),
+                low: Box::new(low),
+                close: Box::new(close),
+            }),
+            name: RwSignal::default(),
+            id: RwSignal::default(),
+            tooltip_group: RwSignal::default(),
+            rising: RwSignal::new(CANDLESTICK_RISING_COLOUR),
+            falling: RwSignal::new(CANDLESTICK_FALLING_COLOUR),
+            gap: RwSignal::new(super::BAR_GAP),
+            wick_width: RwSignal::new(1.0),
+        }
+    }
+
+    /// Set the name of the candlestick. Used in the legend and tooltip.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set a stable ID for the candlestick. See [Candlestick::id] for details.
+    pub fn with_id(self, id: impl Into<String>) -> Self {
+        self.id.set(Some(id.into()));
+        self
+    }
+
+    /// Set the tooltip section to list the candlestick under. See [Candlestick::tooltip_group] for details.
+    pub fn with_tooltip_group(self, group: impl Into<String>) -> Self {
+        self.tooltip_group.set(Some(group.into()));
+        self
+    }
+
+    /// Set the colours of rising and falling candles.
+    pub fn with_colours(self, rising: impl Into<Colour>, falling: impl Into<Colour>) -> Self {
+        self.rising.set(rising.into());
+        self.falling.set(falling.into());
+        self
+    }
+
+    /// Set the gap between candles. Clamped to 0.0 and 1.0. Defaults to 0.1.
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+
+    /// Set the width of the wick line.
+    pub fn with_wick_width(self, width: impl Into<f64>) -> Self {
+        self.wick_width.set(width.into());
+        self
+    }
+}
+
+impl<T, Y> Clone for Candlestick<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_ohlc: self.get_ohlc.clone(),
+            name: self.name,
+            id: self.id,
+            tooltip_group: self.tooltip_group,
+            rising: self.rising,
+            falling: self.falling,
+            gap: self.gap,
+            wick_width: self.wick_width,
+        }
+    }
+}
+
+impl<T, Y: Tick> GetYValue<T, Y> for GetOhlc<T, Y> {
+    fn value(&self, t: &T) -> Option<Y> {
+        Some((self.close)(t))
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<Y> {
+        Some((self.close)(t))
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        vec![(self.open)(t), (self.high)(t), (self.low)(t)]
+    }
+}
+
+impl<T: 'static, Y: Tick> ApplyUseSeries<T, Y> for Candlestick<T, Y> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, Y>) {
+        // Candles are coloured by direction so don't take a colour from the series
+        let rising = self.rising;
+        _ = series.push_line(Memo::new(move |_| rising.get()), (*self).clone());
+    }
+}
+
+impl<T: 'static, Y: Tick> IntoUseLine<T, Y> for Candlestick<T, Y> {
+    fn into_use_line(self, id: usize, _: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, Y>>) {
+        let candlestick = UseY::new_candlestick(
+            id,
+            self.name,
+            self.id,
+            self.tooltip_group.into(),
+            UseCandlestick {
+                rising: self.rising,
+                falling: self.falling,
+                gap: self.gap,
+                wick_width: self.wick_width,
+            },
+        );
+        (candlestick, self.get_ohlc.clone())
+    }
+}
+
+/// Formats a candlestick's tooltip value from its close and extra (open, high, low) values.
+pub(super) fn format_ohlc(close: String, extra: Vec<String>) -> String {
+    match extra.as_slice() {
+        [open, high, low] => format!("O {open} H {high} L {low} C {close}"),
+        _ => close,
+    }
+}
+
+#[component]
+pub fn RenderCandlestick(
+    candlestick: UseCandlestick,
+    #[prop(into)] x_width: Signal<f64>,
+    positions: Signal<Vec<(f64, f64)>>,
+    extras: Signal<Vec<Vec<f64>>>,
+    #[prop(into)] scale: Signal<f64>,
+    #[prop(into, optional)] reversed_y: Signal<bool>,
+) -> impl IntoView {
+    let UseCandlestick {
+        rising,
+        falling,
+        gap,
+        wick_width,
+    } = candlestick;
+
+    let candles = move || {
+        let width = x_width.get() * (1.0 - gap.get().clamp(0.0, 1.0));
+        let wick_width = wick_width.get() * scale.get();
+        let rising = rising.get().to_string();
+        let falling = falling.get().to_string();
+        let reversed_y = reversed_y.get();
+        positions.with(|positions| {
+            extras.with(|extras| {
+                positions
+                    .iter()
+                    .zip(extras)
+                    .filter_map(|(&(x, close), extra)| {
+                        let &[open, high, low] = extra.as_slice() else {
+                            return None;
+                        };
+                        if [x, open, high, low, close].iter().any(|v| v.is_nan()) {
+                            return None;
+                        }
+                        // Note: SVG coords so a rising candle closes above (smaller Y) its open unless Y is reversed
+                        let colour = if (close < open) != reversed_y {
+                            rising.clone()
+                        } else {
+                            falling.clone()
+                        };
+                        Some(view! {
+                            <g fill=colour.clone() stroke=colour>
+                                <line x1=x y1=high x2=x y2=low stroke-width=wick_width />
+                                <rect
+                                    x=x - width / 2.0
+                                    y=open.min(close)
+                                    width=width
+                                    height=(open - close).abs()
+                                    stroke="none" />
+                            </g>
+                        })
+                    })
+                    .collect_view()
+            })
+        })
+    };
+
+    view! {
+        <g class="_chartistry_candlestick">
+            {candles}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ohlc() {
+        let extra = vec!["1".to_string(), "3".to_string(), "0".to_string()];
+        assert_eq!(format_ohlc("2".to_string(), extra), "O 1 H 3 L 0 C 2");
+        assert_eq!(format_ohlc("2".to_string(), vec![]), "2");
+    }
+}
@@ -0,0 +1,299 @@
+use super::{ApplyUseSeries, GetY, GetYValue, IntoUseLine, Line, SeriesAcc, UseY};
+use crate::{coord::svg_point, Colour};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default colour of the fill where the first line is above the second.
+pub const DIFFERENCE_ABOVE_COLOUR: Colour = Colour::from_rgb(0x71, 0xc6, 0x14);
+/// Default colour of the fill where the first line is below the second.
+pub const DIFFERENCE_BELOW_COLOUR: Colour = Colour::from_rgb(0xF5, 0x32, 0x5B);
+
+/// Draws two lines and fills the area between them. The fill uses one colour where the first line (A) is above the second (B) and another where it's below. Known as a difference or "spread" chart e.g., for comparing interest rates or a forecast against actual values.
+///
+/// The tooltip shows the difference (A - B) along with each line. The fill is drawn using straight lines between points so it may not exactly follow a smoothed line. Consider using [Interpolation::Linear](crate::Interpolation::Linear) on both lines.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Rates { x: f64, forecast: f64, actual: f64 }
+/// let series = Series::new(|r: &Rates| r.x)
+///     .difference(Difference::new(
+///         Line::new(|r: &Rates| r.actual).with_name("Actual"),
+///         Line::new(|r: &Rates| r.forecast).with_name("Forecast"),
+///     ).with_name("Actual - Forecast"));
+/// ```
+#[non_exhaustive]
+pub struct Difference<T, Y> {
+    a: Line<T, Y>,
+    b: Line<T, Y>,
+    /// Name of the difference. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Stable ID of the difference. See [UseY::key](crate::UseY::key).
+    pub id: RwSignal<Option<String>>,
+    /// Tooltip section to list the difference under. See [Line::tooltip_group](crate::Line::tooltip_group).
+    pub tooltip_group: RwSignal<Option<String>>,
+    /// Colour of the fill where A is above B.
+    pub above: RwSignal<Colour>,
+    /// Colour of the fill where A is below B.
+    pub below: RwSignal<Colour>,
+    /// Opacity of the fill. Clamped to 0.0 and 1.0. Defaults to 0.3.
+    pub opacity: RwSignal<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseDifference {
+    pub(crate) above: RwSignal<Colour>,
+    below: RwSignal<Colour>,
+    opacity: RwSignal<f64>,
+}
+
+// Difference between two lines: A - B. Extra value holds B for rendering.
+struct GetDifference<T, Y> {
+    get_a: GetY<T, Y>,
+    get_b: GetY<T, Y>,
+}
+
+impl<T, Y> Difference<T, Y> {
+    /// Creates a new difference between two lines: A and B.
+    pub fn new(a: impl Into<Line<T, Y>>, b: impl Into<Line<T, Y>>) -> Self {
+        Self {
+            a: a.into(),
+            b: b.into(),
+            name: RwSignal::default(),
+            id: RwSignal::default(),
+            tooltip_group: RwSignal::default(),
+            above: RwSignal::new(DIFFERENCE_ABOVE_COLOUR),
+            below: RwSignal::new(DIFFERENCE_BELOW_COLOUR),
+            opacity: RwSignal::new(0.3),
+        }
+    }
+
+    /// Set the name of the difference. Used in the legend and tooltip.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set a stable ID for the difference. See [Difference::id] for details.
+    pub fn with_id(self, id: impl Into<String>) -> Self {
+        self.id.set(Some(id.into()));
+        self
+    }
+
+    /// Set the tooltip section to list the difference under. See [Difference::tooltip_group] for details.
+    pub fn with_tooltip_group(self, group: impl Into<String>) -> Self {
+        self.tooltip_group.set(Some(group.into()));
+        self
+    }
+
+    /// Set the fill colours for where A is above and below B.
+    pub fn with_colours(self, above: impl Into<Colour>, below: impl Into<Colour>) -> Self {
+        self.above.set(above.into());
+        self.below.set(below.into());
+        self
+    }
+
+    /// Set the opacity of the fill.
+    pub fn with_opacity(self, opacity: f64) -> Self {
+        self.opacity.set(opacity);
+        self
+    }
+}
+
+impl<T, Y> Clone for Difference<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            name: self.name,
+            id: self.id,
+            tooltip_group: self.tooltip_group,
+            above: self.above,
+            below: self.below,
+            opacity: self.opacity,
+        }
+    }
+}
+
+impl<T> GetYValue<T, f64> for GetDifference<T, f64> {
+    fn value(&self, t: &T) -> Option<f64> {
+        Some(self.get_a.value(t)? - self.get_b.value(t)?)
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<f64> {
+        self.get_a.value(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<f64> {
+        vec![self.get_b.value(t).unwrap_or(f64::NAN)]
+    }
+}
+
+impl<T: 'static> ApplyUseSeries<T, f64> for Difference<T, f64> {
+    fn apply_use_series(self: Arc<Self>, series: &mut SeriesAcc<T, f64>) {
+        let colour = series.next_colour();
+        let get_a = series.push_line(colour, self.a.clone());
+        let colour = series.next_colour();
+        let get_b = series.push_line(colour, self.b.clone());
+        // Fill between the lines
+        let above = self.above;
+        let fill = UseDifferenceFill {
+            difference: (*self).clone(),
+            get_y: Arc::new(GetDifference { get_a, get_b }),
+        };
+        _ = series.push_line(Memo::new(move |_| above.get()), fill);
+    }
+}
+
+struct UseDifferenceFill<T, Y> {
+    difference: Difference<T, Y>,
+    get_y: GetY<T, Y>,
+}
+
+impl<T, Y> IntoUseLine<T, Y> for UseDifferenceFill<T, Y> {
+    fn into_use_line(self, id: usize, _: Memo<Colour>) -> (UseY, GetY<T, Y>) {
+        let difference = self.difference;
+        let fill = UseY::new_difference(
+            id,
+            difference.name,
+            difference.id,
+            difference.tooltip_group.into(),
+            UseDifference {
+                above: difference.above,
+                below: difference.below,
+                opacity: difference.opacity,
+            },
+        );
+        (fill, self.get_y)
+    }
+}
+
+/// Splits the area between lines A and B into polygons where A is above or below B. Takes (x, a, b) SVG coords. Returns (above, polygon) pairs. NaN values break the fill.
+fn difference_polygons(points: &[(f64, f64, f64)]) -> Vec<(bool, Vec<(f64, f64)>)> {
+    let mut polygons = Vec::new();
+    let mut top = Vec::<(f64, f64)>::new();
+    let mut bottom = Vec::<(f64, f64)>::new();
+    let mut above: Option<bool> = None;
+    let mut prev: Option<(f64, f64, f64)> = None;
+
+    let mut flush = |above: Option<bool>, top: &mut Vec<_>, bottom: &mut Vec<_>| {
+        let mut polygon = std::mem::take(top);
+        let mut bottom = std::mem::take(bottom);
+        // Skip zero area runs
+        if let (Some(above), true) = (above, polygon.len() >= 2) {
+            bottom.reverse();
+            polygon.extend(bottom);
+            polygons.push((above, polygon));
+        }
+    };
+
+    for &(x, a, b) in points {
+        if x.is_nan() || a.is_nan() || b.is_nan() {
+            flush(above.take(), &mut top, &mut bottom);
+            prev = None;
+            continue;
+        }
+        // Note: SVG coords so A is above B when its Y is smaller
+        let diff = b - a;
+        if let Some((prev_x, prev_a, prev_b)) = prev {
+            let prev_diff = prev_b - prev_a;
+            // Lines cross: split at the intersection
+            if prev_diff != 0.0 && diff != 0.0 && (prev_diff > 0.0) != (diff > 0.0) {
+                let t = prev_diff / (prev_diff - diff);
+                let cross = (prev_x + t * (x - prev_x), prev_a + t * (a - prev_a));
+                top.push(cross);
+                bottom.push(cross);
+                flush(above.take(), &mut top, &mut bottom);
+                top.push(cross);
+                bottom.push(cross);
+            }
+        }
+        if diff != 0.0 {
+            above.get_or_insert(diff > 0.0);
+        }
+        top.push((x, a));
+        bottom.push((x, b));
+        prev = Some((x, a, b));
+    }
+    flush(above, &mut top, &mut bottom);
+    polygons
+}
+
+#[component]
+pub fn RenderDifference(
+    difference: UseDifference,
+    positions: Signal<Vec<(f64, f64)>>,
+    extras: Signal<Vec<Vec<f64>>>,
+) -> impl IntoView {
+    let UseDifference {
+        above,
+        below,
+        opacity,
+    } = difference;
+
+    let polygons = move || {
+        let points = positions.with(|positions| {
+            extras.with(|extras| {
+                positions
+                    .iter()
+                    .zip(extras)
+                    .map(|(&(x, a), extra)| (x, a, extra.first().copied().unwrap_or(f64::NAN)))
+                    .collect::<Vec<_>>()
+            })
+        });
+        let above = above.get().to_string();
+        let below = below.get().to_string();
+        difference_polygons(&points)
+            .into_iter()
+            .map(|(is_above, polygon)| {
+                let points = polygon
+                    .into_iter()
+                    .map(svg_point)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let fill = if is_above {
+                    above.clone()
+                } else {
+                    below.clone()
+                };
+                view! { <polygon points=points fill=fill /> }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <g
+            class="_chartistry_difference"
+            fill-opacity=move || opacity.get().clamp(0.0, 1.0)
+            stroke="none">
+            {polygons}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference_polygons() {
+        // A above B (smaller Y) throughout
+        assert_eq!(
+            difference_polygons(&[(0.0, 1.0, 2.0), (1.0, 1.0, 3.0)]),
+            vec![(true, vec![(0.0, 1.0), (1.0, 1.0), (1.0, 3.0), (0.0, 2.0)])]
+        );
+        // Lines cross at x = 1
+        assert_eq!(
+            difference_polygons(&[(0.0, 0.0, 2.0), (2.0, 2.0, 0.0)]),
+            vec![
+                (true, vec![(0.0, 0.0), (1.0, 1.0), (1.0, 1.0), (0.0, 2.0)]),
+                (false, vec![(1.0, 1.0), (2.0, 2.0), (2.0, 0.0), (1.0, 1.0)]),
+            ]
+        );
+        // NaN breaks the fill and equal lines have no area
+        assert_eq!(
+            difference_polygons(&[(0.0, 1.0, 1.0), (1.0, f64::NAN, 1.0), (2.0, 1.0, 1.0)]),
+            vec![]
+        );
+    }
+}
@@ -0,0 +1,30 @@
+/// Turns each point into a flat step `half_width` either side of it e.g., across the width of its bar. Neighbouring steps are joined by vertical lines when drawn with linear interpolation so values change at bar edges. Missing points (NaN) are kept to break the line.
+pub fn bar_steps(positions: &[(f64, f64)], half_width: f64) -> Vec<(f64, f64)> {
+    let mut steps = Vec::with_capacity(positions.len() * 2);
+    for &(x, y) in positions {
+        if x.is_nan() || y.is_nan() {
+            steps.push((x, y));
+        } else {
+            steps.push((x - half_width, y));
+            steps.push((x + half_width, y));
+        }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_steps() {
+        let steps = bar_steps(&[(10.0, 1.0), (30.0, 2.0), (50.0, f64::NAN)], 10.0);
+        assert_eq!(
+            steps[..4],
+            [(0.0, 1.0), (20.0, 1.0), (20.0, 2.0), (40.0, 2.0)]
+        );
+        assert!(steps[4].1.is_nan());
+        assert_eq!(steps.len(), 5);
+        assert!(bar_steps(&[], 10.0).is_empty());
+    }
+}
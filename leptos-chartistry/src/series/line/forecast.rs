@@ -0,0 +1,97 @@
+/// Observed and forecast line positions.
+pub type Segments = (Vec<(f64, f64)>, Vec<(f64, f64)>);
+
+/// Splits line positions into observed and forecast segments at the given X boundary. The segments share an interpolated point on the boundary so they join without a gap.
+pub fn split(positions: &[(f64, f64)], boundary: Option<f64>) -> Segments {
+    let Some(boundary) = boundary.filter(|b| !b.is_nan()) else {
+        return (positions.to_vec(), Vec::new());
+    };
+    let index = positions.partition_point(|&(x, _)| x <= boundary);
+    let mut observed = positions[..index].to_vec();
+    let mut forecast = Vec::with_capacity(positions.len() - index + 1);
+    // Join the two segments on the boundary
+    if let (Some(&(x1, y1)), Some(&(x2, y2))) = (observed.last(), positions.get(index)) {
+        if x1 == boundary {
+            forecast.push((x1, y1));
+        } else {
+            let join = (boundary, y1 + (y2 - y1) * (boundary - x1) / (x2 - x1));
+            if !join.1.is_nan() {
+                observed.push(join);
+                forecast.push(join);
+            }
+        }
+    }
+    forecast.extend_from_slice(&positions[index..]);
+    (observed, forecast)
+}
+
+/// Splits paths separated by NaN points (e.g., [bridges](super::gaps::bridges)) into observed and forecast paths. See [split].
+pub fn split_paths(paths: &[(f64, f64)], boundary: Option<f64>) -> Segments {
+    let (mut observed, mut forecast) = (Vec::new(), Vec::new());
+    for path in paths.split(|&(x, y)| x.is_nan() && y.is_nan()) {
+        let (path_observed, path_forecast) = split(path, boundary);
+        for (all, part) in [
+            (&mut observed, path_observed),
+            (&mut forecast, path_forecast),
+        ] {
+            if part.len() > 1 {
+                if !all.is_empty() {
+                    all.push((f64::NAN, f64::NAN));
+                }
+                all.extend(part);
+            }
+        }
+    }
+    (observed, forecast)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POSITIONS: &[(f64, f64)] = &[(0.0, 0.0), (2.0, 4.0), (4.0, 0.0)];
+
+    #[test]
+    fn test_split() {
+        // No boundary
+        assert_eq!(split(POSITIONS, None), (POSITIONS.to_vec(), vec![]));
+        // Between points
+        assert_eq!(
+            split(POSITIONS, Some(1.0)),
+            (
+                vec![(0.0, 0.0), (1.0, 2.0)],
+                vec![(1.0, 2.0), (2.0, 4.0), (4.0, 0.0)]
+            )
+        );
+        // On a point
+        assert_eq!(
+            split(POSITIONS, Some(2.0)),
+            (vec![(0.0, 0.0), (2.0, 4.0)], vec![(2.0, 4.0), (4.0, 0.0)])
+        );
+        // Outside of data
+        assert_eq!(split(POSITIONS, Some(-1.0)), (vec![], POSITIONS.to_vec()));
+        assert_eq!(split(POSITIONS, Some(5.0)), (POSITIONS.to_vec(), vec![]));
+        // Gap on boundary
+        let gap = &[(0.0, 0.0), (2.0, f64::NAN), (4.0, 0.0)];
+        let (observed, forecast) = split(gap, Some(1.0));
+        assert_eq!(observed, vec![(0.0, 0.0)]);
+        assert_eq!(forecast.len(), 2);
+    }
+
+    #[test]
+    fn test_split_paths() {
+        let nan = (f64::NAN, f64::NAN);
+        let paths = [(0.0, 0.0), (2.0, 2.0), nan, (4.0, 0.0), (6.0, 2.0)];
+        let (observed, forecast) = split_paths(&paths, Some(5.0));
+        assert_eq!(observed.len(), 5);
+        assert_eq!(observed[..2], [(0.0, 0.0), (2.0, 2.0)]);
+        assert!(observed[2].0.is_nan());
+        assert_eq!(observed[3..], [(4.0, 0.0), (5.0, 1.0)]);
+        assert_eq!(forecast, vec![(5.0, 1.0), (6.0, 2.0)]);
+        // No boundary
+        assert_eq!(
+            split_paths(&paths[..2], None),
+            (paths[..2].to_vec(), vec![])
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::projection::Projection;
+
+/// Maximum gap along X (in X positions) between line points in SVG coords. See [Line::max_gap](super::Line::max_gap).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaxGap {
+    pub gap: f64,
+    pub projection: Projection,
+}
+
+impl MaxGap {
+    /// Whether two SVG X coords are further than the gap apart. Compared in X positions so it holds on any scale. Unaffected by [Line::x_offset](super::Line::x_offset) which shifts both by the same positions.
+    pub fn is_wide(&self, x1: f64, x2: f64) -> bool {
+        let position = |x: f64| self.projection.svg_to_position(x, 0.0).0;
+        (position(x2) - position(x1)).abs() > self.gap
+    }
+}
+
+/// Breaks a line between consecutive points that are `is_wide` apart along X. Breaks are NaN points like missing data.
+pub fn break_wide(points: &[(f64, f64)], is_wide: impl Fn(f64, f64) -> bool) -> Vec<(f64, f64)> {
+    let mut broken = Vec::with_capacity(points.len());
+    let mut prev: Option<(f64, f64)> = None;
+    for &(x, y) in points {
+        let valid = !(x.is_nan() || y.is_nan());
+        if let (true, Some((prev_x, _))) = (valid, prev) {
+            if is_wide(prev_x, x) {
+                broken.push((f64::NAN, f64::NAN));
+            }
+        }
+        prev = valid.then_some((x, y));
+        broken.push((x, y));
+    }
+    broken
+}
+
+/// Pairs of points either side of missing data (NaN points) to draw across. Gaps that are `is_wide` along X are skipped. Pairs are separated by NaN points so they can be drawn as one path.
+pub fn bridges(points: &[(f64, f64)], is_wide: impl Fn(f64, f64) -> bool) -> Vec<(f64, f64)> {
+    let mut bridges = Vec::new();
+    let mut last_valid: Option<(f64, f64)> = None;
+    let mut in_gap = false;
+    for &(x, y) in points {
+        if x.is_nan() || y.is_nan() {
+            in_gap = true;
+            continue;
+        }
+        if let (true, Some(start)) = (in_gap, last_valid) {
+            if !is_wide(start.0, x) {
+                if !bridges.is_empty() {
+                    bridges.push((f64::NAN, f64::NAN));
+                }
+                bridges.extend([start, (x, y)]);
+            }
+        }
+        in_gap = false;
+        last_valid = Some((x, y));
+    }
+    bridges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_break(point: (f64, f64)) -> bool {
+        point.0.is_nan() && point.1.is_nan()
+    }
+
+    fn wider_than(max_gap: f64) -> impl Fn(f64, f64) -> bool {
+        move |x1, x2| (x2 - x1).abs() > max_gap
+    }
+
+    #[test]
+    fn test_break_wide() {
+        let points = [(0.0, 0.0), (1.0, 1.0), (5.0, 2.0), (6.0, 3.0)];
+        let broken = break_wide(&points, wider_than(2.0));
+        assert_eq!(broken.len(), 5);
+        assert_eq!(broken[..2], points[..2]);
+        assert!(is_break(broken[2]));
+        assert_eq!(broken[3..], points[2..]);
+        // Nothing to break
+        assert_eq!(break_wide(&points, wider_than(10.0)), points.to_vec());
+        // Existing gaps aren't doubled up
+        let gap = [(0.0, 0.0), (f64::NAN, f64::NAN), (5.0, 2.0)];
+        assert_eq!(break_wide(&gap, wider_than(2.0)).len(), 3);
+    }
+
+    #[test]
+    fn test_bridges() {
+        let nan = (f64::NAN, f64::NAN);
+        let points = [
+            (0.0, 0.0),
+            (1.0, 1.0),
+            nan,
+            (2.0, 2.0),
+            nan,
+            nan,
+            (9.0, 3.0),
+        ];
+        assert_eq!(
+            bridges(&points, wider_than(5.0)),
+            vec![(1.0, 1.0), (2.0, 2.0)]
+        );
+        let all = bridges(&points, |_, _| false);
+        assert_eq!(all.len(), 5);
+        assert_eq!(all[..2], [(1.0, 1.0), (2.0, 2.0)]);
+        assert!(is_break(all[2]));
+        assert_eq!(all[3..], [(2.0, 2.0), (9.0, 3.0)]);
+        // No gaps
+        assert_eq!(bridges(&points[..2], |_, _| false), vec![]);
+    }
+
+    #[test]
+    fn test_max_gap_log_scale() {
+        use crate::{bounds::Bounds, scale::LogScale};
+        let bounds = Bounds::from_points(0.0, 0.0, 100.0, 100.0);
+        let projection = Projection::with_scales(
+            bounds,
+            Some((1.0, 10_000.0)),
+            Some((0.0, 1.0)),
+            LogScale::default().into(),
+            Default::default(),
+        );
+        let max_gap = MaxGap {
+            gap: 50.0,
+            projection,
+        };
+        // Same SVG distance, different X distance: 1 to 10 and 1,000 to 10,000
+        assert!(!max_gap.is_wide(0.0, 25.0));
+        assert!(max_gap.is_wide(75.0, 100.0));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::coord::svg_num;
+
 /// Line interpolation. This is used to determine how to draw the line between points.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 #[non_exhaustive]
@@ -71,6 +73,26 @@ impl Interpolation {
             Self::Monotone => monotone(points),
         }
     }
+
+    /// Draws one path per chunk of points. Chunks overlap by one point so the line stays continuous.
+    pub(super) fn chunked_paths(
+        self,
+        points: &[(f64, f64)],
+        chunk_size: Option<usize>,
+    ) -> Vec<String> {
+        match chunk_size {
+            Some(size) if size >= 2 && points.len() > size => {
+                chunks(points, size).map(|chunk| self.path(chunk)).collect()
+            }
+            _ => vec![self.path(points)],
+        }
+    }
+}
+
+fn chunks(points: &[(f64, f64)], size: usize) -> impl Iterator<Item = &[(f64, f64)]> {
+    (0..points.len().saturating_sub(1))
+        .step_by(size - 1)
+        .map(move |start| &points[start..(start + size).min(points.len())])
 }
 
 fn linear(points: &[(f64, f64)]) -> String {
@@ -83,9 +105,9 @@ fn linear(points: &[(f64, f64)]) -> String {
                 "".to_string()
             } else if need_move {
                 need_move = false;
-                format!("M {} {} ", x, y)
+                format!("M {} {} ", svg_num(*x), svg_num(*y))
             } else {
-                format!("L {} {} ", x, y)
+                format!("L {} {} ", svg_num(*x), svg_num(*y))
             }
         })
         .collect::<String>()
@@ -103,18 +125,20 @@ impl Step {
                 } else if let Some((prev_x, prev_y)) = prev {
                     prev = Some((x, y));
                     match self {
-                        Self::Horizontal => format!("H {} V {} ", x, y),
+                        Self::Horizontal => format!("H {} V {} ", svg_num(x), svg_num(y)),
                         Self::HorizontalMiddle => {
-                            format!("H {} V {} H {} ", (x + prev_x) / 2.0, y, x)
+                            let mid_x = svg_num((x + prev_x) / 2.0);
+                            format!("H {} V {} H {} ", mid_x, svg_num(y), svg_num(x))
                         }
-                        Self::Vertical => format!("V {} H {} ", y, x),
+                        Self::Vertical => format!("V {} H {} ", svg_num(y), svg_num(x)),
                         Self::VerticalMiddle => {
-                            format!("V {} H {} V {} ", (y + prev_y) / 2.0, x, y)
+                            let mid_y = svg_num((y + prev_y) / 2.0);
+                            format!("V {} H {} V {} ", mid_y, svg_num(x), svg_num(y))
                         }
                     }
                 } else {
                     prev = Some((x, y));
-                    format!("M {} {} ", x, y)
+                    format!("M {} {} ", svg_num(x), svg_num(y))
                 }
             })
             .collect::<String>()
@@ -146,15 +170,16 @@ fn monotone(points: &[(f64, f64)]) -> String {
             "".to_string()
         } else if x_prev.is_nan() || y_prev.is_nan() {
             // Start of a new segment
-            format!("M {x},{y} ")
+            format!("M {},{} ", svg_num(x), svg_num(y))
         } else if x_next.is_nan() || y_next.is_nan() {
             // End of a segment
-            format!("L {x},{y} ")
+            format!("L {},{} ", svg_num(x), svg_num(y))
         } else {
             let tangent = tangent(x_prev, x, x_next, y_prev, y, y_next);
             let dx = (x - x_prev) / 3.0;
             let x_c = x - dx;
             let y_c = y - dx * tangent;
+            let (x_c, y_c, x, y) = (svg_num(x_c), svg_num(y_c), svg_num(x), svg_num(y));
             format!("S {x_c},{y_c} {x},{y} ")
         };
         path.push_str(&cmd);
@@ -181,3 +206,26 @@ fn tangent(x_prev: f64, x: f64, x_next: f64, y_prev: f64, y: f64, y_next: f64) -
     // Tangent
     (slope_prev.signum() + slope.signum()) * slope_prev.abs().min(0.5 * para.abs())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks() {
+        let points = &[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 4.0)];
+        assert_eq!(
+            chunks(points, 3).collect::<Vec<_>>(),
+            vec![&points[0..3], &points[2..5]]
+        );
+        assert_eq!(
+            chunks(&points[..4], 3).collect::<Vec<_>>(),
+            vec![&points[0..3], &points[2..4]]
+        );
+        // Small enough for one path
+        let linear = Interpolation::Linear;
+        assert_eq!(linear.chunked_paths(points, Some(5)).len(), 1);
+        assert_eq!(linear.chunked_paths(points, None).len(), 1);
+        assert_eq!(linear.chunked_paths(points, Some(2)).len(), 4);
+    }
+}
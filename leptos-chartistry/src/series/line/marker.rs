@@ -95,26 +95,86 @@ impl Marker {
 }
 
 #[component]
-pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) -> impl IntoView {
-    let marker = line.marker.clone();
+pub(super) fn LineMarkers(
+    line: UseLine,
+    positions: Signal<Vec<(f64, f64)>>,
+    flags: Signal<Vec<bool>>,
+    scale: Signal<f64>,
+    max_diameter: Signal<Option<f64>>,
+) -> impl IntoView {
+    // Split positions into regular and flagged points
+    let split = move |flagged: bool| {
+        Signal::derive(move || {
+            positions.with(|positions| {
+                flags.with(|flags| {
+                    positions
+                        .iter()
+                        .enumerate()
+                        .filter(|&(index, _)| flags.get(index).copied().unwrap_or(false) == flagged)
+                        .map(|(_, &position)| position)
+                        .collect::<Vec<_>>()
+                })
+            })
+        })
+    };
+
+    view! {
+        <MarkerGroup
+            class="_chartistry_line_markers"
+            marker=line.marker.clone()
+            line=line.clone()
+            positions=split(false)
+            scale=scale
+            max_diameter=max_diameter />
+        <MarkerGroup
+            class="_chartistry_line_flagged_markers"
+            marker=line.flagged_marker.clone()
+            line=line
+            positions=split(true)
+            scale=scale
+            max_diameter=max_diameter />
+    }
+}
+
+#[component]
+fn MarkerGroup(
+    class: &'static str,
+    marker: Marker,
+    line: UseLine,
+    positions: Signal<Vec<(f64, f64)>>,
+    scale: Signal<f64>,
+    max_diameter: Signal<Option<f64>>,
+) -> impl IntoView {
+    let Marker {
+        shape,
+        colour,
+        scale: marker_scale,
+        border,
+        border_width,
+    } = marker;
+    let line_colour = line.colour;
+    let line_width = line.width;
 
     // Disable border if no marker
     let border_width = Signal::derive(move || {
-        if marker.shape.get() == MarkerShape::None {
+        if shape.get() == MarkerShape::None {
             0.0
         } else {
-            marker.border_width.get()
+            border_width.get()
         }
     });
 
     let markers = move || {
-        let shape = marker.shape.get();
+        let shape = shape.get();
         // Size of our marker: proportionate to our line width
-        let line_width = line.width.get();
-        let diameter = line_width * WIDTH_TO_MARKER * marker.scale.get();
+        let (line_width, diameter) = marker_size(
+            line_width.get() * scale.get(),
+            marker_scale.get(),
+            max_diameter.get(),
+        );
 
         // Avoid the cost of empty nodes
-        if shape != MarkerShape::None {
+        if shape == MarkerShape::None {
             return vec![].collect_view();
         };
 
@@ -138,15 +198,24 @@ pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) ->
 
     view! {
         <g
-            fill=move || marker.colour.get().unwrap_or_else(|| line.colour.get()).to_string()
-            stroke=move || marker.border.get().unwrap_or_else(|| line.colour.get()).to_string()
-            stroke-width=move || border_width.get() * 2.0 // Half of the stroke is inside
-            class="_chartistry_line_markers">
+            fill=move || colour.get().unwrap_or_else(|| line_colour.get()).to_string()
+            stroke=move || border.get().unwrap_or_else(|| line_colour.get()).to_string()
+            stroke-width=move || border_width.get() * scale.get() * 2.0 // Half of the stroke is inside
+            class=class>
             {markers}
         </g>
     }
 }
 
+/// Line width and diameter of a marker. Shrinks both to fit within `max_diameter` (if any) e.g., a legend taster.
+fn marker_size(line_width: f64, marker_scale: f64, max_diameter: Option<f64>) -> (f64, f64) {
+    let diameter = line_width * WIDTH_TO_MARKER * marker_scale;
+    match max_diameter {
+        Some(max) if diameter > max && diameter > 0.0 => (line_width * max / diameter, max),
+        _ => (line_width, diameter),
+    }
+}
+
 /// Renders the marker shape in a square. They should all be similar in size and not just extend to the edge e.g., square is a rotated diamond.
 #[component]
 fn MarkerShape(
@@ -273,3 +342,17 @@ impl std::fmt::Display for MarkerShape {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_size() {
+        assert_eq!(marker_size(2.0, 1.0, None), (2.0, 16.0));
+        assert_eq!(marker_size(2.0, 0.5, Some(12.0)), (2.0, 8.0));
+        // Shrinks proportionally
+        assert_eq!(marker_size(2.0, 1.0, Some(8.0)), (1.0, 8.0));
+        assert_eq!(marker_size(0.0, 1.0, Some(8.0)), (0.0, 0.0));
+    }
+}
@@ -1,11 +1,18 @@
+pub(crate) mod bar_steps;
+mod forecast;
+mod gaps;
 mod interpolation;
 mod marker;
+mod ribbon;
+mod simplify;
+pub(crate) use gaps::MaxGap;
 pub use interpolation::{Interpolation, Step};
 pub use marker::{Marker, MarkerShape};
 
-use super::{ApplyUseSeries, IntoUseLine, SeriesAcc, UseData, UseY};
+use super::{ApplyUseSeries, IntoUseLine, Resample, SeriesAcc, UseData, UseY, YAxis};
 use crate::{
     colours::{Colour, DivergingGradient, LinearGradientSvg, SequentialGradient, BERLIN, LIPARI},
+    coord::svg_point,
     series::GetYValue,
     ColourScheme, Tick,
 };
@@ -44,27 +51,109 @@ pub const DIVERGING_GRADIENT: DivergingGradient = BERLIN;
 #[non_exhaustive]
 pub struct Line<T, Y> {
     get_y: Arc<dyn GetYValue<T, Y>>,
+    // Wraps the Y getter to draw the delta from a baseline. Applied last so it holds for ribbons and flags in any order
+    baseline: Option<WithBaseline<T, Y>>,
     /// Name of the line. Used in the legend.
     pub name: RwSignal<String>,
+    /// Stable ID of the line. Also used in the line's gradient ID. See [UseY::key](crate::UseY::key).
+    pub id: RwSignal<Option<String>>,
+    /// Tooltip section to list the line under e.g., "Stacked" or "Secondary". Sections are shown in order of first appearance with the section name as a subheading. Default is `None` which lists the line before any sections.
+    pub tooltip_group: RwSignal<Option<String>>,
     /// Colour of the line. If not set, the next colour in the series will be used.
     pub colour: RwSignal<Option<Colour>>,
     /// Use a linear gradient (colour scheme) for the line. Default is `None` with fallback to the line colour.
     pub gradient: RwSignal<Option<ColourScheme>>,
+    /// Number of discrete colour bands to split the gradient into. Default is `None` for a smooth gradient. See [Line::with_gradient_steps].
+    pub gradient_steps: RwSignal<Option<usize>>,
     /// Width of the line.
     pub width: RwSignal<f64>,
     /// Interpolation method of the line, aka line smoothing (or not). Describes how the line is drawn between two points. Default is [Interpolation::Monotone].
     pub interpolation: RwSignal<Interpolation>,
     /// Marker at each point on the line.
     pub marker: Marker,
+    /// Marker at each flagged point on the line. Replaces [marker](Self::marker) for flagged points. See [Line::with_flagged].
+    pub flagged_marker: Marker,
+    /// X position (see [Tick::position]) after which the line is drawn as a forecast. Default is `None` for no forecast. See [Line::with_forecast_after].
+    pub forecast_after: RwSignal<Option<f64>>,
+    /// SVG dash array of the forecast part of the line. Default is `"4 4"`.
+    pub forecast_dash: RwSignal<String>,
+    /// Opacity of the forecast part of the line. Default is 0.6.
+    pub forecast_opacity: RwSignal<f64>,
+    /// Opacity of the ribbon around the line. Default is 0.2. See [Line::with_ribbon].
+    pub ribbon_opacity: RwSignal<f64>,
+    /// Simplify the drawn line by dropping points within this tolerance (in pixels). Default is `None` (no simplification). See [Line::with_simplify].
+    pub simplify: RwSignal<Option<f64>>,
+    /// Split the drawn line into multiple SVG paths of this many points. Default is `None` for a single path. See [Line::with_chunk_size].
+    pub chunk_size: RwSignal<Option<usize>>,
+    /// Maximum gap along X (in X positions) to draw across. Wider gaps break the line. Default is `None` for no limit. See [Line::with_max_gap].
+    pub max_gap: RwSignal<Option<f64>>,
+    /// Interpolation used to draw across missing data. Default is `None` which breaks the line. See [Line::with_gap_interpolation].
+    pub gap_interpolation: RwSignal<Option<Interpolation>>,
+    /// Shift the drawn line along X by this many X positions (see [Tick::position]). Default is 0.0. See [Line::with_x_offset].
+    pub x_offset: RwSignal<f64>,
+    /// Whether each value is drawn as a flat step across the width of its bar. Default is false. See [Line::with_bar_steps].
+    pub bar_steps: RwSignal<bool>,
+    /// Index the line to this base at its first visible value e.g., 100. Default is `None` for no indexing. See [Line::with_index_to].
+    pub index_to: RwSignal<Option<f64>>,
+    /// Whether markers can be dragged vertically to edit values. See [Line::with_editable].
+    pub editable: RwSignal<bool>,
+    /// Aggregates points by period when zoomed out. See [Resample] for details. Default is `None` which always draws every point.
+    pub resample: RwSignal<Option<Resample>>,
+    /// Y axis the line is plotted against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+    has_ribbon: bool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseLine {
-    colour: Signal<Colour>,
+    pub(crate) colour: Signal<Colour>,
     gradient: RwSignal<Option<ColourScheme>>,
+    gradient_steps: RwSignal<Option<usize>>,
     width: RwSignal<f64>,
     interpolation: RwSignal<Interpolation>,
     marker: Marker,
+    flagged_marker: Marker,
+    pub(crate) forecast_after: RwSignal<Option<f64>>,
+    forecast_dash: RwSignal<String>,
+    forecast_opacity: RwSignal<f64>,
+    ribbon_opacity: RwSignal<f64>,
+    pub(crate) has_ribbon: bool,
+    simplify: RwSignal<Option<f64>>,
+    chunk_size: RwSignal<Option<usize>>,
+    pub(crate) max_gap: RwSignal<Option<f64>>,
+    gap_interpolation: RwSignal<Option<Interpolation>>,
+    pub(crate) x_offset: RwSignal<f64>,
+    pub(crate) bar_steps: RwSignal<bool>,
+    pub(crate) index_to: RwSignal<Option<f64>>,
+    pub(crate) editable: RwSignal<bool>,
+    pub(crate) resample: RwSignal<Option<Resample>>,
+    pub(crate) y_axis: RwSignal<YAxis>,
+}
+
+// Wraps a line's Y getter with a flag predicate
+struct Flagged<T, Y> {
+    get_y: Arc<dyn GetYValue<T, Y>>,
+    is_flagged: Box<dyn Fn(&T) -> bool + Send + Sync>,
+}
+
+// Y getter returning `None` for missing values
+struct OptionalY<F>(F);
+
+type WithBaseline<T, Y> =
+    Arc<dyn Fn(Arc<dyn GetYValue<T, Y>>) -> Arc<dyn GetYValue<T, Y>> + Send + Sync>;
+
+// Wraps a line's Y getter to draw the delta from a baseline
+struct Baseline<T, Y> {
+    get_y: Arc<dyn GetYValue<T, Y>>,
+    get_base: Arc<dyn Fn(&T) -> Y + Send + Sync>,
+}
+
+type GetBounds<T, Y> = Box<dyn Fn(&T) -> (Y, Y) + Send + Sync>;
+
+// Wraps a line's Y getter with (low, high) bounds
+struct Ribbon<T, Y> {
+    get_y: Arc<dyn GetYValue<T, Y>>,
+    get_bounds: GetBounds<T, Y>,
 }
 
 impl<T, Y> Line<T, Y> {
@@ -75,14 +164,60 @@ impl<T, Y> Line<T, Y> {
     where
         Y: Tick,
     {
+        Self::from_get_y(Arc::new(get_y))
+    }
+
+    /// Create a new line from a `get_y` function that returns `None` for missing values. These are drawn as gaps (see [Line::max_gap] to bridge short gaps) and shown as missing in the tooltip (see [Tooltip::missing](crate::Tooltip::missing)). Same as returning `f64::NAN` but works for any `Y`.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Reading { temp: Option<f64> }
+    /// let line = Line::new_optional(|r: &Reading| r.temp).with_name("temperature");
+    /// ```
+    pub fn new_optional(get_y: impl Fn(&T) -> Option<Y> + Send + Sync + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self::from_get_y(Arc::new(OptionalY(get_y)))
+    }
+
+    fn from_get_y(get_y: Arc<dyn GetYValue<T, Y>>) -> Self {
         Self {
-            get_y: Arc::new(get_y),
+            get_y,
+            baseline: None,
             name: RwSignal::default(),
+            id: RwSignal::default(),
+            tooltip_group: RwSignal::default(),
             colour: RwSignal::default(),
             gradient: RwSignal::default(),
+            gradient_steps: RwSignal::default(),
             width: RwSignal::new(1.0),
             interpolation: RwSignal::default(),
             marker: Marker::default(),
+            flagged_marker: Marker::default(),
+            forecast_after: RwSignal::default(),
+            forecast_dash: RwSignal::new("4 4".to_string()),
+            forecast_opacity: RwSignal::new(0.6),
+            ribbon_opacity: RwSignal::new(0.2),
+            has_ribbon: false,
+            simplify: RwSignal::default(),
+            chunk_size: RwSignal::default(),
+            max_gap: RwSignal::default(),
+            gap_interpolation: RwSignal::default(),
+            x_offset: RwSignal::default(),
+            bar_steps: RwSignal::default(),
+            index_to: RwSignal::default(),
+            editable: RwSignal::default(),
+            resample: RwSignal::default(),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    // Y getter with any baseline applied last
+    fn use_get_y(&self) -> Arc<dyn GetYValue<T, Y>> {
+        match &self.baseline {
+            Some(baseline) => baseline(self.get_y.clone()),
+            None => self.get_y.clone(),
         }
     }
 
@@ -92,6 +227,18 @@ impl<T, Y> Line<T, Y> {
         self
     }
 
+    /// Set a stable ID for the line. See [Line::id] for details.
+    pub fn with_id(self, id: impl Into<String>) -> Self {
+        self.id.set(Some(id.into()));
+        self
+    }
+
+    /// Set the tooltip section to list the line under. See [Line::tooltip_group] for details.
+    pub fn with_tooltip_group(self, group: impl Into<String>) -> Self {
+        self.tooltip_group.set(Some(group.into()));
+        self
+    }
+
     /// Set the colour of the line. If not set, the next colour in the series will be used.
     pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
         self.colour.set(colour.into());
@@ -106,6 +253,20 @@ impl<T, Y> Line<T, Y> {
         self
     }
 
+    /// Split the line's [gradient](Line::with_gradient) into `bands` discrete colours with hard edges instead of a smooth blend. Reads better for threshold encodings e.g., air-quality bands. Bands evenly divide the Y range and take the gradient's colour at their centre.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, y: f64 }
+    /// let line = Line::new(|data: &MyData| data.y)
+    ///     .with_gradient(LINEAR_GRADIENT)
+    ///     .with_gradient_steps(5);
+    /// ```
+    pub fn with_gradient_steps(self, bands: usize) -> Self {
+        self.gradient_steps.set(Some(bands));
+        self
+    }
+
     /// Set the width of the line.
     pub fn with_width(self, width: impl Into<f64>) -> Self {
         self.width.set(width.into());
@@ -123,18 +284,265 @@ impl<T, Y> Line<T, Y> {
         self.marker = marker.into();
         self
     }
+
+    /// Flag points on the line using a predicate e.g., `|t| t.flagged` for suspect sensor samples. Flagged points are drawn with the given marker instead of the line's [marker](Self::marker).
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Sample { x: f64, y: f64, suspect: bool }
+    /// let line = Line::new(|s: &Sample| s.y).with_flagged(
+    ///     |s: &Sample| s.suspect,
+    ///     Marker::from_shape(MarkerShape::Cross).with_colour(Colour::from_rgb(0xF5, 0x32, 0x5B)),
+    /// );
+    /// ```
+    pub fn with_flagged(
+        mut self,
+        is_flagged: impl Fn(&T) -> bool + Send + Sync + 'static,
+        marker: impl Into<Marker>,
+    ) -> Self
+    where
+        T: 'static,
+        Y: Tick,
+    {
+        self.get_y = Arc::new(Flagged {
+            get_y: self.get_y,
+            is_flagged: Box::new(is_flagged),
+        });
+        self.flagged_marker = marker.into();
+        self
+    }
+
+    /// Draw the line as the delta from a baseline rather than from zero e.g., "actual" above or below its "target". The `get_base` function returns the baseline for each point which is usually another series' value. The tooltip and any [ribbon](Self::with_ribbon) also show the delta whichever is set first. Lines in a [Stack](crate::Stack) stack their deltas. Points whose delta can't be represented by `Y` (e.g., a negative unsigned integer) are missing.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Sales { x: f64, actual: f64, target: f64 }
+    /// let series = Series::new(|s: &Sales| s.x).line(
+    ///     Line::new(|s: &Sales| s.actual)
+    ///         .with_name("actual vs target")
+    ///         .with_baseline(|s: &Sales| s.target),
+    /// );
+    /// ```
+    pub fn with_baseline(mut self, get_base: impl Fn(&T) -> Y + Send + Sync + 'static) -> Self
+    where
+        T: 'static,
+        Y: Tick,
+    {
+        let get_base: Arc<dyn Fn(&T) -> Y + Send + Sync> = Arc::new(get_base);
+        self.baseline = Some(Arc::new(
+            move |get_y: Arc<dyn GetYValue<T, Y>>| -> Arc<dyn GetYValue<T, Y>> {
+                Arc::new(Baseline {
+                    get_y,
+                    get_base: get_base.clone(),
+                })
+            },
+        ));
+        self
+    }
+
+    /// Draw a translucent band (ribbon) around the line in the line's colour e.g., a confidence interval. The `get_bounds` function returns the (low, high) bounds for each point. The tooltip shows the bounds after the value.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, y: f64, low: f64, high: f64 }
+    /// let line = Line::new(|data: &MyData| data.y).with_ribbon(|data: &MyData| (data.low, data.high));
+    /// ```
+    pub fn with_ribbon(mut self, get_bounds: impl Fn(&T) -> (Y, Y) + Send + Sync + 'static) -> Self
+    where
+        T: 'static,
+        Y: Tick,
+    {
+        self.get_y = Arc::new(Ribbon {
+            get_y: self.get_y,
+            get_bounds: Box::new(get_bounds),
+        });
+        self.has_ribbon = true;
+        self
+    }
+
+    /// Set the opacity of the ribbon around the line.
+    pub fn with_ribbon_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.ribbon_opacity.set(opacity.into());
+        self
+    }
+
+    /// Simplify the drawn line using the [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm). Points within `tolerance` pixels of the simplified line are dropped after projection. Reduces the size of SVG paths for noisy data with many points without a visible change. A tolerance of 0.5 to 1.0 is a good start.
+    ///
+    /// Markers are still drawn at every point.
+    pub fn with_simplify(self, tolerance: impl Into<f64>) -> Self {
+        self.simplify.set(Some(tolerance.into()));
+        self
+    }
+
+    /// Split the drawn line into multiple SVG paths of `chunk_size` points e.g., 5000. Very long paths are slow for browsers to render. Chunks share their end points so they join up and a [gradient](Line::with_gradient) spans the whole line. Chunks that don't change aren't redrawn.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        self.chunk_size.set(Some(chunk_size));
+        self
+    }
+
+    /// Break the line where consecutive points are more than `dx` X positions (see [Tick::position]) apart e.g., a sensor that stopped reporting. Also limits which gaps [Line::with_gap_interpolation] draws across. Avoids smoothing inventing bumps across long stretches without data.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, y: f64 }
+    /// // Draw straight across short gaps in data but not gaps of over 10 X units
+    /// let line = Line::new(|data: &MyData| data.y)
+    ///     .with_max_gap(10.0)
+    ///     .with_gap_interpolation(Interpolation::Linear);
+    /// ```
+    pub fn with_max_gap(self, dx: impl Into<f64>) -> Self {
+        self.max_gap.set(Some(dx.into()));
+        self
+    }
+
+    /// Draw across missing data (NaN values) using this interpolation rather than breaking the line. Points either side of a gap are joined while the rest of the line keeps its own interpolation. Gaps wider than [Line::with_max_gap] still break the line.
+    pub fn with_gap_interpolation(self, interpolation: impl Into<Interpolation>) -> Self {
+        self.gap_interpolation.set(Some(interpolation.into()));
+        self
+    }
+
+    /// Shift the drawn line along X by `dx` X positions (see [Tick::position]) without changing the data e.g., to compare this week against last week on the same axis. Timestamps use seconds so a week is `7.0 * 24.0 * 3600.0`.
+    ///
+    /// Only the drawn line moves (including its markers, ribbon and forecast). The X range and tooltip still refer to the unshifted data.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, this_week: f64, last_week: f64 }
+    /// let series = Series::new(|data: &MyData| data.x)
+    ///     .line(Line::new(|data: &MyData| data.this_week).with_name("this week"))
+    ///     .line(
+    ///         Line::new(|data: &MyData| data.last_week)
+    ///             .with_name("last week")
+    ///             .with_x_offset(7.0),
+    ///     );
+    /// ```
+    pub fn with_x_offset(self, dx: impl Into<f64>) -> Self {
+        self.x_offset.set(dx.into());
+        self
+    }
+
+    /// Draw each value as a flat step across the full width of its bar so the line changes at bar edges e.g., a tariff or limit over usage bars. Sets a [linear](Interpolation::Linear) interpolation to join the steps. Markers stay at the centre of each bar.
+    ///
+    /// Steps span one band of the X axis: the width given to each X value whether or not bars are drawn. Combine with [Line::with_x_offset] to shift the steps e.g., by 0.5 to change value at bar centres instead.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { hour: f64, usage: f64, tariff: f64 }
+    /// let series = Series::new(|data: &MyData| data.hour)
+    ///     .bar(|data: &MyData| data.usage)
+    ///     .line(Line::new(|data: &MyData| data.tariff).with_bar_steps(true));
+    /// ```
+    pub fn with_bar_steps(self, bar_steps: bool) -> Self {
+        self.bar_steps.set(bar_steps);
+        if bar_steps {
+            self.interpolation.set(Interpolation::Linear);
+        }
+        self
+    }
+
+    /// Index the line to `base` (e.g., 100) by dividing by its first visible value. Compares the relative growth of lines with different magnitudes. Follows the visible X range so windows, panning and range sliders re-index from the new first value. Lines in a [Stack](crate::Stack) are indexed before being stacked. The tooltip shows indexed values.
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, shares: f64, index: f64 }
+    /// let series = Series::new(|data: &MyData| data.x)
+    ///     .line(Line::new(|data: &MyData| data.shares).with_index_to(100.0))
+    ///     .line(Line::new(|data: &MyData| data.index).with_index_to(100.0));
+    /// ```
+    pub fn with_index_to(self, base: impl Into<f64>) -> Self {
+        self.index_to.set(Some(base.into()));
+        self
+    }
+
+    /// Let markers be dragged vertically to edit values e.g., adjusting a forecast or tuning a threshold. Each drag calls [Chart](crate::Chart)'s `on_edit` with the new Y value which should be written back to the data. Not supported with [Line::with_x_offset] or [Line::with_index_to].
+    ///
+    /// ```rust
+    /// # use leptos::prelude::*;
+    /// # use leptos_chartistry::*;
+    /// # #[derive(Clone)]
+    /// # struct MyData { x: f64, y: f64 }
+    /// # #[component]
+    /// # fn EditableChart(data: RwSignal<Vec<MyData>>) -> impl IntoView {
+    /// view! {
+    ///     <Chart
+    ///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+    ///         series=Series::new(|d: &MyData| d.x)
+    ///             .line(Line::new(|d: &MyData| d.y)
+    ///                 .with_marker(MarkerShape::Circle)
+    ///                 .with_editable())
+    ///         data=data
+    ///         on_edit=Callback::new(move |ev: EditEvent<f64>| data.update(|data| data[ev.index].y = ev.y))
+    ///     />
+    /// }
+    /// # }
+    /// ```
+    pub fn with_editable(self) -> Self {
+        self.editable.set(true);
+        self
+    }
+
+    /// Aggregates points by period when there are more points than pixels e.g., `Resample::Mean(Period::Hour)` or `Resample::Auto(Aggregate::Mean)` to follow zoom. Flags and ribbons are hidden while resampled. Lines in a [Stack](crate::Stack) sum the resampled lines below them. See [Resample] for details.
+    pub fn with_resample(self, resample: impl Into<Option<Resample>>) -> Self {
+        self.resample.set(resample.into());
+        self
+    }
+
+    /// Plot the line against a Y axis e.g., [YAxis::Secondary] for a right-hand scale. See [YAxis] for details.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+
+    /// Draw the line as a forecast after the given X value e.g., to distinguish predicted values from observed data. The forecast part is dashed and translucent, see [Line::with_forecast_style].
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: f64, y: f64 }
+    /// let line = Line::new(|data: &MyData| data.y).with_forecast_after(10.0);
+    /// ```
+    pub fn with_forecast_after<X: Tick>(self, x: X) -> Self {
+        self.forecast_after.set(Some(x.position()));
+        self
+    }
+
+    /// Set the dash array and opacity of the forecast part of the line.
+    pub fn with_forecast_style(self, dash: impl Into<String>, opacity: impl Into<f64>) -> Self {
+        self.forecast_dash.set(dash.into());
+        self.forecast_opacity.set(opacity.into());
+        self
+    }
 }
 
 impl<T, Y> Clone for Line<T, Y> {
     fn clone(&self) -> Self {
         Self {
             get_y: self.get_y.clone(),
+            baseline: self.baseline.clone(),
             name: self.name,
+            id: self.id,
+            tooltip_group: self.tooltip_group,
             colour: self.colour,
             gradient: self.gradient,
+            gradient_steps: self.gradient_steps,
             width: self.width,
             interpolation: self.interpolation,
             marker: self.marker.clone(),
+            flagged_marker: self.flagged_marker.clone(),
+            forecast_after: self.forecast_after,
+            forecast_dash: self.forecast_dash,
+            forecast_opacity: self.forecast_opacity,
+            ribbon_opacity: self.ribbon_opacity,
+            has_ribbon: self.has_ribbon,
+            simplify: self.simplify,
+            chunk_size: self.chunk_size,
+            max_gap: self.max_gap,
+            gap_interpolation: self.gap_interpolation,
+            x_offset: self.x_offset,
+            bar_steps: self.bar_steps,
+            index_to: self.index_to,
+            editable: self.editable,
+            resample: self.resample,
+            y_axis: self.y_axis,
         }
     }
 }
@@ -146,12 +554,88 @@ impl<T, Y: Tick, F: Fn(&T) -> Y + Send + Sync + 'static> From<F> for Line<T, Y>
 }
 
 impl<T, Y: Tick, U: Fn(&T) -> Y + Send + Sync> GetYValue<T, Y> for U {
-    fn value(&self, t: &T) -> Y {
-        self(t)
+    fn value(&self, t: &T) -> Option<Y> {
+        Some(self(t))
     }
 
-    fn stacked_value(&self, t: &T) -> Y {
-        self(t)
+    fn stacked_value(&self, t: &T) -> Option<Y> {
+        Some(self(t))
+    }
+}
+
+impl<T, Y: Tick, F: Fn(&T) -> Option<Y> + Send + Sync> GetYValue<T, Y> for OptionalY<F> {
+    fn value(&self, t: &T) -> Option<Y> {
+        (self.0)(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<Y> {
+        (self.0)(t)
+    }
+}
+
+impl<T, Y: Tick> GetYValue<T, Y> for Flagged<T, Y> {
+    fn value(&self, t: &T) -> Option<Y> {
+        self.get_y.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<Y> {
+        self.get_y.stacked_value(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        self.get_y.extra_values(t)
+    }
+
+    fn is_flagged(&self, t: &T) -> bool {
+        (self.is_flagged)(t)
+    }
+}
+
+impl<T, Y: Tick> GetYValue<T, Y> for Baseline<T, Y> {
+    fn value(&self, t: &T) -> Option<Y> {
+        delta(self.get_y.value(t)?, &(self.get_base)(t))
+    }
+
+    // Stacks sum each line's delta
+    fn stacked_value(&self, t: &T) -> Option<Y> {
+        self.value(t)
+    }
+
+    // None if any delta can't be represented
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        let base = (self.get_base)(t);
+        (self.get_y.extra_values(t).into_iter())
+            .map(|y| delta(y, &base))
+            .collect::<Option<_>>()
+            .unwrap_or_default()
+    }
+
+    fn is_flagged(&self, t: &T) -> bool {
+        self.get_y.is_flagged(t)
+    }
+}
+
+/// Difference between a value and its baseline. Returns `None` if the difference can't be represented by Y.
+fn delta<Y: Tick>(y: Y, base: &Y) -> Option<Y> {
+    y.with_position(y.position() - base.position())
+}
+
+impl<T, Y: Tick> GetYValue<T, Y> for Ribbon<T, Y> {
+    fn value(&self, t: &T) -> Option<Y> {
+        self.get_y.value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<Y> {
+        self.get_y.stacked_value(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        let (low, high) = (self.get_bounds)(t);
+        vec![low, high]
+    }
+
+    fn is_flagged(&self, t: &T) -> bool {
+        self.get_y.is_flagged(t)
     }
 }
 
@@ -169,15 +653,34 @@ impl<T, Y> IntoUseLine<T, Y> for Line<T, Y> {
         let line = UseY::new_line(
             id,
             self.name,
+            self.id,
+            self.tooltip_group.into(),
             UseLine {
                 colour,
                 gradient: self.gradient,
+                gradient_steps: self.gradient_steps,
                 width: self.width,
                 interpolation: self.interpolation,
                 marker: self.marker.clone(),
+                flagged_marker: self.flagged_marker.clone(),
+                forecast_after: self.forecast_after,
+                forecast_dash: self.forecast_dash,
+                forecast_opacity: self.forecast_opacity,
+                ribbon_opacity: self.ribbon_opacity,
+                has_ribbon: self.has_ribbon,
+                simplify: self.simplify,
+                chunk_size: self.chunk_size,
+                max_gap: self.max_gap,
+                gap_interpolation: self.gap_interpolation,
+                x_offset: self.x_offset,
+                bar_steps: self.bar_steps,
+                index_to: self.index_to,
+                editable: self.editable,
+                resample: self.resample,
+                y_axis: self.y_axis,
             },
         );
-        (line, self.get_y.clone())
+        (line, self.use_get_y())
     }
 }
 
@@ -188,11 +691,69 @@ pub fn RenderLine<X: Tick, Y: Tick>(
     data: UseData<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
     markers: Signal<Vec<(f64, f64)>>,
+    #[prop(into)] scale: Signal<f64>,
+    #[prop(into, optional)] flags: Signal<Vec<bool>>,
+    #[prop(into, optional)] forecast_x: Signal<Option<f64>>,
+    #[prop(into, optional)] ribbon: Signal<Vec<Vec<f64>>>,
+    #[prop(into, optional)] max_gap: Signal<Option<MaxGap>>,
+    #[prop(into, optional)] max_marker: Signal<Option<f64>>,
+    #[prop(into, optional)] gradient_y: Signal<Option<(f64, f64)>>,
 ) -> impl IntoView {
-    let path = move || positions.with(|positions| line.interpolation.get().path(positions));
+    // Break the line across wide gaps
+    let line_positions = Memo::new(move |_| match max_gap.get() {
+        Some(max_gap) => positions
+            .with(|positions| gaps::break_wide(positions, |x1, x2| max_gap.is_wide(x1, x2))),
+        None => positions.get(),
+    });
+    // Bridges across missing data. Dashed like the rest of the line after the forecast boundary
+    let gap_interpolation = line.gap_interpolation;
+    let bridges = Memo::new(move |_| {
+        if gap_interpolation.get().is_none() {
+            return Default::default();
+        }
+        let max_gap = max_gap.get();
+        let is_wide = |x1, x2| max_gap.as_ref().is_some_and(|gap| gap.is_wide(x1, x2));
+        line_positions.with(|positions| {
+            forecast::split_paths(&gaps::bridges(positions, is_wide), forecast_x.get())
+        })
+    });
+    let bridges_path = move || {
+        gap_interpolation
+            .get()
+            .map(|interpolation| bridges.with(|(observed, _)| interpolation.path(observed)))
+    };
+    let forecast_bridges_path = move || {
+        gap_interpolation
+            .get()
+            .map(|interpolation| bridges.with(|(_, forecast)| interpolation.path(forecast)))
+    };
+
+    let simplify_px = line.simplify;
+    let segments = Memo::new(move |_| {
+        line_positions.with(|positions| match simplify_px.get() {
+            Some(tolerance) => {
+                forecast::split(&simplify::simplify(positions, tolerance), forecast_x.get())
+            }
+            None => forecast::split(positions, forecast_x.get()),
+        })
+    });
+    let interpolation = line.interpolation;
+    let chunk_size = line.chunk_size;
+    let paths = Memo::new(move |_| {
+        segments
+            .with(|(observed, _)| (interpolation.get()).chunked_paths(observed, chunk_size.get()))
+    });
+    // Keyed by index. Chunks only redraw when their own path changes
+    let chunks = move || paths.with(|paths| (0..paths.len()).collect::<Vec<_>>());
+    let chunk_path = move |index: usize| {
+        let d = Memo::new(move |_| paths.with(|paths| paths.get(index).cloned()));
+        view! { <path d=d fill="none" /> }
+    };
+    let forecast_path = move || segments.with(|(_, forecast)| interpolation.get().path(forecast));
+    let (forecast_dash, forecast_opacity) = (line.forecast_dash, line.forecast_opacity);
 
     // Line colour
-    let gradient_id = format!("line_{}_gradient", use_y.id);
+    let gradient_id = use_y.svg_id("line_gradient");
     let stroke = {
         let colour = line.colour;
         let gradient_id = gradient_id.clone();
@@ -210,9 +771,30 @@ pub fn RenderLine<X: Tick, Y: Tick>(
             .get()
             .unwrap_or_else(|| LINEAR_GRADIENT.into())
     });
-    let range_y = Signal::derive(move || data.range_y.read().positions());
+    let (y_axis, range_y, range_y_secondary) = (line.y_axis, data.range_y, data.range_y_secondary);
+    let range_y = Signal::derive(move || match y_axis.get() {
+        YAxis::Secondary => range_y_secondary.read().positions(),
+        _ => range_y.read().positions(),
+    });
+
+    let ribbon_opacity = line.ribbon_opacity;
+    let ribbon = move || {
+        markers
+            .with(|markers| ribbon.with(|extras| ribbon::polygons(markers, extras)))
+            .into_iter()
+            .map(|polygon| {
+                let points = polygon
+                    .into_iter()
+                    .map(svg_point)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                view! { <polygon points=points /> }
+            })
+            .collect_view()
+    };
 
     let width = line.width;
+    let width = move || width.get() * scale.get();
     view! {
         <g
             class="_chartistry_line"
@@ -225,11 +807,104 @@ pub fn RenderLine<X: Tick, Y: Tick>(
                     <LinearGradientSvg
                         id=gradient_id.clone()
                         scheme=gradient
-                        range_y=range_y />
+                        steps=line.gradient_steps
+                        range_y=range_y
+                        svg_y=gradient_y />
                 </Show>
             </defs>
-            <path d=path fill="none" />
-            <marker::LineMarkers line=line positions=markers />
+            <g
+                class="_chartistry_line_ribbon"
+                fill=stroke
+                fill-opacity=move || ribbon_opacity.get().clamp(0.0, 1.0)
+                stroke="none">
+                {ribbon}
+            </g>
+            <For each=chunks key=|&index| index children=chunk_path />
+            <path class="_chartistry_line_gaps" d=bridges_path fill="none" />
+            <path
+                class="_chartistry_line_gaps _chartistry_line_forecast"
+                d=forecast_bridges_path
+                fill="none"
+                stroke-dasharray=forecast_dash
+                opacity=forecast_opacity />
+            <Show when=move || segments.with(|(_, forecast)| !forecast.is_empty())>
+                <path
+                    class="_chartistry_line_forecast"
+                    d=forecast_path
+                    fill="none"
+                    stroke-dasharray=forecast_dash
+                    opacity=forecast_opacity />
+            </Show>
+            <marker::LineMarkers
+                line=line
+                positions=markers
+                flags=flags
+                scale=scale
+                max_diameter=max_marker />
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sales {
+        actual: f64,
+        target: f64,
+        low: f64,
+        high: f64,
+    }
+
+    #[test]
+    fn test_baseline() {
+        let sales = Sales {
+            actual: 8.0,
+            target: 10.0,
+            low: 7.0,
+            high: 12.0,
+        };
+        // Order doesn't matter
+        let ribbon_first = Line::new(|s: &Sales| s.actual)
+            .with_ribbon(|s: &Sales| (s.low, s.high))
+            .with_baseline(|s: &Sales| s.target);
+        let baseline_first = Line::new(|s: &Sales| s.actual)
+            .with_baseline(|s: &Sales| s.target)
+            .with_ribbon(|s: &Sales| (s.low, s.high));
+        for line in [ribbon_first, baseline_first] {
+            let get_y = line.use_get_y();
+            assert_eq!(get_y.value(&sales), Some(-2.0));
+            assert_eq!(get_y.stacked_value(&sales), Some(-2.0));
+            assert_eq!(get_y.extra_values(&sales), vec![-3.0, 2.0]);
+        }
+    }
+
+    #[test]
+    fn test_baseline_unrepresentable() {
+        let line = Line::new(|&(actual, _): &(u64, u64)| actual)
+            .with_ribbon(|&(actual, target): &(u64, u64)| (actual, target))
+            .with_baseline(|&(_, target): &(u64, u64)| target);
+        let get_y = line.use_get_y();
+        assert_eq!(get_y.value(&(5, 3)), Some(2));
+        assert_eq!(get_y.extra_values(&(5, 3)), vec![2, 0]);
+        // Below an unsigned baseline
+        assert_eq!(get_y.value(&(3, 5)), None);
+        assert_eq!(get_y.stacked_value(&(3, 5)), None);
+        assert!(get_y.extra_values(&(3, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_optional() {
+        let line = Line::new_optional(|s: &Sales| (s.actual > 0.0).then_some(s.actual));
+        let mut sales = Sales {
+            actual: 8.0,
+            target: 10.0,
+            low: 7.0,
+            high: 12.0,
+        };
+        assert_eq!(line.get_y.value(&sales), Some(8.0));
+        sales.actual = 0.0;
+        assert_eq!(line.get_y.value(&sales), None);
+        assert_eq!(line.get_y.stacked_value(&sales), None);
+    }
+}
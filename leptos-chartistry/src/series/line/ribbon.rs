@@ -0,0 +1,64 @@
+/// Builds ribbon polygons around a line. Takes SVG positions and their extra (low, high) values. NaN values break the ribbon.
+pub fn polygons(positions: &[(f64, f64)], extras: &[Vec<f64>]) -> Vec<Vec<(f64, f64)>> {
+    let mut polygons = Vec::new();
+    let mut high = Vec::<(f64, f64)>::new();
+    let mut low = Vec::<(f64, f64)>::new();
+
+    let mut flush = |high: &mut Vec<_>, low: &mut Vec<_>| {
+        let mut polygon = std::mem::take(high);
+        let mut low = std::mem::take(low);
+        // Skip zero width runs
+        if polygon.len() >= 2 {
+            low.reverse();
+            polygon.extend(low);
+            polygons.push(polygon);
+        }
+    };
+
+    for (&(x, _), extra) in positions.iter().zip(extras) {
+        let (y_low, y_high) = match extra.as_slice() {
+            &[y_low, y_high, ..] => (y_low, y_high),
+            _ => (f64::NAN, f64::NAN),
+        };
+        if x.is_nan() || y_low.is_nan() || y_high.is_nan() {
+            flush(&mut high, &mut low);
+            continue;
+        }
+        high.push((x, y_high));
+        low.push((x, y_low));
+    }
+    flush(&mut high, &mut low);
+    polygons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polygons() {
+        let positions = &[
+            (0.0, 2.0),
+            (1.0, 2.0),
+            (2.0, f64::NAN),
+            (3.0, 2.0),
+            (4.0, 2.0),
+        ];
+        let extras = &[
+            vec![3.0, 1.0],
+            vec![4.0, 0.0],
+            vec![f64::NAN, 1.0],
+            vec![3.0, 1.0],
+            vec![3.0, 1.0],
+        ];
+        assert_eq!(
+            polygons(positions, extras),
+            vec![
+                vec![(0.0, 1.0), (1.0, 0.0), (1.0, 4.0), (0.0, 3.0)],
+                vec![(3.0, 1.0), (4.0, 1.0), (4.0, 3.0), (3.0, 3.0)],
+            ]
+        );
+        // No extras
+        assert_eq!(polygons(positions, &[]), Vec::<Vec<_>>::new());
+    }
+}
@@ -0,0 +1,101 @@
+/// Simplifies a line using the Ramer–Douglas–Peucker algorithm. Points within `tolerance` (SVG pixels) of the simplified line are dropped. NaN points (gaps) are kept as breaks in the line.
+pub fn simplify(points: &[(f64, f64)], tolerance: f64) -> Vec<(f64, f64)> {
+    if tolerance <= 0.0 || points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut simplified = Vec::with_capacity(points.len());
+    for (index, segment) in points.split(|(x, y)| x.is_nan() || y.is_nan()).enumerate() {
+        // Keep gaps between segments
+        if index > 0 {
+            simplified.push((f64::NAN, f64::NAN));
+        }
+        rdp(segment, tolerance, &mut simplified);
+    }
+    simplified
+}
+
+// Uses an explicit stack rather than recursion so long segments can't overflow the call stack
+fn rdp(points: &[(f64, f64)], tolerance: f64, out: &mut Vec<(f64, f64)>) {
+    if points.len() < 3 {
+        out.extend_from_slice(points);
+        return;
+    }
+    let mut keep = vec![false; points.len()];
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        keep[start] = true;
+        keep[end] = true;
+        if end - start < 2 {
+            continue;
+        }
+        // Find furthest point from the line between first and last
+        let (first, last) = (points[start], points[end]);
+        let (index, distance) = (start + 1..end)
+            .map(|index| (index, distance(points[index], first, last)))
+            .fold(
+                (start, 0.0),
+                |max, cur| if cur.1 > max.1 { cur } else { max },
+            );
+        if distance > tolerance {
+            ranges.push((index, end));
+            ranges.push((start, index));
+        }
+    }
+    out.extend(
+        (points.iter().zip(keep))
+            .filter(|(_, keep)| *keep)
+            .map(|(&point, _)| point),
+    );
+}
+
+// Perpendicular distance from a point to the line through a and b
+fn distance((x, y): (f64, f64), (x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return (x - x1).hypot(y - y1);
+    }
+    (dy * x - dx * y + x2 * y1 - y2 * x1).abs() / length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplify() {
+        let points = &[(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 0.0)];
+        // No tolerance
+        assert_eq!(simplify(points, 0.0), points.to_vec());
+        // Drops noise, keeps the peak
+        assert_eq!(
+            simplify(points, 0.5),
+            vec![(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (4.0, 0.0)]
+        );
+        // Straight line
+        assert_eq!(
+            simplify(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)], 0.1),
+            vec![(0.0, 0.0), (2.0, 2.0)]
+        );
+        // Gaps are kept
+        let gap = simplify(
+            &[
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (3.0, f64::NAN),
+                (4.0, 1.0),
+            ],
+            0.1,
+        );
+        assert_eq!(gap.len(), 4);
+        assert_eq!(gap[0..2], [(0.0, 0.0), (2.0, 0.0)]);
+        assert!(gap[2].0.is_nan());
+        assert_eq!(gap[3], (4.0, 1.0));
+        // Deeply split segments don't overflow the stack
+        let zigzag = (0..5_000)
+            .map(|x| (x as f64, (x % 2) as f64))
+            .collect::<Vec<_>>();
+        assert_eq!(simplify(&zigzag, 0.1), zigzag);
+    }
+}
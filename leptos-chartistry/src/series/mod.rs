@@ -1,23 +1,31 @@
 mod bar;
+mod candlestick;
+mod difference;
 mod line;
+mod resample;
 mod stack;
 mod use_data;
 mod use_y;
 
-pub use bar::{Bar, BarPlacement, BAR_GAP, BAR_GAP_INNER};
+pub(crate) use bar::stand_in_bar;
+pub use bar::{Bar, BarPlacement, BarStack, BarWidth, BAR_GAP, BAR_GAP_INNER};
+pub use candlestick::{Candlestick, CANDLESTICK_FALLING_COLOUR, CANDLESTICK_RISING_COLOUR};
+pub use difference::{Difference, DIFFERENCE_ABOVE_COLOUR, DIFFERENCE_BELOW_COLOUR};
 pub use line::{
     Interpolation, Line, Marker, MarkerShape, Step, DIVERGING_GRADIENT, LINEAR_GRADIENT,
 };
-pub use stack::{Stack, STACK_COLOUR_SCHEME};
-pub use use_data::{RenderData, UseData};
-pub use use_y::{Snippet, UseY};
+pub use resample::{Aggregate, Resample};
+pub use stack::{Stack, STACK_COLOUR_SCHEME, STACK_TOTAL_COLOUR};
+pub use use_data::{Range, RenderData, UseData};
+pub use use_y::{Snippet, Taster, UseY};
 
 use crate::{
     colours::{Colour, ColourScheme},
+    scale::AxisScale,
     Tick,
 };
 use leptos::prelude::*;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 /// Arbitrary colours for a brighter palette than BATLOW
 pub const SERIES_COLOUR_SCHEME: [Colour; 10] = [
@@ -33,12 +41,43 @@ pub const SERIES_COLOUR_SCHEME: [Colour; 10] = [
     Colour::from_rgb(0xea, 0x60, 0xdf), // Pink
 ];
 
+/// Default background colour of a [Y break](Series::y_break). Assumes a light background.
+pub const Y_BREAK_COLOUR: Colour = Colour::from_rgb(0xFF, 0xFF, 0xFF);
+
+/// Y axis a line or bar is plotted against. A secondary axis has its own range e.g., temperature on the left and pressure on the right. Show it with [TickLabels::with_y_axis](crate::TickLabels::with_y_axis) on the right edge.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum YAxis {
+    /// The main Y axis. Series options such as [min_y](Series::min_y) and [clamp_y](Series::clamp_y) apply to it.
+    #[default]
+    Primary,
+    /// A second, independent Y axis. Its range fits the lines and bars plotted against it.
+    Secondary,
+}
+
 type GetX<T, X> = Arc<dyn Fn(&T) -> X + Send + Sync>;
+type GetMeta<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
 type GetY<T, Y> = Arc<dyn GetYValue<T, Y>>;
 
 trait GetYValue<T, Y>: Send + Sync {
-    fn value(&self, t: &T) -> Y;
-    fn stacked_value(&self, t: &T) -> Y;
+    // Missing values (`None`) are drawn as gaps
+    fn value(&self, t: &T) -> Option<Y>;
+    fn stacked_value(&self, t: &T) -> Option<Y>;
+
+    // Extra values attached to each point e.g., a candlestick's open, high and low. Extends the Y range. Must return the same number of values for every `T` or none if they can't be given e.g., a ribbon's delta from a baseline that `Y` can't represent.
+    fn extra_values(&self, _: &T) -> Vec<Y> {
+        Vec::new()
+    }
+
+    // Whether a point is flagged e.g., a suspect sample. Flagged points use a different marker.
+    fn is_flagged(&self, _: &T) -> bool {
+        false
+    }
+
+    // IDs of the series whose values are summed to make the stacked value e.g., the lines below in a stack and this one. Empty if not stacked.
+    fn stack_ids(&self) -> Vec<usize> {
+        Vec::new()
+    }
 }
 
 /// Describes how to render a series of data. A series is a collection of lines, bars, etc. that share the same X and Y axes.
@@ -109,10 +148,10 @@ trait GetYValue<T, Y>: Send + Sync {
 /// ## Other options
 ///
 /// Finally, like most other components, you can control aspects such as the colour scheme and data ranges of X and Y.
-#[derive(Clone)]
 #[non_exhaustive]
 pub struct Series<T: Send + Sync + 'static, X: Tick, Y: Tick> {
     get_x: GetX<T, X>,
+    get_meta: Option<GetMeta<T>>,
     series: Vec<Arc<dyn ApplyUseSeries<T, Y> + Send + Sync>>,
     /// Optional minimum X value. Extends the lower bound of the X axis if set.
     pub min_x: RwSignal<Option<X>>,
@@ -122,14 +161,100 @@ pub struct Series<T: Send + Sync + 'static, X: Tick, Y: Tick> {
     pub min_y: RwSignal<Option<Y>>,
     /// Optional maximum Y value. Extends the upper bound of the Y axis if set.
     pub max_y: RwSignal<Option<Y>>,
+    /// Optional (low, high) percentiles from 0 to 100 of Y values to use as the Y range instead of the minimum and maximum. Stops a single spike from flattening the rest of the chart. Points outside the range are clipped and marked on the edge of the chart. Still extended by [min_y](Self::min_y) and [max_y](Self::max_y). Default is `None` which uses the full range.
+    pub y_percentile: RwSignal<Option<(f64, f64)>>,
+    /// Whether [min_y](Self::min_y) and [max_y](Self::max_y) fix the Y range rather than extend it. Values outside the Y range (including a [percentile](Self::y_percentile) or rescaled range) are clamped to the edge of the chart and marked with an off-scale arrow instead of being drawn outside it. Default is false.
+    pub clamp_y: RwSignal<bool>,
+    /// Scale of the X axis e.g., a [LogScale](crate::LogScale). Transforms X positions before they're drawn. Default is [LinearScale](crate::LinearScale).
+    pub scale_x: RwSignal<AxisScale>,
+    /// Scale of the main Y axis e.g., a [LogScale](crate::LogScale) or a [PowerScale::sqrt](crate::PowerScale::sqrt) for areas. Use your own monotonic transform with [Scale](crate::Scale) or [CustomScale](crate::CustomScale). Values without a position on the scale (e.g., zero on a log scale) are left out of the range. Pair a log scale with [LogFloats](crate::LogFloats) tick labels. Default is [LinearScale](crate::LinearScale).
+    pub scale_y: RwSignal<AxisScale>,
+    /// Optional (low, high) band of Y values cut out of the main Y axis e.g., `(10.0, 100.0)` to show 0–10 then 100–110. Useful for two widely separated clusters where a log scale isn't appropriate. The band is squeezed into a small gap marked with a zig-zag. Ignored unless inside the Y range. Default is `None`.
+    pub y_break: RwSignal<Option<(Y, Y)>>,
+    /// Colour of the [Y break](Self::y_break) band. Should match the chart's background. Default is [Y_BREAK_COLOUR].
+    pub y_break_colour: RwSignal<Colour>,
+    /// Whether Y values increase downwards e.g., for depth or rankings. Flips both Y axes and drops any [Y break](Self::y_break). Default is false.
+    pub reversed_y: RwSignal<bool>,
+    /// Whether data is expected to be sorted by X. Debug builds warn about unsorted data unless this is false e.g., a depth profile that joins points in depth order. Hovering works either way. Default is true.
+    pub sorted_x: RwSignal<bool>,
+    /// Whether the Y range only fits series that aren't [hidden](crate::ViewState::hidden). Rescales as series are hidden e.g., from the legend. Default is false which keeps the Y range steady.
+    pub fit_visible: RwSignal<bool>,
     /// Colour scheme for the series. If there are more lines than colours, the colours will repeat.
     pub colours: RwSignal<ColourScheme>,
+    /// Optional number of X values to show at a time. The rest can be scrolled to with the mouse wheel or by dragging the inner chart area. Useful for bar charts with hundreds of categories. Overrides the X range. A window of zero shows one X value. Default is `None` which shows all X values.
+    pub window_x: RwSignal<Option<usize>>,
+    /// Optional span of a live window in X positions (see [Tick::position](crate::Tick::position)) e.g., seconds for timestamps. Anchors the X range to the newest data point. Default is `None` which shows the whole X range.
+    pub live_window: RwSignal<Option<f64>>,
+    /// Whether to drop points that fall out of the live window. The Y range then only fits the window. Default is false.
+    pub live_drop: RwSignal<bool>,
+    /// Whether to slide the live window smoothly as new data arrives. Default is true.
+    pub live_slide: RwSignal<bool>,
+    /// Whether data is only ever appended to e.g., streaming a point per second. When data grows, only the new points are processed instead of rebuilding every point. Points dropped from the front (by a [live window](Self::live_window) or a [ChartBuffer](crate::ChartBuffer)) are dropped without a rebuild too. Earlier points must not change and X must be sorted. Replacing data rebuilds as usual. Default is false.
+    pub append_only: RwSignal<bool>,
+}
+
+impl<T: Send + Sync + 'static, X: Tick, Y: Tick> Clone for Series<T, X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_x: self.get_x.clone(),
+            get_meta: self.get_meta.clone(),
+            series: self.series.clone(),
+            min_x: self.min_x,
+            max_x: self.max_x,
+            min_y: self.min_y,
+            max_y: self.max_y,
+            y_percentile: self.y_percentile,
+            clamp_y: self.clamp_y,
+            scale_x: self.scale_x,
+            scale_y: self.scale_y,
+            y_break: self.y_break,
+            y_break_colour: self.y_break_colour,
+            reversed_y: self.reversed_y,
+            sorted_x: self.sorted_x,
+            fit_visible: self.fit_visible,
+            colours: self.colours,
+            window_x: self.window_x,
+            live_window: self.live_window,
+            live_drop: self.live_drop,
+            live_slide: self.live_slide,
+            append_only: self.append_only,
+        }
+    }
 }
 
 trait ApplyUseSeries<T, Y> {
     fn apply_use_series(self: Arc<Self>, _: &mut SeriesAcc<T, Y>);
 }
 
+// A series in a stack. Hidden series are left out so the rest of the stack closes up
+struct StackPart<T, Y> {
+    id: usize,
+    get_y: GetY<T, Y>,
+    hidden: Signal<bool>,
+}
+
+impl<T, Y> StackPart<T, Y> {
+    // Value of the series unless it's hidden
+    fn value(&self, t: &T) -> Option<Y> {
+        (!self.hidden.get()).then(|| self.get_y.value(t)).flatten()
+    }
+
+    // ID of the series unless it's hidden
+    fn shown_id(&self) -> Option<usize> {
+        (!self.hidden.get()).then_some(self.id)
+    }
+}
+
+impl<T, Y> Clone for StackPart<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            get_y: self.get_y.clone(),
+            hidden: self.hidden,
+        }
+    }
+}
+
 trait IntoUseLine<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
 }
@@ -141,9 +266,13 @@ trait IntoUseBar<T, Y> {
 struct SeriesAcc<T, Y> {
     colour_id: usize,
     colours: RwSignal<ColourScheme>,
+    // Names or stable IDs of hidden series. See [ViewState::hidden](crate::ViewState::hidden)
+    hidden: RwSignal<Vec<String>>,
     next_id: usize,
     next_group_id: usize,
     lines: Vec<(UseY, GetY<T, Y>)>,
+    // Colour of stacked bars by name. Shared across bar stacks
+    stacked_bars: HashMap<String, Memo<Colour>>,
 }
 
 impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
@@ -155,16 +284,74 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
     pub fn new(get_x: impl Fn(&T) -> X + Send + Sync + 'static) -> Self {
         Self {
             get_x: Arc::new(get_x),
+            get_meta: None,
             min_x: RwSignal::default(),
             max_x: RwSignal::default(),
             min_y: RwSignal::default(),
             max_y: RwSignal::default(),
+            y_percentile: RwSignal::default(),
+            clamp_y: RwSignal::default(),
+            scale_x: RwSignal::default(),
+            scale_y: RwSignal::default(),
+            y_break: RwSignal::default(),
+            y_break_colour: RwSignal::new(Y_BREAK_COLOUR),
+            reversed_y: RwSignal::default(),
+            sorted_x: RwSignal::new(true),
+            fit_visible: RwSignal::default(),
             colours: RwSignal::new(SERIES_COLOUR_SCHEME.into()),
+            window_x: RwSignal::default(),
+            live_window: RwSignal::default(),
+            live_drop: RwSignal::default(),
+            live_slide: RwSignal::new(true),
+            append_only: RwSignal::default(),
             series: Vec::new(),
         }
     }
 
+    /// Create a series of named lines from a list of columns e.g., a wide telemetry struct with many fields. Each column is a name and a function to extract its Y value. Lines are named after their column, take the next colour in the scheme and use the column name as their [stable ID](Line::with_id).
+    ///
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Telemetry { x: f64, ph: f64, temp: f64, oxygen: f64 }
+    /// let series = Series::from_columns(
+    ///     |t: &Telemetry| t.x,
+    ///     [
+    ///         ("ph", (|t: &Telemetry| t.ph) as fn(&Telemetry) -> f64),
+    ///         ("temp", |t: &Telemetry| t.temp),
+    ///         ("oxygen", |t: &Telemetry| t.oxygen),
+    ///     ],
+    /// );
+    /// ```
+    pub fn from_columns<F>(
+        get_x: impl Fn(&T) -> X + Send + Sync + 'static,
+        columns: impl IntoIterator<Item = (impl Into<String>, F)>,
+    ) -> Self
+    where
+        F: Fn(&T) -> Y + Send + Sync + 'static,
+    {
+        Self::new(get_x).columns(columns)
+    }
+
+    /// Adds a named line per column. See [Series::from_columns] for details.
+    pub fn columns<F>(self, columns: impl IntoIterator<Item = (impl Into<String>, F)>) -> Self
+    where
+        F: Fn(&T) -> Y + Send + Sync + 'static,
+    {
+        self.lines(columns.into_iter().map(|(name, get_y)| {
+            let name = name.into();
+            Line::new(get_y).with_id(name.clone()).with_name(name)
+        }))
+    }
+
+    /// Attach metadata to each data point e.g., a record ID or quality flag. Shown in the tooltip and passed to the chart's `on_hover` and `on_click` callbacks via [DataEvent](crate::DataEvent). Avoids looking up your data by X value.
+    pub fn with_meta(mut self, get_meta: impl Fn(&T) -> String + Send + Sync + 'static) -> Self {
+        self.get_meta = Some(Arc::new(get_meta));
+        self
+    }
+
     /// Set the colour scheme for the series. If there are more lines than colours, the colours will repeat.
+    ///
+    /// Line stacks use their own scheme by default and don't take colours from this one. See [Stack] for how the two interact.
     pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
         self.colours.set(colours.into());
         self
@@ -204,6 +391,101 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self.with_min_y(min_y).with_max_y(max_y)
     }
 
+    /// Set the Y range from percentiles of Y values e.g., `(1.0, 99.0)` to ignore outliers. See [Series::y_percentile] for details.
+    pub fn with_y_percentile(self, low: f64, high: f64) -> Self {
+        self.y_percentile.set(Some((low, high)));
+        self
+    }
+
+    /// Fixes the Y range and clamps values outside it to the edge of the chart. See [Series::clamp_y] for details.
+    pub fn with_clamp_y(self, clamp_y: bool) -> Self {
+        self.clamp_y.set(clamp_y);
+        self
+    }
+
+    /// Set the scale of the X axis. See [Series::scale_x] for details.
+    pub fn with_scale_x(self, scale: impl Into<AxisScale>) -> Self {
+        self.scale_x.set(scale.into());
+        self
+    }
+
+    /// Set the scale of the main Y axis e.g., `LogScale::default()`. See [Series::scale_y] for details.
+    pub fn with_scale_y(self, scale: impl Into<AxisScale>) -> Self {
+        self.scale_y.set(scale.into());
+        self
+    }
+
+    /// Cuts a band of Y values out of the main Y axis. See [Series::y_break] for details.
+    pub fn with_y_break(self, low: Y, high: Y) -> Self {
+        self.y_break.set(Some((low, high)));
+        self
+    }
+
+    /// Sets the colour of the Y break band e.g., to match a dark background. See [Series::y_break_colour] for details.
+    pub fn with_y_break_colour(self, colour: impl Into<Colour>) -> Self {
+        self.y_break_colour.set(colour.into());
+        self
+    }
+
+    /// Set whether Y values increase downwards. See [Series::reversed_y] for details.
+    pub fn with_reversed_y(self, reversed_y: bool) -> Self {
+        self.reversed_y.set(reversed_y);
+        self
+    }
+
+    /// Set whether data is expected to be sorted by X. See [Series::sorted_x] for details.
+    pub fn with_sorted_x(self, sorted_x: bool) -> Self {
+        self.sorted_x.set(sorted_x);
+        self
+    }
+
+    /// Set whether the Y range only fits visible series. See [Series::fit_visible] for details.
+    pub fn with_fit_visible(self, fit_visible: bool) -> Self {
+        self.fit_visible.set(fit_visible);
+        self
+    }
+
+    /// Show a fixed number of X values at a time. See [Series::window_x] for details.
+    pub fn with_window_x(self, window_x: impl Into<Option<usize>>) -> Self {
+        self.window_x.set(window_x.into());
+        self
+    }
+
+    /// Show the last `span` of X positions e.g., "the last 5 minutes" for a live feed. The X range stays anchored to the newest point and slides smoothly as data arrives. See [Series::live_window] for details.
+    ///
+    /// ```rust
+    /// # use chrono::prelude::*;
+    /// # use leptos_chartistry::*;
+    /// # struct MyData { x: DateTime<Utc>, y: f64 }
+    /// // Timestamp positions are in seconds
+    /// let series = Series::new(|data: &MyData| data.x)
+    ///     .line(|data: &MyData| data.y)
+    ///     .with_live_window(5.0 * 60.0)
+    ///     .with_live_drop(true);
+    /// ```
+    pub fn with_live_window(self, span: impl Into<Option<f64>>) -> Self {
+        self.live_window.set(span.into());
+        self
+    }
+
+    /// Drop points that fall out of the live window. See [Series::live_drop] for details.
+    pub fn with_live_drop(self, drop: bool) -> Self {
+        self.live_drop.set(drop);
+        self
+    }
+
+    /// Slide the live window smoothly as new data arrives. See [Series::live_slide] for details.
+    pub fn with_live_slide(self, slide: bool) -> Self {
+        self.live_slide.set(slide);
+        self
+    }
+
+    /// Set whether data is only ever appended to. See [Series::append_only] for details.
+    pub fn with_append_only(self, append_only: bool) -> Self {
+        self.append_only.set(append_only);
+        self
+    }
+
     /// Adds a line to the series. See [Line] for more details.
     pub fn line(mut self, line: impl Into<Line<T, Y>>) -> Self {
         self.series.push(Arc::new(line.into()));
@@ -232,6 +514,12 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self
     }
 
+    /// Adds a candlestick to the series. See [Candlestick] for more details.
+    pub fn candlestick(mut self, candlestick: impl Into<Candlestick<T, Y>>) -> Self {
+        self.series.push(Arc::new(candlestick.into()));
+        self
+    }
+
     /// Gets the current size of the series (number of lines and stacks).
     pub fn len(&self) -> usize {
         self.series.len()
@@ -242,8 +530,8 @@ impl<T: Send + Sync, X: Tick, Y: Tick> Series<T, X, Y> {
         self.series.is_empty()
     }
 
-    fn to_use_lines(&self) -> Vec<(UseY, GetY<T, Y>)> {
-        let mut series = SeriesAcc::new(self.colours);
+    fn to_use_lines(&self, hidden: RwSignal<Vec<String>>) -> Vec<(UseY, GetY<T, Y>)> {
+        let mut series = SeriesAcc::new(self.colours, hidden);
         for seq in self.series.clone() {
             seq.apply_use_series(&mut series);
         }
@@ -257,16 +545,30 @@ impl<T: Send + Sync, X: Tick> Series<T, X, f64> {
         self.series.push(Arc::new(stack.into()));
         self
     }
+
+    /// Adds a stack of bars sharing a slot at each X value. Add several to place stacks side-by-side. See [BarStack] for more details.
+    pub fn bar_stack(mut self, stack: impl Into<BarStack<T, f64>>) -> Self {
+        self.series.push(Arc::new(stack.into()));
+        self
+    }
+
+    /// Adds two lines and fills the area between them. See [Difference] for more details.
+    pub fn difference(mut self, difference: impl Into<Difference<T, f64>>) -> Self {
+        self.series.push(Arc::new(difference.into()));
+        self
+    }
 }
 
 impl<T, Y> SeriesAcc<T, Y> {
-    fn new(colours: RwSignal<ColourScheme>) -> Self {
+    fn new(colours: RwSignal<ColourScheme>, hidden: RwSignal<Vec<String>>) -> Self {
         Self {
             colour_id: 0,
             colours,
+            hidden,
             next_id: 0,
             next_group_id: 0,
             lines: Vec::new(),
+            stacked_bars: HashMap::new(),
         }
     }
 
@@ -287,12 +589,38 @@ impl<T, Y> SeriesAcc<T, Y> {
         get_y
     }
 
+    // The last series added as part of a stack
+    fn last_stack_part(&self) -> Option<StackPart<T, Y>> {
+        let (use_y, get_y) = self.lines.last()?;
+        let (hidden, use_y) = (self.hidden, use_y.clone());
+        Some(StackPart {
+            id: use_y.id,
+            get_y: get_y.clone(),
+            hidden: Signal::derive(move || hidden.with(|hidden| use_y.is_hidden_by(hidden))),
+        })
+    }
+
+    fn next_group_id(&mut self) -> usize {
+        let group_id = self.next_group_id;
+        self.next_group_id += 1;
+        group_id
+    }
+
     fn push_bar(&mut self, colour: Memo<Colour>, bar: impl IntoUseBar<T, Y>) -> GetY<T, Y> {
+        let group_id = self.next_group_id();
+        self.push_bar_to(group_id, colour, bar)
+    }
+
+    // Bars sharing a group ID share a slot e.g., a bar stack
+    fn push_bar_to(
+        &mut self,
+        group_id: usize,
+        colour: Memo<Colour>,
+        bar: impl IntoUseBar<T, Y>,
+    ) -> GetY<T, Y> {
         // Create bar
         let id = self.next_id;
-        let group_id = self.next_group_id;
         self.next_id += 1;
-        self.next_group_id += 1;
         let (bar, get_y) = bar.into_use_bar(id, group_id, colour);
         // Insert bar
         self.lines.push((bar, get_y.clone()));
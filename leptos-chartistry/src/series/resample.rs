@@ -0,0 +1,128 @@
+use crate::Period;
+
+/// Aggregates a line's points by period when there are more points than pixels to draw them on e.g., minute-level data shown hourly when zoomed out and raw when zoomed in. Each period is drawn as a single point at its start.
+///
+/// Only applies to ticks with periods (timestamps). Missing values (NaN) are ignored.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Resample {
+    /// Average of each period.
+    Mean(Period),
+    /// Sum of each period.
+    Sum(Period),
+    /// Maximum of each period.
+    Max(Period),
+    /// Level of detail: picks the shortest period that fits the X range into the chart's width e.g., daily for a year, hourly for a week and raw points for an hour. Follows zooming and scrolling.
+    Auto(Aggregate),
+}
+
+/// How [Resample::Auto] aggregates each period.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Aggregate {
+    /// Average of each period.
+    #[default]
+    Mean,
+    /// Sum of each period.
+    Sum,
+    /// Maximum of each period.
+    Max,
+}
+
+impl Resample {
+    /// Period the points are grouped by. Returns `None` for [Resample::Auto] which depends on the X range.
+    pub fn period(&self) -> Option<Period> {
+        match *self {
+            Resample::Mean(period) | Resample::Sum(period) | Resample::Max(period) => Some(period),
+            Resample::Auto(_) => None,
+        }
+    }
+
+    /// Picks the period of [Resample::Auto] for an X range `span` (in positions e.g., seconds) drawn over `width` pixels. Other resamples are unchanged. Returns `None` if no period fits.
+    pub(crate) fn resolve(self, span: f64, width: f64) -> Option<Resample> {
+        let Resample::Auto(aggregate) = self else {
+            return Some(self);
+        };
+        // Shortest period first
+        let period = (Period::all().into_iter().rev())
+            .find(|&period| span / approx_seconds(period) <= width)?;
+        Some(match aggregate {
+            Aggregate::Mean => Resample::Mean(period),
+            Aggregate::Sum => Resample::Sum(period),
+            Aggregate::Max => Resample::Max(period),
+        })
+    }
+
+    /// Aggregates the values of a period. Returns NaN if there are no values.
+    pub(crate) fn aggregate(&self, values: &[f64]) -> f64 {
+        let values = values.iter().copied().filter(|v| !v.is_nan());
+        let (count, sum, max) = values.fold((0, 0.0, f64::NEG_INFINITY), |(n, sum, max), v| {
+            (n + 1, sum + v, max.max(v))
+        });
+        if count == 0 {
+            return f64::NAN;
+        }
+        let aggregate = match *self {
+            Resample::Mean(_) => Aggregate::Mean,
+            Resample::Sum(_) => Aggregate::Sum,
+            Resample::Max(_) => Aggregate::Max,
+            Resample::Auto(aggregate) => aggregate,
+        };
+        match aggregate {
+            Aggregate::Mean => sum / count as f64,
+            Aggregate::Sum => sum,
+            Aggregate::Max => max,
+        }
+    }
+}
+
+/// Average length of a period in seconds (timestamp positions).
+fn approx_seconds(period: Period) -> f64 {
+    const DAY: f64 = 24.0 * 60.0 * 60.0;
+    match period {
+        Period::Nanosecond => 1e-9,
+        Period::Microsecond => 1e-6,
+        Period::Millisecond => 1e-3,
+        Period::Second => 1.0,
+        Period::Minute => 60.0,
+        Period::Hour => 60.0 * 60.0,
+        Period::Day => DAY,
+        Period::Month => DAY * 365.25 / 12.0,
+        _ => DAY * 365.25,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate() {
+        let values = [1.0, f64::NAN, 2.0, 6.0];
+        assert_eq!(Resample::Mean(Period::Hour).aggregate(&values), 3.0);
+        assert_eq!(Resample::Sum(Period::Hour).aggregate(&values), 9.0);
+        assert_eq!(Resample::Max(Period::Hour).aggregate(&values), 6.0);
+        assert_eq!(Resample::Auto(Aggregate::Max).aggregate(&values), 6.0);
+        assert!(Resample::Mean(Period::Hour).aggregate(&[f64::NAN]).is_nan());
+        assert!(Resample::Sum(Period::Hour).aggregate(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let auto = Resample::Auto(Aggregate::Mean);
+        let day = 24.0 * 60.0 * 60.0;
+        // A year over 500 pixels: 365 days fit
+        assert_eq!(
+            auto.resolve(365.0 * day, 500.0),
+            Some(Resample::Mean(Period::Day))
+        );
+        // A week: 168 hours fit
+        assert_eq!(
+            auto.resolve(7.0 * day, 500.0),
+            Some(Resample::Mean(Period::Hour))
+        );
+        // Fixed periods are unchanged
+        let fixed = Resample::Max(Period::Minute);
+        assert_eq!(fixed.resolve(365.0 * day, 500.0), Some(fixed));
+    }
+}
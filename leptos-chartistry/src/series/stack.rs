@@ -1,4 +1,4 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY};
+use super::{ApplyUseSeries, GetY, GetYValue, IntoUseLine, SeriesAcc, StackPart, UseY};
 use crate::{
     colours::{Colour, ColourScheme, BATLOW},
     Line,
@@ -9,6 +9,9 @@ use std::sync::Arc;
 /// Default colour scheme for stack. Assumes a light background with dark values for high values.
 pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 
+/// Default colour for a stack's total line.
+pub const STACK_TOTAL_COLOUR: Colour = Colour::from_rgb(0x33, 0x33, 0x33);
+
 /// Draws a stack of lines on top of each other.
 ///
 /// # Example
@@ -20,12 +23,42 @@ pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 ///     .line(Line::new(|data: &MyData| data.y2).with_name("pixies"));
 /// ```
 /// See this in action with the [stacked line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-line-chart).
+///
+/// # Colours
+///
+/// Each stack colours its lines from its own [colour scheme](Self::with_colours) (default [STACK_COLOUR_SCHEME]) interpolated across the stack. Lines outside of stacks keep taking the next colour of the [series' scheme](crate::Series::with_colours) and are unaffected by stacks. Use [Stack::with_series_colours] for the stack's lines to take the series' colours instead. Either way, a line's own [colour](Line::with_colour) takes precedence.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64, y3: f64, y4: f64, y5: f64 }
+/// let series = Series::new(|data: &MyData| data.x)
+///     // Blues for the first stack
+///     .stack(
+///         Stack::new()
+///             .line(|data: &MyData| data.y1)
+///             .line(|data: &MyData| data.y2)
+///             .with_colours([Colour::from_rgb(0x1F, 0x4E, 0x79), Colour::from_rgb(0x9D, 0xC3, 0xE6)]),
+///     )
+///     // Reds for the second stack
+///     .stack(
+///         Stack::new()
+///             .line(|data: &MyData| data.y3)
+///             .line(|data: &MyData| data.y4)
+///             .with_colours([Colour::from_rgb(0x8B, 0x1A, 0x1A), Colour::from_rgb(0xF4, 0xA5, 0x82)]),
+///     )
+///     // First colour of the series' scheme
+///     .line(|data: &MyData| data.y5);
+/// ```
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct Stack<T, Y> {
     lines: Vec<Line<T, Y>>,
-    /// Colour scheme for the stack. Interpolates colours across the whole scheme.
+    /// Colour scheme for the stack. Interpolates colours across the whole scheme. Ignored if the stack takes the [series' colours](Self::with_series_colours).
     pub colours: RwSignal<ColourScheme>,
+    // Name of the optional total line
+    total: Option<String>,
+    // Whether lines take the next colours of the series' scheme
+    series_colours: bool,
 }
 
 impl<T, Y> Stack<T, Y> {
@@ -50,11 +83,23 @@ impl<T, Y> Stack<T, Y> {
         self.lines.is_empty()
     }
 
-    /// Sets the colour scheme for the stack.
-    pub fn with_colours<Opt>(self, colours: impl Into<ColourScheme>) -> Self {
+    /// Adds a line drawn over the stack showing the total of its lines. Derived from the stack's lines so it doesn't need its own getter. Has its own legend entry and tooltip row. Uses [STACK_TOTAL_COLOUR].
+    pub fn with_total(mut self, name: impl Into<String>) -> Self {
+        self.total = Some(name.into());
+        self
+    }
+
+    /// Sets the colour scheme for the stack. Interpolated across the stack's lines so the first and last lines take the first and last colours. Doesn't affect lines outside of the stack. See [Stack] for how colours interact.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
         self.colours.set(colours.into());
         self
     }
+
+    /// Sets whether the stack's lines take the next colours of the [series' scheme](crate::Series::with_colours) like lines outside of stacks, instead of the stack's own [colours](Self::with_colours). Lines added after the stack continue from where the stack left off. Default is false. See [Stack] for how colours interact.
+    pub fn with_series_colours(mut self, series_colours: bool) -> Self {
+        self.series_colours = series_colours;
+        self
+    }
 }
 
 impl<T, Y> Default for Stack<T, Y> {
@@ -62,6 +107,8 @@ impl<T, Y> Default for Stack<T, Y> {
         Self {
             lines: Vec::new(),
             colours: RwSignal::new(ColourScheme::from(STACK_COLOUR_SCHEME).invert()),
+            total: None,
+            series_colours: false,
         }
     }
 }
@@ -82,53 +129,145 @@ impl<T: 'static> ApplyUseSeries<T, f64> for Stack<T, f64> {
         let total_lines = self.lines.len();
         let mut previous = Vec::with_capacity(total_lines);
         for (id, line) in self.lines.clone().into_iter().enumerate() {
-            let colour = Memo::new(move |_| colours.get().interpolate(id, total_lines));
+            let colour = if self.series_colours {
+                series.next_colour()
+            } else {
+                Memo::new(move |_| colours.get().interpolate(id, total_lines))
+            };
             let line = StackedLine {
                 line,
                 previous: previous.clone(),
             };
             // Add line
-            let get_y = series.push_line(colour, line);
+            _ = series.push_line(colour, line);
             // Sum next line with this one
-            previous.push(get_y);
+            previous.extend(series.last_stack_part());
+        }
+
+        // Total of all lines drawn last (on top)
+        if let Some(name) = self.total.clone() {
+            let total = StackTotal {
+                line: Line::new(|_: &T| f64::NAN).with_name(name),
+                lines: previous,
+            };
+            _ = series.push_line(Memo::new(|_| STACK_TOTAL_COLOUR), total);
         }
     }
 }
 
-#[derive(Clone)]
+// Line summing the shown lines of a stack. Ignores its own getter
+struct StackTotal<T, Y> {
+    line: Line<T, Y>,
+    lines: Vec<StackPart<T, Y>>,
+}
+
+struct UseStackTotal<T, Y>(Vec<StackPart<T, Y>>);
+
+impl<T: 'static> IntoUseLine<T, f64> for StackTotal<T, f64> {
+    fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, f64>>) {
+        let (line, _) = self.line.into_use_line(id, colour);
+        (line, Arc::new(UseStackTotal(self.lines)))
+    }
+}
+
+impl<T> GetYValue<T, f64> for UseStackTotal<T, f64> {
+    fn value(&self, t: &T) -> Option<f64> {
+        self.stacked_value(t)
+    }
+
+    fn stacked_value(&self, t: &T) -> Option<f64> {
+        let total = (self.0.iter())
+            .filter_map(|part| part.value(t))
+            .filter(|v| !v.is_nan())
+            .sum();
+        Some(total)
+    }
+
+    fn stack_ids(&self) -> Vec<usize> {
+        self.0.iter().filter_map(StackPart::shown_id).collect()
+    }
+}
+
+// Line drawn on top of the shown lines below it in a stack
 struct StackedLine<T, Y> {
     line: Line<T, Y>,
-    previous: Vec<Arc<dyn GetYValue<T, Y>>>,
+    previous: Vec<StackPart<T, Y>>,
 }
 
-#[derive(Clone)]
 struct UseStackLine<T, Y> {
-    line: Arc<dyn GetYValue<T, Y>>,
-    previous: Vec<Arc<dyn GetYValue<T, Y>>>,
+    id: usize,
+    line: GetY<T, Y>,
+    previous: Vec<StackPart<T, Y>>,
 }
 
 impl<T: 'static> IntoUseLine<T, f64> for StackedLine<T, f64> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Arc<dyn GetYValue<T, f64>>) {
         let (line, get_y) = self.line.into_use_line(id, colour);
         let get_y = Arc::new(UseStackLine {
+            id,
             line: get_y,
-            previous: self.previous.clone(),
+            previous: self.previous,
         });
         (line, get_y)
     }
 }
 
 impl<T> GetYValue<T, f64> for UseStackLine<T, f64> {
-    fn value(&self, t: &T) -> f64 {
+    fn value(&self, t: &T) -> Option<f64> {
         self.line.value(t)
     }
 
-    fn stacked_value(&self, t: &T) -> f64 {
-        self.previous
-            .iter()
-            .chain(std::iter::once(&self.line))
-            .map(|get_y| get_y.value(t))
-            .filter(|v| v.is_normal())
-            .sum()
+    fn stacked_value(&self, t: &T) -> Option<f64> {
+        // Missing values leave a gap but don't break the stack above
+        self.line.value(t)?;
+        let stacked = (self.previous.iter())
+            .filter_map(|part| part.value(t))
+            .chain(self.line.value(t))
+            .filter(|v| !v.is_nan())
+            .sum();
+        Some(stacked)
+    }
+
+    fn stack_ids(&self) -> Vec<usize> {
+        (self.previous.iter().filter_map(StackPart::shown_id))
+            .chain(std::iter::once(self.id))
+            .collect()
+    }
+
+    fn is_flagged(&self, t: &T) -> bool {
+        self.line.is_flagged(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stacked_line() {
+        let get_y = |y: f64| Arc::new(move |_: &()| y) as GetY<(), f64>;
+        let part = |id: usize, y: f64, hidden: bool| StackPart {
+            id,
+            get_y: get_y(y),
+            hidden: Signal::derive(move || hidden),
+        };
+        let previous = vec![
+            part(0, 1.0, false),
+            part(1, f64::NAN, false),
+            part(2, -4.0, false),
+            part(3, 8.0, true),
+        ];
+        let line = UseStackLine {
+            id: 4,
+            line: get_y(3.0),
+            previous: previous.clone(),
+        };
+        assert_eq!(line.value(&()), Some(3.0));
+        // Hidden and missing values are left out
+        assert_eq!(line.stacked_value(&()), Some(0.0));
+        assert_eq!(line.stack_ids(), vec![0, 1, 2, 4]);
+        let total = UseStackTotal(previous);
+        assert_eq!(total.stacked_value(&()), Some(-3.0));
+        assert_eq!(total.stack_ids(), vec![0, 1, 2]);
     }
 }
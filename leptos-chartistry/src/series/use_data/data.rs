@@ -1,23 +1,45 @@
-use super::Range;
+use super::{range::WindowRange, Range};
 use crate::{
-    series::{GetX, GetY},
-    Tick,
+    series::{GetMeta, GetX, GetY},
+    Resample, Tick,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
-#[derive(Clone, Debug, PartialEq)]
+// Points are held in VecDeques so dropping from the front (e.g., a live window) only costs the points dropped
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct Data<X, Y> {
-    data_x: Vec<X>,
-    data_y: Vec<HashMap<usize, Y>>,
+    data_x: VecDeque<X>,
+    data_y: VecDeque<HashMap<usize, Y>>,
+    // Extra Y values per point e.g., candlestick open, high and low. Only present for series that use them
+    data_extra: VecDeque<HashMap<usize, Vec<Y>>>,
+    // Optional metadata per point e.g., a record ID
+    data_meta: VecDeque<String>,
+    // Number of points dropped from the start of the source data e.g., by a live window
+    offset: usize,
+    // Number of points dropped by [Data::drop_front]. Indexes the window ranges
+    dropped: usize,
 
     // Data index: X position to data
-    x_to_data: Vec<f64>,
+    x_to_data: VecDeque<f64>,
+    // Whether X positions are in ascending order. Searches fall back to a linear scan otherwise
+    sorted_x: bool,
     // Rendering data
-    coords: HashMap<usize, Vec<(f64, f64)>>,
+    coords: HashMap<usize, VecDeque<(f64, f64)>>,
+    extra_coords: HashMap<usize, VecDeque<Vec<f64>>>,
+    flags: HashMap<usize, VecDeque<bool>>,
+    // Series plotted against the secondary Y axis
+    secondary: Vec<usize>,
+    // Stacked series and the IDs of the series summed to make them
+    stacks: HashMap<usize, Vec<usize>>,
 
     range_x: Range<X>,
     range_y: Range<Y>,
+    range_y_secondary: Range<Y>,
+    // Ranges kept up to date as points are dropped from the front
+    window_x: WindowRange<X>,
+    window_y: WindowRange<Y>,
+    window_y_secondary: WindowRange<Y>,
 }
 
 impl<X: Tick, Y: Tick> Data<X, Y> {
@@ -27,64 +49,416 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
 
         // Empty positions
         let mut built = Self {
-            data_x: Vec::with_capacity(cap),
-            data_y: Vec::with_capacity(cap),
-            x_to_data: Vec::with_capacity(cap * y_cap),
+            data_x: VecDeque::with_capacity(cap),
+            data_y: VecDeque::with_capacity(cap),
+            data_extra: VecDeque::with_capacity(cap),
+            data_meta: VecDeque::new(),
+            offset: 0,
+            dropped: 0,
+            x_to_data: VecDeque::with_capacity(cap * y_cap),
+            sorted_x: true,
             coords: HashMap::with_capacity(cap),
+            extra_coords: HashMap::new(),
+            flags: HashMap::with_capacity(y_cap),
+            secondary: Vec::new(),
+            stacks: (get_ys.iter())
+                .map(|(&id, get_y)| (id, get_y.stack_ids()))
+                .filter(|(_, ids)| !ids.is_empty())
+                .collect(),
             range_x: Range::default(),
             range_y: Range::default(),
+            range_y_secondary: Range::default(),
+            window_x: WindowRange::default(),
+            window_y: WindowRange::default(),
+            window_y_secondary: WindowRange::default(),
         };
 
         for datum in data {
-            // X
-            let x = (get_x)(datum);
-            let x_position = x.position();
-            built.range_x.update(&x);
-            built.x_to_data.push(x_position);
-
-            // Y
-            let mut y_data = HashMap::with_capacity(y_cap);
-            for (&id, get_y) in &get_ys {
-                let y = get_y.value(datum);
-                // Note: cumulative can differ from Y when stacked
-                let y_stacked = get_y.stacked_value(datum);
-                built.range_y.update(&y_stacked);
-
-                // Insert
-                y_data.insert(id, y);
-                built
-                    .coords
-                    .entry(id)
-                    .or_insert_with(|| Vec::with_capacity(cap))
-                    .push((x_position, y_stacked.position()));
+            built.push(&get_x, &get_ys, datum);
+        }
+
+        built
+    }
+
+    // Adds a point to the end of the data. Y ranges are updated for the series' axis
+    fn push<T>(&mut self, get_x: &GetX<T, X>, get_ys: &HashMap<usize, GetY<T, Y>>, datum: &T) {
+        let (cap, y_cap) = (self.data_x.capacity(), get_ys.len());
+        let index = self.dropped + self.data_x.len();
+        // X
+        let x = (get_x)(datum);
+        let x_position = x.position();
+        self.range_x.update(&x);
+        self.window_x.push(index, &x);
+        if self.x_to_data.back().is_some_and(|&last| x_position < last) {
+            self.sorted_x = false;
+        }
+        self.x_to_data.push_back(x_position);
+
+        // Y
+        let mut y_data = HashMap::with_capacity(y_cap);
+        let mut y_extra = HashMap::new();
+        for (&id, get_y) in get_ys {
+            let (range_y, window_y) = if self.secondary.contains(&id) {
+                (&mut self.range_y_secondary, &mut self.window_y_secondary)
+            } else {
+                (&mut self.range_y, &mut self.window_y)
+            };
+            // Missing values are left out of the data and drawn as gaps
+            let y = get_y.value(datum);
+            // Note: cumulative can differ from Y when stacked
+            let y_stacked = get_y.stacked_value(datum);
+            if let Some(y_stacked) = &y_stacked {
+                range_y.update(y_stacked);
+                window_y.push(index, y_stacked);
+            }
+
+            // Extra values
+            let extra = get_y.extra_values(datum);
+            if !extra.is_empty() || self.extra_coords.contains_key(&id) {
+                for y in &extra {
+                    range_y.update(y);
+                    window_y.push(index, y);
+                }
+                let coords =
+                    (self.extra_coords.entry(id)).or_insert_with(|| VecDeque::with_capacity(cap));
+                // Earlier points without extra values are left empty
+                coords.resize(self.data_x.len(), Vec::new());
+                coords.push_back(extra.iter().map(|y| y.position()).collect());
+            }
+            if !extra.is_empty() {
+                y_extra.insert(id, extra);
             }
 
+            // Flagged points
+            self.flags
+                .entry(id)
+                .or_insert_with(|| VecDeque::with_capacity(cap))
+                .push_back(get_y.is_flagged(datum));
+
             // Insert
-            built.data_x.push(x);
-            built.data_y.push(y_data);
+            if let Some(y) = y {
+                y_data.insert(id, y);
+            }
+            self.coords
+                .entry(id)
+                .or_insert_with(|| VecDeque::with_capacity(cap))
+                .push_back((x_position, y_stacked.map_or(f64::NAN, |y| y.position())));
         }
 
-        built
+        // Insert
+        self.data_x.push_back(x);
+        self.data_y.push_back(y_data);
+        self.data_extra.push_back(y_extra);
     }
 
-    pub fn len(&self) -> usize {
-        self.data_x.len()
+    /// Appends points to the end of the data without rebuilding it. Points must follow on from the data already built. Pass the same `get_meta` as [with_meta](Self::with_meta) if used.
+    pub fn extend<T>(
+        &mut self,
+        get_x: &GetX<T, X>,
+        get_ys: &HashMap<usize, GetY<T, Y>>,
+        get_meta: Option<&GetMeta<T>>,
+        data: &[T],
+    ) {
+        for datum in data {
+            self.push(get_x, get_ys, datum);
+        }
+        if let Some(get_meta) = get_meta {
+            (self.data_meta).extend(data.iter().map(|datum| (get_meta)(datum)));
+        }
+    }
+
+    /// Drops the first `count` points e.g., that fell out of a live window. Costs the number of points dropped: ranges are kept up to date without rescanning the points left.
+    pub fn drop_front(&mut self, count: usize) {
+        let count = count.min(self.len());
+        if count == 0 {
+            return;
+        }
+        self.data_x.drain(..count);
+        self.data_y.drain(..count);
+        self.data_extra.drain(..count);
+        self.data_meta.drain(..count.min(self.data_meta.len()));
+        self.x_to_data.drain(..count);
+        for coords in self.coords.values_mut() {
+            coords.drain(..count.min(coords.len()));
+        }
+        for extra in self.extra_coords.values_mut() {
+            extra.drain(..count.min(extra.len()));
+        }
+        for flags in self.flags.values_mut() {
+            flags.drain(..count.min(flags.len()));
+        }
+        self.dropped += count;
+        self.window_x.drop_before(self.dropped);
+        self.window_y.drop_before(self.dropped);
+        self.window_y_secondary.drop_before(self.dropped);
+        self.range_x = self.window_x.range();
+        self.range_y = self.window_y.range();
+        self.range_y_secondary = self.window_y_secondary.range();
+    }
+
+    /// Number of points to drop from the front so that the rest line up with source data starting at X position `first` and holding `len` points. Returns `None` if they can't line up e.g., the data was replaced, X isn't sorted or there's nothing new.
+    pub fn front_drop(&self, first: f64, len: usize) -> Option<usize> {
+        if !self.sorted_x {
+            return None;
+        }
+        let drop = self.x_to_data.partition_point(|&x| x < first);
+        let kept = self.len().checked_sub(drop).filter(|&kept| kept > 0)?;
+        let lines_up = self.x_to_data[drop] == first && kept <= len;
+        (lines_up && (drop > 0 || kept < len)).then_some(drop)
+    }
+
+    /// Attaches metadata to each point.
+    pub fn with_meta<T>(mut self, get_meta: &GetMeta<T>, data: &[T]) -> Self {
+        self.data_meta = data.iter().map(|datum| (get_meta)(datum)).collect();
+        self
+    }
+
+    /// Offsets indices reported by events. Used when points have been dropped from the start of the source data.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Moves series to the secondary Y axis. Their positions make up [range_y_secondary](Self::range_y_secondary) instead of the primary Y range.
+    pub fn with_secondary(mut self, ids: Vec<usize>) -> Self {
+        if ids.is_empty() {
+            return self;
+        }
+        self.secondary = ids;
+        self.rebuild_range_y();
+        self
+    }
+
+    /// Series plotted against the secondary Y axis.
+    pub fn secondary(&self) -> &[usize] {
+        &self.secondary
+    }
+
+    /// Whether a series is plotted against the secondary Y axis.
+    pub fn is_secondary(&self, id: usize) -> bool {
+        self.secondary.contains(&id)
     }
 
-    pub fn range_x(&self) -> Range<X> {
-        self.range_x.clone()
+    pub fn len(&self) -> usize {
+        self.data_x.len()
     }
 
     pub fn range_y(&self) -> Range<Y> {
         self.range_y.clone()
     }
 
+    /// Y range of series on the secondary Y axis. Empty without any.
+    pub fn range_y_secondary(&self) -> Range<Y> {
+        self.range_y_secondary.clone()
+    }
+
+    /// Y range of the given series on the primary Y axis.
+    pub fn range_y_of(&self, ids: &[usize]) -> Range<Y> {
+        self.range_y_from(self.positions_y(|id| ids.contains(id) && !self.is_secondary(*id)))
+    }
+
+    /// Y positions of (stacked) series and their extra values. Filtered by series ID.
+    fn positions_y<'a>(
+        &'a self,
+        keep: impl Fn(&usize) -> bool + Copy + 'a,
+    ) -> impl Iterator<Item = f64> + 'a {
+        let extra = (self.extra_coords.iter())
+            .filter(move |(id, _)| keep(id))
+            .flat_map(|(_, extra)| extra.iter().flatten().copied());
+        (self.coords.iter())
+            .filter(move |(id, _)| keep(id))
+            .flat_map(|(_, coords)| coords.iter().map(|&(_, y)| y))
+            .chain(extra)
+    }
+
+    /// Y range of positions. Uses the built Y ranges as a reference to convert positions to values.
+    fn range_y_from(&self, positions: impl Iterator<Item = f64>) -> Range<Y> {
+        let mut range = Range::default();
+        let reference = (self.range_y.range())
+            .or_else(|| self.range_y_secondary.range())
+            .map(|(first, _)| first.clone());
+        if let Some(reference) = reference {
+            positions
+                .filter_map(|y| reference.with_position(y))
+                .for_each(|y| range.update(&y));
+        }
+        range
+    }
+
+    // Rebuild Y ranges from (stacked) positions
+    fn rebuild_range_y(&mut self) {
+        let (mut primary, mut secondary) = (WindowRange::default(), WindowRange::default());
+        let reference = (self.range_y.range())
+            .or_else(|| self.range_y_secondary.range())
+            .map(|(first, _)| first.clone());
+        if let Some(reference) = reference {
+            // Point by point so the window ranges see indexes in order
+            for index in 0..self.len() {
+                for (id, coords) in &self.coords {
+                    let window = if self.is_secondary(*id) {
+                        &mut secondary
+                    } else {
+                        &mut primary
+                    };
+                    let extra = (self.extra_coords.get(id))
+                        .and_then(|extra| extra.get(index))
+                        .into_iter()
+                        .flatten();
+                    (coords.get(index).map(|&(_, y)| y).into_iter())
+                        .chain(extra.copied())
+                        .filter_map(|y| reference.with_position(y))
+                        .for_each(|y| window.push(self.dropped + index, &y));
+                }
+            }
+        }
+        self.range_y = primary.range();
+        self.range_y_secondary = secondary.range();
+        self.window_y = primary;
+        self.window_y_secondary = secondary;
+    }
+
+    /// Returns the Y range between two percentiles (0 to 100) of (stacked) Y positions on the primary Y axis. Only uses the given series if any. Ignores missing values.
+    pub fn range_y_percentile(&self, low: f64, high: f64, ids: Option<&[usize]>) -> Range<Y> {
+        let Some((reference, _)) = self.range_y.range() else {
+            return Range::default();
+        };
+        let keep = |id: &usize| !self.is_secondary(*id) && ids.is_none_or(|ids| ids.contains(id));
+        let mut positions = (self.positions_y(keep))
+            .filter(|y| y.is_finite())
+            .collect::<Vec<_>>();
+        positions.sort_by(f64::total_cmp);
+        let mut range = Range::default();
+        [percentile(&positions, low), percentile(&positions, high)]
+            .into_iter()
+            .filter_map(|y| reference.with_position(y))
+            .for_each(|y| range.update(&y));
+        range
+    }
+
+    /// Returns the X range of values with a position on the scale e.g., above zero on a log scale. Ignores the rest instead of collapsing the range.
+    pub fn range_x_on_scale(&self, valid: impl Fn(f64) -> bool) -> Range<X> {
+        if (self.range_x.positions()).is_none_or(|(min, max)| valid(min) && valid(max)) {
+            return self.range_x.clone();
+        }
+        let mut range = Range::default();
+        (self.data_x.iter())
+            .filter(|x| valid(x.position()))
+            .for_each(|x| range.update(x));
+        range
+    }
+
+    /// Returns `range` if both ends have a position on the scale. Otherwise the Y range of (stacked) positions on the primary Y axis that do e.g., above zero on a log scale. Only uses the given series if any.
+    pub fn range_y_on_scale(
+        &self,
+        range: Range<Y>,
+        ids: Option<&[usize]>,
+        valid: impl Fn(f64) -> bool,
+    ) -> Range<Y> {
+        if range
+            .positions()
+            .is_none_or(|(min, max)| valid(min) && valid(max))
+        {
+            return range;
+        }
+        let keep = |id: &usize| !self.is_secondary(*id) && ids.is_none_or(|ids| ids.contains(id));
+        self.range_y_from(self.positions_y(keep).filter(|&y| valid(y)))
+    }
+
+    /// Returns the X range of `len` values starting at `start`. Clamped to the data.
+    pub fn window_range_x(&self, start: usize, len: usize) -> Range<X> {
+        let len = len.min(self.data_x.len());
+        let start = start.min(self.data_x.len() - len);
+        let mut range = Range::default();
+        for x in self.data_x.range(start..start + len) {
+            range.update(x);
+        }
+        range
+    }
+
+    /// Factors to rescale series (by ID) by so that their first value at or after `from_x` equals the given base e.g., 100. Uses each series' own values rather than stacked ones. Series without a finite, non-zero first value are left out.
+    pub fn index_factors(&self, bases: &HashMap<usize, f64>, from_x: f64) -> HashMap<usize, f64> {
+        let start = self.x_to_data.partition_point(|&x| x < from_x);
+        (bases.iter())
+            .filter_map(|(&id, &base)| {
+                let first = (self.data_y.iter().skip(start))
+                    .filter_map(|ys| Some(ys.get(&id)?.position()))
+                    .find(|y| y.is_finite() && *y != 0.0)?;
+                Some((id, base / first))
+            })
+            .collect()
+    }
+
+    /// Rescales series (by ID) by the given [factors](Self::index_factors). Stacks containing a rescaled series are summed again from their series' values. Other series are unchanged.
+    pub fn index_to(&mut self, factors: &HashMap<usize, f64>) {
+        if factors.is_empty() {
+            return;
+        }
+        let scale = |y: &mut Y, factor: f64| {
+            if let Some(scaled) = y.with_position(y.position() * factor) {
+                *y = scaled;
+            }
+        };
+
+        for (&id, &factor) in factors {
+            // Positions
+            if let Some(coords) = self.coords.get_mut(&id) {
+                coords.iter_mut().for_each(|(_, y)| *y *= factor);
+            }
+            if let Some(extra) = self.extra_coords.get_mut(&id) {
+                extra.iter_mut().flatten().for_each(|y| *y *= factor);
+            }
+            // Values
+            for y in self.data_y.iter_mut().filter_map(|ys| ys.get_mut(&id)) {
+                scale(y, factor);
+            }
+            for y in (self.data_extra.iter_mut())
+                .filter_map(|extra| extra.get_mut(&id))
+                .flatten()
+            {
+                scale(y, factor);
+            }
+        }
+
+        // Restack from the rescaled values
+        for (id, parts) in &self.stacks {
+            if !parts.iter().any(|part| factors.contains_key(part)) {
+                continue;
+            }
+            let Some(coords) = self.coords.get_mut(id) else {
+                continue;
+            };
+            for (coord, ys) in coords.iter_mut().zip(self.data_y.iter_mut()) {
+                // Missing values stay missing
+                if coord.1.is_nan() {
+                    continue;
+                }
+                coord.1 = (parts.iter())
+                    .filter_map(|part| Some(ys.get(part)?.position()))
+                    .filter(|y| !y.is_nan())
+                    .sum();
+                // Series not summing themselves (e.g., a stack's total) take the stacked value
+                let total = (ys.get(id)).filter(|_| !parts.contains(id));
+                if let Some(total) = total.and_then(|y| y.with_position(coord.1)) {
+                    ys.insert(*id, total);
+                }
+            }
+        }
+
+        self.rebuild_range_y();
+    }
+
     /// Finds the index of the _nearest_ position to the given X. Returns None if no data.
     fn nearest_index(&self, pos_x: f64) -> Option<usize> {
         // No values
         if self.x_to_data.is_empty() {
             return None;
         }
+        if !self.sorted_x {
+            return (self.x_to_data.iter().enumerate())
+                .filter(|(_, x)| !x.is_nan())
+                .min_by(|(_, a), (_, b)| (*a - pos_x).abs().total_cmp(&(*b - pos_x).abs()))
+                .map(|(index, _)| index);
+        }
         // Find index after pos
         let index = self.x_to_data.partition_point(|&v| v < pos_x);
         // No value before
@@ -116,19 +490,251 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
             .unwrap_or_default()
     }
 
+    /// Position of each series at the nearest data point. Stacked series are at their stacked position.
+    pub fn nearest_positions(&self, pos_x: f64) -> HashMap<usize, (f64, f64)> {
+        let Some(index) = self.nearest_index(pos_x) else {
+            return HashMap::new();
+        };
+        (self.coords.iter())
+            .filter_map(|(&id, coords)| Some((id, *coords.get(index)?)))
+            .collect()
+    }
+
+    /// Extra positions of each series at the nearest data point e.g., a candlestick's open, high and low or a stacked bar's bottom.
+    pub fn nearest_extra_positions(&self, pos_x: f64) -> HashMap<usize, Vec<f64>> {
+        let Some(index) = self.nearest_index(pos_x) else {
+            return HashMap::new();
+        };
+        (self.extra_coords.iter())
+            .filter_map(|(&id, extra)| Some((id, extra.get(index)?.clone())))
+            .collect()
+    }
+
+    pub fn nearest_data_extra(&self, pos_x: f64) -> HashMap<usize, Vec<Y>> {
+        self.nearest_index(pos_x)
+            .map(|index| self.data_extra[index].clone())
+            .unwrap_or_default()
+    }
+
+    /// Last value of each series at or before the given X position that isn't missing (NaN). Returned with its X value.
+    pub fn last_known_y(&self, pos_x: f64) -> HashMap<usize, (X, Y)> {
+        let mut found = HashMap::new();
+        // Search backwards from the last point at or before the position
+        let end = if self.sorted_x {
+            self.x_to_data.partition_point(|&x| x <= pos_x)
+        } else {
+            self.x_to_data.len()
+        };
+        for i in (0..end).rev() {
+            if self.x_to_data[i] > pos_x || self.x_to_data[i].is_nan() {
+                continue;
+            }
+            for (&id, y) in &self.data_y[i] {
+                if !y.position().is_nan() {
+                    (found.entry(id)).or_insert_with(|| (self.data_x[i].clone(), y.clone()));
+                }
+            }
+            if found.len() == self.coords.len() {
+                break;
+            }
+        }
+        found
+    }
+
+    /// Index of the nearest data point along with its X value and metadata.
+    pub fn nearest_point(&self, pos_x: f64) -> Option<(usize, X, Option<String>)> {
+        self.nearest_index(pos_x).map(|index| {
+            let meta = self.data_meta.get(index).cloned();
+            (self.offset + index, self.data_x[index].clone(), meta)
+        })
+    }
+
+    /// Y values of each series at a data point. Takes an index as returned by [Data::nearest_point] or [Data::select].
+    pub fn y_at(&self, index: usize) -> HashMap<usize, Y> {
+        (index.checked_sub(self.offset))
+            .and_then(|index| self.data_y.get(index).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Data points where any of the given series is inside a selection. `inside` is passed the series ID and position. Returns the index, X value and metadata of each point in order.
+    pub fn select(
+        &self,
+        ids: &[usize],
+        inside: impl Fn(usize, (f64, f64)) -> bool,
+    ) -> Vec<(usize, X, Option<String>)> {
+        (0..self.data_x.len())
+            .filter(|&index| {
+                ids.iter().any(|&id| {
+                    (self.coords.get(&id))
+                        .and_then(|coords| coords.get(index))
+                        .is_some_and(|&coord| inside(id, coord))
+                })
+            })
+            .map(|index| {
+                let meta = self.data_meta.get(index).cloned();
+                (self.offset + index, self.data_x[index].clone(), meta)
+            })
+            .collect()
+    }
+
+    /// Series ID and index of the point of the given series with the smallest distance. `distance` is passed the series ID and position. Ignores missing points. The index is offset like [Data::select].
+    pub fn nearest_coord(
+        &self,
+        ids: &[usize],
+        distance: impl Fn(usize, (f64, f64)) -> f64,
+    ) -> Option<(usize, usize, f64)> {
+        (ids.iter())
+            .filter_map(|&id| Some((id, self.coords.get(&id)?)))
+            .flat_map(|(id, coords)| {
+                (coords.iter().enumerate()).map(move |(index, &coord)| (id, index, coord))
+            })
+            .map(|(id, index, coord)| (id, index, distance(id, coord)))
+            .filter(|(_, _, distance)| !distance.is_nan())
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+            .map(|(id, index, distance)| (id, self.offset + index, distance))
+    }
+
+    /// Y value of a series' point moved to a new Y position. Takes an index as returned by [Data::nearest_coord].
+    pub fn y_with_position(&self, id: usize, index: usize, pos_y: f64) -> Option<Y> {
+        let index = index.checked_sub(self.offset)?;
+        self.data_y.get(index)?.get(&id)?.with_position(pos_y)
+    }
+
     /// Given an arbitrary (unaligned to data) X position, find the nearest X position aligned to data. Returns `f64::NAN` if no data. Note a position covers a range dependent on the chart width.
     pub fn nearest_position_x(&self, pos_x: f64) -> Option<f64> {
         self.nearest_index(pos_x).map(|index| self.x_to_data[index])
     }
 
+    /// X position of each data point.
+    pub fn positions_x(&self) -> &VecDeque<f64> {
+        &self.x_to_data
+    }
+
     pub fn series_positions(&self, id: usize) -> Vec<(f64, f64)> {
-        self.coords.get(&id).cloned().unwrap_or_default()
+        (self.coords.get(&id))
+            .map(|coords| coords.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Positions of a series aggregated by the resample's period. Each period is placed at its start. Returns the positions unchanged if X has no periods or the resample has no period (unresolved [Resample::Auto]).
+    pub fn resampled_positions(&self, id: usize, resample: Resample) -> Vec<(f64, f64)> {
+        let Some(coords) = self.coords.get(&id) else {
+            return Vec::new();
+        };
+        let Some(period) = resample.period() else {
+            return coords.iter().copied().collect();
+        };
+        // Start of each period and the indices of its points
+        let mut periods: Vec<(f64, std::ops::Range<usize>)> = Vec::new();
+        for (index, x) in self.data_x.iter().enumerate() {
+            let Some(start) = x.period_start(period) else {
+                return coords.iter().copied().collect();
+            };
+            let start = start.position();
+            match periods.last_mut() {
+                Some((at, points)) if *at == start => points.end = index + 1,
+                _ => periods.push((start, index..index + 1)),
+            }
+        }
+
+        // Stacked series sum the resampled values of their series
+        let series_values = |part: &usize, points: std::ops::Range<usize>| {
+            (self.data_y.range(points))
+                .map(|ys| ys.get(part).map_or(f64::NAN, |y| y.position()))
+                .collect::<Vec<_>>()
+        };
+        let aggregate = |points: std::ops::Range<usize>| match self.stacks.get(&id) {
+            Some(parts) => {
+                let values = (parts.iter())
+                    .map(|part| {
+                        (
+                            part,
+                            resample.aggregate(&series_values(part, points.clone())),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                // Missing if the series itself is
+                if values.iter().any(|&(part, y)| *part == id && y.is_nan()) {
+                    return f64::NAN;
+                }
+                values.iter().map(|(_, y)| y).filter(|y| !y.is_nan()).sum()
+            }
+            None => resample.aggregate(&coords.range(points).map(|&(_, y)| y).collect::<Vec<_>>()),
+        };
+        (periods.into_iter())
+            .map(|(at, points)| (at, aggregate(points)))
+            .collect()
+    }
+
+    pub fn series_flags(&self, id: usize) -> Vec<bool> {
+        (self.flags.get(&id))
+            .map(|flags| flags.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn series_extra_positions(&self, id: usize) -> Vec<Vec<f64>> {
+        (self.extra_coords.get(&id))
+            .map(|extra| extra.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+// Compares the points and ranges. Window ranges follow from them and count indexes from different starts
+impl<X: PartialEq, Y: PartialEq> PartialEq for Data<X, Y> {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            data_x,
+            data_y,
+            data_extra,
+            data_meta,
+            offset,
+            dropped: _,
+            x_to_data,
+            sorted_x,
+            coords,
+            extra_coords,
+            flags,
+            secondary,
+            stacks,
+            range_x,
+            range_y,
+            range_y_secondary,
+            window_x: _,
+            window_y: _,
+            window_y_secondary: _,
+        } = self;
+        *data_x == other.data_x
+            && *data_y == other.data_y
+            && *data_extra == other.data_extra
+            && *data_meta == other.data_meta
+            && *offset == other.offset
+            && *x_to_data == other.x_to_data
+            && *sorted_x == other.sorted_x
+            && *coords == other.coords
+            && *extra_coords == other.extra_coords
+            && *flags == other.flags
+            && *secondary == other.secondary
+            && *stacks == other.stacks
+            && *range_x == other.range_x
+            && *range_y == other.range_y
+            && *range_y_secondary == other.range_y_secondary
     }
 }
 
+/// Linearly interpolated percentile (0 to 100) of sorted values. Returns NaN if empty.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return f64::NAN;
+    };
+    let rank = (p.clamp(0.0, 100.0) / 100.0) * last as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{series::GetYValue, Period};
     use std::sync::Arc;
 
     #[derive(Clone, Debug, PartialEq)]
@@ -158,6 +764,81 @@ mod tests {
         Data::new(Arc::new(|d: &MyData| d.x), get_ys, data)
     }
 
+    #[test]
+    fn test_data_meta() {
+        let data = test_data(DATA);
+        assert_eq!(data.nearest_point(4.2), Some((1, 4.0, None)));
+        let get_meta: GetMeta<MyData> = Arc::new(|d: &MyData| format!("id-{}", d.x));
+        let data = data.with_meta(&get_meta, DATA);
+        assert_eq!(
+            data.nearest_point(0.0),
+            Some((0, 1.0, Some("id-1".to_string())))
+        );
+        assert_eq!(
+            data.nearest_point(6.0),
+            Some((2, 7.0, Some("id-7".to_string())))
+        );
+        assert_eq!(test_data(&[]).nearest_point(1.0), None);
+    }
+
+    #[test]
+    fn test_select() {
+        let data = test_data(DATA);
+        let inside = |_, (x, y): (f64, f64)| x >= 3.0 && y >= 5.5;
+        assert_eq!(
+            data.select(&[66, 5], inside),
+            vec![(1, 4.0, None), (2, 7.0, None)]
+        );
+        assert_eq!(data.select(&[66], inside), vec![(2, 7.0, None)]);
+        assert_eq!(data.select(&[], inside), vec![]);
+        assert_eq!(data.select(&[404], inside), vec![]);
+    }
+
+    #[test]
+    fn test_unsorted_x() {
+        let data = test_data(&[DATA[2].clone(), DATA[0].clone(), DATA[1].clone()]);
+        assert_eq!(data.nearest_point(1.2), Some((1, 1.0, None)));
+        assert_eq!(data.nearest_point(5.0), Some((2, 4.0, None)));
+        assert_eq!(data.nearest_point(9.0), Some((0, 7.0, None)));
+    }
+
+    #[test]
+    fn test_offset() {
+        let data = test_data(&DATA[1..]).with_offset(1);
+        assert_eq!(data.nearest_point(4.2), Some((1, 4.0, None)));
+        assert_eq!(
+            data.select(&[66], |_, (x, _)| x > 5.0),
+            vec![(2, 7.0, None)]
+        );
+        let distance = |_, (x, _): (f64, f64)| (x - 7.0).abs();
+        let (id, index, _) = data.nearest_coord(&[66], distance).unwrap();
+        assert_eq!((id, index), (66, 2));
+        assert_eq!(data.y_with_position(66, 2, 42.0), Some(42.0));
+        assert_eq!(data.y_with_position(66, 0, 42.0), None);
+    }
+
+    #[test]
+    fn test_y_at() {
+        let data = test_data(&DATA[1..]).with_offset(1);
+        let y = data.y_at(2);
+        assert_eq!((y.get(&66), y.get(&5)), (Some(&8.0), Some(&9.0)));
+        assert!(data.y_at(0).is_empty());
+        assert!(data.y_at(3).is_empty());
+    }
+
+    #[test]
+    fn test_nearest_coord() {
+        let data = test_data(DATA);
+        let distance = |_, (x, y): (f64, f64)| (x - 4.0).hypot(y - 5.9);
+        let (id, index, _) = data.nearest_coord(&[66, 5], distance).unwrap();
+        assert_eq!((id, index), (5, 1));
+        let (id, index, _) = data.nearest_coord(&[66], distance).unwrap();
+        assert_eq!((id, index), (66, 1));
+        assert_eq!(data.nearest_coord(&[], distance), None);
+        assert_eq!(data.y_with_position(66, 1, 42.0), Some(42.0));
+        assert_eq!(data.y_with_position(66, 3, 42.0), None);
+    }
+
     #[test]
     fn test_data_new() {
         let data = test_data(DATA);
@@ -174,12 +855,14 @@ mod tests {
         // Positions
         assert_eq!(data.x_to_data, vec![1.0, 4.0, 7.0]);
         assert_eq!(
-            data.coords,
-            HashMap::from([
-                (66, vec![(1.0, 2.0), (4.0, 5.0), (7.0, 8.0)]),
-                (5, vec![(1.0, 3.0), (4.0, 6.0), (7.0, 9.0)]),
-            ])
+            data.series_positions(66),
+            vec![(1.0, 2.0), (4.0, 5.0), (7.0, 8.0)]
         );
+        assert_eq!(
+            data.series_positions(5),
+            vec![(1.0, 3.0), (4.0, 6.0), (7.0, 9.0)]
+        );
+        assert_eq!(data.series_flags(66), vec![false; 3]);
         // Ranges
         assert_eq!(data.range_x.range(), Some((&1.0, &7.0)));
         assert_eq!(data.range_x.positions(), Some((1.0, 7.0)));
@@ -187,6 +870,419 @@ mod tests {
         assert_eq!(data.range_y.positions(), Some((2.0, 9.0)));
     }
 
+    #[test]
+    fn test_extend() {
+        let get_x: GetX<MyData, f64> = Arc::new(|d: &MyData| d.x);
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(66, Arc::new(|d: &MyData| d.y1));
+        get_ys.insert(5, Arc::new(|d: &MyData| d.y2));
+        let mut data = test_data(&DATA[..1]);
+        data.extend(&get_x, &get_ys, None, &DATA[1..]);
+        assert_eq!(data, test_data(DATA));
+        // Keeps the axes apart
+        let mut data = test_data(&DATA[..1]).with_secondary(vec![5]);
+        data.extend(&get_x, &get_ys, None, &DATA[1..]);
+        assert_eq!(data, test_data(DATA).with_secondary(vec![5]));
+        // Metadata
+        let get_meta: GetMeta<MyData> = Arc::new(|d: &MyData| format!("id-{}", d.x));
+        let mut data = test_data(&[]).with_meta(&get_meta, &[]);
+        data.extend(&get_x, &get_ys, Some(&get_meta), DATA);
+        assert_eq!(data, test_data(DATA).with_meta(&get_meta, DATA));
+    }
+
+    #[test]
+    fn test_drop_front() {
+        let get_meta: GetMeta<MyData> = Arc::new(|d: &MyData| format!("id-{}", d.x));
+        let mut data = test_data(DATA).with_meta(&get_meta, DATA);
+        data.drop_front(1);
+        let expected = test_data(&DATA[1..]).with_meta(&get_meta, &DATA[1..]);
+        assert_eq!(data, expected);
+        assert_eq!(data.range_x.positions(), Some((4.0, 7.0)));
+        assert_eq!(data.range_y().positions(), Some((5.0, 9.0)));
+        // Keeps the axes apart
+        let mut data = test_data(DATA).with_secondary(vec![5]);
+        data.drop_front(2);
+        assert_eq!(data, test_data(&DATA[2..]).with_secondary(vec![5]));
+        // Everything
+        let mut data = test_data(DATA);
+        data.drop_front(5);
+        assert_eq!(data.len(), 0);
+        assert_eq!(data.range_y(), Range::default());
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        let get_x: GetX<MyData, f64> = Arc::new(|d: &MyData| d.x);
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(66, Arc::new(|d: &MyData| d.y1));
+        get_ys.insert(5, Arc::new(|d: &MyData| d.y2));
+        // Rising, falling and missing values
+        let source = (0..20)
+            .map(|x| {
+                let x = x as f64;
+                let y2 = if x % 7.0 == 0.0 { f64::NAN } else { 20.0 - x };
+                MyData::new(x, x * 2.0, y2)
+            })
+            .collect::<Vec<_>>();
+        let mut data = test_data(&source[..5]).with_secondary(vec![5]);
+        for end in 6..=source.len() {
+            data.drop_front(1);
+            data.extend(&get_x, &get_ys, None, &source[end - 1..end]);
+            let expected = test_data(&source[end - 5..end]).with_secondary(vec![5]);
+            assert_eq!(data.range_x, expected.range_x);
+            assert_eq!(data.range_y(), expected.range_y());
+            assert_eq!(data.range_y_secondary(), expected.range_y_secondary());
+        }
+    }
+
+    #[test]
+    fn test_front_drop() {
+        let data = test_data(DATA);
+        // Appended to
+        assert_eq!(data.front_drop(1.0, 4), Some(0));
+        // Dropped from the front and appended to
+        assert_eq!(data.front_drop(4.0, 2), Some(1));
+        assert_eq!(data.front_drop(7.0, 3), Some(2));
+        // Nothing new
+        assert_eq!(data.front_drop(1.0, 3), None);
+        // Replaced
+        assert_eq!(data.front_drop(5.0, 3), None);
+        assert_eq!(data.front_drop(1.0, 2), None);
+        assert_eq!(data.front_drop(8.0, 3), None);
+        assert_eq!(test_data(&[]).front_drop(1.0, 1), None);
+    }
+
+    #[test]
+    fn test_secondary() {
+        let data = test_data(DATA);
+        assert_eq!(data.range_y().positions(), Some((2.0, 9.0)));
+        assert_eq!(data.range_y_secondary().positions(), None);
+        let data = data.with_secondary(vec![5]);
+        assert!(data.is_secondary(5) && !data.is_secondary(66));
+        assert_eq!(data.range_y().positions(), Some((2.0, 8.0)));
+        assert_eq!(data.range_y_secondary().positions(), Some((3.0, 9.0)));
+        // Indexing keeps the axes apart
+        let mut data = data;
+        data.index_to(&HashMap::from([(5, 2.0)]));
+        assert_eq!(data.range_y_secondary().positions(), Some((6.0, 18.0)));
+        assert_eq!(data.range_y().positions(), Some((2.0, 8.0)));
+    }
+
+    #[test]
+    fn test_range_y_of() {
+        let data = test_data(DATA);
+        assert_eq!(data.range_y_of(&[66]).positions(), Some((2.0, 8.0)));
+        assert_eq!(data.range_y_of(&[5, 66]).positions(), Some((2.0, 9.0)));
+        assert_eq!(data.range_y_of(&[]).positions(), None);
+        let data = data.with_secondary(vec![5]);
+        assert_eq!(data.range_y_of(&[5]).positions(), None);
+    }
+
+    #[test]
+    fn test_range_y_percentile() {
+        assert!(percentile(&[], 50.0).is_nan());
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0, 5.0], 50.0), 3.0);
+        assert_eq!(percentile(&[0.0, 10.0], 25.0), 2.5);
+        assert_eq!(percentile(&[0.0, 10.0], 150.0), 10.0);
+
+        let data = test_data(DATA);
+        assert_eq!(
+            data.range_y_percentile(0.0, 100.0, None).positions(),
+            Some((2.0, 9.0))
+        );
+        assert_eq!(
+            data.range_y_percentile(20.0, 80.0, None).positions(),
+            Some((3.0, 8.0))
+        );
+        assert_eq!(
+            data.range_y_percentile(0.0, 100.0, Some(&[5])).positions(),
+            Some((3.0, 9.0))
+        );
+        assert_eq!(
+            test_data(&[])
+                .range_y_percentile(1.0, 99.0, None)
+                .positions(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_range_on_scale() {
+        let data = test_data(&[
+            MyData::new(0.0, 0.0, 3.0),
+            MyData::new(4.0, 5.0, -6.0),
+            MyData::new(7.0, 8.0, 9.0),
+        ]);
+        let above_zero = |pos: f64| pos > 0.0;
+        assert_eq!(
+            data.range_x_on_scale(above_zero).positions(),
+            Some((4.0, 7.0))
+        );
+        assert_eq!(
+            data.range_y_on_scale(data.range_y(), None, above_zero)
+                .positions(),
+            Some((3.0, 9.0))
+        );
+        assert_eq!(
+            data.range_y_on_scale(data.range_y(), Some(&[66]), above_zero)
+                .positions(),
+            Some((5.0, 8.0))
+        );
+        // Already on the scale
+        let data = test_data(DATA);
+        assert_eq!(data.range_x_on_scale(above_zero), data.range_x);
+        assert_eq!(
+            data.range_y_on_scale(data.range_y(), None, above_zero),
+            data.range_y()
+        );
+    }
+
+    #[test]
+    fn test_resampled_positions() {
+        use chrono::prelude::*;
+        let at = |h, m| Utc.with_ymd_and_hms(2024, 5, 6, h, m, 0).unwrap();
+        let data = [(at(7, 0), 1.0), (at(7, 30), 3.0), (at(8, 15), 5.0)];
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(0, Arc::new(|d: &(DateTime<Utc>, f64)| d.1));
+        let data = Data::new(Arc::new(|d: &(DateTime<Utc>, f64)| d.0), get_ys, &data);
+        let hour = |h| at(h, 0).position();
+        assert_eq!(
+            data.resampled_positions(0, Resample::Mean(Period::Hour)),
+            vec![(hour(7), 2.0), (hour(8), 5.0)]
+        );
+        assert_eq!(
+            data.resampled_positions(0, Resample::Sum(Period::Day)),
+            vec![(hour(0), 9.0)]
+        );
+        assert_eq!(
+            data.resampled_positions(1, Resample::Max(Period::Hour)),
+            vec![]
+        );
+        // X without periods is unchanged
+        assert_eq!(
+            test_data(DATA).resampled_positions(66, Resample::Max(Period::Hour)),
+            vec![(1.0, 2.0), (4.0, 5.0), (7.0, 8.0)]
+        );
+    }
+
+    type Stacked = (chrono::DateTime<chrono::Utc>, f64, f64);
+
+    // Second value stacked on the first (ID 0)
+    struct StackedSecond;
+
+    impl GetYValue<Stacked, f64> for StackedSecond {
+        fn value(&self, d: &Stacked) -> Option<f64> {
+            Some(d.2)
+        }
+
+        fn stacked_value(&self, d: &Stacked) -> Option<f64> {
+            Some(d.1 + d.2)
+        }
+
+        fn stack_ids(&self) -> Vec<usize> {
+            vec![0, 1]
+        }
+    }
+
+    #[test]
+    fn test_resampled_stack() {
+        use chrono::prelude::*;
+        let at = |h, m| Utc.with_ymd_and_hms(2024, 5, 6, h, m, 0).unwrap();
+        let data = [
+            (at(7, 0), 1.0, 30.0),
+            (at(7, 30), 3.0, 10.0),
+            (at(8, 15), 5.0, 20.0),
+        ];
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(0, Arc::new(|d: &Stacked| d.1));
+        get_ys.insert(1, Arc::new(StackedSecond));
+        let data = Data::new(Arc::new(|d: &Stacked| d.0), get_ys, &data);
+        let hour = |h| at(h, 0).position();
+        // Each series is resampled before stacking
+        assert_eq!(
+            data.resampled_positions(1, Resample::Max(Period::Hour)),
+            vec![(hour(7), 33.0), (hour(8), 25.0)]
+        );
+    }
+
+    struct Spread;
+
+    impl GetYValue<MyData, f64> for Spread {
+        fn value(&self, d: &MyData) -> Option<f64> {
+            Some(d.y1)
+        }
+
+        fn stacked_value(&self, d: &MyData) -> Option<f64> {
+            Some(d.y1)
+        }
+
+        fn extra_values(&self, d: &MyData) -> Vec<f64> {
+            vec![d.y1 - 10.0, d.y2 + 10.0]
+        }
+    }
+
+    #[test]
+    fn test_data_extra() {
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(1, Arc::new(Spread));
+        get_ys.insert(2, Arc::new(|d: &MyData| d.y2));
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA);
+        assert_eq!(
+            data.series_extra_positions(1),
+            vec![vec![-8.0, 13.0], vec![-5.0, 16.0], vec![-2.0, 19.0]]
+        );
+        assert!(data.series_extra_positions(2).is_empty());
+        assert_eq!(
+            data.nearest_data_extra(4.0),
+            HashMap::from([(1, vec![-5.0, 16.0])])
+        );
+        // Extra values extend the Y range
+        assert_eq!(data.range_y.positions(), Some((-8.0, 19.0)));
+    }
+
+    // Missing when Y is over 6
+    struct Capped;
+
+    impl GetYValue<MyData, f64> for Capped {
+        fn value(&self, d: &MyData) -> Option<f64> {
+            (d.y1 <= 6.0).then_some(d.y1)
+        }
+
+        fn stacked_value(&self, d: &MyData) -> Option<f64> {
+            self.value(d)
+        }
+    }
+
+    #[test]
+    fn test_data_missing() {
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(1, Arc::new(Capped));
+        let data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA);
+        let positions = data.series_positions(1);
+        assert_eq!(positions[..2], [(1.0, 2.0), (4.0, 5.0)]);
+        assert!(positions[2].1.is_nan());
+        assert_eq!(data.nearest_data_y(7.0), HashMap::new());
+        assert_eq!(data.range_y.positions(), Some((2.0, 5.0)));
+        assert_eq!(data.last_known_y(7.0), HashMap::from([(1, (4.0, 5.0))]));
+    }
+
+    #[test]
+    fn test_last_known_y() {
+        let data = test_data(&[
+            MyData::new(1.0, 2.0, 3.0),
+            MyData::new(4.0, f64::NAN, 6.0),
+            MyData::new(7.0, f64::NAN, f64::NAN),
+        ]);
+        assert_eq!(
+            data.last_known_y(7.0),
+            HashMap::from([(66, (1.0, 2.0)), (5, (4.0, 6.0))])
+        );
+        // Not after the position even if nearer
+        assert_eq!(
+            data.last_known_y(3.5),
+            HashMap::from([(66, (1.0, 2.0)), (5, (1.0, 3.0))])
+        );
+        assert!(data.last_known_y(0.0).is_empty());
+        assert!(test_data(&[]).last_known_y(1.0).is_empty());
+    }
+
+    #[test]
+    fn test_window_range_x() {
+        let data = test_data(DATA);
+        assert_eq!(data.window_range_x(1, 2).positions(), Some((4.0, 7.0)));
+        // Clamped to the data
+        assert_eq!(data.window_range_x(2, 2).positions(), Some((4.0, 7.0)));
+        assert_eq!(data.window_range_x(0, 5).positions(), Some((1.0, 7.0)));
+        assert_eq!(test_data(&[]).window_range_x(0, 2).positions(), None);
+    }
+
+    #[test]
+    fn test_index_factors() {
+        let data = test_data(DATA);
+        let bases = HashMap::from([(66, 100.0)]);
+        assert_eq!(data.index_factors(&bases, 0.0), HashMap::from([(66, 50.0)]));
+        // First visible value
+        assert_eq!(data.index_factors(&bases, 2.0), HashMap::from([(66, 20.0)]));
+        // Nothing to index
+        assert!(data.index_factors(&HashMap::new(), 0.0).is_empty());
+        assert!(data.index_factors(&bases, 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_index_to() {
+        let data = test_data(DATA);
+        let mut indexed = data.clone();
+        indexed.index_to(&HashMap::from([(66, 50.0)]));
+        assert_eq!(
+            indexed.series_positions(66),
+            vec![(1.0, 100.0), (4.0, 250.0), (7.0, 400.0)]
+        );
+        assert_eq!(indexed.series_positions(5), data.series_positions(5));
+        assert_eq!(indexed.nearest_data_y(4.0).get(&66), Some(&250.0));
+        assert_eq!(indexed.range_y.positions(), Some((3.0, 400.0)));
+        // Nothing to index
+        indexed = data.clone();
+        indexed.index_to(&HashMap::new());
+        assert_eq!(indexed, data);
+    }
+
+    // Y2 stacked on top of Y1 (ID 66)
+    struct StackedY2;
+
+    impl GetYValue<MyData, f64> for StackedY2 {
+        fn value(&self, d: &MyData) -> Option<f64> {
+            Some(d.y2)
+        }
+
+        fn stacked_value(&self, d: &MyData) -> Option<f64> {
+            Some(d.y1 + d.y2)
+        }
+
+        fn stack_ids(&self) -> Vec<usize> {
+            vec![66, 5]
+        }
+    }
+
+    // Total of Y1 (ID 66) and Y2 (ID 5)
+    struct Total;
+
+    impl GetYValue<MyData, f64> for Total {
+        fn value(&self, d: &MyData) -> Option<f64> {
+            self.stacked_value(d)
+        }
+
+        fn stacked_value(&self, d: &MyData) -> Option<f64> {
+            Some(d.y1 + d.y2)
+        }
+
+        fn stack_ids(&self) -> Vec<usize> {
+            vec![66, 5]
+        }
+    }
+
+    #[test]
+    fn test_index_stacked() {
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(66, Arc::new(|d: &MyData| d.y1));
+        get_ys.insert(5, Arc::new(StackedY2));
+        get_ys.insert(7, Arc::new(Total));
+        let mut data = Data::new(Arc::new(|d: &MyData| d.x), get_ys, DATA);
+        let factors = data.index_factors(&HashMap::from([(5, 6.0)]), 0.0);
+        assert_eq!(factors, HashMap::from([(5, 2.0)]));
+        data.index_to(&factors);
+        // Indexed before stacking
+        assert_eq!(data.nearest_data_y(4.0).get(&5), Some(&12.0));
+        assert_eq!(
+            data.series_positions(5),
+            vec![(1.0, 8.0), (4.0, 17.0), (7.0, 26.0)]
+        );
+        // Totals follow
+        assert_eq!(data.series_positions(7), data.series_positions(5));
+        assert_eq!(data.nearest_data_y(4.0).get(&7), Some(&17.0));
+        assert_eq!(data.series_positions(66)[1], (4.0, 5.0));
+        assert_eq!(data.range_y.positions(), Some((2.0, 26.0)));
+    }
+
     #[test]
     fn test_nearest_index() {
         let data = test_data(DATA);
@@ -217,6 +1313,15 @@ mod tests {
         assert_eq!(data.nearest_data_x(4.0), Some(4.0));
     }
 
+    #[test]
+    fn test_nearest_positions() {
+        let data = test_data(DATA);
+        let positions = data.nearest_positions(3.0);
+        assert_eq!(positions.get(&66), Some(&(4.0, 5.0)));
+        assert_eq!(positions.get(&5), Some(&(4.0, 6.0)));
+        assert!(test_data(&[]).nearest_positions(3.0).is_empty());
+    }
+
     #[test]
     fn test_nearest_aligned_position_x() {
         let data = test_data(DATA);
@@ -0,0 +1,87 @@
+use leptos::{leptos_dom::helpers::request_animation_frame, prelude::*};
+
+// Fraction of the remaining distance moved each frame
+const SLIDE_EASING: f64 = 0.25;
+// Snaps to the target once within this fraction of the window span
+const SLIDE_SNAP: f64 = 1e-3;
+
+/// Anchor (the newest X position) of a live window. See [Series::with_live_window](crate::Series::with_live_window).
+#[derive(Clone, Copy)]
+pub struct LiveAnchor {
+    target: Memo<Option<f64>>,
+    span: RwSignal<Option<f64>>,
+    anchor: RwSignal<Option<f64>>,
+    animating: StoredValue<bool>,
+}
+
+impl LiveAnchor {
+    /// Follows the `target` (newest X position). When sliding, moves smoothly towards it over a few animation frames otherwise jumps to it.
+    pub fn new(
+        target: Memo<Option<f64>>,
+        span: RwSignal<Option<f64>>,
+        slide: RwSignal<bool>,
+    ) -> Self {
+        let live = Self {
+            target,
+            span,
+            anchor: RwSignal::new(target.get_untracked()),
+            animating: StoredValue::new(false),
+        };
+        Effect::new(move |_| {
+            let target = target.get();
+            let slide = slide.get() && live.anchor.get_untracked().is_some() && target.is_some();
+            if !slide {
+                live.anchor.set(target);
+            } else if !live.animating.get_value() {
+                live.animating.set_value(true);
+                request_animation_frame(move || live.step());
+            }
+        });
+        live
+    }
+
+    pub fn get(&self) -> Signal<Option<f64>> {
+        self.anchor.into()
+    }
+
+    /// Moves one frame towards the target. Keeps animating until it's reached.
+    fn step(self) {
+        let target = self.target.get_untracked();
+        let span = self.span.get_untracked().unwrap_or_default();
+        let next = match (self.anchor.get_untracked(), target) {
+            (Some(anchor), Some(target)) => Some(slide_step(anchor, target, span)),
+            _ => target,
+        };
+        self.anchor.set(next);
+        if next == target {
+            self.animating.set_value(false);
+        } else {
+            request_animation_frame(move || self.step());
+        }
+    }
+}
+
+/// Next anchor position when sliding from `anchor` to `target`. Eases out and snaps once close (relative to the window span).
+fn slide_step(anchor: f64, target: f64, span: f64) -> f64 {
+    let next = anchor + (target - anchor) * SLIDE_EASING;
+    if (target - next).abs() <= span.abs() * SLIDE_SNAP || !next.is_finite() {
+        target
+    } else {
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slide_step() {
+        assert_eq!(slide_step(0.0, 100.0, 1000.0), 25.0);
+        assert_eq!(slide_step(100.0, 0.0, 1000.0), 75.0);
+        // Snaps when close
+        assert_eq!(slide_step(99.9, 100.0, 1000.0), 100.0);
+        assert_eq!(slide_step(100.0, 100.0, 0.0), 100.0);
+        assert_eq!(slide_step(f64::NAN, 100.0, 1000.0), 100.0);
+    }
+}
@@ -1,62 +1,153 @@
 mod data;
+mod live;
+mod off_scale;
 mod range;
+mod y_break;
 
 pub use range::Range;
 
 use crate::{
-    series::{use_y::RenderUseY, UseY},
+    bounds::Bounds,
+    projection::Projection,
+    selection::point_in_polygon,
+    series::{bar::BarHitAreas, use_y::RenderUseY, UseY},
     state::State,
-    Series, Tick,
+    AxisScale, BarPlacement, Colour, DataEvent, Resample, Series, Tick, YAxis,
 };
 use data::Data;
 use leptos::prelude::*;
+use live::LiveAnchor;
+use off_scale::OffScale;
+use std::collections::HashMap;
+use y_break::YBreak;
 
 #[derive(Clone)]
 #[non_exhaustive]
 pub struct UseData<X: Tick, Y: Tick> {
-    data: Memo<Data<X, Y>>,
+    data: BuiltData<X, Y>,
     pub len: Memo<usize>,
     pub series: Memo<Vec<UseY>>,
+    /// Series that aren't hidden. See [ViewState::hidden](crate::ViewState::hidden).
+    pub visible_series: Memo<Vec<UseY>>,
+    /// Names or stable IDs of hidden series. See [ViewState::hidden](crate::ViewState::hidden).
+    pub hidden: RwSignal<Vec<String>>,
     pub includes_bars: Memo<bool>,
+    /// Number of X values shown at a time. At least one. See [Series::window_x](crate::Series::window_x).
+    pub window_x: Memo<Option<usize>>,
+    /// Number of X values shown: the window or all data.
+    pub len_x: Memo<usize>,
     pub range_x: Memo<Range<X>>,
     pub range_y: Memo<Range<Y>>,
+    /// Y range of lines and bars on the [secondary axis](crate::YAxis::Secondary). Empty without any.
+    pub range_y_secondary: Memo<Range<Y>>,
+    /// X range of all data before any scrolling, panning or restriction.
+    pub full_range_x: Memo<Range<X>>,
+    /// Scale of the X axis. See [Series::scale_x](crate::Series::scale_x).
+    pub scale_x: RwSignal<AxisScale>,
+    /// Scale of the main Y axis. See [Series::scale_y](crate::Series::scale_y).
+    pub scale_y: RwSignal<AxisScale>,
+    /// Band of Y positions cut out of the main Y axis. See [Series::y_break](crate::Series::y_break).
+    pub y_break: Memo<Option<(f64, f64)>>,
+    /// Background colour of the Y break. See [Series::y_break_colour](crate::Series::y_break_colour).
+    pub y_break_colour: RwSignal<Colour>,
+    /// Whether Y values increase downwards. See [Series::reversed_y](crate::Series::reversed_y).
+    pub reversed_y: RwSignal<bool>,
+    /// Whether data is expected to be sorted by X. See [Series::sorted_x](crate::Series::sorted_x).
+    pub sorted_x: RwSignal<bool>,
+    min_y: RwSignal<Option<Y>>,
+    max_y: RwSignal<Option<Y>>,
+    y_percentile: RwSignal<Option<(f64, f64)>>,
+    clamp_y: RwSignal<bool>,
+    fit_visible: RwSignal<bool>,
+    live_window: RwSignal<Option<f64>>,
+    live_slide: RwSignal<bool>,
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
+    /// Builds the data of a series. Series whose name or [stable ID](UseY::key) is `hidden` are left out of the chart and stacks.
     pub fn new<T: Send + Sync + 'static>(
         series: Series<T, X, Y>,
         data: Signal<Vec<T>>,
+        hidden: RwSignal<Vec<String>>,
     ) -> UseData<X, Y> {
-        let lines = series.to_use_lines();
+        let lines = series.to_use_lines(hidden);
+        let series_window_x = series.window_x;
+        let (min_y, max_y) = (series.min_y, series.max_y);
+        let (y_percentile, clamp_y) = (series.y_percentile, series.clamp_y);
+        let fit_visible = series.fit_visible;
+        let (scale_x, scale_y) = (series.scale_x, series.scale_y);
+        let (y_break, reversed_y) = (series.y_break, series.reversed_y);
+        let (y_break_colour, sorted_x) = (series.y_break_colour, series.sorted_x);
+        let (live_window, live_drop) = (series.live_window, series.live_drop);
+        let live_slide = series.live_slide;
+        let append_only = series.append_only;
 
         // Data values
         let data = {
-            let lines = lines.clone();
-            Memo::new(move |_| {
+            let get_ys: HashMap<_, _> = (lines.iter())
+                .map(|(use_y, get_y)| (use_y.id, get_y.clone()))
+                .collect();
+            let use_ys: Vec<_> = lines.iter().map(|(use_y, _)| use_y.clone()).collect();
+            Memo::new_owning(move |prev: Option<Data<X, Y>>| {
                 let get_x = series.get_x.clone();
+                let get_meta = series.get_meta.clone();
+                // Moving a series to another Y axis rebuilds its range
+                let secondary: Vec<_> = (use_ys.iter())
+                    .filter(|use_y| use_y.y_axis() == YAxis::Secondary)
+                    .map(|use_y| use_y.id)
+                    .collect();
                 data.with(|data| {
-                    Data::new(
-                        get_x,
-                        lines
-                            .clone()
-                            .into_iter()
-                            .map(|(use_y, get_y)| (use_y.id, get_y))
-                            .collect(),
-                        data,
-                    )
+                    // Drop points before a live window
+                    let offset = match (live_window.get(), live_drop.get(), data.last()) {
+                        (Some(span), true, Some(last)) => {
+                            let from = (get_x)(last).position() - span;
+                            data.partition_point(|datum| (get_x)(datum).position() < from)
+                        }
+                        _ => 0,
+                    };
+                    let kept = &data[offset..];
+
+                    // Only new points need adding when data is appended to. Points dropped from the front (by a live window or a trimmed buffer) are dropped from the built data too
+                    let front_drop = (prev.as_ref())
+                        .filter(|prev| append_only.get() && prev.secondary() == secondary)
+                        .zip(kept.first())
+                        .and_then(|(prev, first)| {
+                            prev.front_drop((get_x)(first).position(), kept.len())
+                        });
+                    match (prev, front_drop) {
+                        (Some(mut prev), Some(drop)) => {
+                            prev.drop_front(drop);
+                            let mut prev = prev.with_offset(offset);
+                            let end = prev.len();
+                            prev.extend(&get_x, &get_ys, get_meta.as_ref(), &kept[end..]);
+                            (prev, true)
+                        }
+                        (prev, _) => {
+                            let built = Data::new(get_x, get_ys.clone(), kept);
+                            let built = built.with_offset(offset).with_secondary(secondary);
+                            let built = match get_meta {
+                                Some(get_meta) => built.with_meta(&get_meta, kept),
+                                None => built,
+                            };
+                            let changed = prev.as_ref() != Some(&built);
+                            (built, changed)
+                        }
+                    }
                 })
             })
         };
 
+        let data = BuiltData {
+            data,
+            indexed: None,
+        };
+
         // Range signals
         let range_x: Memo<Range<X>> = Memo::new(move |_| {
-            data.with(|data| data.range_x())
+            let scale_x = scale_x.get();
+            data.with(|data| data.range_x_on_scale(|x| scale_x.apply(x).is_finite()))
                 .maybe_update(vec![series.min_x.get(), series.max_x.get()])
         });
-        let range_y: Memo<Range<Y>> = Memo::new(move |_| {
-            data.with(|data| data.range_y())
-                .maybe_update(vec![series.min_y.get(), series.max_y.get()])
-        });
 
         // Sort series by name
         let series = {
@@ -67,21 +158,138 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
                 lines
             })
         };
+        let visible_series = Memo::new(move |_| {
+            let mut series = series.get();
+            hidden.with(|hidden| series.retain(|use_y| !use_y.is_hidden_by(hidden)));
+            series
+        });
+        // Only fits the visible series if asked
+        let range_y = auto_range_y(
+            data,
+            visible_series,
+            scale_y,
+            fit_visible,
+            min_y,
+            max_y,
+            y_percentile,
+            clamp_y,
+        );
+        let range_y_secondary = auto_range_y_secondary(data, series);
         let includes_bars =
-            Memo::new(move |_| series.get().iter().any(|use_y| use_y.bar().is_some()));
+            Memo::new(move |_| series.get().iter().any(|use_y| use_y.is_bar_like()));
+
+        let y_break = Memo::new(move |_| {
+            y_break.with(|y_break| {
+                y_break.as_ref().map(|(low, high)| {
+                    let (low, high) = (low.position(), high.position());
+                    (low.min(high), low.max(high))
+                })
+            })
+        });
+
+        let len = Memo::new(move |_| data.with(|data| data.len()));
+        // An empty window would leave each X value infinitely wide
+        let window_x = Memo::new(move |_| series_window_x.get().map(|window| window.max(1)));
+        let len_x = Memo::new(move |_| {
+            let len = len.get();
+            window_x.get().map_or(len, |window| window.min(len))
+        });
 
         UseData {
             data,
-            len: Memo::new(move |_| data.with(|data| data.len())),
+            len,
             series,
+            visible_series,
+            hidden,
             includes_bars,
+            window_x,
+            len_x,
             range_x,
             range_y,
+            range_y_secondary,
+            full_range_x: range_x,
+            scale_x,
+            scale_y,
+            y_break,
+            y_break_colour,
+            reversed_y,
+            sorted_x,
+            min_y,
+            max_y,
+            y_percentile,
+            clamp_y,
+            fit_visible,
+            live_window,
+            live_slide,
         }
     }
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
+    /// Restricts the X range to a window of X values starting at `scroll_x`. Does nothing without a [window](crate::Series::window_x).
+    pub fn scroll_window(&mut self, scroll_x: RwSignal<usize>) {
+        let (data, window_x, range_x) = (self.data, self.window_x, self.range_x);
+        self.range_x = Memo::new(move |_| match window_x.get() {
+            Some(window) => data.with(|data| data.window_range_x(scroll_x.get(), window)),
+            None => range_x.get(),
+        });
+    }
+
+    /// Anchors the X range to the newest data point when there's a [live window](crate::Series::with_live_window). Slides smoothly as new data arrives.
+    pub fn live_window(&mut self) {
+        let (data, span, range_x) = (self.data, self.live_window, self.range_x);
+        let newest = Memo::new(move |_| {
+            span.get()?;
+            data.with(|data| data.positions_x().back().copied())
+        });
+        let anchor = LiveAnchor::new(newest, span, self.live_slide).get();
+        self.range_x = Memo::new(move |_| {
+            let range_x = range_x.get();
+            match (span.get(), anchor.get()) {
+                (Some(span), Some(anchor)) => range_x.map_positions(|_, _| (anchor - span, anchor)),
+                _ => range_x,
+            }
+        });
+    }
+
+    /// Rescales series with an [index](crate::Line::with_index_to) so their first value in the X range equals their base. Call once the X range is settled (e.g., after windows and panning) so the first visible value is used.
+    pub fn index_series(&mut self) {
+        let (data, series, range_x) = (self.data.data, self.series, self.range_x);
+        // Only changes when the first visible value does
+        let factors = Memo::new(move |_| {
+            let bases = series.with(|series| {
+                (series.iter())
+                    .filter_map(|use_y| use_y.index_to().map(|base| (use_y.id, base)))
+                    .collect::<HashMap<_, _>>()
+            });
+            if bases.is_empty() {
+                return HashMap::new();
+            }
+            let from_x = range_x.with(|range| range.positions().map_or(f64::MIN, |(min, _)| min));
+            data.with(|data| data.index_factors(&bases, from_x))
+        });
+        self.data.indexed = Some(Memo::new(move |_| {
+            factors.with(|factors| {
+                (!factors.is_empty()).then(|| {
+                    let mut indexed = data.get();
+                    indexed.index_to(factors);
+                    indexed
+                })
+            })
+        }));
+        self.range_y = auto_range_y(
+            self.data,
+            self.visible_series,
+            self.scale_y,
+            self.fit_visible,
+            self.min_y,
+            self.max_y,
+            self.y_percentile,
+            self.clamp_y,
+        );
+        self.range_y_secondary = auto_range_y_secondary(self.data, self.series);
+    }
+
     pub fn nearest_data_x(&self, pos_x: Memo<f64>) -> Memo<Option<X>> {
         let data = self.data;
         Memo::new(move |_| data.with(|data| data.nearest_data_x(pos_x.get())))
@@ -92,9 +300,83 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
         Memo::new(move |_| data.with(|data| data.nearest_position_x(pos_x.get())))
     }
 
+    /// Nearest data point to the given X position. Used for events.
+    pub fn nearest_event(&self, pos_x: Memo<f64>) -> Memo<Option<DataEvent<X, Y>>> {
+        let (data, visible) = (self.data, self.visible_series);
+        Memo::new(move |_| {
+            let keys = (visible.get().iter())
+                .map(|series| (series.id, series.key()))
+                .collect::<Vec<_>>();
+            data.with(|data| {
+                (data.nearest_point(pos_x.get())).map(|point| data_event(data, &keys, point))
+            })
+        })
+    }
+
+    /// Data points of visible series inside a polygon (in SVG space). Each series is projected against its own Y axis. Used for selection events.
+    pub fn select_events(
+        &self,
+        polygon: &[(f64, f64)],
+        proj: &Projection,
+        proj_secondary: &Projection,
+    ) -> Vec<DataEvent<X, Y>> {
+        let visible = self.visible_series.get_untracked();
+        let projections = series_projections(&visible, proj, proj_secondary);
+        let keys = (visible.iter())
+            .map(|series| (series.id, series.key()))
+            .collect::<Vec<_>>();
+        let ids = keys.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        self.data.with_untracked(|data| {
+            data.select(&ids, |id, (x, y)| {
+                point_in_polygon(projections[&id].position_to_svg(x, y), polygon)
+            })
+            .into_iter()
+            .map(|point| data_event(data, &keys, point))
+            .collect()
+        })
+    }
+
+    /// Series ID and index of the nearest editable point within `radius` of an SVG position. Each series is projected against its own Y axis. See [Line::with_editable](crate::Line::with_editable).
+    pub fn nearest_editable(
+        &self,
+        svg: (f64, f64),
+        proj: &Projection,
+        proj_secondary: &Projection,
+        radius: f64,
+    ) -> Option<(usize, usize)> {
+        let editable = (self.visible_series.get_untracked().into_iter())
+            .filter(|series| series.is_editable())
+            .collect::<Vec<_>>();
+        let projections = series_projections(&editable, proj, proj_secondary);
+        let ids = editable.iter().map(|series| series.id).collect::<Vec<_>>();
+        self.data
+            .with_untracked(|data| {
+                data.nearest_coord(&ids, |id, (x, y)| {
+                    let (x, y) = projections[&id].position_to_svg(x, y);
+                    (x - svg.0).hypot(y - svg.1)
+                })
+            })
+            .filter(|&(_, _, distance)| distance <= radius)
+            .map(|(id, index, _)| (id, index))
+    }
+
+    /// Y value of a series' point moved to a new Y position. Used to edit points.
+    pub fn y_with_position(&self, id: usize, index: usize, pos_y: f64) -> Option<Y> {
+        self.data
+            .with_untracked(|data| data.y_with_position(id, index, pos_y))
+    }
+
+    pub fn nearest_meta(&self, pos_x: Memo<f64>) -> Memo<Option<String>> {
+        let data = self.data;
+        Memo::new(move |_| {
+            data.with(|data| data.nearest_point(pos_x.get()))
+                .and_then(|(_, _, meta)| meta)
+        })
+    }
+
     // TODO: this can never be None
     pub fn nearest_data_y(&self, pos_x: Memo<f64>) -> Memo<Vec<(UseY, Option<Y>)>> {
-        let series = self.series;
+        let series = self.visible_series;
         let data = self.data;
         Memo::new(move |_| {
             let y_values = data.with(|data| data.nearest_data_y(pos_x.get()));
@@ -108,31 +390,350 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
                 .collect::<Vec<_>>()
         })
     }
+
+    /// Last value of each series at or before the given X position that isn't missing (NaN). Used to fill in missing values.
+    pub fn nearest_last_known_y(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, (X, Y)>> {
+        let data = self.data;
+        Memo::new(move |_| data.with(|data| data.last_known_y(pos_x.get())))
+    }
+
+    /// Position of each series at the nearest data point. Used to place markers on series.
+    pub fn nearest_positions(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, (f64, f64)>> {
+        let data = self.data;
+        Memo::new(move |_| data.with(|data| data.nearest_positions(pos_x.get())))
+    }
+
+    /// Extra positions of each series at the nearest data point. Used to find the ends of bars and candlesticks.
+    pub fn nearest_extra_positions(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, Vec<f64>>> {
+        let data = self.data;
+        Memo::new(move |_| data.with(|data| data.nearest_extra_positions(pos_x.get())))
+    }
+
+    pub fn nearest_data_extra(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, Vec<Y>>> {
+        let data = self.data;
+        Memo::new(move |_| data.with(|data| data.nearest_data_extra(pos_x.get())))
+    }
+
+    /// X position of each data point.
+    pub fn positions_x(&self) -> Signal<Vec<f64>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.positions_x().iter().copied().collect()))
+    }
+
+    /// Positions of every point of the visible series in SVG coordinates.
+    pub fn svg_positions(&self, proj: Memo<Projection>) -> Memo<Vec<(f64, f64)>> {
+        let (data, series) = (self.data, self.visible_series);
+        let limits = self.clamp_limits();
+        // Only gathered when the data or series change, not when panning or zooming
+        let positions = Memo::new(move |_| {
+            series.with(|series| {
+                data.with(|data| {
+                    // Primary axis only
+                    (series.iter())
+                        .filter(|use_y| !data.is_secondary(use_y.id))
+                        .flat_map(|use_y| data.series_positions(use_y.id))
+                        .collect::<Vec<_>>()
+                })
+            })
+        });
+        Memo::new(move |_| {
+            let (proj, limits) = (proj.get(), limits.get());
+            positions.with(|positions| {
+                (positions.iter())
+                    .map(|&(x, y)| proj.position_to_svg(x, clamp(y, limits)))
+                    .collect()
+            })
+        })
+    }
+
+    /// Number of data points in the X range per pixel of the inner chart area. Above 1.0 points are drawn on top of each other.
+    pub fn points_per_pixel(&self, inner: Memo<Bounds>) -> Memo<f64> {
+        let (data, range_x) = (self.data, self.range_x);
+        Memo::new(move |_| {
+            let Some((min, max)) = range_x.with(|range| range.positions()) else {
+                return 0.0;
+            };
+            let points = data.with(|data| {
+                let xs = data.positions_x();
+                xs.partition_point(|&x| x <= max) - xs.partition_point(|&x| x < min)
+            });
+            points as f64 / inner.get().width()
+        })
+    }
+
+    /// SVG positions of a series resampled by period when there are more points than pixels. See [Line::with_resample](crate::Line::with_resample). Returns `None` when raw points should be drawn.
+    pub fn svg_resampled_positions(
+        &self,
+        id: usize,
+        resample: RwSignal<Option<Resample>>,
+        inner: Memo<Bounds>,
+        proj: Memo<Projection>,
+    ) -> Memo<Option<Vec<(f64, f64)>>> {
+        let (data, range_x) = (self.data, self.range_x);
+        let points_per_pixel = self.points_per_pixel(inner);
+        let limits = self.series_limits(id);
+        // Level of detail: pick a period for the X range. Only changes when the period does
+        let resolved = Memo::new(move |_| {
+            let resample = resample.get().filter(|_| points_per_pixel.get() > 1.0)?;
+            let span = range_x.with(|range| range.positions().map(|(min, max)| max - min))?;
+            resample.resolve(span, inner.get().width())
+        });
+        let positions = Memo::new(move |_| {
+            let resample = resolved.get()?;
+            Some(data.with(|data| data.resampled_positions(id, resample)))
+        });
+        Memo::new(move |_| {
+            let (proj, limits) = (proj.get(), limits.get());
+            positions.with(|positions| {
+                let positions = (positions.as_ref()?.iter())
+                    .map(|&(x, y)| proj.position_to_svg(x, clamp(y, limits)))
+                    .collect();
+                Some(positions)
+            })
+        })
+    }
+
+    /// Y position range to clamp values to if [Series::clamp_y](crate::Series::clamp_y) is set.
+    fn clamp_limits(&self) -> Memo<Option<(f64, f64)>> {
+        let (clamp_y, range_y) = (self.clamp_y, self.range_y);
+        Memo::new(move |_| {
+            clamp_y
+                .get()
+                .then(|| range_y.with(|range| range.positions()))
+                .flatten()
+        })
+    }
+
+    /// Clamp limits of a series. Only applies to the primary Y axis.
+    fn series_limits(&self, id: usize) -> Memo<Option<(f64, f64)>> {
+        let (data, limits) = (self.data, self.clamp_limits());
+        Memo::new(move |_| {
+            if data.with(|data| data.is_secondary(id)) {
+                None
+            } else {
+                limits.get()
+            }
+        })
+    }
+
+    /// Whether each point of a series is flagged. See [Line::with_flagged](crate::Line::with_flagged).
+    pub fn series_flags(&self, id: usize) -> Signal<Vec<bool>> {
+        let data = self.data;
+        Signal::derive(move || data.with(|data| data.series_flags(id)))
+    }
+
+    /// Extra Y values (e.g., a candlestick's open, high and low) for each point of a series in SVG coordinates.
+    pub fn svg_extra_positions(&self, id: usize, proj: Memo<Projection>) -> Signal<Vec<Vec<f64>>> {
+        let data = self.data;
+        let limits = self.series_limits(id);
+        Signal::derive(move || {
+            let proj = proj.get();
+            let limits = limits.get();
+            data.with(|data| {
+                data.series_extra_positions(id)
+                    .into_iter()
+                    .map(|extra| {
+                        extra
+                            .into_iter()
+                            .map(|y| proj.position_to_svg(0.0, clamp(y, limits)).1)
+                            .collect()
+                    })
+                    .collect()
+            })
+        })
+    }
+}
+
+/// Y range of the data or its percentiles, extended (or fixed if clamped) by the series' min and max Y. Only fits visible series if asked. Ignores positions without a value on the Y scale e.g., zero on a log scale.
+#[allow(clippy::too_many_arguments)]
+fn auto_range_y<X: Tick, Y: Tick>(
+    data: BuiltData<X, Y>,
+    visible: Memo<Vec<UseY>>,
+    scale_y: RwSignal<AxisScale>,
+    fit_visible: RwSignal<bool>,
+    min_y: RwSignal<Option<Y>>,
+    max_y: RwSignal<Option<Y>>,
+    y_percentile: RwSignal<Option<(f64, f64)>>,
+    clamp_y: RwSignal<bool>,
+) -> Memo<Range<Y>> {
+    Memo::new(move |_| {
+        let ids = (fit_visible.get()).then(|| {
+            visible.with(|series| series.iter().map(|use_y| use_y.id).collect::<Vec<_>>())
+        });
+        let scale_y = scale_y.get();
+        let range = data.with(|data| {
+            let range = match (y_percentile.get(), &ids) {
+                (Some((low, high)), ids) => data.range_y_percentile(low, high, ids.as_deref()),
+                (None, Some(ids)) => data.range_y_of(ids),
+                (None, None) => data.range_y(),
+            };
+            data.range_y_on_scale(range, ids.as_deref(), |y| scale_y.apply(y).is_finite())
+        });
+        let (min_y, max_y) = (min_y.get(), max_y.get());
+        let range = range.maybe_update(vec![min_y.clone(), max_y.clone()]);
+        if clamp_y.get() {
+            range.restrict(min_y, max_y)
+        } else {
+            range
+        }
+    })
+}
+
+/// Y range of the secondary axis. Includes zero when a bar on it extends from the zero line.
+fn auto_range_y_secondary<X: Tick, Y: Tick>(
+    data: BuiltData<X, Y>,
+    series: Memo<Vec<UseY>>,
+) -> Memo<Range<Y>> {
+    Memo::new(move |_| {
+        let range = data.with(|data| data.range_y_secondary());
+        let zero_bars = series.with(|series| {
+            series.iter().any(|use_y| {
+                use_y.y_axis() == YAxis::Secondary
+                    && (use_y.bar()).is_some_and(|bar| bar.placement.get() == BarPlacement::Zero)
+            })
+        });
+        if zero_bars {
+            range.map_positions(|min, max| (min.min(0.0), max.max(0.0)))
+        } else {
+            range
+        }
+    })
+}
+
+// Built data. Only copied when a series is indexed
+struct BuiltData<X: Tick, Y: Tick> {
+    data: Memo<Data<X, Y>>,
+    // Data with indexed series rescaled. `None` without any
+    indexed: Option<Memo<Option<Data<X, Y>>>>,
+}
+
+impl<X: Tick, Y: Tick> Clone for BuiltData<X, Y> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<X: Tick, Y: Tick> Copy for BuiltData<X, Y> {}
+
+impl<X: Tick, Y: Tick> BuiltData<X, Y> {
+    fn with<R>(&self, f: impl FnOnce(&Data<X, Y>) -> R) -> R {
+        match self.indexed {
+            Some(indexed) => indexed.with(|indexed| match indexed {
+                Some(indexed) => f(indexed),
+                None => self.data.with(f),
+            }),
+            None => self.data.with(f),
+        }
+    }
+
+    fn with_untracked<R>(&self, f: impl FnOnce(&Data<X, Y>) -> R) -> R {
+        untrack(|| self.with(f))
+    }
+}
+
+/// Builds an event from a data point (index, X and metadata) with the Y values of the named series.
+fn data_event<X: Tick, Y: Tick>(
+    data: &Data<X, Y>,
+    keys: &[(usize, String)],
+    (index, x, meta): (usize, X, Option<String>),
+) -> DataEvent<X, Y> {
+    let ys = data.y_at(index);
+    let y = (keys.iter())
+        .filter_map(|(id, key)| Some((key.clone(), ys.get(id)?.clone())))
+        .collect();
+    DataEvent { index, x, y, meta }
+}
+
+/// The projection of each series' Y axis by series ID.
+fn series_projections<'a>(
+    series: &[UseY],
+    proj: &'a Projection,
+    proj_secondary: &'a Projection,
+) -> HashMap<usize, &'a Projection> {
+    (series.iter())
+        .map(|series| match series.y_axis() {
+            YAxis::Secondary => (series.id, proj_secondary),
+            _ => (series.id, proj),
+        })
+        .collect()
+}
+
+/// Clamps a Y position to the given (min, max) if any. Missing values (NaN) are kept.
+fn clamp(y: f64, limits: Option<(f64, f64)>) -> f64 {
+    limits
+        .filter(|(min, max)| min <= max)
+        .map_or(y, |(min, max)| y.clamp(min, max))
 }
 
 #[component]
 pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
     let data = state.pre.data.clone();
-    let mk_svg_coords = move |id| {
-        Signal::derive(move || {
-            let proj = state.projection.get();
-            data.data.with(|data| {
-                data.series_positions(id)
-                    .into_iter()
-                    .map(|(x, y)| proj.position_to_svg(x, y))
-                    .collect::<Vec<_>>()
+    let mk_svg_coords = {
+        let data = data.clone();
+        move |state: &State<X, Y>, id| {
+            let (proj, limits) = (state.projection, data.series_limits(id));
+            let data = data.data;
+            Signal::derive(move || {
+                let proj = proj.get();
+                let limits = limits.get();
+                data.with(|data| {
+                    data.series_positions(id)
+                        .into_iter()
+                        .map(|(x, y)| proj.position_to_svg(x, clamp(y, limits)))
+                        .collect::<Vec<_>>()
+                })
             })
-        })
+        }
     };
 
+    let includes_bars = state.pre.data.includes_bars;
+    let (y_percentile, clamp_y) = (state.pre.data.y_percentile, state.pre.data.clamp_y);
+    let off_scale = move || y_percentile.get().is_some() || clamp_y.get();
+    // Clip to the inner area when only showing part of the data e.g., a window of X values, a selected range or a percentile Y range
+    let inner = state.layout.inner;
+    let (range_x, full_range_x) = (state.pre.data.range_x, state.pre.data.full_range_x);
+    let overflow = move || {
+        let windowed = range_x.with(|range| full_range_x.with(|full| range != full));
+        if windowed || off_scale() {
+            "hidden"
+        } else {
+            "visible"
+        }
+    };
+    let hit_state = state.clone();
+    let off_scale_state = state.clone();
+    let y_break_state = state.clone();
     view! {
-        <g class="_chartistry_series">
-            <For
-                each=move || data.series.get()
-                key=|use_y| use_y.id
-                let:use_y>
-                <RenderUseY use_y=use_y.clone() state=state.clone() positions=mk_svg_coords(use_y.id) />
-            </For>
-        </g>
+        <svg
+            x=move || inner.get().left_x()
+            y=move || inner.get().top_y()
+            width=move || inner.get().width()
+            height=move || inner.get().height()
+            viewBox=move || inner.with(|i| format!("{} {} {} {}", i.left_x(), i.top_y(), i.width(), i.height()))
+            style:overflow=overflow>
+            <g class="_chartistry_series">
+                <Show when=move || includes_bars.get()>
+                    <BarHitAreas state=hit_state.clone() />
+                </Show>
+                <For
+                    each=move || data.visible_series.get()
+                    key=|use_y| use_y.id
+                    let:use_y>
+                    {
+                        // Plot against the series' Y axis
+                        let state = state.with_y_axis(use_y.y_axis());
+                        view! {
+                            <g data-series=use_y.key()>
+                                <RenderUseY use_y=use_y.clone() state=state.clone() positions=mk_svg_coords(&state, use_y.id) />
+                            </g>
+                        }
+                    }
+                </For>
+            </g>
+            <Show when=off_scale>
+                <OffScale state=off_scale_state.clone() />
+            </Show>
+            <YBreak state=y_break_state />
+        </svg>
     }
 }
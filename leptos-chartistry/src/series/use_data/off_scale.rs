@@ -0,0 +1,83 @@
+use crate::{state::State, Tick, YAxis};
+use leptos::prelude::*;
+
+// Width and height of an off-scale marker before scaling
+const MARKER_SIZE: f64 = 6.0;
+
+/// X positions of points above (true) or below (false) the Y range. Ignores missing points.
+fn off_scale(positions: &[(f64, f64)], min_y: f64, max_y: f64) -> Vec<(f64, bool)> {
+    positions
+        .iter()
+        .filter_map(|&(x, y)| match y {
+            y if y > max_y => Some((x, true)),
+            y if y < min_y => Some((x, false)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Marks points outside the Y range (e.g., percentile or clamped) with a small arrow on the top or bottom edge of the inner chart area. Coloured by series.
+#[component]
+pub(super) fn OffScale<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
+    let data = state.pre.data.clone();
+    let (proj, inner, scale) = (state.projection, state.layout.inner, state.pre.scale);
+    let markers = move || {
+        let Some((min_y, max_y)) = data.range_y.with(|range| range.positions()) else {
+            return Vec::new();
+        };
+        let (proj, inner) = (proj.get(), inner.get());
+        let reversed_y = proj.is_reversed_y();
+        let size = MARKER_SIZE * scale.get();
+        (data.visible_series.get().into_iter())
+            .filter(|use_y| use_y.y_axis() == YAxis::Primary)
+            .flat_map(|use_y| {
+                let colour = use_y.colour();
+                let points = data
+                    .data
+                    .with(|data| off_scale(&data.series_positions(use_y.id), min_y, max_y));
+                points.into_iter().map(move |(x, above)| (x, above, colour))
+            })
+            .filter_map(|(x, above, colour)| {
+                let (x, _) = proj.position_to_svg(x, min_y);
+                if x < inner.left_x() || x > inner.right_x() {
+                    return None;
+                }
+                // Point away from the data. Values above the range are at the bottom if Y is reversed
+                let (y, dy) = if above != reversed_y {
+                    (inner.top_y(), size)
+                } else {
+                    (inner.bottom_y(), -size)
+                };
+                let d = format!("M {x},{y} l {},{dy} h {size} Z", -size / 2.0);
+                Some(view! {
+                    <path d=d fill=move || colour.get().to_string() />
+                })
+            })
+            .collect_view()
+    };
+
+    view! {
+        <g class="_chartistry_off_scale">{markers}</g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_off_scale() {
+        let positions = [
+            (1.0, 5.0),
+            (2.0, 11.0),
+            (3.0, f64::NAN),
+            (4.0, -1.0),
+            (5.0, 10.0),
+        ];
+        assert_eq!(
+            off_scale(&positions, 0.0, 10.0),
+            vec![(2.0, true), (4.0, false)]
+        );
+        assert_eq!(off_scale(&[], 0.0, 10.0), vec![]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::Tick;
+use std::collections::VecDeque;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Range<T>(Option<InnerRange<T>>);
@@ -38,6 +39,61 @@ impl<T> Range<T> {
         self
     }
 
+    /// Extends the range to include another range.
+    pub fn merge(self, other: &Self) -> Self
+    where
+        T: Tick,
+    {
+        let ts = other
+            .range()
+            .map(|(min, max)| vec![Some(min.clone()), Some(max.clone())])
+            .unwrap_or_default();
+        self.maybe_update(ts)
+    }
+
+    /// Maps the (min, max) positions to a new range. Returns the range unchanged if the new positions can't be represented by T.
+    pub fn map_positions(self, f: impl FnOnce(f64, f64) -> (f64, f64)) -> Self
+    where
+        T: Tick,
+    {
+        let Some(inner) = &self.0 else {
+            return self;
+        };
+        let (min, max) = f(inner.min.1, inner.max.1);
+        match (
+            inner.min.0.with_position(min),
+            inner.max.0.with_position(max),
+        ) {
+            (Some(min_t), Some(max_t)) => Range(Some(InnerRange {
+                min: (min_t, min),
+                max: (max_t, max),
+            })),
+            _ => self,
+        }
+    }
+
+    /// Restricts the range to the given bounds (if set). Bounds outside of the range are ignored.
+    pub fn restrict(self, min: Option<T>, max: Option<T>) -> Self
+    where
+        T: Tick,
+    {
+        let Some(mut inner) = self.0 else {
+            return self;
+        };
+        let with_pos = |t: T| InnerRange::position(&t).map(|pos| (t, pos));
+        if let Some(min) = min.and_then(with_pos) {
+            if inner.min.1 < min.1 && min.1 < inner.max.1 {
+                inner.min = min;
+            }
+        }
+        if let Some(max) = max.and_then(with_pos) {
+            if inner.min.1 < max.1 && max.1 < inner.max.1 {
+                inner.max = max;
+            }
+        }
+        Range(Some(inner))
+    }
+
     // Returns the (min, max) of T if it exists
     pub fn range(&self) -> Option<(&T, &T)> {
         self.0.as_ref().map(|r| (&r.min.0, &r.max.0))
@@ -77,3 +133,107 @@ impl<T: Tick> InnerRange<T> {
         }
     }
 }
+
+/// Range of a sliding window of values: values are pushed at the back and dropped from the front. Amortised O(1) per value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowRange<T> {
+    // Candidate (index, value, position) minimums in ascending order and maximums in descending order. The front of each is the window's min or max
+    min: VecDeque<(usize, T, f64)>,
+    max: VecDeque<(usize, T, f64)>,
+}
+
+impl<T> Default for WindowRange<T> {
+    fn default() -> Self {
+        Self {
+            min: VecDeque::new(),
+            max: VecDeque::new(),
+        }
+    }
+}
+
+impl<T: Tick> WindowRange<T> {
+    /// Adds a value at the given index. Indexes must not decrease. Ignores NaN.
+    pub fn push(&mut self, index: usize, t: &T) {
+        let pos = t.position();
+        if pos.is_nan() {
+            return;
+        }
+        // Values beaten by a later value can never be the min or max again
+        while self.min.back().is_some_and(|(_, _, min)| *min >= pos) {
+            self.min.pop_back();
+        }
+        while self.max.back().is_some_and(|(_, _, max)| *max <= pos) {
+            self.max.pop_back();
+        }
+        self.min.push_back((index, t.clone(), pos));
+        self.max.push_back((index, t.clone(), pos));
+    }
+
+    /// Drops values with an index before `index`.
+    pub fn drop_before(&mut self, index: usize) {
+        while self.min.front().is_some_and(|(at, _, _)| *at < index) {
+            self.min.pop_front();
+        }
+        while self.max.front().is_some_and(|(at, _, _)| *at < index) {
+            self.max.pop_front();
+        }
+    }
+
+    pub fn range(&self) -> Range<T> {
+        match (self.min.front(), self.max.front()) {
+            (Some((_, min, min_pos)), Some((_, max, max_pos))) => Range(Some(InnerRange {
+                min: (min.clone(), *min_pos),
+                max: (max.clone(), *max_pos),
+            })),
+            _ => Range::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restrict() {
+        let range = Range::default().maybe_update(vec![Some(1.0), Some(9.0)]);
+        assert_eq!(
+            range.clone().restrict(Some(3.0), Some(6.0)).positions(),
+            Some((3.0, 6.0))
+        );
+        assert_eq!(
+            range.clone().restrict(None, Some(6.0)).positions(),
+            Some((1.0, 6.0))
+        );
+        // Outside of the range
+        assert_eq!(
+            range.restrict(Some(0.0), Some(10.0)).positions(),
+            Some((1.0, 9.0))
+        );
+        assert_eq!(
+            Range::<f64>::default().restrict(Some(3.0), None),
+            Range::default()
+        );
+    }
+
+    #[test]
+    fn test_window_range() {
+        let mut window = WindowRange::default();
+        assert_eq!(window.range(), Range::default());
+        for (index, y) in [3.0, 1.0, 4.0, f64::NAN, 1.0, 5.0, 2.0]
+            .into_iter()
+            .enumerate()
+        {
+            window.push(index, &y);
+        }
+        assert_eq!(window.range().positions(), Some((1.0, 5.0)));
+        window.drop_before(2);
+        assert_eq!(window.range().positions(), Some((1.0, 5.0)));
+        window.drop_before(5);
+        assert_eq!(window.range().positions(), Some((2.0, 5.0)));
+        window.drop_before(6);
+        assert_eq!(window.range().positions(), Some((2.0, 2.0)));
+        window.drop_before(7);
+        assert_eq!(window.range(), Range::default());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{state::State, Tick, AXIS_MARKER_COLOUR};
+use leptos::prelude::*;
+
+// Width of each zig-zag before scaling
+const ZIG_SIZE: f64 = 8.0;
+
+/// SVG path of a zig-zag from left to right along Y. Each zig is `size` wide and half as tall.
+fn zig_zag(left: f64, right: f64, y: f64, size: f64) -> String {
+    let mut d = format!("M {left},{y}");
+    let step = size / 2.0;
+    if step <= 0.0 || right <= left {
+        return d;
+    }
+    let count = ((right - left) / step).ceil() as usize;
+    for i in 1..=count {
+        let x = (left + i as f64 * step).min(right);
+        let y = match i {
+            i if i == count => y,
+            i if i % 2 == 1 => y - step / 2.0,
+            _ => y + step / 2.0,
+        };
+        d.push_str(&format!(" L {x},{y}"));
+    }
+    d
+}
+
+/// Marks a [Y break](crate::Series::y_break) with a blank band edged by zig-zags across the inner chart area. Hides series crossing the break.
+#[component]
+pub(super) fn YBreak<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
+    let (proj, inner, scale) = (state.projection, state.layout.inner, state.pre.scale);
+    let colour = state.pre.data.y_break_colour;
+    // SVG Y of the (top, bottom) of the break
+    let edges = Memo::new(move |_| {
+        let proj = proj.get();
+        proj.y_break().map(|(low, high)| {
+            let top = proj.position_to_svg(0.0, high).1;
+            let bottom = proj.position_to_svg(0.0, low).1;
+            (top, bottom)
+        })
+    });
+    let marker = move || {
+        let (top, bottom) = edges.get()?;
+        let inner = inner.get();
+        let size = ZIG_SIZE * scale.get();
+        let (left, right) = (inner.left_x(), inner.right_x());
+        Some(view! {
+            <rect x=left y=top width=inner.width() height=bottom - top fill=colour.get().to_string() />
+            <path
+                d=format!("{} {}", zig_zag(left, right, top, size), zig_zag(left, right, bottom, size))
+                fill="none"
+                stroke=AXIS_MARKER_COLOUR.to_string() />
+        })
+    };
+
+    view! {
+        <g class="_chartistry_y_break">{marker}</g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zig_zag() {
+        assert_eq!(
+            zig_zag(0.0, 8.0, 10.0, 4.0),
+            "M 0,10 L 2,9 L 4,11 L 6,9 L 8,10"
+        );
+        assert_eq!(zig_zag(0.0, 3.0, 10.0, 4.0), "M 0,10 L 2,9 L 3,10");
+        assert_eq!(zig_zag(0.0, 8.0, 10.0, 0.0), "M 0,10");
+    }
+}
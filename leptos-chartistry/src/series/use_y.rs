@@ -1,33 +1,136 @@
 use super::{
     bar::{RenderBar, UseBar},
-    line::{RenderLine, UseLine},
+    candlestick::{format_ohlc, RenderCandlestick, UseCandlestick},
+    difference::{RenderDifference, UseDifference},
+    line::{bar_steps, MaxGap, RenderLine, UseLine},
+    YAxis,
 };
-use crate::{bounds::Bounds, debug::DebugRect, state::State, Tick};
-use leptos::{either::Either, prelude::*};
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::{either::EitherOf4, prelude::*};
 
+/// A series as used by a chart. Given to [custom tooltips](crate::Tooltip::custom) and [series snippets](crate::SeriesSnippet).
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct UseY {
+    /// Positional identifier of the series within the chart. Changes when series are reordered, see [UseY::key] for a stable ID.
     pub id: usize,
+    /// Name of the series.
     pub name: RwSignal<String>,
+    key: RwSignal<Option<String>>,
+    tooltip_group: Signal<Option<String>>,
     desc: UseYDesc,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 enum UseYDesc {
-    Line(UseLine),
+    Line(Box<UseLine>),
     Bar(UseBar),
+    Candlestick(UseCandlestick),
+    Difference(UseDifference),
 }
 
 impl UseY {
-    pub(super) fn new_line(id: usize, name: RwSignal<String>, line: UseLine) -> Self {
-        let desc = UseYDesc::Line(line);
-        Self { id, name, desc }
+    pub(super) fn new_line(
+        id: usize,
+        name: RwSignal<String>,
+        key: RwSignal<Option<String>>,
+        tooltip_group: Signal<Option<String>>,
+        line: UseLine,
+    ) -> Self {
+        let desc = UseYDesc::Line(Box::new(line));
+        Self {
+            id,
+            name,
+            key,
+            tooltip_group,
+            desc,
+        }
     }
 
-    pub(super) fn new_bar(id: usize, name: RwSignal<String>, bar: UseBar) -> Self {
+    pub(super) fn new_bar(
+        id: usize,
+        name: RwSignal<String>,
+        key: RwSignal<Option<String>>,
+        tooltip_group: Signal<Option<String>>,
+        bar: UseBar,
+    ) -> Self {
         let desc = UseYDesc::Bar(bar);
-        Self { id, name, desc }
+        Self {
+            id,
+            name,
+            key,
+            tooltip_group,
+            desc,
+        }
+    }
+
+    pub(super) fn new_candlestick(
+        id: usize,
+        name: RwSignal<String>,
+        key: RwSignal<Option<String>>,
+        tooltip_group: Signal<Option<String>>,
+        candlestick: UseCandlestick,
+    ) -> Self {
+        let desc = UseYDesc::Candlestick(candlestick);
+        Self {
+            id,
+            name,
+            key,
+            tooltip_group,
+            desc,
+        }
+    }
+
+    pub(super) fn new_difference(
+        id: usize,
+        name: RwSignal<String>,
+        key: RwSignal<Option<String>>,
+        tooltip_group: Signal<Option<String>>,
+        difference: UseDifference,
+    ) -> Self {
+        let desc = UseYDesc::Difference(difference);
+        Self {
+            id,
+            name,
+            key,
+            tooltip_group,
+            desc,
+        }
+    }
+
+    /// Stable ID of the series. Used in callbacks, as a CSS hook (the `data-series` attribute) and by [ViewState::hidden](crate::ViewState::hidden). Set with e.g., [Line::with_id](crate::Line::with_id).
+    ///
+    /// Without an ID this falls back to the positional [id](UseY::id) prefixed with `#` (e.g., `#0`) which changes when series are reordered. The `#` prefix is reserved: an ID that starts with `#` gets a second one (`#a` becomes `##a`) so it can't collide with a positional ID.
+    pub fn key(&self) -> String {
+        match self.key.get_untracked() {
+            Some(key) if key.starts_with('#') => format!("#{key}"),
+            Some(key) => key,
+            None => format!("#{}", self.id),
+        }
+    }
+
+    /// ID of an SVG element generated for this series e.g., a gradient. The [key](UseY::key) is escaped so it's always a valid ID and can be referenced with `url(#...)`.
+    pub(crate) fn svg_id(&self, kind: &str) -> String {
+        format!("_chartistry_{kind}_{}", escape_id(&self.key()))
+    }
+
+    /// Whether the series is hidden by name or stable ID. See [ViewState::hidden](crate::ViewState::hidden).
+    pub(crate) fn is_hidden_by(&self, hidden: &[String]) -> bool {
+        hidden.contains(&self.name.get()) || hidden.contains(&self.key())
+    }
+
+    /// Tooltip section of the series. See [Line::tooltip_group](crate::Line::tooltip_group).
+    pub(crate) fn tooltip_group(&self) -> Option<String> {
+        self.tooltip_group.get()
+    }
+
+    /// Y axis the series is plotted against. Only lines and bars can use the secondary axis.
+    pub(crate) fn y_axis(&self) -> YAxis {
+        match &self.desc {
+            UseYDesc::Line(line) => line.y_axis.get(),
+            UseYDesc::Bar(bar) => bar.y_axis.get(),
+            _ => YAxis::Primary,
+        }
     }
 
     pub(crate) fn bar(&self) -> Option<&UseBar> {
@@ -37,16 +140,78 @@ impl UseY {
         }
     }
 
+    /// Whether the series has a legend entry. Bars sharing a name across [stacks](crate::BarStack) share one.
+    pub(crate) fn in_legend(&self) -> bool {
+        self.bar().is_none_or(UseBar::in_legend)
+    }
+
+    /// Base to index the series to. See [Line::with_index_to](crate::Line::with_index_to).
+    pub(crate) fn index_to(&self) -> Option<f64> {
+        match &self.desc {
+            UseYDesc::Line(line) => line.index_to.get(),
+            _ => None,
+        }
+    }
+
+    /// Whether the series' points can be dragged. See [Line::with_editable](crate::Line::with_editable).
+    pub(crate) fn is_editable(&self) -> bool {
+        match &self.desc {
+            UseYDesc::Line(line) => line.editable.get_untracked(),
+            _ => false,
+        }
+    }
+
+    /// Main colour of the series. Candlesticks use their rising colour and differences their above colour.
+    pub(crate) fn colour(&self) -> Signal<Colour> {
+        match &self.desc {
+            UseYDesc::Line(line) => line.colour,
+            UseYDesc::Bar(bar) => bar.colour,
+            UseYDesc::Candlestick(candlestick) => candlestick.rising.into(),
+            UseYDesc::Difference(difference) => difference.above.into(),
+        }
+    }
+
+    /// Returns true if the series takes up the width of an X value (e.g., bars and candlesticks) rather than a point.
+    pub(crate) fn is_bar_like(&self) -> bool {
+        matches!(self.desc, UseYDesc::Bar(_) | UseYDesc::Candlestick(_))
+    }
+
+    /// Formats a tooltip value along with any extra values (e.g., a candlestick's open, high and low).
+    pub(crate) fn format_extra(&self, value: String, extra: Vec<String>) -> String {
+        match &self.desc {
+            UseYDesc::Candlestick(_) => format_ohlc(value, extra),
+            UseYDesc::Line(line) if line.has_ribbon => match extra.as_slice() {
+                [low, high] => format!("{value} ({low} – {high})"),
+                _ => value,
+            },
+            _ => value,
+        }
+    }
+
     fn taster_bounds(font_height: Memo<f64>, font_width: Memo<f64>) -> Memo<Bounds> {
         Memo::new(move |_| Bounds::new(font_width.get() * 2.5, font_height.get()))
     }
 
+    /// Width of a series taster and the gap after it for the given font height and width.
     pub fn snippet_width(font_height: Memo<f64>, font_width: Memo<f64>) -> Signal<f64> {
         let taster_bounds = Self::taster_bounds(font_height, font_width);
         Signal::derive(move || taster_bounds.get().width() + font_width.get())
     }
 }
 
+/// Escapes a key for use in an SVG ID. Keeps ASCII letters, digits and `-`. Anything else (including `_`) is written as its hex code point between underscores so distinct keys give distinct IDs.
+fn escape_id(key: &str) -> String {
+    let mut escaped = String::with_capacity(key.len());
+    for c in key.chars() {
+        if c.is_ascii_alphanumeric() || c == '-' {
+            escaped.push(c);
+        } else {
+            escaped.push_str(&format!("_{:x}_", c as u32));
+        }
+    }
+    escaped
+}
+
 #[component]
 pub(super) fn RenderUseY<X: Tick, Y: Tick>(
     use_y: UseY,
@@ -55,17 +220,144 @@ pub(super) fn RenderUseY<X: Tick, Y: Tick>(
 ) -> impl IntoView {
     let desc = use_y.desc.clone();
     match desc {
-        UseYDesc::Line(line) => Either::Left(view! {
-            <RenderLine
-                use_y=use_y
-                line=line
-                data=state.pre.data
-                positions=positions
-                markers=positions />
-        }),
-        UseYDesc::Bar(bar) => Either::Right(view! {
-            <RenderBar bar=bar state=state positions=positions />
-        }),
+        UseYDesc::Line(line) => {
+            // Resampled points replace the raw points. Flags and ribbons no longer line up
+            let resampled = state.pre.data.svg_resampled_positions(
+                use_y.id,
+                line.resample,
+                state.layout.inner,
+                state.projection,
+            );
+            let is_resampled = move || resampled.with(Option::is_some);
+            let flags = {
+                let flags = state.pre.data.series_flags(use_y.id);
+                Signal::derive(move || if is_resampled() { vec![] } else { flags.get() })
+            };
+            // Shift along X by X positions. Per point so it holds on any scale
+            let (x_offset, proj) = (line.x_offset, state.projection);
+            let positions = Signal::derive(move || {
+                let x_offset = x_offset.get();
+                let shift = |positions: &Vec<(f64, f64)>| {
+                    if x_offset == 0.0 {
+                        return positions.clone();
+                    }
+                    proj.with(|proj| {
+                        (positions.iter())
+                            .map(|&(x, y)| {
+                                let (pos_x, _) = proj.svg_to_position(x, 0.0);
+                                (proj.position_to_svg(pos_x + x_offset, 0.0).0, y)
+                            })
+                            .collect()
+                    })
+                };
+                resampled.with(|resampled| match resampled {
+                    Some(resampled) => shift(resampled),
+                    None => positions.with(shift),
+                })
+            });
+            // Steps across each bar's band. Markers stay at the centre
+            let line_positions = {
+                let (bar_steps, x_width) = (line.bar_steps, state.layout.x_width);
+                Signal::derive(move || {
+                    if bar_steps.get() {
+                        positions
+                            .with(|positions| bar_steps::bar_steps(positions, x_width.get() / 2.0))
+                    } else {
+                        positions.get()
+                    }
+                })
+            };
+            let max_gap = {
+                let max_gap = line.max_gap;
+                Signal::derive(move || {
+                    max_gap.get().map(|gap| MaxGap {
+                        gap,
+                        projection: proj.get(),
+                    })
+                })
+            };
+            let forecast_x = {
+                let after = line.forecast_after;
+                Signal::derive(move || {
+                    let x_offset = x_offset.get();
+                    (after.get()).map(|x| proj.read().position_to_svg(x + x_offset, 0.0).0)
+                })
+            };
+            let ribbon = {
+                let ribbon = state
+                    .pre
+                    .data
+                    .svg_extra_positions(use_y.id, state.projection);
+                Signal::derive(move || if is_resampled() { vec![] } else { ribbon.get() })
+            };
+            // Gradient spans the line's Y range so chunked paths share it
+            let gradient_y = {
+                let (y_axis, proj_secondary) = (line.y_axis, state.projection_secondary);
+                let (range_y, range_y_secondary) =
+                    (state.pre.data.range_y, state.pre.data.range_y_secondary);
+                Signal::derive(move || {
+                    let (proj, range_y) = match y_axis.get() {
+                        YAxis::Secondary => (proj_secondary, range_y_secondary),
+                        _ => (proj, range_y),
+                    };
+                    let (bottom, top) = range_y.read().positions()?;
+                    let proj = proj.read();
+                    Some((
+                        proj.position_to_svg(0.0, bottom).1,
+                        proj.position_to_svg(0.0, top).1,
+                    ))
+                })
+            };
+            EitherOf4::A(view! {
+                <RenderLine
+                    use_y=use_y
+                    line=*line
+                    data=state.pre.data
+                    positions=line_positions
+                    markers=positions
+                    scale=state.pre.scale
+                    flags=flags
+                    forecast_x=forecast_x
+                    ribbon=ribbon
+                    max_gap=max_gap
+                    gradient_y=gradient_y />
+            })
+        }
+        UseYDesc::Bar(bar) => {
+            let extras = state
+                .pre
+                .data
+                .svg_extra_positions(use_y.id, state.projection);
+            EitherOf4::B(view! {
+                <RenderBar bar=bar state=state positions=positions extras=extras />
+            })
+        }
+        UseYDesc::Candlestick(candlestick) => {
+            let extras = state
+                .pre
+                .data
+                .svg_extra_positions(use_y.id, state.projection);
+            let proj = state.projection;
+            let reversed_y = Signal::derive(move || proj.read().is_reversed_y());
+            EitherOf4::C(view! {
+                <RenderCandlestick
+                    candlestick=candlestick
+                    x_width=state.layout.x_width
+                    positions=positions
+                    extras=extras
+                    scale=state.pre.scale
+                    reversed_y=reversed_y />
+            })
+        }
+        UseYDesc::Difference(difference) => {
+            let extras = state
+                .pre
+                .data
+                .svg_extra_positions(use_y.id, state.projection);
+            EitherOf4::D(view! {
+                <RenderDifference difference=difference positions=positions extras=extras />
+            })
+        }
     }
 }
 
@@ -83,7 +375,7 @@ pub fn Snippet<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoV
 }
 
 #[component]
-fn Taster<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoView {
+pub fn Taster<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoView {
     const Y_OFFSET: f64 = 2.0;
     let debug = state.pre.debug;
     let font_width = state.pre.font_width;
@@ -98,23 +390,97 @@ fn Taster<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoView {
 
     let desc = match &series.desc {
         UseYDesc::Line(line) => {
-            // One marker in the middle
+            // One marker in the middle. Shrunk to fit so its shape can be told apart
             let markers = Signal::derive(move || {
                 let bounds = bounds.get();
                 vec![(bounds.centre_x(), bounds.centre_y() + Y_OFFSET)]
             });
-            Either::Left(view! {
+            let max_marker = Signal::derive(move || Some(bounds.get().height() - Y_OFFSET * 2.0));
+            // Band around the line
+            let has_ribbon = line.has_ribbon;
+            let ribbon = Signal::derive(move || {
+                if !has_ribbon {
+                    return vec![];
+                }
+                let bounds = bounds.get();
+                let y = bounds.centre_y() + Y_OFFSET;
+                vec![vec![y + Y_OFFSET, y - Y_OFFSET * 2.0]; 2]
+            });
+            EitherOf4::A(view! {
                 <RenderLine
                     use_y=series.clone()
-                    line=line.clone()
+                    line=(**line).clone()
                     data=state.pre.data
                     positions=positions
-                    markers=markers />
+                    markers=markers
+                    scale=state.pre.scale
+                    ribbon=ribbon
+                    max_marker=max_marker />
+            })
+        }
+        UseYDesc::Bar(bar) => {
+            // Right half shows the negative colour
+            let negative_colour = bar.negative_colour;
+            let negative = move || {
+                negative_colour.get().map(|colour| {
+                    let bounds = bounds.get();
+                    view! {
+                        <rect
+                            x=bounds.centre_x()
+                            y=bounds.top_y()
+                            width=bounds.width() / 2.0
+                            height=bounds.height()
+                            fill=colour.to_string() />
+                    }
+                })
+            };
+            EitherOf4::B(view! {
+                <RenderBar bar=bar.clone() state=state positions=positions />
+                {negative}
+            })
+        }
+        UseYDesc::Candlestick(candlestick) => {
+            // One rising candle in the middle
+            let width = Signal::derive(move || bounds.get().width() / 2.0);
+            let positions = Signal::derive(move || {
+                let bounds = bounds.get();
+                vec![(bounds.centre_x(), bounds.centre_y() - Y_OFFSET)]
+            });
+            let extras = Signal::derive(move || {
+                let bounds = bounds.get();
+                let open = bounds.centre_y() + Y_OFFSET;
+                vec![vec![open, bounds.top_y() + 1.0, bounds.bottom_y() - 1.0]]
+            });
+            EitherOf4::C(view! {
+                <RenderCandlestick
+                    candlestick=candlestick.clone()
+                    x_width=width
+                    positions=positions
+                    extras=extras
+                    scale=state.pre.scale />
+            })
+        }
+        UseYDesc::Difference(difference) => {
+            // Two lines crossing in the middle
+            let positions = Signal::derive(move || {
+                let bounds = bounds.get();
+                let y = bounds.centre_y();
+                vec![
+                    (bounds.left_x(), y - Y_OFFSET),
+                    (bounds.right_x(), y + Y_OFFSET),
+                ]
+            });
+            let extras = Signal::derive(move || {
+                let y = bounds.get().centre_y();
+                vec![vec![y + Y_OFFSET], vec![y - Y_OFFSET]]
+            });
+            EitherOf4::D(view! {
+                <RenderDifference
+                    difference=difference.clone()
+                    positions=positions
+                    extras=extras />
             })
         }
-        UseYDesc::Bar(bar) => Either::Right(view! {
-            <RenderBar bar=bar.clone() state=state positions=positions />
-        }),
     };
 
     view! {
@@ -131,3 +497,18 @@ fn Taster<X: Tick, Y: Tick>(series: UseY, state: State<X, Y>) -> impl IntoView {
         </svg>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_id() {
+        assert_eq!(escape_id("price-2024"), "price-2024");
+        assert_eq!(escape_id("a b"), "a_20_b");
+        assert_eq!(escape_id("a_b"), "a_5f_b");
+        assert_eq!(escape_id("#0"), "_23_0");
+        assert_eq!(escape_id("é"), "_e9_");
+        assert_ne!(escape_id("a_20_b"), escape_id("a b"));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{series::Range, Tick};
+use leptos::prelude::*;
+
+/// Values registered by a group of charts. Each chart's entry is removed when it's cleaned up.
+struct Registry<V: Send + Sync + 'static> {
+    entries: RwSignal<Vec<(usize, V)>>,
+    next_id: StoredValue<usize>,
+}
+
+impl<V: Send + Sync + 'static> Clone for Registry<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: Send + Sync + 'static> Copy for Registry<V> {}
+
+impl<V: Send + Sync + 'static> Default for Registry<V> {
+    fn default() -> Self {
+        Self {
+            entries: RwSignal::default(),
+            next_id: StoredValue::new(0),
+        }
+    }
+}
+
+impl<V: Clone + Send + Sync + 'static> Registry<V> {
+    fn register(&self, value: V) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        let entries = self.entries;
+        entries.update(|entries| entries.push((id, value)));
+        on_cleanup(move || {
+            entries.try_update(|entries| entries.retain(|(other, _)| *other != id));
+        });
+    }
+
+    fn values(&self) -> Vec<V> {
+        self.entries
+            .read()
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+}
+
+/// Aligns the left edge width of a group of charts. Each chart uses the widest left edge so that their inner plot areas line up vertically e.g., when stacking charts with different Y tick label widths.
+///
+/// Provide it as a [context](leptos::prelude::provide_context) and every [Chart](crate::Chart) below it will take part. Used by [ChartGrid](crate::ChartGrid).
+///
+/// # Example
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// #[component]
+/// fn StackedCharts() -> impl IntoView {
+///     provide_context(SharedLeftWidth::new());
+///     view! {
+///         // Two or more charts...
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct SharedLeftWidth(Registry<Memo<f64>>);
+
+impl SharedLeftWidth {
+    /// Creates a new, empty, shared left edge width.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a chart's left edge width and returns the aligned width.
+    pub(crate) fn align(&self, width: Memo<f64>) -> Memo<f64> {
+        self.0.register(width);
+        let registry = self.0;
+        Memo::new(move |_| {
+            registry
+                .values()
+                .into_iter()
+                .map(|width| width.get())
+                .fold(width.get(), f64::max)
+        })
+    }
+}
+
+/// Shares the X range across a group of charts so that they show the same X domain.
+pub(crate) struct SharedRangeX<X: Tick>(Registry<Memo<Range<X>>>);
+
+impl<X: Tick> Clone for SharedRangeX<X> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<X: Tick> Copy for SharedRangeX<X> {}
+
+impl<X: Tick> Default for SharedRangeX<X> {
+    fn default() -> Self {
+        Self(Registry::default())
+    }
+}
+
+impl<X: Tick> SharedRangeX<X> {
+    /// Registers a chart's X range and returns the union of all registered ranges.
+    pub(crate) fn share(&self, range: Memo<Range<X>>) -> Memo<Range<X>> {
+        self.0.register(range);
+        let registry = self.0;
+        Memo::new(move |_| {
+            registry
+                .values()
+                .into_iter()
+                .fold(range.get(), |acc, other| acc.merge(&other.get()))
+        })
+    }
+}
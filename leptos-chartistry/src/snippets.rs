@@ -0,0 +1,113 @@
+use crate::{
+    series::{Snippet, Taster, UseY},
+    state::State,
+    Tick,
+};
+use leptos::prelude::*;
+
+/// Handle to a chart's series for rendering their snippets outside of the chart. Lets apps build their own legends, series pickers or settings panels that match the chart's styling exactly.
+///
+/// Pass to a [Chart](crate::Chart) via its `snippets` prop. Once the chart renders, [series](Self::series) lists its series which can be drawn with [SeriesSnippet] (taster and name) or [SeriesTaster] (taster only).
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64 }
+/// # #[component]
+/// # fn PickerChart(data: Signal<Vec<MyData>>) -> impl IntoView {
+/// let snippets = SeriesSnippets::new();
+/// view! {
+///     <ul>
+///         <For each=move || snippets.series().get() key=|series| series.id let:series>
+///             <li><SeriesSnippet snippets=snippets series=series /></li>
+///         </For>
+///     </ul>
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         series=Series::new(|d: &MyData| d.x)
+///             .line(Line::new(|d: &MyData| d.y1).with_name("pears"))
+///             .line(Line::new(|d: &MyData| d.y2).with_name("apples"))
+///         data=data
+///         snippets=snippets
+///     />
+/// }
+/// # }
+/// ```
+#[non_exhaustive]
+pub struct SeriesSnippets<X: Tick, Y: Tick> {
+    state: RwSignal<Option<State<X, Y>>>,
+}
+
+impl<X: Tick, Y: Tick> Clone for SeriesSnippets<X, Y> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<X: Tick, Y: Tick> Copy for SeriesSnippets<X, Y> {}
+
+impl<X: Tick, Y: Tick> Default for SeriesSnippets<X, Y> {
+    fn default() -> Self {
+        Self {
+            state: RwSignal::default(),
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> SeriesSnippets<X, Y> {
+    /// Creates a new handle. Empty until passed to a rendered chart.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Series of the chart sorted by name. Includes hidden series. Empty until the chart renders.
+    pub fn series(&self) -> Signal<Vec<UseY>> {
+        let state = self.state;
+        Signal::derive(move || {
+            state
+                .read()
+                .as_ref()
+                .map(|state| state.pre.data.series.get())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Keeps the handle up to date with a chart's state.
+    pub(crate) fn track(&self, state: &State<X, Y>) {
+        self.state.set(Some(state.clone()));
+    }
+}
+
+/// Renders a series' taster (a small preview of the series' style and colour) followed by its name. The same snippet used by legends and tooltips. See [SeriesSnippets] for details.
+#[component]
+pub fn SeriesSnippet<X: Tick, Y: Tick>(
+    /// Handle passed to the chart.
+    snippets: SeriesSnippets<X, Y>,
+    /// Series to render. See [SeriesSnippets::series].
+    series: UseY,
+) -> impl IntoView {
+    let state = snippets.state;
+    move || {
+        let series = series.clone();
+        state
+            .get()
+            .map(|state| view! { <Snippet series=series state=state /> })
+    }
+}
+
+/// Renders a series' taster: a small preview of the series' style and colour. See [SeriesSnippets] for details.
+#[component]
+pub fn SeriesTaster<X: Tick, Y: Tick>(
+    /// Handle passed to the chart.
+    snippets: SeriesSnippets<X, Y>,
+    /// Series to render. See [SeriesSnippets::series].
+    series: UseY,
+) -> impl IntoView {
+    let state = snippets.state;
+    move || {
+        let series = series.clone();
+        state
+            .get()
+            .map(|state| view! { <Taster series=series state=state /> })
+    }
+}
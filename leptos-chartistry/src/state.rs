@@ -1,6 +1,6 @@
 use crate::{
-    layout::Layout, projection::Projection, series::UseData, use_watched_node::UseWatchedNode,
-    Padding, Tick,
+    axis_drag::AxisDrag, layout::Layout, projection::Projection, series::UseData,
+    use_watched_node::UseWatchedNode, Messages, Padding, Tick, YAxis,
 };
 use leptos::prelude::*;
 
@@ -11,7 +11,11 @@ pub struct PreState<X: Tick, Y: Tick> {
     pub font_height: Memo<f64>,
     pub font_width: Memo<f64>,
     pub padding: Signal<Padding>,
+    /// Multiplies stroke widths and marker diameters. See [PixelScale](crate::PixelScale).
+    pub scale: Memo<f64>,
     pub data: UseData<X, Y>,
+    pub axis_drag: AxisDrag,
+    pub messages: Messages,
 }
 
 #[derive(Clone)]
@@ -20,6 +24,8 @@ pub struct State<X: Tick, Y: Tick> {
     pub pre: PreState<X, Y>,
     pub layout: Layout,
     pub projection: Memo<Projection>,
+    /// Projection of the [secondary Y axis](YAxis::Secondary). Shares the X range.
+    pub projection_secondary: Memo<Projection>,
 
     pub svg_zero: Memo<(f64, f64)>,
 
@@ -31,22 +37,41 @@ pub struct State<X: Tick, Y: Tick> {
     pub hover_inner: Signal<bool>,
     /// X mouse coord in data position space
     pub hover_position_x: Memo<f64>,
+    /// Where a drag of the inner chart area started (SVG coords) until it ends. Only set for drags that aren't taken by selection, zoom, scrolling or editing e.g., for a [Brush](crate::Brush).
+    pub inner_drag: RwSignal<Option<(f64, f64)>>,
 }
 
 impl<X: Tick, Y: Tick> PreState<X, Y> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         debug: Signal<bool>,
         font_height: Memo<f64>,
         font_width: Memo<f64>,
         padding: Signal<Padding>,
+        scale: Memo<f64>,
         data: UseData<X, Y>,
+        axis_drag: AxisDrag,
+        messages: Messages,
     ) -> Self {
         Self {
             debug,
             font_height,
             font_width,
             padding,
+            scale,
             data,
+            axis_drag,
+            messages,
+        }
+    }
+
+    /// Overrides the font size e.g., for an edge layout with a bigger title. Unset values fall back to the chart's font.
+    pub fn with_font(&self, height: RwSignal<Option<f64>>, width: RwSignal<Option<f64>>) -> Self {
+        let (chart_height, chart_width) = (self.font_height, self.font_width);
+        Self {
+            font_height: Memo::new(move |_| height.get().unwrap_or_else(|| chart_height.get())),
+            font_width: Memo::new(move |_| width.get().unwrap_or_else(|| chart_width.get())),
+            ..self.clone()
         }
     }
 }
@@ -57,6 +82,7 @@ impl<X: Tick, Y: Tick> State<X, Y> {
         node: &UseWatchedNode,
         layout: Layout,
         proj: Memo<Projection>,
+        proj_secondary: Memo<Projection>,
     ) -> Self {
         // Mouse
         let mouse_chart = node.mouse_chart;
@@ -73,12 +99,37 @@ impl<X: Tick, Y: Tick> State<X, Y> {
             pre,
             layout,
             projection: proj,
+            projection_secondary: proj_secondary,
             svg_zero: Memo::new(move |_| proj.get().position_to_svg(0.0, 0.0)),
 
             mouse_page: node.mouse_page,
             mouse_chart,
             hover_inner,
             hover_position_x,
+            inner_drag: RwSignal::default(),
+        }
+    }
+
+    /// Swaps in the projection of a Y axis e.g., to draw a series or tick labels against the secondary axis.
+    pub fn with_y_axis(&self, y_axis: YAxis) -> Self {
+        match y_axis {
+            YAxis::Secondary => {
+                let proj = self.projection_secondary;
+                Self {
+                    projection: proj,
+                    svg_zero: Memo::new(move |_| proj.get().position_to_svg(0.0, 0.0)),
+                    ..self.clone()
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Overrides the font size. See [PreState::with_font].
+    pub fn with_font(&self, height: RwSignal<Option<f64>>, width: RwSignal<Option<f64>>) -> Self {
+        Self {
+            pre: self.pre.with_font(height, width),
+            ..self.clone()
         }
     }
 }
@@ -1,11 +1,12 @@
 use crate::bounds::Bounds;
-use leptos::{html::Div, prelude::*};
+use leptos::{ev, html::Div, leptos_dom::helpers::request_animation_frame, prelude::*};
 use leptos_use::{
-    use_element_hover, use_mouse_with_options, use_resize_observer_with_options, UseMouseCoordType,
-    UseMouseOptions, UseMouseSourceType, UseResizeObserverOptions,
+    use_element_hover, use_event_listener, use_intersection_observer, use_mouse_with_options,
+    use_resize_observer_with_options, UseMouseCoordType, UseMouseOptions, UseMouseSourceType,
+    UseResizeObserverOptions,
 };
 use std::convert::Infallible;
-use web_sys::ResizeObserverBoxOptions;
+use web_sys::{ResizeObserverBoxOptions, TouchEvent};
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -14,6 +15,7 @@ pub struct UseWatchedNode {
     pub mouse_page: Signal<(f64, f64)>,
     pub mouse_chart: Signal<(f64, f64)>,
     pub mouse_chart_hover: Signal<bool>,
+    pub touches: Signal<Vec<(f64, f64)>>,
 }
 
 pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
@@ -44,11 +46,11 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
 
     // Mouse absolute coords on page
     let mouse_page_type = mouse_page.source_type;
-    let mouse_page = Signal::derive(move || {
+    let mouse_page = batch_to_frame(Signal::derive(move || {
         let x = mouse_page.x.get();
         let y = mouse_page.y.get();
         (x, y)
-    });
+    }));
 
     // Mouse relative to SVG
     let mouse_client = use_mouse_with_options(
@@ -57,7 +59,11 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
             .coord_type(UseMouseCoordType::<Infallible>::Client)
             .reset_on_touch_ends(true),
     );
+    let mouse_client = batch_to_frame(Signal::derive(move || {
+        (mouse_client.x.get(), mouse_client.y.get())
+    }));
     let mouse_chart: Signal<_> = Memo::new(move |_| {
+        let (client_x, client_y) = mouse_client.get();
         let (left, top) = node
             .get()
             .map(|target| {
@@ -65,9 +71,7 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
                 (rect.left(), rect.top())
             })
             .unwrap_or_default();
-        let x = mouse_client.x.get() - left;
-        let y: f64 = mouse_client.y.get() - top;
-        (x, y)
+        (client_x - left, client_y - top)
     })
     .into();
 
@@ -84,12 +88,78 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
     })
     .into();
 
+    // Touch points relative to SVG
+    let touches = RwSignal::new(Vec::<(f64, f64)>::new());
+    let on_touch = move |ev: TouchEvent| {
+        let (left, top) = node
+            .get_untracked()
+            .map(|target| {
+                let rect = target.get_bounding_client_rect();
+                (rect.left(), rect.top())
+            })
+            .unwrap_or_default();
+        let list = ev.touches();
+        let points = (0..list.length())
+            .filter_map(|index| list.get(index))
+            .map(|touch| {
+                (
+                    touch.client_x() as f64 - left,
+                    touch.client_y() as f64 - top,
+                )
+            })
+            .collect::<Vec<_>>();
+        // Multi-touch gestures are ours: stop the page from scrolling / zooming
+        if points.len() >= 2 && ev.cancelable() {
+            ev.prevent_default();
+        }
+        touches.set(points);
+    };
+    let _ = use_event_listener(node, ev::touchstart, on_touch);
+    let _ = use_event_listener(node, ev::touchmove, on_touch);
+    let _ = use_event_listener(node, ev::touchend, on_touch);
+    let _ = use_event_listener(node, ev::touchcancel, on_touch);
+
     UseWatchedNode {
         bounds,
         mouse_page,
         mouse_chart,
         mouse_chart_hover,
+        touches: batch_to_frame(touches.into()),
+    }
+}
+
+/// Whether the node has scrolled into view. Stays true once seen. Always true if not `lazy`. Lazy nodes are only observed until first seen and are never seen on the server.
+pub fn use_seen(node: NodeRef<Div>, lazy: bool) -> Signal<bool> {
+    let seen = RwSignal::new(!lazy);
+    if lazy {
+        use_intersection_observer(node, move |entries, observer| {
+            if !seen.get_untracked() && entries.iter().any(|entry| entry.is_intersecting()) {
+                seen.set(true);
+                observer.disconnect();
+            }
+        });
     }
+    seen.into()
+}
+
+/// Batches updates to animation frames. Mouse events can fire many times per frame, this avoids redrawing hover-derived components (guide lines, tooltips) more than once per frame.
+fn batch_to_frame<T: Clone + Send + Sync + 'static>(source: Signal<T>) -> Signal<T> {
+    let batched = RwSignal::new(source.get_untracked());
+    let pending = StoredValue::new(false);
+    Effect::new(move |_| {
+        source.track();
+        if !pending.get_value() {
+            pending.set_value(true);
+            request_animation_frame(move || {
+                pending.set_value(false);
+                // The chart may have been removed before the frame
+                if let Some(value) = source.try_get_untracked() {
+                    batched.set(value);
+                }
+            });
+        }
+    });
+    batched.into()
 }
 
 impl UseWatchedNode {
@@ -0,0 +1,239 @@
+use leptos::prelude::*;
+
+/// Interaction state of a chart: the zoom window and hidden series. Survives data refreshes (e.g., polling) and rebuilding a chart's [Series](crate::Series). Tooltips aren't included: they follow the mouse and can't be pinned.
+///
+/// A [Chart](crate::Chart) creates its own state by default which is lost when the chart is rebuilt. Create a view state once (outside of any reactive closure that rebuilds the chart) and pass it to the chart to keep it:
+///
+/// ```rust
+/// # use leptos::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y: f64 }
+/// # #[component]
+/// # fn PollingChart(data: Signal<Vec<MyData>>) -> impl IntoView {
+/// let view_state = ViewState::new();
+/// view! {
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+///         bottom=TickLabels::aligned_floats().with_drag(true)
+///         series=Series::new(|d: &MyData| d.x).line(Line::new(|d: &MyData| d.y).with_name("y"))
+///         data=data
+///         view_state=view_state
+///     />
+///     <button on:click=move |_| view_state.reset()>"Reset zoom"</button>
+/// }
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ViewState {
+    /// Offset applied to the X range in position space (see [Tick::position](crate::Tick::position)). Default is zero.
+    pub pan_x: RwSignal<f64>,
+    /// Scale factor applied to the X range around its centre. Default is 1.
+    pub scale_x: RwSignal<f64>,
+    /// Offset applied to the Y range in position space. Default is zero.
+    pub pan_y: RwSignal<f64>,
+    /// Scale factor applied to the Y range around its centre. Default is 1.
+    pub scale_y: RwSignal<f64>,
+    /// Names or [stable IDs](crate::Line::with_id) of series to hide. Hidden series are not drawn or shown in the tooltip. Default is empty.
+    pub hidden: RwSignal<Vec<String>>,
+    /// Index of the first X value shown when the series has a [window](crate::Series::window_x). Default is zero.
+    pub scroll_x: RwSignal<usize>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self {
+            pan_x: RwSignal::new(0.0),
+            scale_x: RwSignal::new(1.0),
+            pan_y: RwSignal::new(0.0),
+            scale_y: RwSignal::new(1.0),
+            hidden: RwSignal::default(),
+            scroll_x: RwSignal::default(),
+        }
+    }
+}
+
+impl ViewState {
+    /// Creates a new view state with no zoom and no hidden series.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hides the series with the given name or stable ID.
+    pub fn with_hidden(self, name: impl Into<String>) -> Self {
+        self.hidden.update(|hidden| hidden.push(name.into()));
+        self
+    }
+
+    /// Resets the zoom window and scroll position.
+    pub fn reset(&self) {
+        self.pan_x.set(0.0);
+        self.scale_x.set(1.0);
+        self.pan_y.set(0.0);
+        self.scale_y.set(1.0);
+        self.scroll_x.set(0);
+    }
+
+    /// Restores the state from a string made by [ViewState]'s `Display` e.g., to follow a deep link. Leaves the state unchanged on error.
+    pub fn restore(&self, state: &str) -> Result<(), String> {
+        let snapshot = state.parse::<Snapshot>()?;
+        self.pan_x.set(snapshot.pan_x);
+        self.scale_x.set(snapshot.scale_x);
+        self.pan_y.set(snapshot.pan_y);
+        self.scale_y.set(snapshot.scale_y);
+        self.scroll_x.set(snapshot.scroll_x);
+        self.hidden.set(snapshot.hidden);
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            pan_x: self.pan_x.get(),
+            scale_x: self.scale_x.get(),
+            pan_y: self.pan_y.get(),
+            scale_y: self.scale_y.get(),
+            scroll_x: self.scroll_x.get(),
+            hidden: self.hidden.get(),
+        }
+    }
+}
+
+/// Writes a compact, URL-safe string of the zoom window and hidden series e.g., `x86400_0.5~h2~hrain` to share "zoomed to last Tuesday with rain hidden". Defaults are left out so an untouched chart is an empty string. Parse it with `str::parse` or [ViewState::restore]. Reactive: use it in a closure to keep a URL in sync.
+impl std::fmt::Display for ViewState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.snapshot().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ViewState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let state = Self::new();
+        state.restore(s)?;
+        Ok(state)
+    }
+}
+
+// Plain values of a view state
+#[derive(Clone, Debug, PartialEq)]
+struct Snapshot {
+    pan_x: f64,
+    scale_x: f64,
+    pan_y: f64,
+    scale_y: f64,
+    scroll_x: usize,
+    hidden: Vec<String>,
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self {
+            pan_x: 0.0,
+            scale_x: 1.0,
+            pan_y: 0.0,
+            scale_y: 1.0,
+            scroll_x: 0,
+            hidden: Vec::new(),
+        }
+    }
+}
+
+// Parts are separated by `~` and start with a key: `x` and `y` for the pan and scale of an axis, `s` for the scroll position and `h` for each hidden series. Only uses URL-safe characters.
+impl std::fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+        if (self.pan_x, self.scale_x) != (0.0, 1.0) {
+            parts.push(format!("x{}_{}", self.pan_x, self.scale_x));
+        }
+        if (self.pan_y, self.scale_y) != (0.0, 1.0) {
+            parts.push(format!("y{}_{}", self.pan_y, self.scale_y));
+        }
+        if self.scroll_x != 0 {
+            parts.push(format!("s{}", self.scroll_x));
+        }
+        for name in &self.hidden {
+            parts.push(format!("h{}", encode(name)));
+        }
+        write!(f, "{}", parts.join("~"))
+    }
+}
+
+impl std::str::FromStr for Snapshot {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |part: &str| format!("invalid view state: `{}`", part);
+        let pan_scale = |part: &str, value: &str| {
+            let (pan, scale) = value.split_once('_').ok_or_else(|| invalid(part))?;
+            let pan = pan.parse::<f64>().map_err(|_| invalid(part))?;
+            let scale = scale.parse::<f64>().map_err(|_| invalid(part))?;
+            Ok::<_, String>((pan, scale))
+        };
+        let mut snapshot = Self::default();
+        for part in s.split('~').filter(|part| !part.is_empty()) {
+            let (key, value) = part.split_at(part.chars().next().map_or(0, char::len_utf8));
+            match key {
+                "x" => (snapshot.pan_x, snapshot.scale_x) = pan_scale(part, value)?,
+                "y" => (snapshot.pan_y, snapshot.scale_y) = pan_scale(part, value)?,
+                "s" => snapshot.scroll_x = value.parse().map_err(|_| invalid(part))?,
+                "h" => snapshot
+                    .hidden
+                    .push(decode(value).ok_or_else(|| invalid(part))?),
+                _ => return Err(invalid(part)),
+            }
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Percent-encodes everything except ASCII letters, digits, `-` and `.`. Leaves `_` and `~` free to separate parts.
+fn encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Reverses [encode]. Returns `None` on an invalid escape or UTF-8.
+fn decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            let hex = std::str::from_utf8(&hex).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+        } else {
+            bytes.push(b);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        assert_eq!(Snapshot::default().to_string(), "");
+        assert_eq!("".parse::<Snapshot>(), Ok(Snapshot::default()));
+        let snapshot = Snapshot {
+            pan_x: 86400.0,
+            scale_x: 0.5,
+            pan_y: 0.0,
+            scale_y: 1.0,
+            scroll_x: 3,
+            hidden: vec!["rain".to_string(), "hail_2 ~ ü".to_string()],
+        };
+        let s = snapshot.to_string();
+        assert_eq!(s, "x86400_0.5~s3~hrain~hhail%5F2%20%7E%20%C3%BC");
+        assert_eq!(s.parse::<Snapshot>(), Ok(snapshot));
+        assert!("x1".parse::<Snapshot>().is_err());
+        assert!("q1".parse::<Snapshot>().is_err());
+        assert!("h%4".parse::<Snapshot>().is_err());
+    }
+}
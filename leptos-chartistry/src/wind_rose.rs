@@ -0,0 +1,347 @@
+use crate::{
+    colours::{Colour, ColourScheme, BATLOW},
+    debug::DebugRect,
+    AXIS_MARKER_COLOUR,
+};
+use leptos::prelude::*;
+use std::sync::Arc;
+
+/// Default colour scheme for wind rose magnitude bands. Interpolated across the bands.
+pub const WIND_ROSE_COLOUR_SCHEME: [Colour; 10] = BATLOW;
+
+// Compass points for labelling sectors, clockwise from north
+const COMPASS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+// Frequency rings drawn as a fraction of the busiest sector
+const RINGS: [f64; 4] = [0.25, 0.5, 0.75, 1.0];
+
+type GetValue<T> = Arc<dyn Fn(&T) -> f64 + Send + Sync>;
+
+/// Describes a wind rose: how often data falls into each direction sector, split into magnitude bands e.g., wind speed by direction. Drawn by [WindRoseChart].
+///
+/// Each sector is a wedge whose length is the share of data blowing from that direction. Wedges are split into bands coloured by [colours](Self::colours).
+///
+/// Note that a wind rose isn't a [Series](crate::Series): a [Chart](crate::Chart) only projects onto X and Y axes and has no polar projection to draw sectors with. So [WindRoseChart] bins and draws the rose itself and doesn't take a chart's edge or inner layouts, tooltip or interactions.
+///
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Wind { bearing: f64, knots: f64 }
+/// let rose = WindRose::new(|w: &Wind| w.bearing, |w: &Wind| w.knots)
+///     .with_sectors(8)
+///     .with_bands([0.0, 10.0, 20.0, 30.0]);
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct WindRose<T> {
+    get_direction: GetValue<T>,
+    get_magnitude: GetValue<T>,
+    /// Number of direction sectors. The first is centred on north. Default is 16.
+    pub sectors: RwSignal<usize>,
+    /// Ascending lower bounds of magnitude bands. Magnitudes below the first bound are left out. Default is 0, 5, 10, 15 and 20.
+    pub bands: RwSignal<Vec<f64>>,
+    /// Colour scheme of the bands. Interpolated across the bands. Default is [WIND_ROSE_COLOUR_SCHEME].
+    pub colours: RwSignal<ColourScheme>,
+}
+
+impl<T> WindRose<T> {
+    /// Creates a new wind rose. `get_direction` returns degrees clockwise from north (e.g., a compass bearing) and `get_magnitude` the value to band by e.g., wind speed. NaN values are left out.
+    pub fn new(
+        get_direction: impl Fn(&T) -> f64 + Send + Sync + 'static,
+        get_magnitude: impl Fn(&T) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            get_direction: Arc::new(get_direction),
+            get_magnitude: Arc::new(get_magnitude),
+            sectors: RwSignal::new(16),
+            bands: RwSignal::new(vec![0.0, 5.0, 10.0, 15.0, 20.0]),
+            colours: RwSignal::new(WIND_ROSE_COLOUR_SCHEME.into()),
+        }
+    }
+
+    /// Sets the number of direction sectors e.g., 8 or 16.
+    pub fn with_sectors(self, sectors: usize) -> Self {
+        self.sectors.set(sectors);
+        self
+    }
+
+    /// Sets the ascending lower bounds of magnitude bands. See [WindRose::bands] for details.
+    pub fn with_bands(self, bands: impl IntoIterator<Item = f64>) -> Self {
+        self.bands.set(bands.into_iter().collect());
+        self
+    }
+
+    /// Sets the colour scheme of the bands.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+}
+
+/// Counts (direction, magnitude) pairs into `counts[sector][band]`. Sector 0 is centred on north. Bands are ascending lower bounds: a magnitude falls into the last band it reaches. Pairs with NaN or below the first band are left out.
+fn bin(
+    values: impl IntoIterator<Item = (f64, f64)>,
+    sectors: usize,
+    bands: &[f64],
+) -> Vec<Vec<usize>> {
+    let mut counts = vec![vec![0; bands.len()]; sectors];
+    if sectors == 0 {
+        return counts;
+    }
+    let width = 360.0 / sectors as f64;
+    for (direction, magnitude) in values {
+        if direction.is_nan() || magnitude.is_nan() {
+            continue;
+        }
+        let Some(band) = bands.iter().rposition(|&low| magnitude >= low) else {
+            continue;
+        };
+        let sector = ((direction + width / 2.0).rem_euclid(360.0) / width) as usize % sectors;
+        counts[sector][band] += 1;
+    }
+    counts
+}
+
+/// SVG point at a radius and angle (degrees clockwise from north) around a centre.
+fn polar_to_svg((cx, cy): (f64, f64), radius: f64, angle: f64) -> (f64, f64) {
+    let angle = angle.to_radians();
+    (cx + radius * angle.sin(), cy - radius * angle.cos())
+}
+
+/// SVG path of a ring segment between two radii and two angles (degrees clockwise from north). A full turn or more is a whole ring e.g., a single sector.
+fn wedge(centre: (f64, f64), inner: f64, outer: f64, start: f64, end: f64) -> String {
+    if end - start >= 360.0 {
+        // An arc can't end where it starts so draw each circle as two halves. The inner circle runs the other way to cut out the middle
+        let (x1, y1) = polar_to_svg(centre, outer, start);
+        let (x2, y2) = polar_to_svg(centre, outer, start + 180.0);
+        let (x3, y3) = polar_to_svg(centre, inner, start);
+        let (x4, y4) = polar_to_svg(centre, inner, start + 180.0);
+        return format!(
+            "M {x1},{y1} A {outer},{outer} 0 1 1 {x2},{y2} A {outer},{outer} 0 1 1 {x1},{y1} Z M {x3},{y3} A {inner},{inner} 0 1 0 {x4},{y4} A {inner},{inner} 0 1 0 {x3},{y3} Z"
+        );
+    }
+    let large = if end - start > 180.0 { 1 } else { 0 };
+    let (x1, y1) = polar_to_svg(centre, outer, start);
+    let (x2, y2) = polar_to_svg(centre, outer, end);
+    let (x3, y3) = polar_to_svg(centre, inner, end);
+    let (x4, y4) = polar_to_svg(centre, inner, start);
+    format!(
+        "M {x1},{y1} A {outer},{outer} 0 {large} 1 {x2},{y2} L {x3},{y3} A {inner},{inner} 0 {large} 0 {x4},{y4} Z"
+    )
+}
+
+/// Compass name of an angle e.g., "NE". Rounds to the nearest of 16 points.
+fn compass(angle: f64) -> &'static str {
+    let point = (angle.rem_euclid(360.0) / 22.5).round() as usize % COMPASS.len();
+    COMPASS[point]
+}
+
+/// Renders a [WindRose]: a polar chart of direction sectors split into magnitude bands. Drawn in SVG with a legend of bands below. A standalone component rather than a [Chart](crate::Chart) series, see [WindRose] for details.
+#[component]
+pub fn WindRoseChart<T: Send + Sync + 'static>(
+    /// Describes the wind rose.
+    wind_rose: WindRose<T>,
+    /// Data to bin.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+    /// Width and height of the rose in pixels.
+    #[prop(into)]
+    size: Signal<f64>,
+    /// Debug mode. See [Chart](crate::Chart) for details.
+    #[prop(into, optional)]
+    debug: Signal<bool>,
+) -> impl IntoView {
+    let WindRose {
+        get_direction,
+        get_magnitude,
+        sectors,
+        bands,
+        colours,
+    } = wind_rose;
+
+    let counts = Memo::new(move |_| {
+        data.with(|data| {
+            let values = (data.iter()).map(|datum| (get_direction(datum), get_magnitude(datum)));
+            bands.with(|bands| bin(values, sectors.get(), bands))
+        })
+    });
+    // Sector totals: the busiest is drawn to the edge
+    let total = Memo::new(move |_| counts.with(|counts| counts.iter().flatten().sum::<usize>()));
+    let busiest = Memo::new(move |_| {
+        counts.with(|counts| {
+            counts
+                .iter()
+                .map(|sector| sector.iter().sum::<usize>())
+                .max()
+        })
+    });
+    let band_colour = move |band: usize| colours.get().interpolate(band, bands.with(Vec::len));
+
+    let geometry = move || {
+        let size = size.get();
+        let centre = (size / 2.0, size / 2.0);
+        // Leave room for compass labels
+        (centre, size * 0.4)
+    };
+
+    let rings = move || {
+        let (centre, radius) = geometry();
+        RINGS
+            .iter()
+            .map(|ring| {
+                view! {
+                    <circle
+                        cx=centre.0
+                        cy=centre.1
+                        r=ring * radius
+                        fill="none"
+                        stroke=AXIS_MARKER_COLOUR.to_string() />
+                }
+            })
+            .collect_view()
+    };
+
+    let labels = move || {
+        let (centre, radius) = geometry();
+        [0.0, 90.0, 180.0, 270.0]
+            .into_iter()
+            .map(|angle| {
+                let (x, y) = polar_to_svg(centre, radius * 1.15, angle);
+                view! {
+                    <text x=x y=y text-anchor="middle" dominant-baseline="middle">
+                        {compass(angle)}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    let wedges = move || {
+        let (centre, radius) = geometry();
+        let (total, busiest) = (total.get(), busiest.get().unwrap_or_default());
+        if busiest == 0 {
+            return Vec::new();
+        }
+        let sectors = counts.get();
+        let width = 360.0 / sectors.len() as f64;
+        let bands = bands.get();
+        let mut wedges = Vec::new();
+        for (sector, counts) in sectors.iter().enumerate() {
+            let centre_angle = sector as f64 * width;
+            let (start, end) = (centre_angle - width / 2.0, centre_angle + width / 2.0);
+            let mut below = 0;
+            for (band, &count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+                let inner = below as f64 / busiest as f64 * radius;
+                below += count;
+                let outer = below as f64 / busiest as f64 * radius;
+                let range = match bands.get(band + 1) {
+                    Some(high) => format!("{}–{}", bands[band], high),
+                    None => format!("≥{}", bands[band]),
+                };
+                let share = count as f64 / total as f64 * 100.0;
+                let title = format!("{}: {range} ({share:.1}%)", compass(centre_angle));
+                wedges.push(view! {
+                    <path
+                        d=wedge(centre, inner, outer, start, end)
+                        fill=band_colour(band).to_string()
+                        stroke="#fff">
+                        <title>{title}</title>
+                    </path>
+                });
+            }
+        }
+        wedges
+    };
+
+    let legend = move || {
+        let bands = bands.get();
+        (bands.iter().enumerate())
+            .map(|(band, low)| {
+                let label = match bands.get(band + 1) {
+                    Some(high) => format!("{low}–{high}"),
+                    None => format!("≥{low}"),
+                };
+                view! {
+                    <span style="margin-right: 1em; white-space: nowrap;">
+                        <span
+                            style="display: inline-block; width: 1em; height: 1em; margin-right: 0.25em; vertical-align: middle;"
+                            style:background-color=band_colour(band).to_string() />
+                        {label}
+                    </span>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="_chartistry_wind_rose" style:width=move || format!("{}px", size.get())>
+            <svg
+                width=move || size.get()
+                height=move || size.get()
+                viewBox=move || format!("0 0 {0} {0}", size.get())
+                font-family="monospace">
+                <DebugRect label="WindRose" debug=debug />
+                <g class="_chartistry_wind_rose_rings">{rings}</g>
+                <g class="_chartistry_wind_rose_sectors">{wedges}</g>
+                <g class="_chartistry_wind_rose_labels">{labels}</g>
+            </svg>
+            <p class="_chartistry_wind_rose_legend" style="margin: 0; font-family: monospace;">
+                {legend}
+            </p>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin() {
+        let bands = [0.0, 5.0, 10.0];
+        let values = [
+            (0.0, 1.0),
+            (350.0, 6.0),  // Rounds to north
+            (-10.0, 12.0), // Negative bearings wrap
+            (90.0, 20.0),
+            (180.0, -1.0), // Below the first band
+            (f64::NAN, 3.0),
+        ];
+        let counts = bin(values, 4, &bands);
+        assert_eq!(
+            counts,
+            vec![vec![1, 1, 1], vec![0, 0, 1], vec![0, 0, 0], vec![0, 0, 0]]
+        );
+        assert!(bin(values, 0, &bands).is_empty());
+    }
+
+    #[test]
+    fn test_polar_to_svg() {
+        let round = |(x, y): (f64, f64)| (x.round(), y.round());
+        assert_eq!(round(polar_to_svg((50.0, 50.0), 10.0, 0.0)), (50.0, 40.0));
+        assert_eq!(round(polar_to_svg((50.0, 50.0), 10.0, 90.0)), (60.0, 50.0));
+        assert_eq!(round(polar_to_svg((50.0, 50.0), 10.0, 180.0)), (50.0, 60.0));
+    }
+
+    #[test]
+    fn test_wedge() {
+        assert_eq!(
+            wedge((50.0, 50.0), 0.0, 10.0, 90.0, 180.0),
+            "M 60,50 A 10,10 0 0 1 50,60 L 50,50 A 0,0 0 0 0 50,50 Z"
+        );
+        // A single sector is a whole ring
+        let ring = wedge((50.0, 50.0), 5.0, 10.0, -180.0, 180.0);
+        assert_eq!(ring.matches(" A ").count(), 4);
+        assert_eq!(ring.matches("M ").count(), 2);
+    }
+
+    #[test]
+    fn test_compass() {
+        assert_eq!(compass(0.0), "N");
+        assert_eq!(compass(44.0), "NE");
+        assert_eq!(compass(350.0), "N");
+        assert_eq!(compass(-90.0), "W");
+    }
+}
@@ -0,0 +1,90 @@
+use leptos::prelude::*;
+
+/// Scrolls a fixed window of X values through the data. Scroll with the mouse wheel or by dragging the inner chart area. See [Series::window_x](crate::Series::window_x).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowScroll {
+    scroll_x: RwSignal<usize>,
+    window_x: Memo<Option<usize>>,
+    len: Memo<usize>,
+    start: RwSignal<Option<(f64, usize)>>,
+}
+
+impl WindowScroll {
+    pub fn new(scroll_x: RwSignal<usize>, window_x: Memo<Option<usize>>, len: Memo<usize>) -> Self {
+        Self {
+            scroll_x,
+            window_x,
+            len,
+            start: RwSignal::default(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.window_x.get_untracked().is_some()
+    }
+
+    pub fn is_dragging(&self) -> Signal<bool> {
+        let start = self.start;
+        Signal::derive(move || start.with(|start| start.is_some()))
+    }
+
+    /// Scrolls the window by a number of X values.
+    pub fn scroll_by(&self, delta: isize) {
+        let Some(window) = self.window_x.get_untracked() else {
+            return;
+        };
+        let scroll = self.scroll_x.get_untracked() as isize + delta;
+        self.scroll_x
+            .set(clamp_scroll(scroll, self.len.get_untracked(), window));
+    }
+
+    /// Starts a drag from the mouse X position.
+    pub fn start(&self, mouse_x: f64) {
+        if self.is_enabled() {
+            self.start
+                .set(Some((mouse_x, self.scroll_x.get_untracked())));
+        }
+    }
+
+    /// Updates the current drag (if any). Dragging right moves the data right.
+    pub fn update(&self, mouse_x: f64, x_width: f64) {
+        let (Some((start_x, start_scroll)), Some(window)) =
+            (self.start.get_untracked(), self.window_x.get_untracked())
+        else {
+            return;
+        };
+        let delta = ((start_x - mouse_x) / x_width.max(1.0)).round() as isize;
+        let scroll = clamp_scroll(
+            start_scroll as isize + delta,
+            self.len.get_untracked(),
+            window,
+        );
+        if scroll != self.scroll_x.get_untracked() {
+            self.scroll_x.set(scroll);
+        }
+    }
+
+    pub fn end(&self) {
+        self.start.set(None);
+    }
+}
+
+/// Clamps a scroll position so that the window stays within the data.
+fn clamp_scroll(scroll: isize, len: usize, window: usize) -> usize {
+    let max = len.saturating_sub(window) as isize;
+    scroll.clamp(0, max) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_scroll() {
+        assert_eq!(clamp_scroll(3, 10, 4), 3);
+        assert_eq!(clamp_scroll(-2, 10, 4), 0);
+        assert_eq!(clamp_scroll(8, 10, 4), 6);
+        // Window larger than data
+        assert_eq!(clamp_scroll(2, 3, 4), 0);
+    }
+}